@@ -1,6 +1,6 @@
 // ------ IMPORTS
 
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use honeycomb::prelude::CMap2;
 use honeycomb_benches::FloatType;
 use honeycomb_core::cmap::CMapBuilder;
@@ -8,29 +8,53 @@ use honeycomb_core::cmap::CMapBuilder;
 // ------ CONTENT
 
 pub fn criterion_benchmark(c: &mut Criterion) {
-    let n_square = 512;
-    let map: CMap2<FloatType> = CMapBuilder::unit_grid(n_square).build().unwrap();
-
     let mut group = c.benchmark_group("fetch-icells");
 
-    group.bench_with_input(BenchmarkId::new("fetch-vertices", ""), &map, |b, m| {
-        b.iter(|| {
-            let mut vertices: Vec<_> = m.iter_vertices().collect();
-            black_box(&mut vertices);
-        })
-    });
-    group.bench_with_input(BenchmarkId::new("fetch-edges", ""), &map, |b, m| {
-        b.iter(|| {
-            let mut edges: Vec<_> = m.iter_edges().collect();
-            black_box(&mut edges);
-        })
-    });
-    group.bench_with_input(BenchmarkId::new("fetch-faces", ""), &map, |b, m| {
-        b.iter(|| {
-            let mut faces: Vec<_> = m.iter_faces().collect();
-            black_box(&mut faces);
-        })
-    });
+    for multiplier in 6..10 {
+        let size = 2_usize.pow(multiplier);
+        // triangle meshes have larger vertex orbits than quad meshes
+        let maps: [(&str, CMap2<FloatType>); 2] = [
+            ("squares", CMapBuilder::unit_grid(size).build().unwrap()),
+            (
+                "triangles",
+                CMapBuilder::unit_triangles(size).build().unwrap(),
+            ),
+        ];
+        for (name, map) in &maps {
+            // throughput = number of darts scanned by the iterators
+            group.throughput(Throughput::Elements(map.n_darts() as u64));
+            group.bench_with_input(
+                BenchmarkId::new(format!("fetch-vertices-{name}"), size),
+                map,
+                |b, m| {
+                    b.iter(|| {
+                        let mut vertices: Vec<_> = m.iter_vertices().collect();
+                        black_box(&mut vertices);
+                    })
+                },
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("fetch-edges-{name}"), size),
+                map,
+                |b, m| {
+                    b.iter(|| {
+                        let mut edges: Vec<_> = m.iter_edges().collect();
+                        black_box(&mut edges);
+                    })
+                },
+            );
+            group.bench_with_input(
+                BenchmarkId::new(format!("fetch-faces-{name}"), size),
+                map,
+                |b, m| {
+                    b.iter(|| {
+                        let mut faces: Vec<_> = m.iter_faces().collect();
+                        black_box(&mut faces);
+                    })
+                },
+            );
+        }
+    }
     group.finish();
 }
 
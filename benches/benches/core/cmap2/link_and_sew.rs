@@ -7,13 +7,15 @@
 //!
 //! - `bench_links`: benches all variants of the `link` and `unlink` methods.
 //! - `bench_sews`: benches all variants of the `sew` and `unsew` methods.
+//! - `bench_bulk_sews`: compares 2-sewing all cells of a grid one pair at a time to using
+//!   `two_sew_pairs`.
 //!
 //! Each benchmark is repeated on CMap2 of different sizes.
 
 // ------ IMPORTS
 
 use honeycomb_benches::FloatType;
-use honeycomb_core::prelude::{CMap2, CMapBuilder};
+use honeycomb_core::prelude::{CMap2, CMapBuilder, DartIdType};
 use iai_callgrind::{
     library_benchmark, library_benchmark_group, main, FlamegraphConfig, LibraryBenchmarkConfig,
 };
@@ -44,6 +46,24 @@ fn get_sew_map(n_square: usize) -> CMap2<FloatType> {
     map
 }
 
+/// Return the cells of a grid, without β2 links, along with the pairs of darts to 2-sew.
+fn get_unsewn_grid(n_square: usize) -> (CMap2<FloatType>, Vec<(DartIdType, DartIdType)>) {
+    let grid = get_map(n_square);
+    let map = CMapBuilder::default()
+        .n_darts(grid.n_darts() - 1)
+        .build()
+        .unwrap();
+    for d in 1..grid.n_darts() as DartIdType {
+        map.force_link::<1>(d, grid.beta::<1>(d));
+        map.force_write_vertex(d, grid.force_read_vertex(grid.vertex_id(d)).unwrap());
+    }
+    let pairs = (1..grid.n_darts() as DartIdType)
+        .filter(|d| *d < grid.beta::<2>(*d))
+        .map(|d| (d, grid.beta::<2>(d)))
+        .collect();
+    (map, pairs)
+}
+
 // --- link group
 
 #[library_benchmark]
@@ -138,6 +158,37 @@ library_benchmark_group!(
         two_unsew,
 );
 
+// --- bulk sew group
+
+#[library_benchmark]
+#[bench::small(get_unsewn_grid(16))]
+#[bench::medium(get_unsewn_grid(64))]
+#[bench::large(get_unsewn_grid(256))]
+fn two_sew_each(input: (CMap2<FloatType>, Vec<(DartIdType, DartIdType)>)) -> CMap2<FloatType> {
+    let (map, pairs) = input;
+    for (ld, rd) in pairs {
+        map.force_sew::<2>(ld, rd);
+    }
+    black_box(map)
+}
+
+#[library_benchmark]
+#[bench::small(get_unsewn_grid(16))]
+#[bench::medium(get_unsewn_grid(64))]
+#[bench::large(get_unsewn_grid(256))]
+fn two_sew_pairs(input: (CMap2<FloatType>, Vec<(DartIdType, DartIdType)>)) -> CMap2<FloatType> {
+    let (map, pairs) = input;
+    map.two_sew_pairs(&pairs);
+    black_box(map)
+}
+
+library_benchmark_group!(
+    name = bench_bulk_sews;
+    benchmarks =
+        two_sew_each,
+        two_sew_pairs,
+);
+
 // --- main
 
 main!(
@@ -145,4 +196,5 @@ main!(
     library_benchmark_groups =
         bench_links,
         bench_sews,
+        bench_bulk_sews,
 );
@@ -39,7 +39,7 @@ fn fan_bench() -> Result<(), TriangulateError> {
         .collect();
 
     for (face_id, new_darts) in faces.iter().zip(dart_slices.iter()) {
-        fan_cell(&mut map, *face_id, new_darts)?
+        fan_cell(&mut map, *face_id, new_darts)?;
     }
 
     Ok(())
@@ -73,7 +73,7 @@ fn earclip_bench() -> Result<(), TriangulateError> {
         .collect();
 
     for (face_id, new_darts) in faces.iter().zip(dart_slices.iter()) {
-        earclip_cell(&mut map, *face_id, new_darts)?
+        earclip_cell(&mut map, *face_id, new_darts)?;
     }
 
     Ok(())
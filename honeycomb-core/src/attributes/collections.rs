@@ -77,6 +77,28 @@ impl<A: AttributeBind + AttributeUpdate> UnknownAttributeStorage for AttrSparseV
         self.data.extend((0..length).map(|_| TVar::new(None)));
     }
 
+    fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
+    fn append(&mut self, other: &mut dyn UnknownAttributeStorage) {
+        let other = other
+            .downcast_mut::<Self>()
+            .expect("E: could not downcast generic storage to the appended storage type");
+        self.data
+            .extend(std::mem::take(&mut other.data).into_iter().skip(1));
+    }
+
+    fn compact(&mut self, mapping: &[DartIdType]) {
+        self.data = std::mem::take(&mut self.data)
+            .into_iter()
+            .zip(mapping)
+            .enumerate()
+            .filter(|(id, (_, new_id))| *id == 0 || **new_id != 0)
+            .map(|(_, (val, _))| val)
+            .collect();
+    }
+
     fn n_attributes(&self) -> usize {
         self.data
             .iter()
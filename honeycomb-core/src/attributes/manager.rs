@@ -9,7 +9,7 @@ use crate::stm::{StmClosureResult, Transaction};
 
 use super::{AttributeBind, AttributeStorage, AttributeUpdate, UnknownAttributeStorage};
 use crate::{
-    cmap::CMapResult,
+    cmap::{CMapError, CMapResult},
     prelude::{DartIdType, OrbitPolicy},
 };
 use std::{any::TypeId, collections::HashMap};
@@ -156,6 +156,92 @@ impl AttrStorageManager {
         }
     }
 
+    /// Reserve capacity in all storages of the manager.
+    ///
+    /// # Arguments
+    ///
+    /// - `additional: usize` -- Length by which storages should be extendable without
+    ///   reallocating.
+    pub fn reserve_storages(&mut self, additional: usize) {
+        for storage in self.vertices.values_mut() {
+            storage.reserve(additional);
+        }
+        for storage in self.edges.values_mut() {
+            storage.reserve(additional);
+        }
+        for storage in self.faces.values_mut() {
+            storage.reserve(additional);
+        }
+        for storage in self.volumes.values_mut() {
+            storage.reserve(additional);
+        }
+        for storage in self.others.values_mut() {
+            storage.reserve(additional);
+        }
+    }
+
+    /// Append storages of another manager to the ones of this manager.
+    ///
+    /// Storages are matched by attribute type; for each of them, see
+    /// [`UnknownAttributeStorage::append`].
+    ///
+    /// # Arguments
+    ///
+    /// - `other: &mut Self` -- Manager whose storages' content is moved to `self`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return [`CMapError::UnknownAttribute`] if the two managers do not hold
+    /// the same set of attributes. In that case, no storage is modified.
+    pub fn append_storages(&mut self, other: &mut Self) -> CMapResult<()> {
+        let pairs = [
+            (&mut self.vertices, &mut other.vertices),
+            (&mut self.edges, &mut other.edges),
+            (&mut self.faces, &mut other.faces),
+            (&mut self.volumes, &mut other.volumes),
+            (&mut self.others, &mut other.others),
+        ];
+        if pairs
+            .iter()
+            .any(|(lhs, rhs)| lhs.len() != rhs.len() || lhs.keys().any(|k| !rhs.contains_key(k)))
+        {
+            return Err(CMapError::UnknownAttribute(
+                "appended storages hold a different set of attributes",
+            ));
+        }
+        for (lhs, rhs) in pairs {
+            for (typeid, storage) in lhs.iter_mut() {
+                storage.append(rhs.get_mut(typeid).unwrap().as_mut());
+            }
+        }
+        Ok(())
+    }
+
+    /// Compact all storages of the manager.
+    ///
+    /// See [`UnknownAttributeStorage::compact`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `mapping: &[DartIdType]` -- Old-to-new identifier mapping, `0` denoting removed slots.
+    pub fn compact_storages(&mut self, mapping: &[DartIdType]) {
+        for storage in self.vertices.values_mut() {
+            storage.compact(mapping);
+        }
+        for storage in self.edges.values_mut() {
+            storage.compact(mapping);
+        }
+        for storage in self.faces.values_mut() {
+            storage.compact(mapping);
+        }
+        for storage in self.volumes.values_mut() {
+            storage.compact(mapping);
+        }
+        for storage in self.others.values_mut() {
+            storage.compact(mapping);
+        }
+    }
+
     // attribute-specific
 
     /// Add a new storage to the manager.
@@ -241,6 +327,19 @@ impl AttrStorageManager {
         probably_storage.downcast_ref::<<A as AttributeBind>::StorageType>()
     }
 
+    /// Check whether the manager holds a storage for a given attribute.
+    ///
+    /// Unlike accessors, this method does not print a warning when the storage is missing.
+    ///
+    /// # Arguments
+    ///
+    /// - `A: AttributeBind` -- Attribute stored by the checked storage.
+    #[must_use = "unused return value"]
+    pub fn contains_storage<A: AttributeBind>(&self) -> bool {
+        get_storage!(self, storage);
+        storage.is_some()
+    }
+
     /// Remove an entire attribute storage from the manager.
     ///
     /// This method is useful when implementing routines that uses attributes to run; Those can then be removed
@@ -4,9 +4,13 @@
 
 mod collections;
 mod manager;
+mod scalars;
 mod traits;
 
 pub use collections::AttrSparseVec;
+pub use scalars::{
+    BindTarget, EdgeBound, FaceBound, MaxAttr, MinAttr, SumAttr, VertexBound, VolumeBound,
+};
 pub use traits::{AttributeBind, AttributeStorage, AttributeUpdate, UnknownAttributeStorage};
 
 pub(crate) use manager::AttrStorageManager;
@@ -0,0 +1,199 @@
+//! Ready-made scalar attributes
+//!
+//! This module contains generic wrappers implementing [`AttributeUpdate`] and [`AttributeBind`]
+//! for common merge rules of scalar values. The topological entity the attribute is bound to is
+//! chosen using a marker type implementing [`BindTarget`].
+
+// ------ IMPORTS
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+use crate::attributes::{AttrSparseVec, AttributeBind, AttributeUpdate};
+use crate::cmap::{DartIdType, EdgeIdType, FaceIdType, OrbitPolicy, VertexIdType, VolumeIdType};
+
+// ------ CONTENT
+
+/// Topological entity to which a ready-made attribute is bound.
+///
+/// This trait is implemented by the [`VertexBound`], [`EdgeBound`], [`FaceBound`], and
+/// [`VolumeBound`] marker types.
+pub trait BindTarget: Debug + Clone + Copy + PartialEq + Default + Send + Sync + 'static {
+    /// Identifier type of the entity.
+    type IdentifierType: From<DartIdType> + num_traits::ToPrimitive + Clone;
+
+    /// [`OrbitPolicy`] corresponding to the entity.
+    const BIND_POLICY: OrbitPolicy;
+}
+
+macro_rules! bind_target {
+    ($name: ident, $id: ty, $policy: expr, $doc: literal) => {
+        #[doc = $doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        pub struct $name;
+
+        impl BindTarget for $name {
+            type IdentifierType = $id;
+            const BIND_POLICY: OrbitPolicy = $policy;
+        }
+    };
+}
+
+bind_target!(
+    VertexBound,
+    VertexIdType,
+    OrbitPolicy::Vertex,
+    "Marker binding a ready-made attribute to vertices."
+);
+bind_target!(
+    EdgeBound,
+    EdgeIdType,
+    OrbitPolicy::Edge,
+    "Marker binding a ready-made attribute to edges."
+);
+bind_target!(
+    FaceBound,
+    FaceIdType,
+    OrbitPolicy::Face,
+    "Marker binding a ready-made attribute to faces."
+);
+bind_target!(
+    VolumeBound,
+    VolumeIdType,
+    OrbitPolicy::Volume,
+    "Marker binding a ready-made attribute to volumes."
+);
+
+macro_rules! scalar_attribute {
+    ($name: ident, [$($bound: tt)+], |$a: ident, $b: ident| $merge: expr) => {
+        impl<T, B: BindTarget> $name<T, B> {
+            /// Create a new attribute value.
+            pub fn new(val: T) -> Self {
+                Self(val, PhantomData)
+            }
+        }
+
+        impl<T, B: BindTarget> From<T> for $name<T, B> {
+            fn from(val: T) -> Self {
+                Self::new(val)
+            }
+        }
+
+        impl<T, B> AttributeUpdate for $name<T, B>
+        where
+            T: Copy + Send + Sync + $($bound)+,
+            B: BindTarget,
+        {
+            fn merge(attr1: Self, attr2: Self) -> Self {
+                let ($a, $b) = (attr1.0, attr2.0);
+                Self::new($merge)
+            }
+
+            fn split(attr: Self) -> (Self, Self) {
+                (attr, attr)
+            }
+        }
+
+        impl<T, B> AttributeBind for $name<T, B>
+        where
+            T: Copy + Debug + Send + Sync + 'static + $($bound)+,
+            B: BindTarget,
+        {
+            type StorageType = AttrSparseVec<Self>;
+            type IdentifierType = B::IdentifierType;
+            const BIND_POLICY: OrbitPolicy = B::BIND_POLICY;
+        }
+    };
+}
+
+/// Scalar attribute merged by summing values.
+///
+/// Splitting the attribute duplicates its value.
+///
+/// # Example
+///
+/// ```
+/// use honeycomb_core::attributes::{EdgeBound, SumAttr};
+/// use honeycomb_core::prelude::{CMap2, CMapBuilder};
+///
+/// type Flux = SumAttr<f64, EdgeBound>;
+///
+/// // two opposite segments
+/// let map: CMap2<f64> = CMapBuilder::default()
+///     .n_darts(4)
+///     .add_attribute::<Flux>()
+///     .build()
+///     .unwrap();
+/// map.force_link::<1>(1, 2);
+/// map.force_link::<1>(3, 4);
+/// map.force_write_vertex(1, (0.0, 0.0));
+/// map.force_write_vertex(2, (0.0, 1.0));
+/// map.force_write_vertex(3, (0.0, 1.0));
+/// map.force_write_vertex(4, (0.0, 0.0));
+/// map.force_write_attribute(1, Flux::new(1.5));
+/// map.force_write_attribute(3, Flux::new(2.0));
+///
+/// // sewing the segments merges their values
+/// map.force_sew::<2>(1, 3);
+/// assert_eq!(map.force_read_attribute::<Flux>(1), Some(Flux::new(3.5)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SumAttr<T, B: BindTarget>(pub T, PhantomData<B>);
+
+scalar_attribute!(SumAttr, [Add<Output = T>], |a, b| a + b);
+
+/// Scalar attribute merged by keeping the largest value.
+///
+/// Splitting the attribute duplicates its value.
+///
+/// # Example
+///
+/// ```
+/// use honeycomb_core::attributes::{FaceBound, MaxAttr};
+/// use honeycomb_core::prelude::{AttributeUpdate, CMap2, CMapBuilder};
+///
+/// type ErrorEstimate = MaxAttr<f64, FaceBound>;
+///
+/// let map: CMap2<f64> = CMapBuilder::unit_grid(2)
+///     .add_attribute::<ErrorEstimate>()
+///     .build()
+///     .unwrap();
+/// map.force_write_attribute(1, ErrorEstimate::new(0.1));
+/// assert_eq!(map.force_read_attribute::<ErrorEstimate>(1), Some(ErrorEstimate::new(0.1)));
+///
+/// let merged = ErrorEstimate::merge(ErrorEstimate::new(0.1), ErrorEstimate::new(0.3));
+/// assert_eq!(merged.0, 0.3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MaxAttr<T, B: BindTarget>(pub T, PhantomData<B>);
+
+scalar_attribute!(MaxAttr, [PartialOrd], |a, b| if a >= b { a } else { b });
+
+/// Scalar attribute merged by keeping the smallest value.
+///
+/// Splitting the attribute duplicates its value.
+///
+/// # Example
+///
+/// ```
+/// use honeycomb_core::attributes::{MinAttr, VertexBound};
+/// use honeycomb_core::prelude::{AttributeUpdate, CMap2, CMapBuilder};
+///
+/// type Distance = MinAttr<f64, VertexBound>;
+///
+/// let map: CMap2<f64> = CMapBuilder::default()
+///     .n_darts(4)
+///     .add_attribute::<Distance>()
+///     .build()
+///     .unwrap();
+/// map.force_write_attribute(2, Distance::new(0.5));
+/// assert_eq!(map.force_read_attribute::<Distance>(2), Some(Distance::new(0.5)));
+///
+/// let merged = Distance::merge(Distance::new(0.5), Distance::new(0.2));
+/// assert_eq!(merged.0, 0.2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MinAttr<T, B: BindTarget>(pub T, PhantomData<B>);
+
+scalar_attribute!(MinAttr, [PartialOrd], |a, b| if a <= b { a } else { b });
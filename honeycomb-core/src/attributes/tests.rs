@@ -4,8 +4,8 @@ use crate::stm::{atomically, StmError, Transaction, TransactionControl};
 use loom::sync::Arc;
 
 use super::{
-    AttrSparseVec, AttrStorageManager, AttributeBind, AttributeStorage, AttributeUpdate,
-    UnknownAttributeStorage,
+    AttrSparseVec, AttrStorageManager, AttributeBind, AttributeStorage, AttributeUpdate, EdgeBound,
+    FaceBound, MaxAttr, MinAttr, SumAttr, UnknownAttributeStorage, VertexBound, VolumeBound,
 };
 use crate::{
     cmap::{CMapResult, EdgeIdType},
@@ -464,6 +464,43 @@ fn attribute_bind() {
     assert_eq!(inst.type_id(), prim_inst.type_id());
 }
 
+#[test]
+fn scalar_attributes() {
+    type Sum = SumAttr<u32, VertexBound>;
+    type Max = MaxAttr<f64, EdgeBound>;
+    type Min = MinAttr<f64, FaceBound>;
+
+    assert_eq!(Sum::merge(Sum::new(1), Sum::new(2)), Sum::new(3));
+    assert_eq!(Max::merge(Max::new(1.0), Max::new(2.0)), Max::new(2.0));
+    assert_eq!(Min::merge(Min::new(1.0), Min::new(2.0)), Min::new(1.0));
+    assert_eq!(Sum::split(Sum::new(4)), (Sum::new(4), Sum::new(4)));
+    assert_eq!(Sum::BIND_POLICY, OrbitPolicy::Vertex);
+    assert_eq!(Max::BIND_POLICY, OrbitPolicy::Edge);
+    assert_eq!(Min::BIND_POLICY, OrbitPolicy::Face);
+    assert_eq!(
+        MaxAttr::<f64, VolumeBound>::BIND_POLICY,
+        OrbitPolicy::Volume
+    );
+
+    // edge values are merged by the 2-sew
+    let map: CMap2<f64> = CMapBuilder::default()
+        .n_darts(4)
+        .add_attribute::<Max>()
+        .build()
+        .unwrap();
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(3, 4);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (0.0, 1.0));
+    map.force_write_vertex(3, (0.0, 1.0));
+    map.force_write_vertex(4, (0.0, 0.0));
+    map.force_write_attribute(1, Max::new(0.5));
+    map.force_write_attribute(3, Max::from(1.5));
+    map.force_sew::<2>(1, 3);
+    assert_eq!(map.force_read_attribute::<Max>(1), Some(Max::new(1.5)));
+    assert_eq!(map.force_read_attribute::<Max>(3), None);
+}
+
 // storages
 
 macro_rules! generate_sparse {
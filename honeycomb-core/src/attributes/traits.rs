@@ -212,6 +212,44 @@ pub trait UnknownAttributeStorage: Any + Debug + Downcast {
     /// - `length: usize` -- length of which the storage should be extended.
     fn extend(&mut self, length: usize);
 
+    /// Reserve capacity for the storage to be extended without reallocation.
+    ///
+    /// This is only a hint; the default implementation does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// - `additional: usize` -- length by which the storage should be extendable without
+    ///   reallocating.
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Append the content of another storage to this one.
+    ///
+    /// The null slot (i.e. index `0`) of `other` is skipped: the value associated to identifier
+    /// `id` in `other` ends up associated to `id + len - 1`, `len` being the length of `self`
+    /// before the operation. `other` is left empty.
+    ///
+    /// # Arguments
+    ///
+    /// - `other: &mut dyn UnknownAttributeStorage` -- Storage whose content is moved to `self`.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if `other` isn't of the same concrete type as `self`.
+    fn append(&mut self, other: &mut dyn UnknownAttributeStorage);
+
+    /// Drop slots of the storage according to a renumbering of identifiers.
+    ///
+    /// The slot at index `id` is kept if `id` is `0` or if `mapping[id]` is not null. Kept slots
+    /// are moved to index `mapping[id]`; the mapping is expected to be increasing over kept
+    /// identifiers, i.e. to preserve their relative order.
+    ///
+    /// # Arguments
+    ///
+    /// - `mapping: &[DartIdType]` -- Old-to-new identifier mapping, `0` denoting removed slots.
+    fn compact(&mut self, mapping: &[DartIdType]);
+
     /// Return the number of stored attributes, i.e. the number of used slots in the storage (not
     /// its length).
     #[must_use = "unused return value"]
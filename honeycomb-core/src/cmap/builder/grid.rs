@@ -1,8 +1,18 @@
-use crate::prelude::{BuilderError, CMap2, DartIdType, Vector2, Vertex2};
+use crate::prelude::{BuilderError, CMap2, DartIdType, Vector2, Vertex2, VertexIdType};
 use crate::{attributes::AttrStorageManager, geometry::CoordsFloat};
 
 // --- grid descriptor
 
+/// Shape of the cells of a grid
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CellShape {
+    /// Orthogonal cells, optionally split into two triangles.
+    #[default]
+    Quad,
+    /// Regular hexagonal cells, organized as a honeycomb lattice.
+    Hexagon,
+}
+
 /// # Grid description used by the map builder
 ///
 /// The user must specify two out of these three characteristics (third is deduced):
@@ -11,6 +21,11 @@ use crate::{attributes::AttrStorageManager, geometry::CoordsFloat};
 /// - `len_per_cell: [T; 3]` -- The dimensions of cells per axis
 /// - `lens: [T; 3]` -- The total dimensions of the grid per axis
 ///
+/// Cell dimensions can be made non-uniform along a given axis using
+/// [`GridDescriptor::len_per_cell_axis`]. Hexagonal cells can be generated instead of
+/// orthogonal ones using [`GridDescriptor::cell_shape`]. Additionally, the grid can be made
+/// periodic along any axis using [`GridDescriptor::periodic`].
+///
 /// ## Generics
 ///
 /// - `T: CoordsFloat` -- Generic FP type that will be used by the map's vertices.
@@ -21,6 +36,9 @@ pub struct GridDescriptor<T: CoordsFloat> {
     pub(crate) len_per_cell: Option<[T; 3]>,
     pub(crate) lens: Option<[T; 3]>,
     pub(crate) split_quads: bool,
+    pub(crate) periodic: [bool; 3],
+    pub(crate) graded_lens: [Option<Vec<T>>; 3],
+    pub(crate) cell_shape: CellShape,
 }
 
 macro_rules! setters {
@@ -97,6 +115,48 @@ impl<T: CoordsFloat> GridDescriptor<T> {
         self.split_quads = split;
         self
     }
+
+    /// Set the shape of generated cells
+    ///
+    /// Hexagonal cells are pointy-topped, with a circumradius equal to the length per cell along
+    /// the x-axis. Rows of cells are stacked along the y-axis, odd rows being shifted by half a
+    /// cell toward positive x; the origin corresponds to the bottom-left corner of the lattice's
+    /// bounding box. Splitting, periodicity & graded dimensions are not supported for hexagonal
+    /// cells.
+    #[must_use = "unused builder object"]
+    pub fn cell_shape(mut self, shape: CellShape) -> Self {
+        self.cell_shape = shape;
+        self
+    }
+
+    /// Set dimensions of each cell along a given axis
+    ///
+    /// Cells along the axis are sized according to `lens`, from the origin outward; this takes
+    /// precedence over the uniform value that may be set for the axis. The number of lengths must
+    /// match the number of cells of the axis, which has to be specified explicitly.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `axis` is not one of `0`, `1`, or `2`.
+    #[must_use = "unused builder object"]
+    pub fn len_per_cell_axis(mut self, axis: usize, lens: Vec<T>) -> Self {
+        self.graded_lens[axis] = Some(lens);
+        self
+    }
+
+    /// Indicate along which axes the grid should wrap around
+    ///
+    /// Darts of opposite boundaries along a periodic axis are linked together once the grid is
+    /// built, meaning that the resulting map has no free dart on these boundaries. Merged
+    /// vertices keep the value of the bottom / left-most vertex.
+    ///
+    /// A periodic axis must contain at least two cells; periodicity along the z-axis is not
+    /// supported when building a 2D map.
+    #[must_use = "unused builder object"]
+    pub fn periodic(mut self, periodic: [bool; 3]) -> Self {
+        self.periodic = periodic;
+        self
+    }
 }
 
 // --- parsing routine
@@ -111,9 +171,50 @@ macro_rules! check_parameters {
 
 impl<T: CoordsFloat> GridDescriptor<T> {
     /// Parse provided grid parameters to provide what's used to actually generate the grid.
-    #[allow(clippy::type_complexity)]
-    pub(crate) fn parse_2d(self) -> Result<(Vertex2<T>, [usize; 2], [T; 2]), BuilderError> {
-        match (self.n_cells, self.len_per_cell, self.lens) {
+    ///
+    /// Cell dimensions are returned per cell, for each axis.
+    #[allow(clippy::type_complexity, clippy::too_many_lines)]
+    pub(crate) fn parse_2d(
+        mut self,
+    ) -> Result<(Vertex2<T>, [usize; 2], [Vec<T>; 2]), BuilderError> {
+        // graded axes: check consistency & fill in uniform parameters with equivalent values
+        for axis in 0..2 {
+            let Some(cell_lens) = &self.graded_lens[axis] else {
+                continue;
+            };
+            let Some(n) = self.n_cells.map(|ns| ns[axis]) else {
+                return Err(BuilderError::InvalidGridParameters(
+                    "graded axis requires an explicit number of cells",
+                ));
+            };
+            if cell_lens.is_empty() || cell_lens.len() != n {
+                return Err(BuilderError::InvalidGridParameters(
+                    "number of cell lengths doesn't match number of cells",
+                ));
+            }
+            if cell_lens.iter().any(|l| l.is_sign_negative() | l.is_zero()) {
+                return Err(BuilderError::InvalidGridParameters(
+                    "length of a graded cell is null or negative",
+                ));
+            }
+            let total = cell_lens.iter().fold(T::zero(), |acc, l| acc + *l);
+            match (&mut self.len_per_cell, &mut self.lens) {
+                (Some(lpc), lens) => {
+                    lpc[axis] = total / T::from(n).unwrap();
+                    if let Some(lens) = lens {
+                        lens[axis] = total;
+                    }
+                }
+                (None, Some(lens)) => lens[axis] = total,
+                (None, None) => {
+                    let mut lpc = [T::zero(); 3];
+                    lpc[axis] = total / T::from(n).unwrap();
+                    self.len_per_cell = Some(lpc);
+                }
+            }
+        }
+
+        let res = match (self.n_cells, self.len_per_cell, self.lens) {
             // from # cells and lengths per cell
             (Some([nx, ny, _]), Some([lpx, lpy, _]), lens) => {
                 if lens.is_some() {
@@ -150,14 +251,78 @@ impl<T: CoordsFloat> GridDescriptor<T> {
                 Ok((
                     self.origin,
                     [
-                        (lx / lpx).ceil().to_usize().unwrap(),
-                        (ly / lpy).ceil().to_usize().unwrap(),
+                        (lx / lpx)
+                            .ceil()
+                            .to_usize()
+                            .ok_or(BuilderError::InvalidGridParameters(
+                                "number of x cells cannot be represented",
+                            ))?,
+                        (ly / lpy)
+                            .ceil()
+                            .to_usize()
+                            .ok_or(BuilderError::InvalidGridParameters(
+                                "number of y cells cannot be represented",
+                            ))?,
                     ],
                     [lpx, lpy],
                 ))
             }
             (_, _, _) => Err(BuilderError::MissingGridParameters),
+        };
+        if self.cell_shape == CellShape::Hexagon {
+            if self.split_quads {
+                return Err(BuilderError::InvalidGridParameters(
+                    "hexagonal cells cannot be split",
+                ));
+            }
+            if self.periodic.iter().any(|p| *p) {
+                return Err(BuilderError::InvalidGridParameters(
+                    "periodicity is not supported for hexagonal cells",
+                ));
+            }
+            if self.graded_lens.iter().any(Option::is_some) {
+                return Err(BuilderError::InvalidGridParameters(
+                    "graded dimensions are not supported for hexagonal cells",
+                ));
+            }
         }
+        let periodic = self.periodic;
+        let n_darts_per_cell: u128 = if self.split_quads || self.cell_shape == CellShape::Hexagon {
+            6
+        } else {
+            4
+        };
+        let [graded_x, graded_y, _] = self.graded_lens;
+        res.and_then(|(origin, ns, [lpx, lpy])| {
+            // checked before allocating anything, since the product may overflow
+            let requested = ns
+                .iter()
+                .fold(n_darts_per_cell, |acc, n| acc.saturating_mul(*n as u128));
+            if requested > u128::from(DartIdType::MAX) {
+                return Err(BuilderError::TooLarge {
+                    requested,
+                    max: DartIdType::MAX,
+                });
+            }
+            if periodic[2] {
+                return Err(BuilderError::InvalidGridParameters(
+                    "periodicity along z is not supported for 2D grids",
+                ));
+            }
+            if ns.iter().zip(periodic).any(|(n, p)| p && *n < 2) {
+                return Err(BuilderError::InvalidGridParameters(
+                    "periodic axis has less than two cells",
+                ));
+            }
+            Ok((
+                origin,
+                ns,
+                [
+                    graded_x.unwrap_or_else(|| vec![lpx; ns[0]]),
+                    graded_y.unwrap_or_else(|| vec![lpy; ns[1]]),
+                ],
+            ))
+        })
     }
 }
 
@@ -168,9 +333,11 @@ impl<T: CoordsFloat> GridDescriptor<T> {
 pub fn build_2d_grid<T: CoordsFloat>(
     origin: Vertex2<T>,
     [n_square_x, n_square_y]: [usize; 2],
-    [len_per_x, len_per_y]: [T; 2],
+    [lens_x, lens_y]: [Vec<T>; 2],
     manager: AttrStorageManager,
 ) -> CMap2<T> {
+    let (xs, ys) = (cumulative_coords(&lens_x), cumulative_coords(&lens_y));
+
     let map: CMap2<T> = CMap2::new_with_undefined_attributes(4 * n_square_x * n_square_y, manager);
 
     // init beta functions
@@ -188,56 +355,28 @@ pub fn build_2d_grid<T: CoordsFloat>(
         .flat_map(|y_idx| (0..n_square_x).map(move |x_idx| (y_idx, x_idx)))
         .for_each(|(y_idx, x_idx)| {
             let vertex_id = map.vertex_id((1 + x_idx * 4 + y_idx * 4 * n_square_x) as DartIdType);
-            map.force_write_vertex(
-                vertex_id,
-                origin
-                    + Vector2(
-                        T::from(x_idx).unwrap() * len_per_x,
-                        T::from(y_idx).unwrap() * len_per_y,
-                    ),
-            );
+            map.force_write_vertex(vertex_id, origin + Vector2(xs[x_idx], ys[y_idx]));
         });
 
     // top left vertex of all top row cells
     (0..n_square_x).for_each(|x_idx| {
         let y_idx = n_square_y - 1;
         let vertex_id = map.vertex_id((4 + x_idx * 4 + y_idx * 4 * n_square_x) as DartIdType);
-        map.force_write_vertex(
-            vertex_id,
-            origin
-                + Vector2(
-                    T::from(x_idx).unwrap() * len_per_x,
-                    T::from(y_idx + 1).unwrap() * len_per_y,
-                ),
-        );
+        map.force_write_vertex(vertex_id, origin + Vector2(xs[x_idx], ys[y_idx + 1]));
     });
 
     // bottom right vertex of all right col cells
     (0..n_square_y).for_each(|y_idx| {
         let x_idx = n_square_x - 1;
         let vertex_id = map.vertex_id((2 + x_idx * 4 + y_idx * 4 * n_square_x) as DartIdType);
-        map.force_write_vertex(
-            vertex_id,
-            origin
-                + Vector2(
-                    T::from(x_idx + 1).unwrap() * len_per_x,
-                    T::from(y_idx).unwrap() * len_per_y,
-                ),
-        );
+        map.force_write_vertex(vertex_id, origin + Vector2(xs[x_idx + 1], ys[y_idx]));
     });
 
     // top right vertex of the last cell
     {
         let (x_idx, y_idx) = (n_square_x - 1, n_square_y - 1);
         let vertex_id = map.vertex_id((3 + x_idx * 4 + y_idx * 4 * n_square_x) as DartIdType); // top right
-        map.force_write_vertex(
-            vertex_id,
-            origin
-                + Vector2(
-                    T::from(x_idx + 1).unwrap() * len_per_x,
-                    T::from(y_idx + 1).unwrap() * len_per_y,
-                ),
-        );
+        map.force_write_vertex(vertex_id, origin + Vector2(xs[x_idx + 1], ys[y_idx + 1]));
     }
 
     // check the number of built faces
@@ -275,9 +414,11 @@ fn generate_square_beta_values(n_x: usize, n_y: usize) -> impl Iterator<Item = [
 pub fn build_2d_splitgrid<T: CoordsFloat>(
     origin: Vertex2<T>,
     [n_square_x, n_square_y]: [usize; 2],
-    [len_per_x, len_per_y]: [T; 2],
+    [lens_x, lens_y]: [Vec<T>; 2],
     manager: AttrStorageManager,
 ) -> CMap2<T> {
+    let (xs, ys) = (cumulative_coords(&lens_x), cumulative_coords(&lens_y));
+
     let map: CMap2<T> = CMap2::new_with_undefined_attributes(6 * n_square_x * n_square_y, manager);
 
     // init beta functions
@@ -295,56 +436,28 @@ pub fn build_2d_splitgrid<T: CoordsFloat>(
         .flat_map(|y_idx| (0..n_square_x).map(move |x_idx| (y_idx, x_idx)))
         .for_each(|(y_idx, x_idx)| {
             let vertex_id = map.vertex_id((1 + x_idx * 6 + y_idx * 6 * n_square_x) as DartIdType);
-            map.force_write_vertex(
-                vertex_id,
-                origin
-                    + Vector2(
-                        T::from(x_idx).unwrap() * len_per_x,
-                        T::from(y_idx).unwrap() * len_per_y,
-                    ),
-            );
+            map.force_write_vertex(vertex_id, origin + Vector2(xs[x_idx], ys[y_idx]));
         });
 
     // top left vertex of all top row cells
     (0..n_square_x).for_each(|x_idx| {
         let y_idx = n_square_y - 1;
         let vertex_id = map.vertex_id((4 + x_idx * 6 + y_idx * 6 * n_square_x) as DartIdType);
-        map.force_write_vertex(
-            vertex_id,
-            origin
-                + Vector2(
-                    T::from(x_idx).unwrap() * len_per_x,
-                    T::from(y_idx + 1).unwrap() * len_per_y,
-                ),
-        );
+        map.force_write_vertex(vertex_id, origin + Vector2(xs[x_idx], ys[y_idx + 1]));
     });
 
     // bottom right vertex of all right col cells
     (0..n_square_y).for_each(|y_idx| {
         let x_idx = n_square_x - 1;
         let vertex_id = map.vertex_id((2 + x_idx * 6 + y_idx * 6 * n_square_x) as DartIdType);
-        map.force_write_vertex(
-            vertex_id,
-            origin
-                + Vector2(
-                    T::from(x_idx + 1).unwrap() * len_per_x,
-                    T::from(y_idx).unwrap() * len_per_y,
-                ),
-        );
+        map.force_write_vertex(vertex_id, origin + Vector2(xs[x_idx + 1], ys[y_idx]));
     });
 
     // top right vertex of the last cell
     {
         let (x_idx, y_idx) = (n_square_x - 1, n_square_y - 1);
         let vertex_id = map.vertex_id((6 + x_idx * 6 + y_idx * 6 * n_square_x) as DartIdType); // top right
-        map.force_write_vertex(
-            vertex_id,
-            origin
-                + Vector2(
-                    T::from(x_idx + 1).unwrap() * len_per_x,
-                    T::from(y_idx + 1).unwrap() * len_per_y,
-                ),
-        );
+        map.force_write_vertex(vertex_id, origin + Vector2(xs[x_idx + 1], ys[y_idx + 1]));
     }
 
     // check the number of built faces
@@ -378,3 +491,154 @@ fn generate_tris_beta_values(n_x: usize, n_y: usize) -> impl Iterator<Item = [Da
             })
         })
 }
+
+/// Internal grid-building routine
+pub fn build_2d_hexgrid<T: CoordsFloat>(
+    origin: Vertex2<T>,
+    [n_hex_x, n_hex_y]: [usize; 2],
+    [lens_x, _]: [Vec<T>; 2],
+    manager: AttrStorageManager,
+) -> CMap2<T> {
+    let map: CMap2<T> = CMap2::new_with_undefined_attributes(6 * n_hex_x * n_hex_y, manager);
+
+    // init beta functions
+    (1..=(6 * n_hex_x * n_hex_y) as DartIdType)
+        .zip(generate_hex_beta_values(n_hex_x, n_hex_y))
+        .for_each(|(dart, images)| {
+            map.set_betas(dart, images);
+        });
+
+    // place vertices
+    let two = T::from(2.0).unwrap();
+    let radius = lens_x[0];
+    let half_width = radius * T::from(3.0).unwrap().sqrt() / two;
+    // offsets from the cell center, in the same order as darts
+    let corners = [
+        Vector2(T::zero(), -radius),
+        Vector2(half_width, -radius / two),
+        Vector2(half_width, radius / two),
+        Vector2(T::zero(), radius),
+        Vector2(-half_width, radius / two),
+        Vector2(-half_width, -radius / two),
+    ];
+    (0..n_hex_y)
+        .flat_map(|y_idx| (0..n_hex_x).map(move |x_idx| (y_idx, x_idx)))
+        .for_each(|(y_idx, x_idx)| {
+            let center = origin
+                + Vector2(
+                    half_width * T::from(1 + 2 * x_idx + y_idx % 2).unwrap(),
+                    radius + radius * T::from(1.5).unwrap() * T::from(y_idx).unwrap(),
+                );
+            let d1 = 1 + 6 * (x_idx + n_hex_x * y_idx);
+            corners.iter().enumerate().for_each(|(offset, corner)| {
+                // each vertex is written once, by the dart holding its ID
+                let dart = (d1 + offset) as DartIdType;
+                if map.vertex_id(dart) == dart {
+                    map.force_write_vertex(dart, center + *corner);
+                }
+            });
+        });
+
+    // check the number of built faces
+    // this is set as debug only because the operation cost scales with map size
+    // this can quickly overshadow the exectime of all previous code
+    debug_assert_eq!(map.iter_faces().count(), n_hex_x * n_hex_y);
+
+    map
+}
+
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn generate_hex_beta_values(n_x: usize, n_y: usize) -> impl Iterator<Item = [DartIdType; 3]> {
+    // first dart of a cell, or the null dart if the cell is out of bounds
+    let cell = move |ix: isize, iy: isize| {
+        if (0..n_x as isize).contains(&ix) && (0..n_y as isize).contains(&iy) {
+            (1 + 6 * (ix as usize + n_x * iy as usize)) as DartIdType
+        } else {
+            0
+        }
+    };
+    let dart = move |first: DartIdType, offset: DartIdType| {
+        if first == 0 {
+            0
+        } else {
+            first + offset
+        }
+    };
+    // this loop hierarchy yields the value in correct order
+    // left to right first, then bottom to top
+    (0..n_y as isize).flat_map(move |iy| {
+        (0..n_x as isize).flat_map(move |ix| {
+            let d1 = cell(ix, iy);
+            // odd rows are shifted toward positive x
+            let shift = iy % 2;
+            // neighboring cells, in the same order as darts
+            let neighbors = [
+                dart(cell(ix + shift, iy - 1), 3),
+                dart(cell(ix + 1, iy), 4),
+                dart(cell(ix + shift, iy + 1), 5),
+                dart(cell(ix + shift - 1, iy + 1), 0),
+                dart(cell(ix - 1, iy), 1),
+                dart(cell(ix + shift - 1, iy - 1), 2),
+            ];
+            (0..6).map(move |offset| {
+                [
+                    d1 + (offset + 5) % 6,
+                    d1 + (offset + 1) % 6,
+                    neighbors[offset as usize],
+                ]
+            })
+        })
+    })
+}
+
+/// Return coordinates of cell boundaries along an axis, relative to the origin
+fn cumulative_coords<T: CoordsFloat>(lens: &[T]) -> Vec<T> {
+    std::iter::once(T::zero())
+        .chain(lens.iter().scan(T::zero(), |acc, l| {
+            *acc += *l;
+            Some(*acc)
+        }))
+        .collect()
+}
+
+/// Internal routine linking opposite boundaries of a 2D grid along periodic axes
+///
+/// `n_darts_per_cell` is used to differentiate regular grids (4) from split ones (6).
+pub fn wrap_2d_grid<T: CoordsFloat>(
+    map: &CMap2<T>,
+    [n_square_x, n_square_y]: [usize; 2],
+    [periodic_x, periodic_y]: [bool; 2],
+    n_darts_per_cell: usize,
+) {
+    // local offsets of boundary darts in a cell
+    let [bottom, left, right, top] = if n_darts_per_cell == 6 {
+        [0, 2, 4, 5]
+    } else {
+        [0, 3, 1, 2]
+    };
+    let dart = |x_idx: usize, y_idx: usize, offset: usize| {
+        (1 + offset + x_idx * n_darts_per_cell + y_idx * n_darts_per_cell * n_square_x)
+            as DartIdType
+    };
+
+    // vertex IDs may change after linking; we need to clean up stale values
+    let old_vertices: Vec<VertexIdType> = map.iter_vertices().collect();
+
+    if periodic_x {
+        (0..n_square_y).for_each(|y_idx| {
+            map.force_link::<2>(dart(0, y_idx, left), dart(n_square_x - 1, y_idx, right));
+        });
+    }
+    if periodic_y {
+        (0..n_square_x).for_each(|x_idx| {
+            map.force_link::<2>(dart(x_idx, 0, bottom), dart(x_idx, n_square_y - 1, top));
+        });
+    }
+
+    old_vertices
+        .into_iter()
+        .filter(|vid| map.vertex_id(*vid as DartIdType) != *vid)
+        .for_each(|vid| {
+            map.force_remove_vertex(vid);
+        });
+}
@@ -1,7 +1,13 @@
-use crate::prelude::{BuilderError, CMap2, CMapBuilder, DartIdType, Vertex2, VertexIdType};
+use crate::attributes::{AttrSparseVec, AttributeBind, AttributeUpdate};
+use crate::geometry::Vertex3;
+use crate::prelude::{
+    BuilderError, CMap2, CMap3, CMapBuilder, DartIdType, FaceIdType, OrbitPolicy, Vertex2,
+    VertexIdType, NULL_DART_ID,
+};
 use crate::{attributes::AttrStorageManager, geometry::CoordsFloat};
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::BufRead;
 
 use num_traits::Zero;
 use vtkio::model::{CellType, DataSet, VertexNumbers};
@@ -33,19 +39,12 @@ macro_rules! if_predicate_return_err {
     };
 }
 
-macro_rules! build_vertices {
+macro_rules! check_vertices {
     ($v: ident) => {{
         if_predicate_return_err!(
             !($v.len() % 3).is_zero(),
             BuilderError::BadVtkData("vertex list contains an incomplete tuple")
         );
-        $v.chunks_exact(3)
-            .map(|slice| {
-                // WE IGNORE Z values
-                let &[x, y, _] = slice else { unreachable!() };
-                Vertex2(T::from(x).unwrap(), T::from(y).unwrap())
-            })
-            .collect()
     }};
 }
 
@@ -75,7 +74,8 @@ pub fn build_2d_from_vtk<T: CoordsFloat>(
     mut _manager: AttrStorageManager, // FIXME: find a cleaner solution to populate the manager
 ) -> Result<CMap2<T>, BuilderError> {
     let mut cmap: CMap2<T> = CMap2::new(0);
-    let mut sew_buffer: BTreeMap<(usize, usize), DartIdType> = BTreeMap::new();
+    // half-edges are buffered so that non-manifold edges can be detected before sewing
+    let mut half_edges: Vec<((usize, usize), DartIdType)> = Vec::new();
     match value.data {
         DataSet::ImageData { .. }
         | DataSet::StructuredGrid { .. }
@@ -91,17 +91,30 @@ pub fn build_2d_from_vtk<T: CoordsFloat>(
                     return Err(BuilderError::UnsupportedVtkData("not inlined data piece"));
                 };
 
-                // build vertex list
+                // check vertex list
                 // since we're expecting coordinates, we'll assume floating type
-                // we're also converting directly to our vertex type since we're building a 2-map
-                let vertices: Vec<Vertex2<T>> = match tmp.points {
-                    IOBuffer::F64(v) => build_vertices!(v),
-                    IOBuffer::F32(v) => build_vertices!(v),
+                match &tmp.points {
+                    IOBuffer::F64(v) => check_vertices!(v),
+                    IOBuffer::F32(v) => check_vertices!(v),
                     _ => {
                         return Err(BuilderError::UnsupportedVtkData(
                             "unsupported coordinate type",
                         ))
                     }
+                }
+                // vertices are converted when referenced by a cell, instead of being collected
+                // beforehand; WE IGNORE Z values
+                let vertices = &tmp.points;
+                let vertex = |vid: usize| match vertices {
+                    IOBuffer::F64(v) => Vertex2(
+                        T::from(v[3 * vid]).unwrap(),
+                        T::from(v[3 * vid + 1]).unwrap(),
+                    ),
+                    IOBuffer::F32(v) => Vertex2(
+                        T::from(v[3 * vid]).unwrap(),
+                        T::from(v[3 * vid + 1]).unwrap(),
+                    ),
+                    _ => unreachable!(),
                 };
 
                 let vtkio::model::Cells { cell_verts, types } = tmp.cells;
@@ -178,23 +191,23 @@ pub fn build_2d_from_vtk<T: CoordsFloat>(
                                         let (d1, d2) = (d0 + 1, d0 + 2);
                                         cmap.force_write_vertex(
                                             d0 as VertexIdType,
-                                            vertices[vids[0]],
+                                            vertex(vids[0]),
                                         );
                                         cmap.force_write_vertex(
                                             d1 as VertexIdType,
-                                            vertices[vids[1]],
+                                            vertex(vids[1]),
                                         );
                                         cmap.force_write_vertex(
                                             d2 as VertexIdType,
-                                            vertices[vids[2]],
+                                            vertex(vids[2]),
                                         );
                                         cmap.force_link::<1>(d0, d1); // edge d0 links vertices vids[0] & vids[1]
                                         cmap.force_link::<1>(d1, d2); // edge d1 links vertices vids[1] & vids[2]
                                         cmap.force_link::<1>(d2, d0); // edge d2 links vertices vids[2] & vids[0]
                                                                       // record a trace of the built cell for future 2-sew
-                                        sew_buffer.insert((vids[0], vids[1]), d0);
-                                        sew_buffer.insert((vids[1], vids[2]), d1);
-                                        sew_buffer.insert((vids[2], vids[0]), d2);
+                                        half_edges.push(((vids[0], vids[1]), d0));
+                                        half_edges.push(((vids[1], vids[2]), d1));
+                                        half_edges.push(((vids[2], vids[0]), d2));
                                         Ok(())
                                     }
                                     CellType::TriangleStrip => {
@@ -211,11 +224,11 @@ pub fn build_2d_from_vtk<T: CoordsFloat>(
                                                 if i == n_vertices - 1 { d0 } else { di + 1 };
                                             cmap.force_write_vertex(
                                                 di as VertexIdType,
-                                                vertices[vids[i]],
+                                                vertex(vids[i]),
                                             );
                                             cmap.force_link::<1>(di, dip1);
-                                            sew_buffer
-                                                .insert((vids[i], vids[(i + 1) % n_vertices]), di);
+                                            half_edges
+                                                .push(((vids[i], vids[(i + 1) % n_vertices]), di));
                                         });
                                         Ok(())
                                     }
@@ -234,29 +247,29 @@ pub fn build_2d_from_vtk<T: CoordsFloat>(
                                         let (d1, d2, d3) = (d0 + 1, d0 + 2, d0 + 3);
                                         cmap.force_write_vertex(
                                             d0 as VertexIdType,
-                                            vertices[vids[0]],
+                                            vertex(vids[0]),
                                         );
                                         cmap.force_write_vertex(
                                             d1 as VertexIdType,
-                                            vertices[vids[1]],
+                                            vertex(vids[1]),
                                         );
                                         cmap.force_write_vertex(
                                             d2 as VertexIdType,
-                                            vertices[vids[2]],
+                                            vertex(vids[2]),
                                         );
                                         cmap.force_write_vertex(
                                             d3 as VertexIdType,
-                                            vertices[vids[3]],
+                                            vertex(vids[3]),
                                         );
                                         cmap.force_link::<1>(d0, d1); // edge d0 links vertices vids[0] & vids[1]
                                         cmap.force_link::<1>(d1, d2); // edge d1 links vertices vids[1] & vids[2]
                                         cmap.force_link::<1>(d2, d3); // edge d2 links vertices vids[2] & vids[3]
                                         cmap.force_link::<1>(d3, d0); // edge d3 links vertices vids[3] & vids[0]
                                                                       // record a trace of the built cell for future 2-sew
-                                        sew_buffer.insert((vids[0], vids[1]), d0);
-                                        sew_buffer.insert((vids[1], vids[2]), d1);
-                                        sew_buffer.insert((vids[2], vids[3]), d2);
-                                        sew_buffer.insert((vids[3], vids[0]), d3);
+                                        half_edges.push(((vids[0], vids[1]), d0));
+                                        half_edges.push(((vids[1], vids[2]), d1));
+                                        half_edges.push(((vids[2], vids[3]), d2));
+                                        half_edges.push(((vids[3], vids[0]), d3));
                                         Ok(())
                                     }
                                     _ => Err(BuilderError::UnsupportedVtkData(
@@ -279,10 +292,923 @@ pub fn build_2d_from_vtk<T: CoordsFloat>(
             }
         }
     }
+    sew_half_edges(&cmap, half_edges)?;
+    Ok(cmap)
+}
+
+/// 2-sew the matching half-edges `(start, end)` / `(end, start)` of a map built from a file.
+///
+/// # Errors
+///
+/// This function returns a `BuilderError::NonManifold` if an edge is shared by more than two
+/// half-edges (see [`check_manifold`]).
+fn sew_half_edges<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    half_edges: Vec<((usize, usize), DartIdType)>,
+) -> Result<(), BuilderError> {
+    check_manifold(&half_edges)?;
+    let mut sew_buffer: BTreeMap<(usize, usize), DartIdType> = half_edges.into_iter().collect();
     while let Some(((id0, id1), dart_id0)) = sew_buffer.pop_first() {
         if let Some(dart_id1) = sew_buffer.remove(&(id1, id0)) {
             cmap.force_sew::<2>(dart_id0, dart_id1);
         }
     }
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
+/// Internal building routine for [`CMapBuilder::vtk_file_streaming`].
+///
+/// Unlike [`build_2d_from_vtk`], the file is parsed while the map is built: cells are made into
+/// faces as they are read, without collecting the content of the file beforehand. Only the
+/// 2D coordinates of points are kept in memory until the end of the `CELLS` section, since cells
+/// reference points by index.
+///
+/// # Result / Errors
+///
+/// This implementation supports legacy ASCII files describing an unstructured grid. Point and
+/// cell data sections are ignored. Cells with at least three vertices are built before their
+/// type is known; types are checked once the `CELL_TYPES` section is read. This function may
+/// return:
+///
+/// - `Ok(CMap2)` -- The file was successfully parsed and its content made into a 2-map.
+/// - `Err(BuilderError)` -- The function failed for one of the following reasons (sorted
+///   by [`BuilderError`] variants):
+///     - `UnsupportedVtkData`: The file contains unsupported data, i.e.:
+///         - file format isn't legacy ASCII,
+///         - data set is something other than `UNSTRUCTURED_GRID`,
+///         - coordinate representation type isn't `float` or `double`
+///         - mesh contains unsupported cell types (`PolyVertex`, `PolyLine`, `TriangleStrip`,
+///           `Pixel` or anything 3D)
+///     - `BadVtkData`: The file contains inconsistencies, i.e.:
+///         - the header is missing, or a section is incomplete or contains invalid values,
+///         - the `POINTS`, `CELLS` and `CELL_TYPES` sections are missing or out of order,
+///         - a cell references an undefined point,
+///         - the number of `Cells` and `CellTypes` isn't equal
+///         - a given cell has an inconsistent number of vertices with its specified cell type
+///     - `NonManifold`: An edge is shared by more than two cells.
+pub fn build_2d_from_vtk_stream<T: CoordsFloat, R: BufRead>(
+    reader: R,
+    mut _manager: AttrStorageManager, // FIXME: find a cleaner solution to populate the manager
+) -> Result<CMap2<T>, BuilderError> {
+    let mut lines = reader.lines().map_while(Result::ok);
+
+    // header: version, title, format
+    let mut header = lines.by_ref().filter(|line| !line.trim().is_empty());
+    if_predicate_return_err!(
+        !header
+            .next()
+            .is_some_and(|line| line.trim_start().starts_with("# vtk DataFile")),
+        BuilderError::BadVtkData("missing file header")
+    );
+    if_predicate_return_err!(
+        header.next().is_none(),
+        BuilderError::BadVtkData("missing file title")
+    );
+    match header.next().as_deref().map(str::trim) {
+        Some("ASCII") => {}
+        Some("BINARY") => return Err(BuilderError::UnsupportedVtkData("binary format")),
+        _ => return Err(BuilderError::BadVtkData("missing file format")),
+    }
+
+    // the rest of the file is read token by token, one line at a time
+    let mut tokens = lines.flat_map(|line| {
+        line.split_whitespace()
+            .map(str::to_owned)
+            .collect::<Vec<_>>()
+    });
+    let mut next = |err: &'static str| tokens.next().ok_or(BuilderError::BadVtkData(err));
+
+    if_predicate_return_err!(
+        next("missing dataset")? != "DATASET",
+        BuilderError::BadVtkData("missing dataset")
+    );
+    if_predicate_return_err!(
+        next("missing dataset")? != "UNSTRUCTURED_GRID",
+        BuilderError::UnsupportedVtkData("dataset not supported")
+    );
+
+    // points
+    if_predicate_return_err!(
+        next("missing POINTS section")? != "POINTS",
+        BuilderError::BadVtkData("missing POINTS section")
+    );
+    let n_points: usize = parse_vtk(&next("incomplete POINTS header")?, "incorrect # of points")?;
+    if_predicate_return_err!(
+        !matches!(
+            next("incomplete POINTS header")?.as_str(),
+            "float" | "double"
+        ),
+        BuilderError::UnsupportedVtkData("unsupported coordinate type")
+    );
+    let mut vertices: Vec<Vertex2<T>> = Vec::with_capacity(n_points);
+    for _ in 0..n_points {
+        let x: f64 = parse_vtk(&next("incomplete POINTS section")?, "incorrect coordinate")?;
+        let y: f64 = parse_vtk(&next("incomplete POINTS section")?, "incorrect coordinate")?;
+        // WE IGNORE Z values
+        let _: f64 = parse_vtk(&next("incomplete POINTS section")?, "incorrect coordinate")?;
+        vertices.push(Vertex2(T::from(x).unwrap(), T::from(y).unwrap()));
+    }
+
+    // cells
+    if_predicate_return_err!(
+        next("missing CELLS section")? != "CELLS",
+        BuilderError::BadVtkData("missing CELLS section")
+    );
+    let n_cells: usize = parse_vtk(&next("incomplete CELLS header")?, "incorrect # of cells")?;
+    let _: usize = parse_vtk(&next("incomplete CELLS header")?, "incorrect CELLS size")?;
+    let mut cmap: CMap2<T> = CMap2::new(0);
+    let mut half_edges: Vec<((usize, usize), DartIdType)> = Vec::new();
+    // only the number of vertices of each cell is kept, to check cell types afterward
+    let mut n_vertices_per_cell: Vec<usize> = Vec::with_capacity(n_cells);
+    let mut vids: Vec<usize> = Vec::new();
+    for _ in 0..n_cells {
+        let n_vertices: usize = parse_vtk(
+            &next("incomplete CELLS section")?,
+            "incorrect # of cell vertices",
+        )?;
+        vids.clear();
+        for _ in 0..n_vertices {
+            let vid: usize = parse_vtk(&next("incomplete CELLS section")?, "incorrect vertex ID")?;
+            if_predicate_return_err!(
+                vid >= n_points,
+                BuilderError::BadVtkData("cell references an undefined point")
+            );
+            vids.push(vid);
+        }
+        n_vertices_per_cell.push(n_vertices);
+        // cells with fewer vertices are either ignored or invalid, depending on their type
+        if n_vertices >= 3 {
+            let d0 = cmap.add_free_darts(n_vertices);
+            (0..n_vertices).for_each(|i| {
+                let di = d0 + i as DartIdType;
+                let dip1 = if i == n_vertices - 1 { d0 } else { di + 1 };
+                cmap.force_write_vertex(di as VertexIdType, vertices[vids[i]]);
+                cmap.force_link::<1>(di, dip1);
+                half_edges.push(((vids[i], vids[(i + 1) % n_vertices]), di));
+            });
+        }
+    }
+    // the map now holds all the data we need
+    drop(vertices);
+
+    // cell types
+    if_predicate_return_err!(
+        next("missing CELL_TYPES section")? != "CELL_TYPES",
+        BuilderError::BadVtkData("missing CELL_TYPES section")
+    );
+    let n_types: usize = parse_vtk(
+        &next("incomplete CELL_TYPES header")?,
+        "incorrect # of cells",
+    )?;
+    if_predicate_return_err!(
+        n_types != n_cells,
+        BuilderError::BadVtkData("different # of cell in CELLS and CELL_TYPES")
+    );
+    for n_vertices in n_vertices_per_cell {
+        // cell type codes are defined in the VTK file format specification
+        let cell_type: u8 = parse_vtk(
+            &next("incomplete CELL_TYPES section")?,
+            "incorrect cell type",
+        )?;
+        match (cell_type, n_vertices) {
+            (1, 1) | (3, 2) | (5, 3) | (9, 4) => {}
+            (7, n) if n >= 3 => {}
+            (1, _) => {
+                return Err(BuilderError::BadVtkData(
+                    "`Vertex` with incorrect # of vertices (!=1)",
+                ))
+            }
+            (3, _) => {
+                return Err(BuilderError::BadVtkData(
+                    "`Line` with incorrect # of vertices (!=2)",
+                ))
+            }
+            (5, _) => {
+                return Err(BuilderError::BadVtkData(
+                    "`Triangle` with incorrect # of vertices (!=3)",
+                ))
+            }
+            (9, _) => {
+                return Err(BuilderError::BadVtkData(
+                    "`Quad` with incorrect # of vertices (!=4)",
+                ))
+            }
+            (7, _) => {
+                return Err(BuilderError::BadVtkData(
+                    "`Polygon` with incorrect # of vertices (<3)",
+                ))
+            }
+            (2, _) => return Err(BuilderError::UnsupportedVtkData("`PolyVertex` cell type")),
+            (4, _) => return Err(BuilderError::UnsupportedVtkData("`PolyLine` cell type")),
+            (6, _) => {
+                return Err(BuilderError::UnsupportedVtkData(
+                    "`TriangleStrip` cell type",
+                ))
+            }
+            (8, _) => return Err(BuilderError::UnsupportedVtkData("`Pixel` cell type")),
+            _ => {
+                return Err(BuilderError::UnsupportedVtkData(
+                    "CellType not supported in 2-maps",
+                ))
+            }
+        }
+    }
+    // remaining sections (point & cell data) are ignored
+
+    sew_half_edges(&cmap, half_edges)?;
+    Ok(cmap)
+}
+
+/// Parse a single value, returning a `BadVtkData` error with the given message on failure.
+fn parse_vtk<V: std::str::FromStr>(item: &str, err: &'static str) -> Result<V, BuilderError> {
+    item.parse().map_err(|_| BuilderError::BadVtkData(err))
+}
+
+/// Check that no edge is shared by more than two of the half-edges `(start, end)`.
+///
+/// # Errors
+///
+/// This function returns a `BuilderError::NonManifold` for the first offending edge, in
+/// increasing order of vertex indices.
+pub(crate) fn check_manifold(
+    half_edges: &[((usize, usize), DartIdType)],
+) -> Result<(), BuilderError> {
+    let mut counts: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+    for ((id0, id1), _) in half_edges {
+        *counts.entry((*id0.min(id1), *id0.max(id1))).or_default() += 1;
+    }
+    match counts.into_iter().find(|(_, n)| *n > 2) {
+        Some((edge, _)) => Err(BuilderError::NonManifold(edge)),
+        None => Ok(()),
+    }
+}
+
+// --- msh building routine
+
+/// Physical group tag of a face.
+///
+/// This attribute is added to maps built from Gmsh files, using the first tag of each element.
+/// When two faces are merged, the smallest tag is kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PhysicalGroup(pub u32);
+
+impl AttributeUpdate for PhysicalGroup {
+    fn merge(attr1: Self, attr2: Self) -> Self {
+        Self(attr1.0.min(attr2.0))
+    }
+
+    fn split(attr: Self) -> (Self, Self) {
+        (attr, attr)
+    }
+}
+
+impl AttributeBind for PhysicalGroup {
+    type StorageType = AttrSparseVec<Self>;
+    type IdentifierType = FaceIdType;
+    const BIND_POLICY: OrbitPolicy = OrbitPolicy::Face;
+}
+
+#[allow(clippy::too_many_lines)]
+/// Internal building routine for [`CMapBuilder::msh_file`].
+///
+/// # Result / Errors
+///
+/// This implementation only supports ASCII files using the MSH 2.x format. Points & lines are
+/// ignored, triangles & quads are made into faces. The first tag of each element, i.e. its
+/// physical group, is stored using the [`PhysicalGroup`] attribute. This function may return:
+///
+/// - `Ok(CMap2)` -- The file was successfully parsed and its content made into a 2-map.
+/// - `Err(BuilderError::BadMshData)` -- The file contains inconsistent or unsupported data, i.e.:
+///     - the `$MeshFormat`, `$Nodes` or `$Elements` section is missing or unterminated,
+///     - the format version isn't 2.x, or the file isn't ASCII,
+///     - the number of nodes or elements differs from the section header,
+///     - a node or element line is incomplete or contains invalid values,
+///     - an element references an undefined node,
+///     - an element has an unsupported type, or an incorrect number of nodes for its type.
+/// - `Err(BuilderError::NonManifold)` -- An edge is shared by more than two faces.
+pub fn build_2d_from_msh<T: CoordsFloat>(
+    contents: &str,
+    mut manager: AttrStorageManager,
+) -> Result<CMap2<T>, BuilderError> {
+    // check format
+    let format = msh_section(contents, "$MeshFormat", "$EndMeshFormat")
+        .ok_or(BuilderError::BadMshData("missing `$MeshFormat` section"))?;
+    let mut header = format
+        .first()
+        .map(|line| line.split_whitespace())
+        .into_iter()
+        .flatten();
+    match (header.next(), header.next()) {
+        (Some(version), Some("0")) if version.starts_with("2.") => {}
+        _ => {
+            return Err(BuilderError::BadMshData(
+                "only ASCII files using the MSH 2.x format are supported",
+            ))
+        }
+    }
+
+    // build vertex list
+    let nodes = msh_section(contents, "$Nodes", "$EndNodes")
+        .ok_or(BuilderError::BadMshData("missing `$Nodes` section"))?;
+    let Some((n_nodes, nodes)) = nodes.split_first() else {
+        return Err(BuilderError::BadMshData("empty `$Nodes` section"));
+    };
+    if_predicate_return_err!(
+        parse_msh::<usize>(n_nodes, "invalid number of nodes")? != nodes.len(),
+        BuilderError::BadMshData("different # of nodes in header and section")
+    );
+    let mut vertices: HashMap<usize, Vertex2<T>> = HashMap::with_capacity(nodes.len());
+    for line in nodes {
+        // WE IGNORE Z values
+        let &[id, x, y, _] = line.split_whitespace().collect::<Vec<_>>().as_slice() else {
+            return Err(BuilderError::BadMshData("node line isn't `id x y z`"));
+        };
+        vertices.insert(
+            parse_msh(id, "invalid node id")?,
+            Vertex2(
+                T::from(parse_msh::<f64>(x, "invalid node coordinate")?).unwrap(),
+                T::from(parse_msh::<f64>(y, "invalid node coordinate")?).unwrap(),
+            ),
+        );
+    }
+
+    // build cells
+    let elements = msh_section(contents, "$Elements", "$EndElements")
+        .ok_or(BuilderError::BadMshData("missing `$Elements` section"))?;
+    let Some((n_elements, elements)) = elements.split_first() else {
+        return Err(BuilderError::BadMshData("empty `$Elements` section"));
+    };
+    if_predicate_return_err!(
+        parse_msh::<usize>(n_elements, "invalid number of elements")? != elements.len(),
+        BuilderError::BadMshData("different # of elements in header and section")
+    );
+    // the storage may have been added by the user; avoid a duplicate storage warning
+    manager.remove_storage::<PhysicalGroup>();
+    manager.add_storage::<PhysicalGroup>(0);
+    let mut cmap: CMap2<T> = CMap2::new_with_undefined_attributes(0, manager);
+    let mut half_edges: Vec<((usize, usize), DartIdType)> = Vec::new();
+    for line in elements {
+        // line layout: id, type, # of tags, tags, node ids
+        let items: Vec<usize> = line
+            .split_whitespace()
+            .map(|item| parse_msh(item, "invalid element data"))
+            .collect::<Result<_, _>>()?;
+        let [_, elem_type, n_tags, rest @ ..] = items.as_slice() else {
+            return Err(BuilderError::BadMshData("incomplete element line"));
+        };
+        if_predicate_return_err!(
+            rest.len() < *n_tags,
+            BuilderError::BadMshData("incomplete element line")
+        );
+        let (tags, vids) = rest.split_at(*n_tags);
+        let n_vertices = match elem_type {
+            15 => 1, // point
+            1 => 2,  // line
+            2 => 3,  // triangle
+            3 => 4,  // quad
+            _ => return Err(BuilderError::BadMshData("unsupported element type")),
+        };
+        if_predicate_return_err!(
+            vids.len() != n_vertices,
+            BuilderError::BadMshData("element with incorrect # of nodes for its type")
+        );
+        let cell_vertices = vids
+            .iter()
+            .map(|vid| vertices.get(vid).copied())
+            .collect::<Option<Vec<_>>>()
+            .ok_or(BuilderError::BadMshData(
+                "element references an undefined node",
+            ))?;
+        if n_vertices < 3 {
+            // silent ignore
+            continue;
+        }
+        // build the cell
+        let d0 = cmap.add_free_darts(n_vertices);
+        (0..n_vertices).for_each(|i| {
+            let di = d0 + i as DartIdType;
+            let dip1 = if i == n_vertices - 1 { d0 } else { di + 1 };
+            cmap.force_write_vertex(di as VertexIdType, cell_vertices[i]);
+            cmap.force_link::<1>(di, dip1);
+            // record a trace of the built cell for future 2-sew
+            half_edges.push(((vids[i], vids[(i + 1) % n_vertices]), di));
+        });
+        if let Some(tag) = tags.first() {
+            let tag = u32::try_from(*tag)
+                .map_err(|_| BuilderError::BadMshData("physical tag overflows u32"))?;
+            cmap.force_write_attribute(d0 as FaceIdType, PhysicalGroup(tag));
+        }
+    }
+    sew_half_edges(&cmap, half_edges)?;
+    Ok(cmap)
+}
+
+/// Return the non-empty lines located between `start` & `end`, or `None` if the section
+/// is missing or unterminated.
+fn msh_section<'a>(contents: &'a str, start: &str, end: &str) -> Option<Vec<&'a str>> {
+    let mut lines = contents
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| *line != start);
+    lines.next()?;
+    let mut res = Vec::new();
+    for line in lines {
+        if line == end {
+            return Some(res);
+        }
+        if !line.is_empty() {
+            res.push(line);
+        }
+    }
+    None
+}
+
+/// Parse a single value, returning a `BadMshData` error with the given message on failure.
+fn parse_msh<V: std::str::FromStr>(item: &str, err: &'static str) -> Result<V, BuilderError> {
+    item.parse().map_err(|_| BuilderError::BadMshData(err))
+}
+
+// --- medit building routine
+
+/// **MEDIT constructor**
+impl<T: CoordsFloat> CMap3<T> {
+    #[allow(clippy::missing_errors_doc)]
+    /// Build a map from the MEDIT (`.mesh`) file specified by the path.
+    ///
+    /// Each tetrahedron of the file is made into a volume, and volumes sharing a face are
+    /// 3-linked. Only ASCII files are supported; surface elements and feature sections are
+    /// checked, then ignored.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method return a `Result` taking the following values:
+    /// - `Ok(map: CMap3)` if generation was successful,
+    /// - `Err(BuilderError::BadMeditData)` if the file contains inconsistent or unsupported data.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the file cannot be loaded.
+    pub fn from_medit_file(
+        file_path: impl AsRef<std::path::Path> + std::fmt::Debug,
+    ) -> Result<Self, BuilderError> {
+        let medit_file = std::fs::read_to_string(&file_path)
+            .unwrap_or_else(|e| panic!("E: failed to load file {file_path:?}: {e:?}"));
+        build_3d_from_medit(&medit_file)
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+/// Internal building routine for [`CMap3::from_medit_file`].
+///
+/// # Result / Errors
+///
+/// This implementation only supports ASCII files describing 3D meshes. Vertices & tetrahedra
+/// are used to build the map; surface elements (`Edges`, `Triangles`, `Quadrilaterals`) and
+/// feature sections (`Corners`, `Ridges`, ...) are checked, then ignored. References are
+/// ignored. This function may return:
+///
+/// - `Ok(CMap3)` -- The file was successfully parsed and its content made into a 3-map.
+/// - `Err(BuilderError::BadMeditData)` -- The file contains inconsistent or unsupported data,
+///   i.e.:
+///     - the mesh dimension isn't 3, or isn't specified before vertices,
+///     - a section is incomplete or contains invalid values,
+///     - an element references an undefined vertex, or has repeated vertices,
+///     - the file contains unsupported sections, or volumes that aren't tetrahedra,
+///     - a face is shared by more than two tetrahedra, or by two tetrahedra with inconsistent
+///       orientations.
+pub fn build_3d_from_medit<T: CoordsFloat>(contents: &str) -> Result<CMap3<T>, BuilderError> {
+    // comments start with `#` and end with the line
+    let mut tokens = contents.lines().flat_map(|line| {
+        line.split('#')
+            .next()
+            .unwrap_or_default()
+            .split_whitespace()
+    });
+
+    let mut dimension: Option<usize> = None;
+    let mut vertices: Vec<Vertex3<T>> = Vec::new();
+    let mut tets: Vec<[usize; 4]> = Vec::new();
+    while let Some(keyword) = tokens.next() {
+        let n_values_per_item = match keyword {
+            "MeshVersionFormatted" => {
+                parse_medit::<usize>(tokens.next(), "invalid format version")?;
+                continue;
+            }
+            "Dimension" => {
+                let dim = parse_medit(tokens.next(), "invalid dimension")?;
+                if_predicate_return_err!(
+                    dim != 3,
+                    BuilderError::BadMeditData("only 3D meshes are supported")
+                );
+                dimension = Some(dim);
+                continue;
+            }
+            "End" => break,
+            // vertices: x, y, z, ref; elements: vertex indices, ref
+            "Edges" => 3,
+            "Vertices" | "Triangles" => 4,
+            "Tetrahedra" | "Quadrilaterals" => 5,
+            "Corners" | "RequiredVertices" | "Ridges" | "RequiredEdges" => 1,
+            "Hexahedra" | "Prisms" | "Pyramids" => {
+                return Err(BuilderError::BadMeditData(
+                    "non-tetrahedral volumes aren't supported",
+                ))
+            }
+            _ => return Err(BuilderError::BadMeditData("unsupported section")),
+        };
+        let n_items: usize = parse_medit(tokens.next(), "invalid number of items")?;
+        for _ in 0..n_items {
+            let item: Vec<&str> = tokens.by_ref().take(n_values_per_item).collect();
+            if_predicate_return_err!(
+                item.len() != n_values_per_item,
+                BuilderError::BadMeditData("incomplete section")
+            );
+            match keyword {
+                "Vertices" => {
+                    if_predicate_return_err!(
+                        dimension.is_none(),
+                        BuilderError::BadMeditData("dimension must be specified before vertices")
+                    );
+                    let [x, y, z] = [item[0], item[1], item[2]]
+                        .map(|coord| parse_medit::<f64>(Some(coord), "invalid vertex coordinate"));
+                    vertices.push(Vertex3(
+                        T::from(x?).unwrap(),
+                        T::from(y?).unwrap(),
+                        T::from(z?).unwrap(),
+                    ));
+                }
+                "Corners" | "RequiredVertices" | "Ridges" | "RequiredEdges" => {
+                    parse_medit::<usize>(Some(item[0]), "invalid item index")?;
+                }
+                _ => {
+                    // element: vertex indices followed by a reference
+                    let vids = item[..n_values_per_item - 1]
+                        .iter()
+                        .map(|vid| {
+                            let vid: usize = parse_medit(Some(vid), "invalid vertex index")?;
+                            if_predicate_return_err!(
+                                vid == 0 || vid > vertices.len(),
+                                BuilderError::BadMeditData(
+                                    "element references an undefined vertex"
+                                )
+                            );
+                            Ok(vid - 1)
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    if_predicate_return_err!(
+                        (0..vids.len()).any(|i| vids[i + 1..].contains(&vids[i])),
+                        BuilderError::BadMeditData("element with repeated vertices")
+                    );
+                    if keyword == "Tetrahedra" {
+                        tets.push([vids[0], vids[1], vids[2], vids[3]]);
+                    }
+                }
+            }
+        }
+    }
+
+    // build tetrahedra
+    let cmap: CMap3<T> = CMap3::new(12 * tets.len());
+    let mut face_buffer: BTreeMap<(usize, usize, usize), DartIdType> = BTreeMap::new();
+    for (i, &[a, b, c, d]) in tets.iter().enumerate() {
+        let d0 = (12 * i) as DartIdType;
+        // faces: (A, B, C), (B, A, D), (A, C, D), (C, B, D)
+        for (f, face) in [[a, b, c], [b, a, d], [a, c, d], [c, b, d]]
+            .iter()
+            .enumerate()
+        {
+            let fd = d0 + 1 + 3 * f as DartIdType;
+            cmap.force_link::<1>(fd, fd + 1);
+            cmap.force_link::<1>(fd + 1, fd + 2);
+            cmap.force_link::<1>(fd + 2, fd);
+            // record a trace of the built face for future 3-link; each dart is identified by its
+            // source, target, and the remaining vertex of the face
+            for k in 0..3 {
+                let key = (face[k], face[(k + 1) % 3], face[(k + 2) % 3]);
+                if_predicate_return_err!(
+                    face_buffer.insert(key, fd + k as DartIdType).is_some(),
+                    BuilderError::BadMeditData(
+                        "face shared by more than two tetrahedra or with inconsistent orientations"
+                    )
+                );
+            }
+        }
+        for (l, r) in [(1, 4), (3, 7), (5, 9), (2, 10), (6, 11), (8, 12)] {
+            cmap.force_link::<2>(d0 + l, d0 + r);
+        }
+    }
+    while let Some(((id0, id1, id2), dart_id0)) = face_buffer.pop_first() {
+        if cmap.beta::<3>(dart_id0) != NULL_DART_ID {
+            continue;
+        }
+        if let Some(dart_id1) = face_buffer.get(&(id1, id0, id2)) {
+            cmap.force_link::<3>(dart_id0, *dart_id1);
+        }
+    }
+
+    // insert vertex values once the topology is complete
+    for (i, tet) in tets.iter().enumerate() {
+        let d0 = (12 * i) as DartIdType;
+        // darts starting from A, B, C & D
+        for (dart, vid) in [d0 + 1, d0 + 2, d0 + 3, d0 + 6].into_iter().zip(tet) {
+            cmap.force_write_vertex(cmap.vertex_id(dart), vertices[*vid]);
+        }
+    }
     Ok(cmap)
 }
+
+/// Parse a single value, returning a `BadMeditData` error with the given message on failure.
+fn parse_medit<V: std::str::FromStr>(
+    item: Option<&str>,
+    err: &'static str,
+) -> Result<V, BuilderError> {
+    item.and_then(|item| item.parse().ok())
+        .ok_or(BuilderError::BadMeditData(err))
+}
+
+// --- stl building routine
+
+/// **STL constructor**
+impl<T: CoordsFloat> CMap3<T> {
+    #[allow(clippy::missing_errors_doc)]
+    /// Build a surface map from the STL file specified by the path.
+    ///
+    /// Each triangle of the file is made into a face, and faces sharing an edge are 2-linked; the
+    /// map contains no volume, i.e. all darts are 3-free. Vertices closer than `weld_tolerance`
+    /// are welded into a single vertex, so that triangles of the surface can be connected. Both
+    /// ASCII and binary files are supported; degenerate and duplicate triangles are ignored.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method return a `Result` taking the following values:
+    /// - `Ok(map: CMap3)` if generation was successful,
+    /// - `Err(BuilderError::BadStlData)` if the file contains inconsistent or unsupported data,
+    /// - `Err(BuilderError::NonManifold)` if an edge is shared by more than two triangles.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the file cannot be loaded.
+    pub fn from_stl_file(
+        file_path: impl AsRef<std::path::Path> + std::fmt::Debug,
+        weld_tolerance: T,
+    ) -> Result<Self, BuilderError> {
+        let stl_file = std::fs::read(&file_path)
+            .unwrap_or_else(|e| panic!("E: failed to load file {file_path:?}: {e:?}"));
+        build_3d_from_stl(&stl_file, weld_tolerance)
+    }
+}
+
+/// Size of the header of binary STL files, including the number of triangles.
+const STL_HEADER_SIZE: usize = 84;
+/// Size of a triangle record in binary STL files.
+const STL_TRIANGLE_SIZE: usize = 50;
+
+/// Internal building routine for [`CMap3::from_stl_file`].
+///
+/// # Result / Errors
+///
+/// Both ASCII and binary files are supported; a file is read as binary if its size matches the
+/// number of triangles given in its header, if it doesn't start with `solid`, or if it contains
+/// null bytes. Normals and attribute bytes are ignored.
+///
+/// Vertices are welded in order of appearance: a vertex located within `weld_tolerance` of a
+/// previous one is replaced by it. With a zero tolerance, only identical vertices are welded.
+/// Triangles that are degenerate once welded are ignored, as are duplicate triangles, i.e.
+/// triangles made of the same vertices as a previous one. This function may return:
+///
+/// - `Ok(CMap3)` -- The file was successfully parsed and its content made into a 3-map.
+/// - `Err(BuilderError::BadStlData)` -- The file contains inconsistent or unsupported data,
+///   i.e.:
+///     - a binary file is truncated, or its size doesn't match the number of triangles given in
+///       its header,
+///     - an ASCII file isn't valid UTF-8, is truncated, or contains invalid values,
+///     - a facet doesn't have exactly three vertices,
+///     - two triangles sharing an edge have inconsistent orientations.
+/// - `Err(BuilderError::NonManifold)` -- An edge is shared by more than two triangles.
+pub fn build_3d_from_stl<T: CoordsFloat>(
+    contents: &[u8],
+    weld_tolerance: T,
+) -> Result<CMap3<T>, BuilderError> {
+    let triangles = if is_binary_stl(contents) {
+        parse_binary_stl(contents)?
+    } else {
+        parse_ascii_stl(contents)?
+    };
+
+    // weld vertices & filter triangles
+    let tolerance = weld_tolerance.to_f64().unwrap_or_default();
+    let mut welder = VertexWelder::new(tolerance);
+    let mut seen: HashSet<[usize; 3]> = HashSet::new();
+    let faces: Vec<[usize; 3]> = triangles
+        .iter()
+        .filter_map(|triangle| {
+            let face = triangle.map(|v| welder.insert(v));
+            let [a, b, c] = face;
+            if a == b || b == c || c == a {
+                return None;
+            }
+            let mut key = face;
+            key.sort_unstable();
+            seen.insert(key).then_some(face)
+        })
+        .collect();
+
+    // build triangles
+    let cmap: CMap3<T> = CMap3::new(3 * faces.len());
+    let mut half_edges: Vec<((usize, usize), DartIdType)> = Vec::with_capacity(3 * faces.len());
+    for (i, face) in faces.iter().enumerate() {
+        let d0 = (3 * i) as DartIdType + 1;
+        cmap.force_link::<1>(d0, d0 + 1);
+        cmap.force_link::<1>(d0 + 1, d0 + 2);
+        cmap.force_link::<1>(d0 + 2, d0);
+        for k in 0..3 {
+            half_edges.push(((face[k], face[(k + 1) % 3]), d0 + k as DartIdType));
+        }
+    }
+    check_manifold(&half_edges)?;
+    let mut link_buffer: BTreeMap<(usize, usize), DartIdType> = BTreeMap::new();
+    for (edge, dart) in half_edges {
+        if_predicate_return_err!(
+            link_buffer.insert(edge, dart).is_some(),
+            BuilderError::BadStlData("triangles with inconsistent orientations")
+        );
+    }
+    while let Some(((id0, id1), dart_id0)) = link_buffer.pop_first() {
+        if let Some(dart_id1) = link_buffer.remove(&(id1, id0)) {
+            cmap.force_link::<2>(dart_id0, dart_id1);
+        }
+    }
+
+    // insert vertex values once the topology is complete
+    for (i, face) in faces.iter().enumerate() {
+        let d0 = (3 * i) as DartIdType + 1;
+        for (k, vid) in face.iter().enumerate() {
+            let [x, y, z] = welder.vertices[*vid];
+            cmap.force_write_vertex(
+                cmap.vertex_id(d0 + k as DartIdType),
+                Vertex3(
+                    T::from(x).unwrap(),
+                    T::from(y).unwrap(),
+                    T::from(z).unwrap(),
+                ),
+            );
+        }
+    }
+    Ok(cmap)
+}
+
+/// Return `true` if the content should be read as a binary STL file.
+fn is_binary_stl(contents: &[u8]) -> bool {
+    // binary headers may also start with `solid`, so rely on the size of the file first
+    if let Some(n_triangles) = contents.get(80..STL_HEADER_SIZE) {
+        let n_triangles = u32::from_le_bytes(n_triangles.try_into().unwrap()) as usize;
+        if STL_HEADER_SIZE + STL_TRIANGLE_SIZE * n_triangles == contents.len() {
+            return true;
+        }
+    }
+    // otherwise, binary files can usually be told apart by the null bytes they contain
+    !contents.trim_ascii_start().starts_with(b"solid") || contents.contains(&0)
+}
+
+/// Read the triangles of a binary STL file.
+fn parse_binary_stl(contents: &[u8]) -> Result<Vec<[[f64; 3]; 3]>, BuilderError> {
+    let Some(n_triangles) = contents.get(80..STL_HEADER_SIZE) else {
+        return Err(BuilderError::BadStlData("truncated binary header"));
+    };
+    let n_triangles = u32::from_le_bytes(n_triangles.try_into().unwrap()) as usize;
+    let records = &contents[STL_HEADER_SIZE..];
+    if_predicate_return_err!(
+        records.len() < STL_TRIANGLE_SIZE * n_triangles,
+        BuilderError::BadStlData("truncated file")
+    );
+    if_predicate_return_err!(
+        records.len() != STL_TRIANGLE_SIZE * n_triangles,
+        BuilderError::BadStlData("different # of triangles in header and file")
+    );
+    Ok(records
+        .chunks_exact(STL_TRIANGLE_SIZE)
+        .map(|record| {
+            // record layout: normal, 3 vertices, attribute byte count
+            let coord = |i: usize| {
+                let offset = 12 + 4 * i;
+                f64::from(f32::from_le_bytes(
+                    record[offset..offset + 4].try_into().unwrap(),
+                ))
+            };
+            [0, 1, 2].map(|v| [coord(3 * v), coord(3 * v + 1), coord(3 * v + 2)])
+        })
+        .collect())
+}
+
+/// Read the triangles of an ASCII STL file.
+fn parse_ascii_stl(contents: &[u8]) -> Result<Vec<[[f64; 3]; 3]>, BuilderError> {
+    let contents = std::str::from_utf8(contents)
+        .map_err(|_| BuilderError::BadStlData("invalid ASCII data"))?;
+    let mut tokens = contents.split_whitespace();
+    let mut triangles = Vec::new();
+    // the current facet, if any
+    let mut facet: Option<Vec<[f64; 3]>> = None;
+    let mut ended = false;
+    while let Some(token) = tokens.next() {
+        match token {
+            "solid" => ended = false,
+            "endsolid" => ended = true,
+            "facet" => {
+                if_predicate_return_err!(
+                    facet.is_some(),
+                    BuilderError::BadStlData("unterminated facet")
+                );
+                facet = Some(Vec::with_capacity(3));
+            }
+            "vertex" => {
+                let Some(vertices) = facet.as_mut() else {
+                    return Err(BuilderError::BadStlData("vertex outside of a facet"));
+                };
+                let mut coord = || {
+                    tokens
+                        .next()
+                        .and_then(|item| item.parse::<f64>().ok())
+                        .ok_or(BuilderError::BadStlData("invalid vertex coordinate"))
+                };
+                vertices.push([coord()?, coord()?, coord()?]);
+            }
+            "endfacet" => {
+                let Some(vertices) = facet.take() else {
+                    return Err(BuilderError::BadStlData("unexpected facet end"));
+                };
+                let &[v0, v1, v2] = vertices.as_slice() else {
+                    return Err(BuilderError::BadStlData(
+                        "facet with incorrect # of vertices (!=3)",
+                    ));
+                };
+                triangles.push([v0, v1, v2]);
+            }
+            // solid names, normals & loop delimiters
+            _ => {}
+        }
+    }
+    if_predicate_return_err!(
+        facet.is_some() || !ended,
+        BuilderError::BadStlData("truncated file")
+    );
+    Ok(triangles)
+}
+
+/// Helper structure used to weld vertices closer than a given tolerance.
+///
+/// Vertices are hashed on a grid whose cells are as large as the tolerance, so that a vertex
+/// only needs to be compared to those of neighboring cells.
+struct VertexWelder {
+    tolerance: f64,
+    vertices: Vec<[f64; 3]>,
+    grid: HashMap<[i64; 3], Vec<usize>>,
+}
+
+impl VertexWelder {
+    fn new(tolerance: f64) -> Self {
+        Self {
+            tolerance: tolerance.max(0.0),
+            vertices: Vec::new(),
+            grid: HashMap::new(),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    fn cell(&self, vertex: [f64; 3]) -> [i64; 3] {
+        if self.tolerance > 0.0 {
+            // `as` saturates, which takes care of overflows
+            vertex.map(|c| (c / self.tolerance).floor() as i64)
+        } else {
+            // identical vertices only; +0.0 makes both zeros share the same bits
+            vertex.map(|c| (c + 0.0).to_bits() as i64)
+        }
+    }
+
+    /// Return the index of the vertex `vertex` is welded to, inserting it if needed.
+    fn insert(&mut self, vertex: [f64; 3]) -> usize {
+        let cell = self.cell(vertex);
+        if self.tolerance > 0.0 {
+            let tol2 = self.tolerance * self.tolerance;
+            let offsets = (0..27).map(|n| [n / 9 - 1, (n / 3) % 3 - 1, n % 3 - 1]);
+            for offset in offsets {
+                let key = [0, 1, 2].map(|c| cell[c].saturating_add(offset[c]));
+                let close = self.grid.get(&key).and_then(|ids| {
+                    ids.iter().copied().find(|id| {
+                        let other = self.vertices[*id];
+                        (0..3).map(|c| (other[c] - vertex[c]).powi(2)).sum::<f64>() <= tol2
+                    })
+                });
+                if let Some(id) = close {
+                    return id;
+                }
+            }
+        } else if let Some(id) = self.grid.get(&cell).and_then(|ids| ids.first()) {
+            return *id;
+        }
+        let id = self.vertices.len();
+        self.vertices.push(vertex);
+        self.grid.entry(cell).or_default().push(id);
+        id
+    }
+}
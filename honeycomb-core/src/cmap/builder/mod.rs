@@ -4,11 +4,13 @@
 
 pub mod grid;
 pub mod io;
+pub mod soup;
 pub mod structure;
 
 // ------ RE-EXPORTS
 
-pub use grid::GridDescriptor;
+pub use grid::{CellShape, GridDescriptor};
+pub use io::PhysicalGroup;
 pub use structure::{BuilderError, CMapBuilder};
 
 // ------ CONTENT
@@ -0,0 +1,422 @@
+//! Triangle soup building routines
+//!
+//! This module contains constructors building a [`CMap2`] from a raw list of vertices and a list
+//! of triangles (or polygons) indexing into it, as well as the inverse export routines for
+//! [`CMap2`] and [`CMap3`].
+
+// ------ IMPORTS
+
+use crate::attributes::AttrStorageManager;
+use crate::cmap::builder::io::check_manifold;
+use crate::cmap::{CMapError, CMapResult, Orbit3};
+use crate::geometry::{CoordsFloat, Vertex3};
+use crate::prelude::{
+    BuilderError, CMap2, CMap3, DartIdType, OrbitPolicy, Vertex2, VertexIdType, NULL_DART_ID,
+};
+
+use std::collections::BTreeMap;
+
+#[cfg(feature = "par-internals")]
+use rayon::prelude::*;
+
+// ------ CONTENT
+
+/// **Triangle soup constructors**
+impl<T: CoordsFloat> CMap2<T> {
+    /// Build a 2-map from a triangle soup.
+    ///
+    /// Each triangle is built using three darts; the darts of triangle `i` are `3*i+1`, `3*i+2`
+    /// & `3*i+3`, dart `3*i+k+1` going from vertex `triangles[i][k]` to `triangles[i][(k+1)%3]`.
+    /// Triangles sharing an edge are then 2-sewn together.
+    ///
+    /// # Arguments
+    ///
+    /// - `vertices: &[Vertex2<T>]` -- Vertices of the mesh.
+    /// - `triangles: &[[usize; 3]]` -- Triangles of the mesh, as indices into `vertices`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a `BuilderError::BadSoupData` if:
+    /// - a triangle references a vertex that isn't in `vertices`,
+    /// - a triangle uses the same vertex more than once,
+    /// - the same oriented edge is used by more than one triangle, i.e. the mesh is either
+    ///   non-manifold or inconsistently oriented.
+    ///
+    /// # Panics
+    ///
+    /// Triangles are expected to be consistently oriented. Sewing two triangles with opposite
+    /// orientations will result in a panic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use honeycomb_core::prelude::BuilderError;
+    /// # fn main() -> Result<(), BuilderError> {
+    /// use honeycomb_core::prelude::{CMap2, Vertex2};
+    ///
+    /// let vertices = [
+    ///     Vertex2(0.0, 0.0),
+    ///     Vertex2(1.0, 0.0),
+    ///     Vertex2(1.0, 1.0),
+    ///     Vertex2(0.0, 1.0),
+    /// ];
+    /// let triangles = [[0, 1, 2], [0, 2, 3]];
+    ///
+    /// let map: CMap2<f64> = CMap2::from_triangle_soup(&vertices, &triangles)?;
+    ///
+    /// assert_eq!(map.iter_faces().count(), 2);
+    /// assert_eq!(map.iter_edges().count(), 5);
+    /// assert_eq!(map.iter_vertices().count(), 4);
+    /// assert_eq!(map.beta::<2>(3), 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_triangle_soup(
+        vertices: &[Vertex2<T>],
+        triangles: &[[usize; 3]],
+    ) -> Result<Self, BuilderError> {
+        triangles
+            .iter()
+            .try_for_each(|tri| check_triangle(vertices.len(), tri))?;
+
+        let map = CMap2::new(3 * triangles.len());
+        let mut sew_buffer: BTreeMap<(usize, usize), DartIdType> = BTreeMap::new();
+        for (i, tri) in triangles.iter().enumerate() {
+            let d0 = first_dart(i);
+            for (k, dart_id) in (d0..d0 + 3).enumerate() {
+                if sew_buffer
+                    .insert((tri[k], tri[(k + 1) % 3]), dart_id)
+                    .is_some()
+                {
+                    return Err(BuilderError::BadSoupData(
+                        "oriented edge used by more than one triangle",
+                    ));
+                }
+            }
+            build_triangle(&map, d0, tri, vertices);
+        }
+        while let Some(((id0, id1), dart_id0)) = sew_buffer.pop_first() {
+            if let Some(dart_id1) = sew_buffer.remove(&(id1, id0)) {
+                map.force_sew::<2>(dart_id0, dart_id1);
+            }
+        }
+
+        Ok(map)
+    }
+
+    #[cfg(feature = "par-internals")]
+    /// Build a 2-map from a triangle soup, in parallel.
+    ///
+    /// This function yields the same map as [`CMap2::from_triangle_soup`], but is intended for
+    /// very large meshes:
+    /// - triangles are built concurrently; this is conflict-free since each triangle uses its
+    ///   own set of darts,
+    /// - shared edges are found by sorting the oriented edges of the mesh in parallel instead of
+    ///   using a sequential sew buffer,
+    /// - 2-sews are executed concurrently, each in its own transaction.
+    ///
+    /// # Arguments
+    ///
+    /// - `vertices: &[Vertex2<T>]` -- Vertices of the mesh.
+    /// - `triangles: &[[usize; 3]]` -- Triangles of the mesh, as indices into `vertices`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error in the same cases as
+    /// [`CMap2::from_triangle_soup`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic in the same cases as [`CMap2::from_triangle_soup`].
+    pub fn from_triangle_soup_par(
+        vertices: &[Vertex2<T>],
+        triangles: &[[usize; 3]],
+    ) -> Result<Self, BuilderError> {
+        triangles
+            .par_iter()
+            .try_for_each(|tri| check_triangle(vertices.len(), tri))?;
+
+        // sort oriented edges so that duplicates & opposite edges can be found by searching
+        let mut half_edges: Vec<((usize, usize), DartIdType)> = triangles
+            .par_iter()
+            .enumerate()
+            .flat_map_iter(|(i, tri)| {
+                let d0 = first_dart(i);
+                (0..3).map(move |k| ((tri[k], tri[(k + 1) % 3]), d0 + k as DartIdType))
+            })
+            .collect();
+        half_edges.par_sort_unstable_by_key(|(key, _)| *key);
+        if half_edges.par_windows(2).any(|w| w[0].0 == w[1].0) {
+            return Err(BuilderError::BadSoupData(
+                "oriented edge used by more than one triangle",
+            ));
+        }
+
+        let map = CMap2::new(3 * triangles.len());
+        triangles
+            .par_iter()
+            .enumerate()
+            .for_each(|(i, tri)| build_triangle(&map, first_dart(i), tri, vertices));
+        half_edges
+            .par_iter()
+            .filter(|((id0, id1), _)| id0 < id1)
+            .for_each(|((id0, id1), dart_id0)| {
+                if let Ok(idx) = half_edges.binary_search_by_key(&(*id1, *id0), |(key, _)| *key) {
+                    map.force_sew::<2>(*dart_id0, half_edges[idx].1);
+                }
+            });
+
+        Ok(map)
+    }
+}
+
+/// Internal building routine for
+/// [`CMapBuilder::from_connectivity`][crate::prelude::CMapBuilder::from_connectivity].
+///
+/// # Errors
+///
+/// This function will return a `BuilderError::BadConnectivityData` if:
+/// - a face references a vertex that isn't in `vertices`,
+/// - a face has fewer than three vertices,
+/// - the same oriented edge is used by two faces.
+///
+/// It will return a `BuilderError::NonManifold` if an edge is shared by more than two faces. This
+/// check is done first, over all faces.
+pub fn build_2d_from_connectivity<T: CoordsFloat>(
+    vertices: &[Vertex2<T>],
+    faces: &[Vec<usize>],
+    manager: AttrStorageManager,
+) -> Result<CMap2<T>, BuilderError> {
+    if faces
+        .iter()
+        .any(|face| face.iter().any(|id| *id >= vertices.len()))
+    {
+        return Err(BuilderError::BadConnectivityData(
+            "face references an undefined vertex",
+        ));
+    }
+    if faces.iter().any(|face| face.len() < 3) {
+        return Err(BuilderError::BadConnectivityData(
+            "face with fewer than three vertices",
+        ));
+    }
+
+    // half-edges of all faces are checked before building anything, so that the reported error
+    // doesn't depend on the order of faces
+    let mut half_edges: Vec<((usize, usize), DartIdType)> = Vec::new();
+    let mut d0: DartIdType = 1;
+    for face in faces {
+        let n_vertices = face.len();
+        half_edges.extend(
+            (d0..)
+                .take(n_vertices)
+                .enumerate()
+                .map(|(i, dart_id)| ((face[i], face[(i + 1) % n_vertices]), dart_id)),
+        );
+        d0 += n_vertices as DartIdType;
+    }
+    check_manifold(&half_edges)?;
+    let mut sew_buffer: BTreeMap<(usize, usize), DartIdType> = BTreeMap::new();
+    for (edge, dart_id) in &half_edges {
+        if sew_buffer.insert(*edge, *dart_id).is_some() {
+            return Err(BuilderError::BadConnectivityData(
+                "oriented edge used by more than one face",
+            ));
+        }
+    }
+
+    let map: CMap2<T> = CMap2::new_with_undefined_attributes(half_edges.len(), manager);
+    let mut d0: DartIdType = 1;
+    for face in faces {
+        let n_vertices = face.len();
+        for (i, dart_id) in (d0..).take(n_vertices).enumerate() {
+            let next = if i == n_vertices - 1 { d0 } else { dart_id + 1 };
+            map.force_write_vertex(dart_id as VertexIdType, vertices[face[i]]);
+            map.force_link::<1>(dart_id, next);
+        }
+        d0 += n_vertices as DartIdType;
+    }
+    while let Some(((id0, id1), dart_id0)) = sew_buffer.pop_first() {
+        if let Some(dart_id1) = sew_buffer.remove(&(id1, id0)) {
+            map.force_sew::<2>(dart_id0, dart_id1);
+        }
+    }
+
+    Ok(map)
+}
+
+/// **Triangle soup export**
+impl<T: CoordsFloat> CMap2<T> {
+    #[allow(clippy::missing_errors_doc, clippy::type_complexity)]
+    /// Export the map as a triangle soup.
+    ///
+    /// This is the inverse of [`CMap2::from_triangle_soup`]: each vertex of the map is listed
+    /// once, in increasing order of vertex ID, and each face is exported as a triangle indexing
+    /// into the vertex list. Triangles are listed in increasing order of face ID, starting from
+    /// the vertex of the dart holding the face ID, and following the orientation of the face.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method returns a tuple containing the vertex list and the triangle list.
+    ///
+    /// It will return a `CMapError::IncorrectGeometry` if:
+    /// - a face of the map isn't a closed triangle,
+    /// - a vertex of the map has no associated value.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the number of vertices cannot be represented using a `u32`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use honeycomb_core::prelude::BuilderError;
+    /// # fn main() -> Result<(), BuilderError> {
+    /// use honeycomb_core::prelude::{CMap2, Vertex2};
+    ///
+    /// let vertices = [Vertex2(0.0, 0.0), Vertex2(1.0, 0.0), Vertex2(0.0, 1.0)];
+    /// let map: CMap2<f64> = CMap2::from_triangle_soup(&vertices, &[[0, 1, 2]])?;
+    ///
+    /// let (out_vertices, out_triangles) = map.to_triangle_soup().unwrap();
+    ///
+    /// assert_eq!(&out_vertices, &vertices);
+    /// assert_eq!(&out_triangles, &[[0, 1, 2]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_triangle_soup(&self) -> CMapResult<(Vec<Vertex2<T>>, Vec<[u32; 3]>)> {
+        let mut indices: BTreeMap<VertexIdType, u32> = BTreeMap::new();
+        let vertices = self
+            .iter_vertices()
+            .enumerate()
+            .map(|(idx, vid)| {
+                indices.insert(vid, u32::try_from(idx).expect("E: too many vertices"));
+                self.force_read_vertex(vid)
+                    .ok_or(CMapError::IncorrectGeometry("undefined vertex"))
+            })
+            .collect::<CMapResult<Vec<_>>>()?;
+
+        let triangles = self
+            .iter_faces()
+            .map(|fid| {
+                let d0 = fid as DartIdType;
+                let d1 = self.beta::<1>(d0);
+                let d2 = self.beta::<1>(d1);
+                if [d1, d2].iter().any(|d| *d == NULL_DART_ID || *d == d0)
+                    || self.beta::<1>(d2) != d0
+                {
+                    return Err(CMapError::IncorrectGeometry("non-triangular face"));
+                }
+                Ok([d0, d1, d2].map(|d| indices[&self.vertex_id(d)]))
+            })
+            .collect::<CMapResult<Vec<_>>>()?;
+
+        Ok((vertices, triangles))
+    }
+}
+
+/// **Tetrahedron soup export**
+impl<T: CoordsFloat> CMap3<T> {
+    #[allow(clippy::missing_errors_doc, clippy::type_complexity)]
+    /// Export the map as a tetrahedron soup.
+    ///
+    /// Each vertex of the map is listed once, in increasing order of vertex ID, and each volume
+    /// is exported as a tetrahedron indexing into the vertex list, in increasing order of volume
+    /// ID. Given `d` the dart holding the volume ID, the tetrahedron's vertices are those of
+    /// darts `d`, `β1(d)`, `β0(d)`, and `β0(β2(d))`, meaning that a tetrahedron with a positive
+    /// orientation has a positive signed volume.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method returns a tuple containing the vertex list and the tetrahedron list.
+    ///
+    /// It will return a `CMapError::IncorrectGeometry` if:
+    /// - a volume of the map isn't a closed tetrahedron,
+    /// - a vertex of the map has no associated value.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the number of vertices cannot be represented using a `u32`.
+    pub fn to_tet_soup(&self) -> CMapResult<(Vec<Vertex3<T>>, Vec<[u32; 4]>)> {
+        let mut indices: BTreeMap<VertexIdType, u32> = BTreeMap::new();
+        let vertices = self
+            .iter_vertices()
+            .enumerate()
+            .map(|(idx, vid)| {
+                indices.insert(vid, u32::try_from(idx).expect("E: too many vertices"));
+                self.force_read_vertex(vid)
+                    .ok_or(CMapError::IncorrectGeometry("undefined vertex"))
+            })
+            .collect::<CMapResult<Vec<_>>>()?;
+
+        let tets = self
+            .iter_volumes()
+            .map(|vid| {
+                let d = vid as DartIdType;
+                if !self.is_tet(d) {
+                    return Err(CMapError::IncorrectGeometry("non-tetrahedral volume"));
+                }
+                let corners = [
+                    d,
+                    self.beta::<1>(d),
+                    self.beta::<0>(d),
+                    self.beta::<0>(self.beta::<2>(d)),
+                ]
+                .map(|dart| indices[&self.vertex_id(dart)]);
+                if (0..4).any(|i| (i + 1..4).any(|j| corners[i] == corners[j])) {
+                    return Err(CMapError::IncorrectGeometry("non-tetrahedral volume"));
+                }
+                Ok(corners)
+            })
+            .collect::<CMapResult<Vec<_>>>()?;
+
+        Ok((vertices, tets))
+    }
+
+    /// Return `true` if the volume of `d` is made of four closed triangles.
+    fn is_tet(&self, d: DartIdType) -> bool {
+        let mut n_darts = 0;
+        Orbit3::new(self, OrbitPolicy::Volume, d).all(|dart| {
+            n_darts += 1;
+            let (b1, b2) = (self.beta::<1>(dart), self.beta::<2>(dart));
+            b1 != dart
+                && b1 != NULL_DART_ID
+                && b2 != NULL_DART_ID
+                && self.beta::<1>(self.beta::<1>(b1)) == dart
+        }) && n_darts == 12
+    }
+}
+
+/// Return the first dart of the `i`-th triangle of the soup.
+fn first_dart(i: usize) -> DartIdType {
+    (3 * i + 1) as DartIdType
+}
+
+/// Check that a triangle of the soup is valid.
+fn check_triangle(n_vertices: usize, tri: &[usize; 3]) -> Result<(), BuilderError> {
+    if tri.iter().any(|id| *id >= n_vertices) {
+        return Err(BuilderError::BadSoupData(
+            "triangle references an undefined vertex",
+        ));
+    }
+    if tri[0] == tri[1] || tri[1] == tri[2] || tri[2] == tri[0] {
+        return Err(BuilderError::BadSoupData("triangle with repeated vertices"));
+    }
+    Ok(())
+}
+
+/// Build a free triangle using darts `d0`, `d0+1` & `d0+2`.
+fn build_triangle<T: CoordsFloat>(
+    map: &CMap2<T>,
+    d0: DartIdType,
+    tri: &[usize; 3],
+    vertices: &[Vertex2<T>],
+) {
+    let (d1, d2) = (d0 + 1, d0 + 2);
+    map.force_write_vertex(d0 as VertexIdType, vertices[tri[0]]);
+    map.force_write_vertex(d1 as VertexIdType, vertices[tri[1]]);
+    map.force_write_vertex(d2 as VertexIdType, vertices[tri[2]]);
+    map.force_link::<1>(d0, d1); // edge d0 links vertices tri[0] & tri[1]
+    map.force_link::<1>(d1, d2); // edge d1 links vertices tri[1] & tri[2]
+    map.force_link::<1>(d2, d0); // edge d2 links vertices tri[2] & tri[0]
+}
@@ -1,8 +1,12 @@
 // ------ IMPORTS
 
-use crate::prelude::{AttributeBind, CMap2, GridDescriptor};
+use crate::cmap::{ConsistencyError, DartIdType, VertexMergePolicy};
+use crate::prelude::{AttributeBind, CMap2, GridDescriptor, Vertex2};
 use crate::{attributes::AttrStorageManager, geometry::CoordsFloat};
 
+use std::fs::File;
+use std::io::BufReader;
+
 use thiserror::Error;
 use vtkio::Vtk;
 
@@ -21,6 +25,14 @@ pub enum BuilderError {
     /// The builder is missing one or multiple parameters to generate the grid.
     #[error("insufficient parameters - please specifiy at least 2")]
     MissingGridParameters,
+    /// The grid requires more darts than a map can hold.
+    #[error("grid is too large - {requested} darts requested, at most {max} supported")]
+    TooLarge {
+        /// Number of darts needed to build the grid.
+        requested: u128,
+        /// Maximum number of darts of a map.
+        max: DartIdType,
+    },
 
     // vtk-related variants
     /// Specified VTK file contains inconsistent data.
@@ -29,6 +41,42 @@ pub enum BuilderError {
     /// Specified VTK file contains unsupported data.
     #[error("unsupported data in the vtk file - {0}")]
     UnsupportedVtkData(&'static str),
+
+    // msh-related variants
+    /// Specified Gmsh file contains inconsistent or unsupported data.
+    #[error("invalid/unsupported data in the msh file - {0}")]
+    BadMshData(&'static str),
+
+    // medit-related variants
+    /// Specified MEDIT file contains inconsistent or unsupported data.
+    #[error("invalid/unsupported data in the medit file - {0}")]
+    BadMeditData(&'static str),
+
+    // stl-related variants
+    /// Specified STL file contains inconsistent or unsupported data.
+    #[error("invalid/unsupported data in the stl file - {0}")]
+    BadStlData(&'static str),
+
+    // soup-related variants
+    /// Specified triangle soup contains inconsistent data.
+    #[error("invalid/corrupted data in the triangle soup - {0}")]
+    BadSoupData(&'static str),
+
+    // connectivity-related variants
+    /// Specified connectivity arrays contain inconsistent data.
+    #[error("invalid/corrupted connectivity data - {0}")]
+    BadConnectivityData(&'static str),
+
+    // topology-related variants
+    /// An edge of the mesh, given as a pair of vertex indices, is shared by more than two faces.
+    #[error("non-manifold edge between vertices {0:?}")]
+    NonManifold((usize, usize)),
+
+    // validation-related variants
+    /// The built map failed the consistency check enabled by
+    /// [`CMapBuilder::validate_on_build`].
+    #[error("built map is inconsistent - {0:?}")]
+    InconsistentResult(Vec<ConsistencyError>),
 }
 
 /// # Combinatorial map builder structure
@@ -48,15 +96,22 @@ pub enum BuilderError {
 /// # Ok(())
 /// # }
 /// ```
+#[allow(clippy::type_complexity)]
 #[derive(Default)]
 pub struct CMapBuilder<T>
 where
     T: CoordsFloat,
 {
     pub(super) vtk_file: Option<Vtk>,
+    pub(super) vtk_stream: Option<BufReader<File>>,
+    pub(super) msh_file: Option<String>,
     pub(super) grid_descriptor: Option<GridDescriptor<T>>,
+    pub(super) connectivity: Option<(Vec<Vertex2<T>>, Vec<Vec<usize>>)>,
     pub(super) attributes: AttrStorageManager,
     pub(super) n_darts: usize,
+    pub(super) capacity: usize,
+    pub(super) validate: bool,
+    pub(super) vertex_merge_policy: VertexMergePolicy,
     pub(super) coordstype: std::marker::PhantomData<T>,
 }
 
@@ -80,6 +135,17 @@ impl<T: CoordsFloat> CMapBuilder<T> {
         self
     }
 
+    /// Set the number of darts that the created map can hold without reallocating its storages.
+    ///
+    /// All storages of the map, including attribute storages, are presized for the given number
+    /// of darts (excluding the null dart). This is only a hint: it has no effect if the map is
+    /// built with more darts, and darts can still be added beyond this capacity.
+    #[must_use = "unused builder object"]
+    pub fn with_capacity(mut self, n_darts: usize) -> Self {
+        self.capacity = n_darts;
+        self
+    }
+
     /// Set the [`GridDescriptor`] that will be used when building the map.
     #[must_use = "unused builder object"]
     pub fn grid_descriptor(mut self, grid_descriptor: GridDescriptor<T>) -> Self {
@@ -89,6 +155,10 @@ impl<T: CoordsFloat> CMapBuilder<T> {
 
     /// Set the VTK file that will be used when building the map.
     ///
+    /// The whole file is loaded and parsed here, so peak memory usage when building includes
+    /// both the parsed file and the map. For large meshes, consider using
+    /// [`CMapBuilder::vtk_file_streaming`] instead.
+    ///
     /// # Panics
     ///
     /// This function may panic if the file cannot be loaded.
@@ -100,6 +170,43 @@ impl<T: CoordsFloat> CMapBuilder<T> {
         self
     }
 
+    /// Set the VTK file that will be read incrementally when building the map.
+    ///
+    /// Unlike [`CMapBuilder::vtk_file`], which loads and parses the whole file before building
+    /// the map, the file is only opened here; it is then parsed while the map is built. This
+    /// avoids holding the content of the file in memory alongside the map, at the cost of
+    /// supporting fewer files: only legacy ASCII files are accepted.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the file cannot be opened.
+    #[must_use = "unused builder object"]
+    pub fn vtk_file_streaming(
+        mut self,
+        file_path: impl AsRef<std::path::Path> + std::fmt::Debug,
+    ) -> Self {
+        let file = File::open(&file_path)
+            .unwrap_or_else(|e| panic!("E: failed to open file {file_path:?}: {e:?}"));
+        self.vtk_stream = Some(BufReader::new(file));
+        self
+    }
+
+    /// Set the Gmsh file that will be used when building the map.
+    ///
+    /// Only ASCII files using the MSH 2.x format are supported. Physical groups of elements are
+    /// stored in the map using the [`PhysicalGroup`][super::io::PhysicalGroup] attribute.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the file cannot be loaded.
+    #[must_use = "unused builder object"]
+    pub fn msh_file(mut self, file_path: impl AsRef<std::path::Path> + std::fmt::Debug) -> Self {
+        let msh_file = std::fs::read_to_string(&file_path)
+            .unwrap_or_else(|e| panic!("E: failed to load file {file_path:?}: {e:?}"));
+        self.msh_file = Some(msh_file);
+        self
+    }
+
     /// Add the attribute `A` to the attributes the created map will contain.
     ///
     /// # Usage
@@ -114,7 +221,29 @@ impl<T: CoordsFloat> CMapBuilder<T> {
     /// and [here](https://doc.rust-lang.org/rust-by-example/generics/new_types.html)
     #[must_use = "unused builder object"]
     pub fn add_attribute<A: AttributeBind + 'static>(mut self) -> Self {
-        self.attributes.add_storage::<A>(self.n_darts);
+        // storages are extended to the number of darts of the map when building it
+        self.attributes.add_storage::<A>(0);
+        self
+    }
+
+    /// Enable or disable the consistency check of the built map.
+    ///
+    /// When enabled, [`CMapBuilder::build`] runs [`CMap2::check_consistency`] on the map before
+    /// returning it. This is disabled by default, as the check reads the whole map.
+    #[must_use = "unused builder object"]
+    pub fn validate_on_build(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Set the rule used by the created map to merge vertex values when sewing darts.
+    ///
+    /// The policy is set once the map is built; it doesn't affect sews performed when importing
+    /// meshes, which only merge vertices sharing the same node. See [`VertexMergePolicy`] for
+    /// available rules.
+    #[must_use = "unused builder object"]
+    pub fn vertex_merge_policy(mut self, policy: VertexMergePolicy) -> Self {
+        self.vertex_merge_policy = policy;
         self
     }
 
@@ -127,24 +256,61 @@ impl<T: CoordsFloat> CMapBuilder<T> {
     /// - `Ok(map: CMap2)` if generation was successful,
     /// - `Err(BuilderError)` otherwise. See [`BuilderError`] for possible failures.
     ///
+    /// If [`CMapBuilder::validate_on_build`] was enabled, issues detected in the built map are
+    /// returned using [`BuilderError::InconsistentResult`].
+    ///
     /// # Panics
     ///
     /// This method may panic if type casting goes wrong during parameters parsing.
     pub fn build(self) -> Result<CMap2<T>, BuilderError> {
+        let (validate, policy, capacity) = (self.validate, self.vertex_merge_policy, self.capacity);
+        let mut map = self.build_unchecked()?;
+        // `n_darts` accounts for the null dart
+        map.reserve_darts((capacity + 1).saturating_sub(map.n_darts()));
+        map.set_vertex_merge_policy(policy);
+        if validate {
+            map.check_consistency()
+                .map_err(BuilderError::InconsistentResult)?;
+        }
+        Ok(map)
+    }
+
+    /// Internal building routine, without validation.
+    fn build_unchecked(self) -> Result<CMap2<T>, BuilderError> {
         if let Some(vfile) = self.vtk_file {
             // build from vtk
             // this routine should return a Result instead of the map directly
             return super::io::build_2d_from_vtk(vfile, self.attributes);
         }
+        if let Some(vstream) = self.vtk_stream {
+            // build from vtk, parsing the file while building
+            return super::io::build_2d_from_vtk_stream(vstream, self.attributes);
+        }
+        if let Some(mfile) = self.msh_file {
+            // build from msh
+            return super::io::build_2d_from_msh(&mfile, self.attributes);
+        }
+        if let Some((vertices, faces)) = self.connectivity {
+            // build from connectivity arrays
+            return super::soup::build_2d_from_connectivity(&vertices, &faces, self.attributes);
+        }
         if let Some(gridb) = self.grid_descriptor {
             // build from grid descriptor
-            let split = gridb.split_quads;
+            let (split, shape) = (gridb.split_quads, gridb.cell_shape);
+            let [periodic_x, periodic_y, _] = gridb.periodic;
             return gridb.parse_2d().map(|(origin, ns, lens)| {
-                if split {
+                let map = if shape == super::grid::CellShape::Hexagon {
+                    super::grid::build_2d_hexgrid(origin, ns, lens, self.attributes)
+                } else if split {
                     super::grid::build_2d_splitgrid(origin, ns, lens, self.attributes)
                 } else {
                     super::grid::build_2d_grid(origin, ns, lens, self.attributes)
+                };
+                if periodic_x || periodic_y {
+                    let n_darts_per_cell = if split { 6 } else { 4 };
+                    super::grid::wrap_2d_grid(&map, ns, [periodic_x, periodic_y], n_darts_per_cell);
                 }
+                map
             });
         }
         Ok(CMap2::new_with_undefined_attributes(
@@ -156,6 +322,70 @@ impl<T: CoordsFloat> CMapBuilder<T> {
 
 /// # Pre-definite structures
 impl<T: CoordsFloat> CMapBuilder<T> {
+    /// Create a [`CMapBuilder`] for a map made of `n_darts` free darts.
+    ///
+    /// This is equivalent to setting both [`CMapBuilder::n_darts`] and
+    /// [`CMapBuilder::with_capacity`] to `n_darts`, so that storages of attributes added to the
+    /// builder are sized exactly for the built map.
+    #[must_use = "unused builder object"]
+    pub fn from_n_darts(n_darts: usize) -> Self {
+        Self::default().n_darts(n_darts).with_capacity(n_darts)
+    }
+
+    /// Create a [`CMapBuilder`] building a map from in-memory connectivity arrays.
+    ///
+    /// Faces are built in order, using one dart per vertex; the darts of a face go from vertex
+    /// `face[k]` to `face[(k+1)%n]`. Faces sharing an edge are then 2-sewn together, as is done
+    /// when building from a VTK file.
+    ///
+    /// # Arguments
+    ///
+    /// - `vertices: Vec<Vertex2<T>>` -- Vertices of the mesh.
+    /// - `faces: Vec<Vec<usize>>` -- Faces of the mesh, as lists of indices into `vertices`.
+    ///
+    /// # Return
+    ///
+    /// This function return a builder structure with pre-definite parameters set to generate
+    /// the map. Building it will fail with a [`BuilderError::BadConnectivityData`] if:
+    /// - a face references a vertex that isn't in `vertices`,
+    /// - a face has fewer than three vertices,
+    /// - an oriented edge is used by two faces, i.e. faces are inconsistently oriented.
+    ///
+    /// If an edge is shared by more than two faces, i.e. the mesh is non-manifold, building it
+    /// will fail with a [`BuilderError::NonManifold`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use honeycomb_core::prelude::BuilderError;
+    /// # fn main() -> Result<(), BuilderError> {
+    /// use honeycomb_core::prelude::{CMap2, CMapBuilder, Vertex2};
+    ///
+    /// let vertices = vec![
+    ///     Vertex2(0.0, 0.0),
+    ///     Vertex2(1.0, 0.0),
+    ///     Vertex2(2.0, 0.0),
+    ///     Vertex2(1.0, 1.0),
+    ///     Vertex2(0.0, 1.0),
+    /// ];
+    /// let faces = vec![vec![0, 1, 3, 4], vec![1, 2, 3]];
+    ///
+    /// let map: CMap2<f64> = CMapBuilder::from_connectivity(vertices, faces).build()?;
+    ///
+    /// assert_eq!(map.iter_faces().count(), 2);
+    /// assert_eq!(map.iter_edges().count(), 6);
+    /// assert_eq!(map.beta::<2>(2), 7); // (1, 3) <-> (3, 1)
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "unused builder object"]
+    pub fn from_connectivity(vertices: Vec<Vertex2<T>>, faces: Vec<Vec<usize>>) -> Self {
+        CMapBuilder {
+            connectivity: Some((vertices, faces)),
+            ..Default::default()
+        }
+    }
+
     /// Create a [`CMapBuilder`] with a predefinite [`GridDescriptor`] value.
     ///
     /// # Arguments
@@ -1,5 +1,11 @@
 use crate::attributes::AttrStorageManager;
-use crate::prelude::{CMap2, CMapBuilder, DartIdType, GridDescriptor, Orbit2, OrbitPolicy};
+use crate::cmap::PhysicalGroup;
+use crate::cmap::{CMapError, ConsistencyError};
+use crate::geometry::Vertex3;
+use crate::prelude::{
+    BuilderError, CMap2, CMap3, CMapBuilder, CellShape, DartIdType, GridDescriptor, Orbit2,
+    OrbitPolicy, Vertex2,
+};
 
 use vtkio::Vtk;
 
@@ -361,6 +367,427 @@ fn splitsquare_cmap2_correctness() {
     assert_eq!(cmap.beta::<2>(24), 0);
 }
 
+#[test]
+fn periodic_cmap2_correctness() {
+    // torus
+    let descriptor = GridDescriptor::default()
+        .n_cells([3, 2, 0])
+        .len_per_cell([1., 1., 1.])
+        .periodic([true, true, false]);
+    let cmap: CMap2<f64> = CMapBuilder::from(descriptor.clone()).build().unwrap();
+    assert!((1..=4 * 6).all(|d| !cmap.is_i_free::<2>(d)));
+    assert_eq!(cmap.iter_faces().count(), 6);
+    assert_eq!(cmap.iter_edges().count(), 12);
+    assert_eq!(cmap.iter_vertices().count(), 6);
+    assert_eq!(cmap.force_read_vertex(1), Some(Vertex2(0.0, 0.0)));
+    assert!(cmap
+        .iter_vertices()
+        .all(|vid| cmap.force_read_vertex(vid).is_some()));
+    assert_eq!(cmap.n_vertices(), 6);
+
+    // same with split quads
+    let cmap: CMap2<f64> = CMapBuilder::from(descriptor.split_quads(true))
+        .build()
+        .unwrap();
+    assert!((1..=6 * 6).all(|d| !cmap.is_i_free::<2>(d)));
+    assert_eq!(cmap.iter_faces().count(), 12);
+    assert_eq!(cmap.iter_vertices().count(), 6);
+    assert_eq!(cmap.n_vertices(), 6);
+
+    // cylinder; bottom & top boundaries stay free
+    let descriptor = GridDescriptor::default()
+        .n_cells([3, 2, 0])
+        .len_per_cell([1., 1., 1.])
+        .periodic([true, false, false]);
+    let cmap: CMap2<f64> = CMapBuilder::from(descriptor).build().unwrap();
+    assert_eq!((1..=4 * 6).filter(|d| cmap.is_i_free::<2>(*d)).count(), 6);
+    assert_eq!(cmap.iter_vertices().count(), 9);
+    assert_eq!(cmap.n_vertices(), 9);
+}
+
+#[test]
+fn periodic_invalid_parameters() {
+    let descriptor = GridDescriptor::<f64>::default()
+        .n_cells([3, 1, 0])
+        .len_per_cell([1., 1., 1.]);
+    assert!(matches!(
+        descriptor.clone().periodic([false, true, false]).parse_2d(),
+        Err(BuilderError::InvalidGridParameters(_))
+    ));
+    assert!(matches!(
+        descriptor.clone().periodic([true, false, true]).parse_2d(),
+        Err(BuilderError::InvalidGridParameters(_))
+    ));
+    assert!(descriptor.periodic([true, false, false]).parse_2d().is_ok());
+}
+
+#[test]
+fn grid_too_large() {
+    let descriptor = GridDescriptor::<f64>::default().len_per_cell([1., 1., 1.]);
+    assert!(matches!(
+        descriptor.clone().n_cells([1 << 16, 1 << 15, 0]).parse_2d(),
+        Err(BuilderError::TooLarge {
+            requested: 8_589_934_592,
+            max: DartIdType::MAX
+        })
+    ));
+    assert!(matches!(
+        descriptor
+            .clone()
+            .n_cells([usize::MAX, usize::MAX, 0])
+            .parse_2d(),
+        Err(BuilderError::TooLarge { .. })
+    ));
+    // split cells have six darts
+    assert!(matches!(
+        descriptor
+            .clone()
+            .n_cells([1 << 16, 12_000, 0])
+            .split_quads(true)
+            .parse_2d(),
+        Err(BuilderError::TooLarge { .. })
+    ));
+    assert!(descriptor.n_cells([1 << 16, 12_000, 0]).parse_2d().is_ok());
+}
+
+#[test]
+fn graded_cmap2_correctness() {
+    let descriptor = GridDescriptor::default()
+        .n_cells([3, 2, 0])
+        .len_per_cell_y(1.0_f64)
+        .len_per_cell_axis(0, vec![0.5, 1.0, 2.0]);
+    let cmap: CMap2<f64> = CMapBuilder::from(descriptor.clone()).build().unwrap();
+    assert_eq!(cmap.iter_faces().count(), 6);
+    // bottom left vertex of cells, & bottom right vertex of the last one
+    assert_eq!(cmap.force_read_vertex(1), Some(Vertex2(0.0, 0.0)));
+    assert_eq!(
+        cmap.force_read_vertex(cmap.vertex_id(5)),
+        Some(Vertex2(0.5, 0.0))
+    );
+    assert_eq!(
+        cmap.force_read_vertex(cmap.vertex_id(9)),
+        Some(Vertex2(1.5, 0.0))
+    );
+    assert_eq!(
+        cmap.force_read_vertex(cmap.vertex_id(10)),
+        Some(Vertex2(3.5, 0.0))
+    );
+    assert_eq!(
+        cmap.force_read_vertex(cmap.vertex_id(21)),
+        Some(Vertex2(1.5, 1.0))
+    );
+
+    // same with split quads
+    let cmap: CMap2<f64> = CMapBuilder::from(descriptor.split_quads(true))
+        .build()
+        .unwrap();
+    assert_eq!(cmap.iter_faces().count(), 12);
+    assert_eq!(
+        cmap.force_read_vertex(cmap.vertex_id(14)),
+        Some(Vertex2(3.5, 0.0))
+    );
+
+    // both axes graded, no uniform parameters
+    let descriptor = GridDescriptor::default()
+        .n_cells([2, 2, 0])
+        .len_per_cell_axis(0, vec![1.0_f64, 3.0])
+        .len_per_cell_axis(1, vec![2.0, 0.5]);
+    let cmap: CMap2<f64> = CMapBuilder::from(descriptor).build().unwrap();
+    assert_eq!(
+        cmap.force_read_vertex(cmap.vertex_id(15)),
+        Some(Vertex2(4.0, 2.5))
+    );
+}
+
+#[test]
+fn graded_invalid_parameters() {
+    // mismatched number of cells
+    assert!(matches!(
+        GridDescriptor::default()
+            .n_cells([3, 2, 0])
+            .len_per_cell([1.0_f64, 1.0, 1.0])
+            .len_per_cell_axis(0, vec![1.0, 2.0])
+            .parse_2d(),
+        Err(BuilderError::InvalidGridParameters(_))
+    ));
+    // implicit number of cells
+    assert!(matches!(
+        GridDescriptor::default()
+            .len_per_cell([1.0_f64, 1.0, 1.0])
+            .lens([3.0, 2.0, 0.0])
+            .len_per_cell_axis(0, vec![1.0, 2.0])
+            .parse_2d(),
+        Err(BuilderError::InvalidGridParameters(_))
+    ));
+    // negative length
+    assert!(matches!(
+        GridDescriptor::default()
+            .n_cells([2, 2, 0])
+            .len_per_cell([1.0_f64, 1.0, 1.0])
+            .len_per_cell_axis(1, vec![1.0, -2.0])
+            .parse_2d(),
+        Err(BuilderError::InvalidGridParameters(_))
+    ));
+}
+
+#[test]
+fn hex_cmap2_correctness() {
+    let descriptor = GridDescriptor::default()
+        .n_cells([3, 3, 0])
+        .len_per_cell([1., 1., 1.])
+        .cell_shape(CellShape::Hexagon);
+    let cmap: CMap2<f64> = CMapBuilder::from(descriptor).build().unwrap();
+
+    // topology; the lattice is a disk, so V - E + F = 1
+    let (n_faces, n_edges, n_vertices) = (
+        cmap.iter_faces().count(),
+        cmap.iter_edges().count(),
+        cmap.iter_vertices().count(),
+    );
+    assert_eq!(n_faces, 9);
+    assert_eq!(n_vertices + n_faces, n_edges + 1);
+    assert!(cmap
+        .iter_faces()
+        .all(|fid| Orbit2::new(&cmap, OrbitPolicy::Face, fid as DartIdType).count() == 6));
+    // the central cell is surrounded by other ones
+    assert!((25..=30).all(|d| !cmap.is_i_free::<2>(d)));
+
+    // geometry; all edges have a length equal to the circumradius
+    assert_eq!(cmap.n_vertices(), n_vertices);
+    assert!(cmap.iter_edges().all(|eid| {
+        let d = eid as DartIdType;
+        let v1 = cmap.force_read_vertex(cmap.vertex_id(d)).unwrap();
+        let v2 = cmap
+            .force_read_vertex(cmap.vertex_id(cmap.beta::<1>(d)))
+            .unwrap();
+        ((v2 - v1).norm() - 1.0).abs() < 1e-10
+    }));
+    assert_eq!(
+        cmap.force_read_vertex(1),
+        Some(Vertex2(3.0_f64.sqrt() / 2.0, 0.0))
+    );
+}
+
+#[test]
+fn hex_invalid_parameters() {
+    let descriptor = GridDescriptor::default()
+        .n_cells([3, 3, 0])
+        .len_per_cell([1.0_f64, 1.0, 1.0])
+        .cell_shape(CellShape::Hexagon);
+    assert!(matches!(
+        descriptor.clone().split_quads(true).parse_2d(),
+        Err(BuilderError::InvalidGridParameters(_))
+    ));
+    assert!(matches!(
+        descriptor.clone().periodic([true, false, false]).parse_2d(),
+        Err(BuilderError::InvalidGridParameters(_))
+    ));
+    assert!(descriptor.parse_2d().is_ok());
+}
+
+// --- soup
+
+/// 3x3 vertices, making up a 2x2 grid of split squares.
+fn soup() -> (Vec<Vertex2<f64>>, Vec<[usize; 3]>) {
+    let vertices = (0..3)
+        .flat_map(|y| (0..3).map(move |x| Vertex2(f64::from(x), f64::from(y))))
+        .collect();
+    let triangles = vec![
+        [0, 1, 4],
+        [0, 4, 3],
+        [1, 2, 5],
+        [1, 5, 4],
+        [3, 4, 7],
+        [3, 7, 6],
+        [4, 5, 8],
+        [4, 8, 7],
+    ];
+    (vertices, triangles)
+}
+
+#[test]
+fn soup_read() {
+    let (vertices, triangles) = soup();
+    let cmap: CMap2<f64> = CMap2::from_triangle_soup(&vertices, &triangles).unwrap();
+
+    assert_eq!(cmap.n_darts(), 25);
+    assert_eq!(cmap.iter_faces().count(), 8);
+    assert_eq!(cmap.iter_edges().count(), 16);
+    assert_eq!(cmap.iter_vertices().count(), 9);
+
+    // triangle [0, 1, 4] is made of darts 1, 2, 3
+    assert_eq!(cmap.beta::<1>(1), 2);
+    assert_eq!(cmap.beta::<1>(2), 3);
+    assert_eq!(cmap.beta::<1>(3), 1);
+    assert_eq!(cmap.beta::<2>(1), 0); // boundary
+    assert_eq!(cmap.beta::<2>(2), 12); // (1, 4) <-> (4, 1)
+    assert_eq!(cmap.beta::<2>(3), 4); // (4, 0) <-> (0, 4)
+
+    // central vertex
+    assert_eq!(cmap.i_cell::<0>(3).count(), 6);
+    assert_eq!(
+        cmap.force_read_vertex(cmap.vertex_id(3)),
+        Some(Vertex2(1.0, 1.0))
+    );
+}
+
+#[test]
+fn soup_bad_data() {
+    let (vertices, mut triangles) = soup();
+    triangles.push([4, 8, 9]);
+    assert!(matches!(
+        CMap2::from_triangle_soup(&vertices, &triangles),
+        Err(BuilderError::BadSoupData(_))
+    ));
+    triangles.pop();
+    triangles.push([4, 8, 4]);
+    assert!(matches!(
+        CMap2::from_triangle_soup(&vertices, &triangles),
+        Err(BuilderError::BadSoupData(_))
+    ));
+    triangles.pop();
+    triangles.push([4, 5, 2]); // reuses oriented edge (4, 5)
+    assert!(matches!(
+        CMap2::from_triangle_soup(&vertices, &triangles),
+        Err(BuilderError::BadSoupData(_))
+    ));
+}
+
+#[test]
+fn soup_write() {
+    let (vertices, triangles) = soup();
+    let cmap: CMap2<f64> = CMap2::from_triangle_soup(&vertices, &triangles).unwrap();
+    let (out_vertices, out_triangles) = cmap.to_triangle_soup().unwrap();
+
+    assert_eq!(out_vertices.len(), vertices.len());
+    assert_eq!(out_triangles.len(), triangles.len());
+    // indices are identical up to vertex reordering
+    triangles
+        .iter()
+        .zip(out_triangles.iter())
+        .for_each(|(tri, out_tri)| {
+            (0..3).for_each(|k| {
+                assert_eq!(vertices[tri[k]], out_vertices[out_tri[k] as usize]);
+            });
+        });
+
+    // reading the exported soup yields the same map
+    let out_triangles: Vec<[usize; 3]> = out_triangles
+        .iter()
+        .map(|tri| tri.map(|id| id as usize))
+        .collect();
+    let out_cmap: CMap2<f64> = CMap2::from_triangle_soup(&out_vertices, &out_triangles).unwrap();
+    assert!(cmap.diff(&out_cmap).is_empty());
+
+    // quads cannot be exported
+    let cmap: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    assert!(matches!(
+        cmap.to_triangle_soup(),
+        Err(CMapError::IncorrectGeometry(_))
+    ));
+}
+
+#[cfg(feature = "par-internals")]
+#[test]
+fn soup_read_par() {
+    let (vertices, triangles) = soup();
+    let seq: CMap2<f64> = CMap2::from_triangle_soup(&vertices, &triangles).unwrap();
+    let par: CMap2<f64> = CMap2::from_triangle_soup_par(&vertices, &triangles).unwrap();
+
+    assert_eq!(seq.n_darts(), par.n_darts());
+    (1..seq.n_darts() as DartIdType).for_each(|d| {
+        assert_eq!(seq.beta::<0>(d), par.beta::<0>(d));
+        assert_eq!(seq.beta::<1>(d), par.beta::<1>(d));
+        assert_eq!(seq.beta::<2>(d), par.beta::<2>(d));
+    });
+    assert!(seq.iter_vertices().eq(par.iter_vertices()));
+    seq.iter_vertices().for_each(|vid| {
+        assert_eq!(seq.force_read_vertex(vid), par.force_read_vertex(vid));
+    });
+
+    let mut triangles = triangles;
+    triangles.push([4, 5, 2]);
+    assert!(matches!(
+        CMap2::<f64>::from_triangle_soup_par(&vertices, &triangles),
+        Err(BuilderError::BadSoupData(_))
+    ));
+}
+
+// --- connectivity
+
+#[test]
+fn connectivity_read() {
+    // same mesh as the soup, using quads & triangles
+    let (vertices, _) = soup();
+    let faces = vec![
+        vec![0, 1, 4, 3],
+        vec![1, 2, 5],
+        vec![1, 5, 4],
+        vec![3, 4, 7],
+        vec![3, 7, 6],
+        vec![4, 5, 8, 7],
+    ];
+    let cmap: CMap2<f64> = CMapBuilder::from_connectivity(vertices.clone(), faces)
+        .build()
+        .unwrap();
+
+    assert_eq!(cmap.n_darts(), 21);
+    assert_eq!(cmap.iter_faces().count(), 6);
+    assert_eq!(cmap.iter_edges().count(), 14);
+    assert_eq!(cmap.iter_vertices().count(), 9);
+
+    // quad [0, 1, 4, 3] is made of darts 1, 2, 3, 4
+    assert_eq!(cmap.beta::<1>(4), 1);
+    assert_eq!(cmap.beta::<2>(1), 0); // boundary
+    assert_eq!(cmap.beta::<2>(2), 10); // (1, 4) <-> (4, 1)
+    assert_eq!(cmap.beta::<2>(3), 11); // (4, 3) <-> (3, 4)
+
+    // central vertex
+    assert_eq!(cmap.i_cell::<0>(3).count(), 4);
+    assert_eq!(
+        cmap.force_read_vertex(cmap.vertex_id(3)),
+        Some(Vertex2(1.0, 1.0))
+    );
+}
+
+#[test]
+fn connectivity_bad_data() {
+    let (vertices, _) = soup();
+    let build = |faces: Vec<Vec<usize>>| {
+        CMapBuilder::<f64>::from_connectivity(vertices.clone(), faces).build()
+    };
+
+    assert!(matches!(
+        build(vec![vec![0, 1, 4, 9]]),
+        Err(BuilderError::BadConnectivityData(
+            "face references an undefined vertex"
+        ))
+    ));
+    assert!(matches!(
+        build(vec![vec![0, 1]]),
+        Err(BuilderError::BadConnectivityData(
+            "face with fewer than three vertices"
+        ))
+    ));
+    // inconsistent orientation
+    assert!(matches!(
+        build(vec![vec![0, 1, 4], vec![0, 1, 2]]),
+        Err(BuilderError::BadConnectivityData(
+            "oriented edge used by more than one face"
+        ))
+    ));
+    // non-manifold edge (1, 4); the error doesn't depend on the order of faces, even though the
+    // third face also reuses an oriented edge
+    assert!(matches!(
+        build(vec![vec![0, 1, 4], vec![1, 5, 4], vec![1, 4, 2]]),
+        Err(BuilderError::NonManifold((1, 4)))
+    ));
+    assert!(matches!(
+        build(vec![vec![0, 1, 4], vec![1, 4, 2], vec![1, 5, 4]]),
+        Err(BuilderError::NonManifold((1, 4)))
+    ));
+}
+
 // --- IO
 
 #[test]
@@ -394,6 +821,425 @@ fn io_read() {
     assert_eq!(six_count, 1);
 }
 
+#[test]
+fn io_read_non_manifold() {
+    let vtk = Vtk::parse_legacy_be(VTK_T_JUNCTION).unwrap();
+    assert!(matches!(
+        super::io::build_2d_from_vtk::<f64>(vtk, AttrStorageManager::default()),
+        Err(BuilderError::NonManifold((0, 1)))
+    ));
+}
+
+#[test]
+fn io_read_streaming() {
+    let vtk = Vtk::parse_legacy_be(VTK_ASCII).unwrap();
+    let eager: CMap2<f32> =
+        super::io::build_2d_from_vtk(vtk, AttrStorageManager::default()).unwrap();
+    let streamed: CMap2<f32> =
+        super::io::build_2d_from_vtk_stream(VTK_ASCII, AttrStorageManager::default()).unwrap();
+
+    // both routines build cells in the same order
+    assert_eq!(streamed.n_darts(), eager.n_darts());
+    for d in 1..eager.n_darts() as DartIdType {
+        assert_eq!(streamed.beta::<1>(d), eager.beta::<1>(d));
+        assert_eq!(streamed.beta::<2>(d), eager.beta::<2>(d));
+        assert_eq!(
+            streamed.force_read_vertex(streamed.vertex_id(d)),
+            eager.force_read_vertex(eager.vertex_id(d))
+        );
+    }
+
+    let path = std::env::temp_dir().join("honeycomb_io_read_streaming.vtk");
+    std::fs::write(&path, VTK_ASCII).unwrap();
+    let from_file: CMap2<f32> = CMapBuilder::default()
+        .vtk_file_streaming(&path)
+        .build()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(from_file.iter_faces().count(), 4);
+    assert_eq!(from_file.iter_edges().count(), 12);
+    assert_eq!(from_file.iter_vertices().count(), 9);
+}
+
+#[test]
+fn io_read_streaming_errors() {
+    let build = |contents: &[u8]| {
+        super::io::build_2d_from_vtk_stream::<f64, _>(contents, AttrStorageManager::default())
+    };
+    assert!(matches!(
+        build(VTK_T_JUNCTION),
+        Err(BuilderError::NonManifold((0, 1)))
+    ));
+    // binary files are not supported
+    let binary = String::from_utf8_lossy(VTK_ASCII).replace("ASCII", "BINARY");
+    assert!(matches!(
+        build(binary.as_bytes()),
+        Err(BuilderError::UnsupportedVtkData(_))
+    ));
+    // truncated file
+    assert!(matches!(
+        build(&VTK_ASCII[..VTK_ASCII.len() / 2]),
+        Err(BuilderError::BadVtkData(_))
+    ));
+    // undefined point
+    let undefined = String::from_utf8_lossy(VTK_ASCII).replace("3 1 5 2", "3 1 9 2");
+    assert!(matches!(
+        build(undefined.as_bytes()),
+        Err(BuilderError::BadVtkData(_))
+    ));
+    // quad declared as a triangle
+    let mismatch = String::from_utf8_lossy(VTK_ASCII).replace("\n9\n", "\n5\n");
+    assert!(matches!(
+        build(mismatch.as_bytes()),
+        Err(BuilderError::BadVtkData(_))
+    ));
+}
+
+#[test]
+fn msh_read() {
+    let path = std::env::temp_dir().join("honeycomb_msh_read.msh");
+    std::fs::write(&path, MSH_ASCII).unwrap();
+    let cmap: CMap2<f64> = CMapBuilder::default().msh_file(&path).build().unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    // points & lines are ignored
+    assert_eq!(cmap.iter_faces().count(), 3);
+    assert_eq!(cmap.iter_edges().count(), 8);
+    assert_eq!(cmap.iter_vertices().count(), 6);
+    let mut n_vertices_per_face: Vec<usize> = cmap
+        .iter_faces()
+        .map(|id| Orbit2::new(&cmap, OrbitPolicy::Face, id as DartIdType).count())
+        .collect();
+    n_vertices_per_face.sort_unstable();
+    assert_eq!(n_vertices_per_face, vec![3, 3, 4]);
+
+    // vertices are sewn using node ids
+    let vertex = |v: Vertex2<f64>| {
+        cmap.iter_vertices()
+            .find(|vid| cmap.force_read_vertex(*vid) == Some(v))
+            .unwrap()
+    };
+    let vid = vertex(Vertex2(1.0, 1.0));
+    assert_eq!(Orbit2::new(&cmap, OrbitPolicy::Vertex, vid).count(), 3);
+
+    // physical groups are stored on faces
+    let mut groups: Vec<u32> = cmap
+        .iter_faces()
+        .map(|fid| cmap.force_read_attribute::<PhysicalGroup>(fid).unwrap().0)
+        .collect();
+    groups.sort_unstable();
+    assert_eq!(groups, vec![1, 1, 2]);
+}
+
+#[test]
+fn validate_on_build() {
+    // valid file
+    let path = std::env::temp_dir().join("honeycomb_validate_on_build.msh");
+    std::fs::write(&path, MSH_ASCII).unwrap();
+    let cmap: Result<CMap2<f64>, _> = CMapBuilder::default()
+        .msh_file(&path)
+        .validate_on_build(true)
+        .build();
+    assert!(cmap.is_ok());
+
+    // degenerate triangle (1, 5, 1); its two first edges are sewn together
+    let contents = MSH_ASCII.replace("4 2 2 1 1 1 3 4", "4 2 2 1 1 1 5 1");
+    std::fs::write(&path, contents).unwrap();
+    let cmap: Result<CMap2<f64>, _> = CMapBuilder::default().msh_file(&path).build();
+    assert!(cmap.is_ok()); // validation is disabled by default
+    let cmap: Result<CMap2<f64>, _> = CMapBuilder::default()
+        .msh_file(&path)
+        .validate_on_build(true)
+        .build();
+    std::fs::remove_file(&path).unwrap();
+    match cmap {
+        Err(BuilderError::InconsistentResult(errs)) => {
+            assert!(!errs.is_empty());
+            assert!(errs
+                .iter()
+                .all(|e| matches!(e, ConsistencyError::FoldedEdge(_))));
+        }
+        _ => panic!("expected an inconsistent result"),
+    }
+}
+
+#[test]
+fn msh_read_invalid() {
+    let build = |contents: &str| {
+        super::io::build_2d_from_msh::<f64>(contents, AttrStorageManager::default())
+    };
+    // missing section
+    let contents = MSH_ASCII.replace("$Nodes", "$Points");
+    assert!(matches!(build(&contents), Err(BuilderError::BadMshData(_))));
+    // binary file
+    let contents = MSH_ASCII.replace("2.2 0 8", "2.2 1 8");
+    assert!(matches!(build(&contents), Err(BuilderError::BadMshData(_))));
+    // unsupported element type (tetrahedron)
+    let contents = MSH_ASCII.replace("5 3 2 2 2 2 5 10 3", "5 4 2 2 2 2 5 10 3");
+    assert!(matches!(build(&contents), Err(BuilderError::BadMshData(_))));
+    // undefined node
+    let contents = MSH_ASCII.replace("5 3 2 2 2 2 5 10 3", "5 3 2 2 2 2 5 11 3");
+    assert!(matches!(build(&contents), Err(BuilderError::BadMshData(_))));
+    // incorrect count
+    let contents = MSH_ASCII.replace("$Nodes\n6", "$Nodes\n7");
+    assert!(matches!(build(&contents), Err(BuilderError::BadMshData(_))));
+    // third triangle on the edge shared by the first two, as for VTK files
+    let contents = MSH_ASCII
+        .replace("$Elements\n7", "$Elements\n8")
+        .replace("$EndElements", "8 2 2 1 1 1 3 5\n$EndElements");
+    assert!(matches!(
+        build(&contents),
+        Err(BuilderError::NonManifold((1, 3)))
+    ));
+}
+
+#[test]
+fn medit_read_write() {
+    let path = std::env::temp_dir().join("honeycomb_medit_read_write.mesh");
+    std::fs::write(&path, MEDIT_ASCII).unwrap();
+    let cmap: CMap3<f64> = CMap3::from_medit_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    // both tets share a face
+    assert_eq!(cmap.iter_volumes().count(), 2);
+    assert_eq!(cmap.iter_faces().count(), 7);
+    assert_eq!(cmap.iter_edges().count(), 9);
+    assert_eq!(cmap.iter_vertices().count(), 5);
+    let (vertices, tets) = cmap.to_tet_soup().unwrap();
+    assert_eq!(
+        tets.iter()
+            .map(|tet| tet.map(|id| vertices[id as usize]))
+            .collect::<Vec<_>>(),
+        vec![
+            [
+                Vertex3(0.0, 0.0, 0.0),
+                Vertex3(1.0, 0.0, 0.0),
+                Vertex3(0.0, 1.0, 0.0),
+                Vertex3(0.0, 0.0, 1.0)
+            ],
+            [
+                Vertex3(1.0, 0.0, 0.0),
+                Vertex3(0.0, 0.0, 0.0),
+                Vertex3(0.0, 1.0, 0.0),
+                Vertex3(0.0, 0.0, -1.0)
+            ],
+        ]
+    );
+
+    // exported data can be read back
+    let mut buffer = Vec::new();
+    cmap.to_medit(&mut buffer).unwrap();
+    let other: CMap3<f64> =
+        super::io::build_3d_from_medit(&String::from_utf8(buffer).unwrap()).unwrap();
+    assert_eq!(other.to_tet_soup().unwrap(), (vertices, tets));
+}
+
+#[test]
+fn medit_read_invalid() {
+    let build = |contents: &str| super::io::build_3d_from_medit::<f64>(contents);
+    // 2D mesh
+    let contents = MEDIT_ASCII.replace("Dimension 3", "Dimension 2");
+    assert!(matches!(
+        build(&contents),
+        Err(BuilderError::BadMeditData(_))
+    ));
+    // undefined vertex
+    let contents = MEDIT_ASCII.replace("2 1 3 5 0", "2 1 3 6 0");
+    assert!(matches!(
+        build(&contents),
+        Err(BuilderError::BadMeditData(_))
+    ));
+    // inconsistent orientations
+    let contents = MEDIT_ASCII.replace("2 1 3 5 0", "1 2 3 5 0");
+    assert!(matches!(
+        build(&contents),
+        Err(BuilderError::BadMeditData(_))
+    ));
+    // incomplete section
+    let contents = MEDIT_ASCII.replace("Tetrahedra\n2", "Tetrahedra\n3");
+    assert!(matches!(
+        build(&contents),
+        Err(BuilderError::BadMeditData(_))
+    ));
+    // unsupported volumes
+    let contents = MEDIT_ASCII.replace("End", "Hexahedra\n0\nEnd");
+    assert!(matches!(
+        build(&contents),
+        Err(BuilderError::BadMeditData(_))
+    ));
+}
+
+#[test]
+fn stl_read_ascii() {
+    let path = std::env::temp_dir().join("honeycomb_stl_read_ascii.stl");
+    std::fs::write(&path, STL_ASCII).unwrap();
+    let cmap: CMap3<f64> = CMap3::from_stl_file(&path, 0.0).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    // closed surface of a tetrahedron, without volumes
+    assert_eq!(cmap.n_darts(), 13);
+    assert!((1..13).all(|d| !cmap.is_i_free::<2>(d) && cmap.is_i_free::<3>(d)));
+    assert_eq!(cmap.iter_faces().count(), 4);
+    assert_eq!(cmap.iter_edges().count(), 6);
+    assert_eq!(cmap.iter_vertices().count(), 4);
+    assert_eq!(cmap.force_read_vertex(1), Some(Vertex3(0.0, 0.0, 0.0)));
+}
+
+/// Write triangles as a binary STL file, using `n_triangles` as header value.
+fn stl_binary(triangles: &[[[f32; 3]; 3]], n_triangles: u32) -> Vec<u8> {
+    let mut res = b"solid but actually binary".to_vec();
+    res.resize(80, 0);
+    res.extend(n_triangles.to_le_bytes());
+    for triangle in triangles {
+        res.extend([0; 12]); // normal
+        for coord in triangle.as_flattened() {
+            res.extend(coord.to_le_bytes());
+        }
+        res.extend([0; 2]); // attribute byte count
+    }
+    res
+}
+
+#[test]
+fn stl_read_binary() {
+    let (a, b, c, d) = (
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+    );
+    let d_bis = [0.0, 0.0, 1.0001];
+    // the last triangle is a duplicate of the first one
+    let triangles = [[a, c, b], [a, b, d], [a, d_bis, c], [b, c, d], [c, b, a]];
+    let contents = stl_binary(&triangles, 5);
+
+    let cmap = super::io::build_3d_from_stl(&contents, 1e-3).unwrap();
+    assert_eq!(cmap.iter_faces().count(), 4);
+    assert_eq!(cmap.iter_vertices().count(), 4);
+    assert!((1..13).all(|d| !cmap.is_i_free::<2>(d)));
+
+    // without welding, one face is disconnected from the others at the perturbed vertex
+    let cmap = super::io::build_3d_from_stl(&contents, 0.0).unwrap();
+    assert_eq!(cmap.iter_faces().count(), 4);
+    assert_eq!(cmap.iter_vertices().count(), 5);
+    assert_eq!((1..13).filter(|d| cmap.is_i_free::<2>(*d)).count(), 4);
+}
+
+#[test]
+fn stl_read_invalid() {
+    let build = |contents: &[u8]| super::io::build_3d_from_stl::<f64>(contents, 0.0);
+    let triangle = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+
+    // binary header & file size mismatch
+    assert!(matches!(
+        build(&stl_binary(&[triangle, triangle], 3)),
+        Err(BuilderError::BadStlData("truncated file"))
+    ));
+    assert!(matches!(
+        build(&stl_binary(&[triangle, triangle], 1)),
+        Err(BuilderError::BadStlData(
+            "different # of triangles in header and file"
+        ))
+    ));
+    assert!(matches!(build(&[0; 40]), Err(BuilderError::BadStlData(_))));
+    // incomplete facet
+    let stl = String::from_utf8_lossy(STL_ASCII).replace("vertex 0 0 1\n", "");
+    assert!(matches!(
+        build(stl.as_bytes()),
+        Err(BuilderError::BadStlData(_))
+    ));
+    // truncated file
+    assert!(matches!(
+        build(&STL_ASCII[..STL_ASCII.len() / 2]),
+        Err(BuilderError::BadStlData(_))
+    ));
+    // flipped triangle
+    let stl = String::from_utf8_lossy(STL_ASCII).replacen(
+        "vertex 0 1 0\n    vertex 1 0 0",
+        "vertex 1 0 0\n    vertex 0 1 0",
+        1,
+    );
+    assert!(matches!(
+        build(stl.as_bytes()),
+        Err(BuilderError::BadStlData(
+            "triangles with inconsistent orientations"
+        ))
+    ));
+}
+
+#[cfg(test)]
+const MEDIT_ASCII: &str = "MeshVersionFormatted 2
+Dimension 3
+
+# A, B, C, D, E
+Vertices
+5
+0 0 0 0
+1 0 0 0
+0 1 0 0
+0 0 1 0
+0 0 -1 0
+
+Triangles
+1
+1 3 2 1
+
+Tetrahedra
+2
+1 2 3 4 0
+2 1 3 5 0
+
+End
+";
+
+#[cfg(test)]
+const MSH_ASCII: &str = "$MeshFormat
+2.2 0 8
+$EndMeshFormat
+$Nodes
+6
+1 0 0 0
+2 1 0 0
+3 1 1 0
+4 0 1 0
+5 2 0 0
+10 2 1 0
+$EndNodes
+$Elements
+7
+1 15 2 0 1 1
+2 1 2 3 1 1 2
+6 1 2 3 1 2 5
+3 2 2 1 1 1 2 3
+4 2 2 1 1 1 3 4
+5 3 2 2 2 2 5 10 3
+7 1 2 3 2 5 10
+$EndElements
+";
+
+/// Three triangles sharing the edge `(0, 1)`.
+#[cfg(test)]
+const VTK_T_JUNCTION: &[u8] = b"
+# vtk DataFile Version 2.0
+cmap
+ASCII
+
+DATASET UNSTRUCTURED_GRID
+POINTS 5 float
+0 0 0  1 0 0  0.5 1 0
+0.5 -1 0  0.5 0 1
+
+CELLS 3 12
+3 0 1 2
+3 1 0 3
+3 0 1 4
+
+CELL_TYPES 3
+5
+5
+5
+";
+
 #[cfg(test)]
 const VTK_ASCII: &[u8] = b"
 # vtk DataFile Version 2.0
@@ -449,3 +1295,37 @@ POINT_DATA 9
 
 CELL_DATA 17
 ";
+
+/// Surface of the tetrahedron `(0, 0, 0), (1, 0, 0), (0, 1, 0), (0, 0, 1)`.
+#[cfg(test)]
+const STL_ASCII: &[u8] = b"solid tet
+facet normal 0 0 -1
+  outer loop
+    vertex 0 0 0
+    vertex 0 1 0
+    vertex 1 0 0
+  endloop
+endfacet
+facet normal 0 -1 0
+  outer loop
+    vertex 0 0 0
+    vertex 1 0 0
+    vertex 0 0 1
+  endloop
+endfacet
+facet normal -1 0 0
+  outer loop
+    vertex 0 0 0
+    vertex 0 0 1
+    vertex 0 1 0
+  endloop
+endfacet
+facet normal 1 1 1
+  outer loop
+    vertex 1 0 0
+    vertex 0 1 0
+    vertex 0 0 1
+  endloop
+endfacet
+endsolid tet
+";
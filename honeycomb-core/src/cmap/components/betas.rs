@@ -37,6 +37,53 @@ impl<const N: usize> BetaFunctions<N> {
         self.0.extend((0..len).map(|_| new_beta_entry()));
     }
 
+    /// Reserve internal storage capacity
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Append entries of another storage, skipping its null dart
+    ///
+    /// Non-null images are shifted by `offset`.
+    pub fn append(&mut self, other: &Self, offset: DartIdType) {
+        self.0.extend(other.0.iter().skip(1).map(|entry| {
+            entry
+                .iter()
+                .map(|img| {
+                    let img = img.read_atomic();
+                    TVar::new(if img == NULL_DART_ID {
+                        img
+                    } else {
+                        img + offset
+                    })
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap()
+        }));
+    }
+
+    /// Drop entries of removed darts and renumber images of the remaining ones
+    ///
+    /// `mapping` is the old-to-new dart ID mapping, removed darts being mapped to the null dart.
+    /// It is expected to be increasing over kept darts.
+    pub fn compact(&mut self, mapping: &[DartIdType]) {
+        self.0 = std::mem::take(&mut self.0)
+            .into_iter()
+            .zip(mapping)
+            .enumerate()
+            .filter(|(id, (_, new_id))| *id == 0 || **new_id != NULL_DART_ID)
+            .map(|(_, (entry, _))| {
+                entry
+                    .iter()
+                    .map(|img| TVar::new(mapping[img.read_atomic() as usize]))
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap()
+            })
+            .collect();
+    }
+
     /// Return internal storage capacity
     pub fn capacity(&self) -> usize {
         self.0.capacity()
@@ -0,0 +1,37 @@
+// ------ IMPORTS
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::identifiers::DartIdType;
+
+// ------ CONTENT
+
+/// Thread-safe set of darts, used to mark visited darts during parallel scans.
+pub struct AtomicBitset(Vec<AtomicU64>);
+
+impl AtomicBitset {
+    /// Constructor
+    pub fn new(n_darts: usize) -> Self {
+        Self(
+            (0..n_darts.div_ceil(64))
+                .map(|_| AtomicU64::new(0))
+                .collect(),
+        )
+    }
+
+    /// Return whether `dart_id` is in the set.
+    pub fn contains(&self, dart_id: DartIdType) -> bool {
+        let (word, mask) = Self::position(dart_id);
+        self.0[word].load(Ordering::Relaxed) & mask != 0
+    }
+
+    /// Add `dart_id` to the set, returning `true` if it was not already in it.
+    pub fn insert(&self, dart_id: DartIdType) -> bool {
+        let (word, mask) = Self::position(dart_id);
+        self.0[word].fetch_or(mask, Ordering::AcqRel) & mask == 0
+    }
+
+    fn position(dart_id: DartIdType) -> (usize, u64) {
+        (dart_id as usize / 64, 1 << (dart_id % 64))
+    }
+}
@@ -0,0 +1,39 @@
+//! Cell census structure
+
+// ------ CONTENT
+
+/// Number of cells of a map, classified by shape.
+///
+/// This structure is returned by [`CMap2::cell_counts`][crate::cmap::CMap2::cell_counts] and
+/// [`CMap3::cell_counts`][crate::cmap::CMap3::cell_counts]. Faces are classified by degree,
+/// volumes by the number and degrees of their faces. Fields that do not apply to the map's
+/// dimension are left to zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CellCounts {
+    /// Number of faces made up of three edges.
+    pub triangles: usize,
+    /// Number of faces made up of four edges.
+    pub quads: usize,
+    /// Number of other faces.
+    pub polygons: usize,
+    /// Number of volumes made up of four triangular faces.
+    pub tetrahedra: usize,
+    /// Number of volumes made up of six quadrangular faces.
+    pub hexahedra: usize,
+    /// Number of other volumes.
+    pub polyhedra: usize,
+}
+
+impl CellCounts {
+    /// Return the total number of counted faces.
+    #[must_use = "unused return value"]
+    pub fn n_faces(&self) -> usize {
+        self.triangles + self.quads + self.polygons
+    }
+
+    /// Return the total number of counted volumes.
+    #[must_use = "unused return value"]
+    pub fn n_volumes(&self) -> usize {
+        self.tetrahedra + self.hexahedra + self.polyhedra
+    }
+}
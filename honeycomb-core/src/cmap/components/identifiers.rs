@@ -32,6 +32,86 @@ pub const NULL_FACE_ID: FaceIdType = 0; //: FaceId = FaceId(0);
 /// Null volume ID value
 pub const NULL_VOLUME_ID: VolumeIdType = 0; //: VolumeId = VolumeId(0);
 
+// --- dart ranges
+
+/// Contiguous range of dart IDs
+///
+/// This structure is returned by methods allocating several darts at once, e.g.
+/// [`CMap2::add_free_dart_range`][crate::cmap::CMap2::add_free_dart_range]. It can be iterated
+/// upon to yield dart IDs in increasing order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DartRange {
+    /// First dart of the range.
+    start: DartIdType,
+    /// Number of darts in the range.
+    len: usize,
+}
+
+impl DartRange {
+    /// Constructor
+    pub(crate) fn new(start: DartIdType, len: usize) -> Self {
+        Self { start, len }
+    }
+
+    /// Return the first dart of the range.
+    #[must_use = "unused return value"]
+    pub fn first(&self) -> DartIdType {
+        self.start
+    }
+
+    /// Return the number of darts in the range.
+    #[must_use = "unused return value"]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the range contains no dart.
+    #[must_use = "unused return value"]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the `i`-th dart of the range, or `None` if `i` is out of bounds.
+    #[must_use = "unused return value"]
+    pub fn get(&self, i: usize) -> Option<DartIdType> {
+        if i < self.len {
+            Some(self.start + i as DartIdType)
+        } else {
+            None
+        }
+    }
+
+    /// Return `true` if the dart belongs to the range.
+    #[must_use = "unused return value"]
+    pub fn contains(&self, dart_id: DartIdType) -> bool {
+        (self.start..self.start + self.len as DartIdType).contains(&dart_id)
+    }
+
+    /// Return an iterator over consecutive sub-ranges of `chunk_size` darts.
+    ///
+    /// If the length of the range isn't a multiple of `chunk_size`, the remaining darts are
+    /// omitted.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `chunk_size` is zero.
+    pub fn chunks_exact(&self, chunk_size: usize) -> impl Iterator<Item = DartRange> {
+        assert_ne!(chunk_size, 0, "E: chunk size must be non-zero");
+        let start = self.start;
+        (0..self.len / chunk_size)
+            .map(move |i| Self::new(start + (i * chunk_size) as DartIdType, chunk_size))
+    }
+}
+
+impl IntoIterator for DartRange {
+    type Item = DartIdType;
+    type IntoIter = std::ops::Range<DartIdType>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.start..self.start + self.len as DartIdType
+    }
+}
+
 // --- strongly typed variants (unused)
 
 /*
@@ -1,6 +1,9 @@
 //! Common components of the `CMap2` implementation
 
 pub mod betas;
+#[cfg(feature = "par-internals")]
+pub mod bitset;
+pub mod census;
 pub mod identifiers;
 pub mod orbits;
 pub mod unused;
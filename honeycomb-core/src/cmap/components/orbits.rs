@@ -1,3 +1,19 @@
+/// # Orbit policy error enum
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum OrbitError {
+    /// A custom policy doesn't contain any beta function.
+    #[error("custom orbit policy contains no beta function")]
+    EmptyPolicy,
+    /// A custom policy uses a beta function which isn't defined for the map's dimension.
+    #[error("beta{beta} isn't defined for {dim}-maps")]
+    InvalidBeta {
+        /// Index of the beta function.
+        beta: u8,
+        /// Dimension of the map.
+        dim: u8,
+    },
+}
+
 /// # Orbit search policy enum
 ///
 /// This is used to define special cases of orbits that are often used in
@@ -21,3 +37,41 @@ pub enum OrbitPolicy {
     /// Ordered array of beta functions defining the orbit.
     Custom(&'static [u8]),
 }
+
+impl OrbitPolicy {
+    /// Build a custom orbit policy from an ordered array of beta functions.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - `betas` is empty ([`OrbitError::EmptyPolicy`]),
+    /// - `betas` contains an index greater than `3` ([`OrbitError::InvalidBeta`]).
+    ///
+    /// Validity for a given map dimension can be checked using [`OrbitPolicy::check_dim`].
+    pub fn custom(betas: &'static [u8]) -> Result<Self, OrbitError> {
+        let policy = Self::Custom(betas);
+        policy.check_dim(3)?;
+        Ok(policy)
+    }
+
+    /// Check that the policy is valid for a map of dimension `dim`.
+    ///
+    /// Only custom policies are checked; built-in policies are always considered valid.
+    ///
+    /// # Errors
+    ///
+    /// This method returns an error if the policy is custom, and:
+    /// - contains no beta function ([`OrbitError::EmptyPolicy`]),
+    /// - contains an index greater than `dim` ([`OrbitError::InvalidBeta`]).
+    pub fn check_dim(&self, dim: u8) -> Result<(), OrbitError> {
+        if let Self::Custom(betas) = self {
+            if betas.is_empty() {
+                return Err(OrbitError::EmptyPolicy);
+            }
+            if let Some(beta) = betas.iter().find(|b| **b > dim) {
+                return Err(OrbitError::InvalidBeta { beta: *beta, dim });
+            }
+        }
+        Ok(())
+    }
+}
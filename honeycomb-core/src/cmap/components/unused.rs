@@ -26,6 +26,22 @@ impl UnusedDarts {
         self.0.extend((0..len).map(|_| TVar::new(false)));
     }
 
+    /// Reserve internal storage capacity
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    /// Append entries of another storage, skipping its null dart
+    pub fn append(&mut self, other: &Self) {
+        self.0.extend(
+            other
+                .0
+                .iter()
+                .skip(1)
+                .map(|unused| TVar::new(unused.read_atomic())),
+        );
+    }
+
     /// Return internal storage length
     pub fn len(&self) -> usize {
         self.0.len()
@@ -0,0 +1,92 @@
+//! Dart allocation diagnostics
+//!
+//! This module contains code used to inspect unused darts of a [`CMap2`], e.g. to detect
+//! fragmentation after many insertions & removals.
+
+// ------ IMPORTS
+
+use crate::geometry::CoordsFloat;
+use crate::prelude::{CMap2, DartIdType};
+
+// ------ CONTENT
+
+/// Summary of unused darts of a map.
+///
+/// This structure is returned by [`CMap2::unused_dart_audit`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnusedDartReport {
+    /// Total number of unused darts.
+    pub n_unused: usize,
+    /// Blocks of consecutive unused darts, as inclusive `(first, last)` ranges, in increasing
+    /// order.
+    pub blocks: Vec<(DartIdType, DartIdType)>,
+    /// Total number of darts of the map, including the null dart.
+    pub n_darts: usize,
+}
+
+impl UnusedDartReport {
+    /// Return `true` if unused darts form a single block, or if there are none.
+    #[must_use = "unused return value"]
+    pub fn is_contiguous(&self) -> bool {
+        self.blocks.len() <= 1
+    }
+
+    /// Return `true` if unused darts form a single block located at the end of the dart range,
+    /// or if there are none. In this case, they could be released without renumbering used
+    /// darts.
+    #[must_use = "unused return value"]
+    pub fn is_trailing(&self) -> bool {
+        match self.blocks.as_slice() {
+            [] => true,
+            [(_, last)] => *last as usize + 1 == self.n_darts,
+            _ => false,
+        }
+    }
+}
+
+/// **Diagnostics**
+impl<T: CoordsFloat> CMap2<T> {
+    /// Compute a summary of the map's unused darts.
+    ///
+    /// Unused darts are darts that were removed from the map, but whose slots are still
+    /// allocated. A large number of blocks indicates fragmentation of the dart range.
+    ///
+    /// # Return
+    ///
+    /// Return an [`UnusedDartReport`] listing blocks of consecutive unused darts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use honeycomb_core::prelude::{CMap2, CMapBuilder};
+    ///
+    /// let mut map: CMap2<f64> = CMapBuilder::default().n_darts(6).build().unwrap();
+    /// map.remove_free_dart(2);
+    /// map.remove_free_dart(3);
+    /// map.remove_free_dart(5);
+    ///
+    /// let report = map.unused_dart_audit();
+    /// assert_eq!(report.n_unused, 3);
+    /// assert_eq!(report.blocks, vec![(2, 3), (5, 5)]);
+    /// assert!(!report.is_contiguous());
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use = "unused return value"]
+    pub fn unused_dart_audit(&self) -> UnusedDartReport {
+        let mut blocks: Vec<(DartIdType, DartIdType)> = Vec::new();
+        for d in (1..self.n_darts() as DartIdType).filter(|d| self.unused_darts[*d].read_atomic()) {
+            match blocks.last_mut() {
+                Some((_, last)) if *last + 1 == d => *last = d,
+                _ => blocks.push((d, d)),
+            }
+        }
+        UnusedDartReport {
+            n_unused: blocks
+                .iter()
+                .map(|(first, last)| (last - first + 1) as usize)
+                .sum(),
+            blocks,
+            n_darts: self.n_darts(),
+        }
+    }
+}
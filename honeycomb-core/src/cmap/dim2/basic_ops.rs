@@ -11,12 +11,22 @@
 
 use std::collections::{HashSet, VecDeque};
 
+use smallvec::SmallVec;
+
+use crate::cmap::components::unused::UnusedDarts;
+use crate::cmap::{CMapError, CMapResult, CellCounts, DartRange};
 use crate::prelude::{
-    CMap2, DartIdType, EdgeIdType, FaceIdType, Orbit2, OrbitPolicy, VertexIdType, NULL_DART_ID,
+    CMap2, DartIdType, EdgeIdType, FaceIdType, Orbit2, OrbitPolicy, Vertex2, VertexIdType,
+    NULL_DART_ID,
 };
 use crate::stm::{atomically, StmClosureResult, Transaction};
 use crate::{attributes::UnknownAttributeStorage, geometry::CoordsFloat};
 
+#[cfg(feature = "par-internals")]
+use crate::cmap::components::bitset::AtomicBitset;
+#[cfg(feature = "par-internals")]
+use rayon::prelude::*;
+
 // ------ CONTENT
 
 /// **Dart-related methods**
@@ -67,6 +77,62 @@ impl<T: CoordsFloat> CMap2<T> {
         new_id
     }
 
+    /// Add `n_darts` new free darts to the map.
+    ///
+    /// This is equivalent to [`Self::add_free_darts`], but the new darts are returned as a
+    /// [`DartRange`] instead of the first ID.
+    ///
+    /// # Return
+    ///
+    /// Return the contiguous range of new darts.
+    pub fn add_free_dart_range(&mut self, n_darts: usize) -> DartRange {
+        DartRange::new(self.add_free_darts(n_darts), n_darts)
+    }
+
+    /// Reserve capacity for at least `additional` more darts to be added to the map.
+    ///
+    /// This presizes all internal storages, including attribute storages, so that subsequent
+    /// calls to [`Self::add_free_dart`] or [`Self::add_free_darts`] do not trigger reallocations
+    /// until the capacity is exhausted.
+    pub fn reserve_darts(&mut self, additional: usize) {
+        self.betas.reserve(additional);
+        self.unused_darts.reserve(additional);
+        self.vertices.reserve(additional);
+        self.attributes.reserve_storages(additional);
+    }
+
+    /// Merge another map into this one.
+    ///
+    /// Darts of `other` are appended to the dart list of `self`, along with their beta images,
+    /// vertices and attributes. The resulting map contains both original maps as disconnected
+    /// components, which can then be sewn along their shared interface(s).
+    ///
+    /// The vertex merge policy of `self` is kept.
+    ///
+    /// # Arguments
+    ///
+    /// - `other: CMap2<T>` -- Map to merge into `self`.
+    ///
+    /// # Return
+    ///
+    /// Return the offset applied to darts of `other`, i.e. dart `d` of `other` has ID
+    /// `d + offset` in the merged map. Since the null dart isn't duplicated, the offset is
+    /// `self.n_darts() - 1`.
+    ///
+    /// # Errors
+    ///
+    /// This method will return [`CMapError::UnknownAttribute`] if the two maps do not hold the
+    /// same set of attributes. In that case, `self` is left unchanged.
+    pub fn merge(&mut self, mut other: CMap2<T>) -> CMapResult<DartIdType> {
+        self.attributes.append_storages(&mut other.attributes)?;
+        let offset = (self.n_darts - 1) as DartIdType;
+        self.n_darts += other.n_darts - 1;
+        self.betas.append(&other.betas, offset);
+        self.unused_darts.append(&other.unused_darts);
+        self.vertices.append(&mut other.vertices);
+        Ok(offset)
+    }
+
     /// Insert a new free dart in the map.
     ///
     /// The dart may be inserted into an unused spot of the existing dart list. If no free spots
@@ -111,6 +177,186 @@ impl<T: CoordsFloat> CMap2<T> {
             Ok(())
         });
     }
+
+    /// Remove a free dart from the map.
+    ///
+    /// This variant is equivalent to [`remove_free_dart`][Self::remove_free_dart], but uses a
+    /// transaction instead of requiring exclusive access to the map. This allows darts to be
+    /// released as part of a larger transactional operation.
+    ///
+    /// # Arguments
+    ///
+    /// - `trans: &mut Transaction` -- Transaction associated to the operation.
+    /// - `dart_id: DartIdentifier` -- Identifier of the dart to remove.
+    ///
+    /// # Errors
+    ///
+    /// This method is meant to be called in a context where the returned `Result` is used to
+    /// validate the transaction passed as argument. Errors should not be processed manually,
+    /// only processed via the `?` operator.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if:
+    /// - the dart is not *i*-free for all *i*,
+    /// - the dart is already marked as unused.
+    pub fn remove_free_dart_transac(
+        &self,
+        trans: &mut Transaction,
+        dart_id: DartIdType,
+    ) -> StmClosureResult<()> {
+        assert_eq!(self.beta_transac::<0>(trans, dart_id)?, NULL_DART_ID);
+        assert_eq!(self.beta_transac::<1>(trans, dart_id)?, NULL_DART_ID);
+        assert_eq!(self.beta_transac::<2>(trans, dart_id)?, NULL_DART_ID);
+        assert!(!self.unused_darts[dart_id].replace(trans, true)?);
+        Ok(())
+    }
+
+    /// Renumber used darts contiguously, dropping unused ones from the map.
+    ///
+    /// Used darts keep their relative order, which means cell identifiers are preserved up to
+    /// renumbering: the value of a cell of ID `id` is associated to ID `mapping[id]` after the
+    /// operation. Beta images, vertices and attributes are updated accordingly.
+    ///
+    /// If the map contains no unused darts, this method does not modify the map.
+    ///
+    /// # Return
+    ///
+    /// Return the old-to-new dart ID mapping, i.e. a vector of length `self.n_darts()` (as
+    /// evaluated before the operation). Unused darts, as well as the null dart, are mapped to
+    /// the null dart.
+    pub fn compact(&mut self) -> Vec<DartIdType> {
+        let mut mapping = vec![NULL_DART_ID; self.n_darts];
+        let mut new_id: DartIdType = 0;
+        for (dart_id, unused) in self.unused_darts.iter().enumerate().skip(1) {
+            if !unused.read_atomic() {
+                new_id += 1;
+                mapping[dart_id] = new_id;
+            }
+        }
+        let new_len = new_id as usize + 1;
+        if new_len == self.n_darts {
+            return mapping;
+        }
+
+        self.betas.compact(&mapping);
+        self.unused_darts = UnusedDarts::new(new_len);
+        self.vertices.compact(&mapping);
+        self.attributes.compact_storages(&mapping);
+        self.n_darts = new_len;
+        mapping
+    }
+}
+
+/// **Cell removal methods**
+impl<T: CoordsFloat> CMap2<T> {
+    /// Remove a face from the map.
+    ///
+    /// The face is first 2-unsewn from its neighbors, which keep their own copy of the attributes
+    /// previously shared with the face. Its darts are then 1-unlinked, their vertices removed,
+    /// and they are finally released as unused darts.
+    ///
+    /// # Arguments
+    ///
+    /// - `trans: &mut Transaction` -- Transaction associated to the operation.
+    /// - `face_id: FaceIdType` -- Identifier of the face to remove.
+    ///
+    /// # Errors
+    ///
+    /// This method is meant to be called in a context where the returned `Result` is used to
+    /// validate the transaction passed as argument. The returned error may be:
+    /// - [`CMapError::FailedTransaction`] -- should be processed via the `?` operator,
+    /// - [`CMapError::InconsistentCell`] -- if the face isn't closed, or contains unused darts,
+    /// - [`CMapError::FailedAttributeSplit`] -- if the face couldn't be unsewn from its neighbors.
+    ///
+    /// `InconsistentCell` errors are detected before the map is altered.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if the face contains darts that do not exist in the map.
+    pub fn remove_face_tx(&self, trans: &mut Transaction, face_id: FaceIdType) -> CMapResult<()> {
+        if face_id == NULL_DART_ID {
+            return Err(CMapError::InconsistentCell("null face"));
+        }
+        let mut darts: Vec<DartIdType> = Vec::new();
+        let mut d = face_id as DartIdType;
+        loop {
+            if d == NULL_DART_ID || darts.len() > self.n_darts() {
+                return Err(CMapError::InconsistentCell("face isn't closed"));
+            }
+            if self.unused_darts[d].read(trans)? {
+                return Err(CMapError::InconsistentCell("face contains an unused dart"));
+            }
+            darts.push(d);
+            d = self.beta_transac::<1>(trans, d)?;
+            if d == face_id {
+                break;
+            }
+        }
+
+        // 2-unsews do not duplicate vertex values; save them to write them back on the side of
+        // the neighbors once the face is detached
+        let mut values = Vec::with_capacity(darts.len());
+        let mut neighbors = Vec::with_capacity(darts.len());
+        for (i, &d) in darts.iter().enumerate() {
+            let vid = self.vertex_id_transac(trans, d)?;
+            values.push(self.read_vertex(trans, vid)?);
+            let b2d = self.beta_transac::<2>(trans, d)?;
+            if b2d != NULL_DART_ID && !darts.contains(&b2d) {
+                neighbors.push((i, b2d));
+            }
+        }
+        for &d in &darts {
+            // the dart may have been unsewn already if the face is adjacent to itself
+            if self.beta_transac::<2>(trans, d)? != NULL_DART_ID {
+                self.unsew::<2>(trans, d)?;
+            }
+        }
+        for (i, e) in neighbors {
+            // `e` starts where `d` ends, `b1e` starts where `d` starts
+            let b1e = self.beta_transac::<1>(trans, e)?;
+            for (dart, val) in [(e, values[(i + 1) % darts.len()]), (b1e, values[i])] {
+                if dart == NULL_DART_ID {
+                    continue;
+                }
+                let vid = self.vertex_id_transac(trans, dart)?;
+                if let (None, Some(v)) = (self.read_vertex(trans, vid)?, val) {
+                    self.write_vertex(trans, vid, v)?;
+                }
+            }
+        }
+        // the face is isolated: each of its vertices is made of a single dart
+        for &d in &darts {
+            self.remove_vertex(trans, d)?;
+        }
+        for &d in &darts {
+            if self.beta_transac::<1>(trans, d)? != NULL_DART_ID {
+                self.unlink::<1>(trans, d)?;
+            }
+        }
+        for &d in &darts {
+            self.remove_free_dart_transac(trans, d)?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove a face from the map.
+    ///
+    /// This variant is equivalent to [`remove_face_tx`][Self::remove_face_tx], but internally
+    /// uses a transaction that will be retried until validated.
+    ///
+    /// # Errors
+    ///
+    /// This method may fail, returning an error, for the same reasons as the transactional
+    /// variant, `FailedTransaction` excluded.
+    pub fn force_remove_face(&self, face_id: FaceIdType) -> CMapResult<()> {
+        atomically(|trans| match self.remove_face_tx(trans, face_id) {
+            Ok(()) => Ok(Ok(())),
+            Err(CMapError::FailedTransaction(e)) => Err(e),
+            Err(e) => Ok(Err(e)),
+        })
+    }
 }
 
 /// **Beta-related methods**
@@ -378,75 +624,343 @@ impl<T: CoordsFloat> CMap2<T> {
         }
     }
 
-    /// Return an iterator over IDs of all the map's vertices.
-    #[must_use = "unused return value"]
-    pub fn iter_vertices(&self) -> impl Iterator<Item = VertexIdType> + '_ {
-        (1..self.n_darts() as DartIdType)
-            .zip(self.unused_darts.iter().skip(1))
-            .filter_map(
-                |(d, unused)| {
-                    if unused.read_atomic() {
-                        None
-                    } else {
-                        Some(d)
+    #[allow(clippy::needless_pass_by_value)]
+    /// Apply a closure to each dart of an orbit, inside a transaction.
+    ///
+    /// Darts are visited in the same order as [`Orbit2`] would yield them, but the orbit is
+    /// computed using transactional reads, which makes the traversal part of the transaction.
+    /// This removes the need to collect darts of the orbit in a buffer before mutating the map.
+    ///
+    /// Images of a dart are read before applying the closure to it, so `f` may update links of
+    /// the dart it is given. Updates to links of darts that are yet to be visited will be
+    /// reflected in the traversal.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if a read fails, or as soon as `f` returns one. In both
+    /// cases, the error should be passed up to the transaction control method.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the policy refers to 3-cells, or if a custom policy refers to
+    /// an invalid beta function.
+    pub fn for_each_orbit_dart_tx(
+        &self,
+        trans: &mut Transaction,
+        orbit_policy: OrbitPolicy,
+        dart_id: DartIdType,
+        mut f: impl FnMut(&mut Transaction, DartIdType) -> StmClosureResult<()>,
+    ) -> StmClosureResult<()> {
+        debug_assert_eq!(orbit_policy.check_dim(2), Ok(()));
+        let mut marked = HashSet::from([NULL_DART_ID, dart_id]);
+        let mut pending = VecDeque::from([dart_id]);
+
+        while let Some(d) = pending.pop_front() {
+            let mut images: SmallVec<[DartIdType; 3]> = SmallVec::new();
+            match orbit_policy {
+                OrbitPolicy::Vertex => {
+                    let (b0, b2) = (
+                        self.beta_transac::<0>(trans, d)?,
+                        self.beta_transac::<2>(trans, d)?,
+                    );
+                    images.push(self.beta_transac::<1>(trans, b2)?);
+                    images.push(self.beta_transac::<2>(trans, b0)?);
+                }
+                OrbitPolicy::VertexLinear => {
+                    let b2 = self.beta_transac::<2>(trans, d)?;
+                    images.push(self.beta_transac::<1>(trans, b2)?);
+                }
+                OrbitPolicy::Edge => images.push(self.beta_transac::<2>(trans, d)?),
+                OrbitPolicy::Face => {
+                    images.push(self.beta_transac::<1>(trans, d)?);
+                    images.push(self.beta_transac::<0>(trans, d)?);
+                }
+                OrbitPolicy::FaceLinear => images.push(self.beta_transac::<1>(trans, d)?),
+                OrbitPolicy::Custom(beta_slice) => {
+                    for beta_id in beta_slice {
+                        images.push(self.beta_rt_transac(trans, *beta_id, d)?);
                     }
-                },
-            )
-            .filter_map(|d| {
-                let vid = self.vertex_id(d);
-                if d == vid {
-                    Some(vid)
+                }
+                OrbitPolicy::Volume | OrbitPolicy::VolumeLinear => {
+                    unimplemented!("3-cells aren't defined for 2-maps")
+                }
+            }
+            for image in images {
+                if marked.insert(image) {
+                    pending.push_back(image);
+                }
+            }
+            f(trans, d)?;
+        }
+
+        Ok(())
+    }
+
+    /// Return the number of darts making up the orbit of a dart.
+    ///
+    /// This is equivalent to `Orbit2::new(self, orbit_policy, dart_id).count()`, but doesn't
+    /// allocate for predefined policies: the orbit is walked along its beta functions instead of
+    /// being searched. The result is exact as long as beta functions of the map are consistent,
+    /// i.e. if `β0` is the inverse of `β1`, and `β2` an involution.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the policy refers to 3-cells, or if a custom policy refers to
+    /// an invalid beta function.
+    #[must_use = "unused return value"]
+    pub fn orbit_len(&self, orbit_policy: OrbitPolicy, dart_id: DartIdType) -> usize {
+        let vertex_fwd = |d| self.beta::<1>(self.beta::<2>(d));
+        let vertex_bwd = |d| self.beta::<2>(self.beta::<0>(d));
+        match orbit_policy {
+            OrbitPolicy::Vertex => match self.walk_len(dart_id, vertex_fwd) {
+                (n, true) => n,
+                (n, false) => n + self.walk_len(dart_id, vertex_bwd).0 - 1,
+            },
+            OrbitPolicy::VertexLinear => self.walk_len(dart_id, vertex_fwd).0,
+            OrbitPolicy::Edge => {
+                let b2 = self.beta::<2>(dart_id);
+                if b2 == NULL_DART_ID || b2 == dart_id {
+                    1
                 } else {
-                    None
+                    2
                 }
-            })
+            }
+            OrbitPolicy::Face => match self.walk_len(dart_id, |d| self.beta::<1>(d)) {
+                (n, true) => n,
+                (n, false) => n + self.walk_len(dart_id, |d| self.beta::<0>(d)).0 - 1,
+            },
+            OrbitPolicy::FaceLinear => self.walk_len(dart_id, |d| self.beta::<1>(d)).0,
+            OrbitPolicy::Custom(_) => Orbit2::new(self, orbit_policy, dart_id).count(),
+            OrbitPolicy::Volume | OrbitPolicy::VolumeLinear => {
+                unimplemented!("3-cells aren't defined for 2-maps")
+            }
+        }
+    }
+
+    /// Return the number of edges incident to a vertex.
+    ///
+    /// On boundary vertices, i.e. vertices whose orbit is open, the incoming edge at the end of
+    /// the fan is not associated to any dart of the orbit; it is still accounted for.
+    #[must_use = "unused return value"]
+    pub fn vertex_valence(&self, vertex_id: VertexIdType) -> usize {
+        let start = vertex_id as DartIdType;
+        let (mut n, closed) = self.walk_len(start, |d| self.beta::<1>(self.beta::<2>(d)));
+        if closed {
+            return n;
+        }
+        // walk backward until reaching the other end of the fan
+        let mut d = start;
+        loop {
+            let incoming = self.beta::<0>(d);
+            if incoming == NULL_DART_ID {
+                return n;
+            }
+            let prev = self.beta::<2>(incoming);
+            if prev == NULL_DART_ID {
+                return n + 1;
+            }
+            // the bound prevents infinite loops on inconsistent maps
+            if n >= self.n_darts {
+                return n;
+            }
+            n += 1;
+            d = prev;
+        }
+    }
+
+    /// Return the number of darts, i.e. of edges, making up a face.
+    #[must_use = "unused return value"]
+    pub fn face_degree(&self, face_id: FaceIdType) -> usize {
+        self.orbit_len(OrbitPolicy::Face, face_id as DartIdType)
+    }
+
+    /// Return an iterator over IDs of the faces adjacent to a face.
+    ///
+    /// A face ID is yielded for each edge of the face that isn't 2-free, following the order of
+    /// β<sub>1</sub> starting from the dart `face_id`. This means a neighbor sharing multiple
+    /// edges with the face is yielded multiple times, and that the face itself may be yielded
+    /// if it is 2-sewn to itself.
+    #[must_use = "unused return value"]
+    pub fn face_neighbors(&self, face_id: FaceIdType) -> impl Iterator<Item = FaceIdType> + '_ {
+        Orbit2::new(self, OrbitPolicy::Face, face_id as DartIdType)
+            .filter(|d| !self.is_i_free::<2>(*d))
+            .map(|d| self.face_id(self.beta::<2>(d)))
+    }
+
+    /// Count faces of the map by shape.
+    ///
+    /// # Return
+    ///
+    /// Return a [`CellCounts`] where faces are classified, based on their degree, as triangles,
+    /// quadrilaterals, or general polygons. Volume counts are left to zero.
+    #[must_use = "unused return value"]
+    pub fn cell_counts(&self) -> CellCounts {
+        let mut counts = CellCounts::default();
+        self.iter_faces()
+            .for_each(|fid| match self.face_degree(fid) {
+                3 => counts.triangles += 1,
+                4 => counts.quads += 1,
+                _ => counts.polygons += 1,
+            });
+        counts
+    }
+
+    /// Walk from a dart using `step` until coming back to it or reaching the null dart.
+    ///
+    /// Return the number of visited darts, including the starting one, and whether the walk came
+    /// back to its starting point.
+    fn walk_len(
+        &self,
+        dart_id: DartIdType,
+        step: impl Fn(DartIdType) -> DartIdType,
+    ) -> (usize, bool) {
+        let mut n = 1;
+        let mut d = step(dart_id);
+        // the bound prevents infinite loops on inconsistent maps
+        while d != dart_id && d != NULL_DART_ID && n < self.n_darts {
+            n += 1;
+            d = step(d);
+        }
+        (n, d == dart_id)
+    }
+
+    /// Return an iterator over IDs of all the map's vertices.
+    ///
+    /// IDs are yielded in increasing order. Since the ID of a cell is the smallest dart of its
+    /// orbit, this order only depends on the map's topology, which makes it reproducible.
+    #[must_use = "unused return value"]
+    pub fn iter_vertices(&self) -> impl Iterator<Item = VertexIdType> + '_ {
+        self.iter_cells(OrbitPolicy::Vertex)
+    }
+
+    /// Return an iterator over IDs of all the map's vertices, along with their values.
+    ///
+    /// Vertices are yielded in the same order as [`CMap2::iter_vertices`]; vertices without an
+    /// associated value are skipped.
+    #[must_use = "unused return value"]
+    pub fn iter_vertices_with_coords(
+        &self,
+    ) -> impl Iterator<Item = (VertexIdType, Vertex2<T>)> + '_ {
+        self.iter_vertices()
+            .filter_map(|vid| self.force_read_vertex(vid).map(|v| (vid, v)))
     }
 
     /// Return an iterator over IDs of all the map's edges.
+    ///
+    /// IDs are yielded in increasing order.
     #[must_use = "unused return value"]
     pub fn iter_edges(&self) -> impl Iterator<Item = EdgeIdType> + '_ {
+        self.iter_cells(OrbitPolicy::Edge)
+    }
+
+    /// Return an iterator over IDs of all the map's faces.
+    ///
+    /// IDs are yielded in increasing order.
+    #[must_use = "unused return value"]
+    pub fn iter_faces(&self) -> impl Iterator<Item = FaceIdType> + '_ {
+        self.iter_cells(OrbitPolicy::Face)
+    }
+
+    /// Return an iterator over IDs of the cells defined by `orbit_policy`.
+    ///
+    /// Darts are scanned by increasing IDs, marking darts of each encountered orbit. This way,
+    /// the first dart reached in an orbit is its minimum, i.e. the cell ID, and each orbit is
+    /// walked a single time.
+    fn iter_cells(&self, orbit_policy: OrbitPolicy) -> impl Iterator<Item = DartIdType> + '_ {
+        let mut visited = vec![false; self.n_darts()];
         (1..self.n_darts() as DartIdType)
             .zip(self.unused_darts.iter().skip(1))
-            .filter_map(
-                |(d, unused)| {
-                    if unused.read_atomic() {
-                        None
-                    } else {
-                        Some(d)
-                    }
-                },
-            )
-            .filter_map(|d| {
-                let eid = self.edge_id(d);
-                if d == eid {
-                    Some(eid)
-                } else {
-                    None
+            .filter_map(move |(d, unused)| {
+                if unused.read_atomic() || visited[d as usize] {
+                    return None;
                 }
+                Orbit2::new(self, orbit_policy.clone(), d).for_each(|dd| {
+                    visited[dd as usize] = true;
+                });
+                Some(d)
             })
     }
 
-    /// Return an iterator over IDs of all the map's faces.
+    /// Return an iterator over IDs of all the map's faces, along with their vertices.
+    ///
+    /// Vertices of each face are yielded in the order given by β<sub>1</sub>, starting from the
+    /// vertex of the dart sharing the face's ID. Undefined vertices are skipped.
+    ///
+    /// Vertices are stored in a [`SmallVec`], which avoids allocating for triangles and
+    /// quadrilaterals.
     #[must_use = "unused return value"]
-    pub fn iter_faces(&self) -> impl Iterator<Item = FaceIdType> + '_ {
+    pub fn iter_faces_with_geometry(
+        &self,
+    ) -> impl Iterator<Item = (FaceIdType, SmallVec<[Vertex2<T>; 4]>)> + '_ {
+        self.iter_faces().map(|fid| {
+            let mut geometry = SmallVec::new();
+            let mut d = fid as DartIdType;
+            loop {
+                if let Some(v) = self.force_read_vertex(self.vertex_id(d)) {
+                    geometry.push(v);
+                }
+                d = self.beta::<1>(d);
+                if d == fid as DartIdType || d == NULL_DART_ID {
+                    break;
+                }
+            }
+            (fid, geometry)
+        })
+    }
+
+    #[cfg(feature = "par-internals")]
+    /// Return a parallel iterator over IDs of all the map's vertices.
+    ///
+    /// This method yields the same IDs as `iter_vertices`, in no particular order.
+    #[must_use = "unused return value"]
+    pub fn par_iter_vertices(&self) -> impl ParallelIterator<Item = VertexIdType> + '_ {
+        self.par_iter_cells(OrbitPolicy::Vertex)
+    }
+
+    #[cfg(feature = "par-internals")]
+    /// Return a parallel iterator over IDs of all the map's edges.
+    ///
+    /// This method yields the same IDs as `iter_edges`, in no particular order.
+    #[must_use = "unused return value"]
+    pub fn par_iter_edges(&self) -> impl ParallelIterator<Item = EdgeIdType> + '_ {
+        self.par_iter_cells(OrbitPolicy::Edge)
+    }
+
+    #[cfg(feature = "par-internals")]
+    /// Return a parallel iterator over IDs of all the map's faces.
+    ///
+    /// This method yields the same IDs as `iter_faces`, in no particular order.
+    #[must_use = "unused return value"]
+    pub fn par_iter_faces(&self) -> impl ParallelIterator<Item = FaceIdType> + '_ {
+        self.par_iter_cells(OrbitPolicy::Face)
+    }
+
+    #[cfg(feature = "par-internals")]
+    /// Return a parallel iterator over IDs of the cells defined by `orbit_policy`.
+    ///
+    /// Darts are marked in a shared bitset as their orbit is computed, so that each orbit is
+    /// (mostly) walked once. Among the threads reaching a given orbit, only the one marking its
+    /// ID first yields it, which ensures IDs are unique.
+    fn par_iter_cells(
+        &self,
+        orbit_policy: OrbitPolicy,
+    ) -> impl ParallelIterator<Item = DartIdType> + '_ {
+        let visited = AtomicBitset::new(self.n_darts());
         (1..self.n_darts() as DartIdType)
-            .zip(self.unused_darts.iter().skip(1))
-            .filter_map(
-                |(d, unused)| {
-                    if unused.read_atomic() {
-                        None
-                    } else {
-                        Some(d)
-                    }
-                },
-            )
-            .filter_map(|d| {
-                let fid = self.face_id(d);
-                if d == fid {
-                    Some(fid)
-                } else {
-                    None
+            .into_par_iter()
+            .filter(|d| !self.unused_darts[*d].read_atomic())
+            .filter_map(move |d| {
+                if visited.contains(d) {
+                    return None;
                 }
+                let orbit: SmallVec<[DartIdType; 16]> =
+                    Orbit2::new(self, orbit_policy.clone(), d).collect();
+                let cell_id = orbit.iter().copied().min().unwrap_or(d);
+                let first = visited.insert(cell_id);
+                orbit.iter().for_each(|dd| {
+                    visited.insert(*dd);
+                });
+                first.then_some(cell_id)
             })
     }
 }
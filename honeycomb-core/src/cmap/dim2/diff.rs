@@ -0,0 +1,111 @@
+//! Map comparison code
+//!
+//! This module contains code used to compute the difference between two states of a [`CMap2`].
+
+// ------ IMPORTS
+
+use crate::attributes::AttributeStorage;
+use crate::geometry::CoordsFloat;
+use crate::prelude::{CMap2, DartIdType, Vertex2, VertexIdType};
+
+// ------ CONTENT
+
+/// Difference between two states of a map.
+///
+/// This structure is returned by [`CMap2::diff`]. All lists are sorted in increasing order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MapDiff {
+    /// Darts used in the new state, but not in the old one.
+    pub added_darts: Vec<DartIdType>,
+    /// Darts used in the old state, but not in the new one.
+    pub removed_darts: Vec<DartIdType>,
+    /// Darts used in both states whose beta images differ.
+    pub changed_betas: Vec<DartIdType>,
+    /// Vertex identifiers whose associated value differs, including values that were added or
+    /// removed.
+    pub moved_vertices: Vec<VertexIdType>,
+}
+
+impl MapDiff {
+    /// Return `true` if both states are identical.
+    #[must_use = "unused return value"]
+    pub fn is_empty(&self) -> bool {
+        self.added_darts.is_empty()
+            && self.removed_darts.is_empty()
+            && self.changed_betas.is_empty()
+            && self.moved_vertices.is_empty()
+    }
+}
+
+/// **Comparison**
+impl<T: CoordsFloat> CMap2<T> {
+    /// Compute the difference between `self` and `other`.
+    ///
+    /// `self` is considered to be the old state of the map, `other` the new one. Darts are
+    /// compared by identifier, meaning that both maps are expected to originate from the same
+    /// mesh, e.g. a copy of the map taken before an update.
+    ///
+    /// # Arguments
+    ///
+    /// - `other: &CMap2<T>` -- New state of the map.
+    ///
+    /// # Return
+    ///
+    /// Return a [`MapDiff`] listing added/removed darts, darts with modified beta images, and
+    /// vertices whose value changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use honeycomb_core::prelude::{CMap2, CMapBuilder};
+    ///
+    /// let old: CMap2<f64> = CMapBuilder::default().n_darts(2).build().unwrap();
+    /// let mut new: CMap2<f64> = CMapBuilder::default().n_darts(2).build().unwrap();
+    /// new.add_free_dart();
+    /// new.force_link::<1>(1, 2);
+    /// new.force_write_vertex(1, (0.0, 0.0));
+    ///
+    /// let diff = old.diff(&new);
+    ///
+    /// assert_eq!(&diff.added_darts, &[3]);
+    /// assert!(diff.removed_darts.is_empty());
+    /// assert_eq!(&diff.changed_betas, &[1, 2]);
+    /// assert_eq!(&diff.moved_vertices, &[1]);
+    /// ```
+    #[must_use = "unused return value"]
+    pub fn diff(&self, other: &CMap2<T>) -> MapDiff {
+        let mut res = MapDiff::default();
+        let n_darts = self.n_darts.max(other.n_darts);
+        for dart_id in 1..n_darts as DartIdType {
+            match (self.is_used(dart_id), other.is_used(dart_id)) {
+                (false, true) => res.added_darts.push(dart_id),
+                (true, false) => res.removed_darts.push(dart_id),
+                (true, true) => {
+                    if (0..3).any(|i| self.beta_rt(i, dart_id) != other.beta_rt(i, dart_id)) {
+                        res.changed_betas.push(dart_id);
+                    }
+                }
+                (false, false) => {}
+            }
+            let vertex_id = dart_id as VertexIdType;
+            if self.read_vertex_if_any(vertex_id) != other.read_vertex_if_any(vertex_id) {
+                res.moved_vertices.push(vertex_id);
+            }
+        }
+        res
+    }
+
+    /// Return `true` if the dart exists in the map & isn't marked as unused.
+    fn is_used(&self, dart_id: DartIdType) -> bool {
+        (dart_id as usize) < self.n_darts && !self.unused_darts[dart_id].read_atomic()
+    }
+
+    /// Return the value associated to the vertex, or `None` if it is out of bounds.
+    fn read_vertex_if_any(&self, vertex_id: VertexIdType) -> Option<Vertex2<T>> {
+        if (vertex_id as usize) < self.n_darts {
+            self.vertices.force_read(vertex_id)
+        } else {
+            None
+        }
+    }
+}
@@ -6,14 +6,23 @@
 
 // ------ IMPORT
 
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
 use crate::stm::{StmClosureResult, Transaction};
 
-use crate::prelude::{AttributeBind, AttributeUpdate, CMap2, Vertex2, VertexIdType};
+use crate::cmap::{CMapError, CMapResult, VertexMergePolicy};
+use crate::prelude::{
+    AttributeBind, AttributeUpdate, CMap2, DartIdType, FaceIdType, Vertex2, VertexIdType,
+    NULL_DART_ID,
+};
 use crate::{
     attributes::{AttributeStorage, UnknownAttributeStorage},
     geometry::CoordsFloat,
 };
 
+#[cfg(feature = "par-internals")]
+use rayon::prelude::*;
+
 // ------ CONTENT
 
 /// **Built-in vertex-related methods**
@@ -50,6 +59,79 @@ impl<T: CoordsFloat> CMap2<T> {
         self.vertices.read(trans, vertex_id)
     }
 
+    #[allow(clippy::missing_errors_doc)]
+    /// Return the vertex associated to a given identifier, interpolating it if it is undefined.
+    ///
+    /// If there is no value associated to the vertex, this method computes the average of the
+    /// defined vertices sharing an edge with it. The interpolated value is not written to the map.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method is meant to be called in a context where the returned `Result` is used to
+    /// validate the transaction passed as argument. Errors should not be processed manually,
+    /// only processed via the `?` operator.
+    ///
+    /// This method return a `Option` taking the following values:
+    /// - `Some(v: Vertex2)` if there is a vertex associated to this ID, or if it can be
+    ///   interpolated,
+    /// - `None` if the vertex and all of its neighbors are undefined.
+    ///
+    /// # Panics
+    ///
+    /// The method may panic if:
+    /// - the index lands out of bounds,
+    /// - the index cannot be converted to `usize`.
+    pub fn read_vertex_or_interpolate(
+        &self,
+        trans: &mut Transaction,
+        vertex_id: VertexIdType,
+    ) -> StmClosureResult<Option<Vertex2<T>>> {
+        if let Some(v) = self.vertices.read(trans, vertex_id)? {
+            return Ok(Some(v));
+        }
+
+        // collect neighbors by walking through the vertex's darts
+        let mut marked = HashSet::from([NULL_DART_ID, vertex_id as DartIdType]);
+        let mut pending = VecDeque::from([vertex_id as DartIdType]);
+        let mut neighbors = BTreeSet::new();
+        while let Some(d) = pending.pop_front() {
+            // THIS CODE IS ONLY VALID IN 2D
+            let (b0d, b1d, b2d) = (
+                self.beta_transac::<0>(trans, d)?,
+                self.beta_transac::<1>(trans, d)?,
+                self.beta_transac::<2>(trans, d)?,
+            );
+            // both outgoing & incoming darts lead to a neighbor
+            for other in [b1d, b0d] {
+                if other != NULL_DART_ID {
+                    neighbors.insert(self.vertex_id_transac(trans, other)?);
+                }
+            }
+            for image in [
+                self.beta_transac::<1>(trans, b2d)?,
+                self.beta_transac::<2>(trans, b0d)?,
+            ] {
+                if marked.insert(image) {
+                    pending.push_back(image);
+                }
+            }
+        }
+
+        let (mut sum, mut n) = (Vertex2(T::zero(), T::zero()), 0_usize);
+        for nid in neighbors {
+            if let Some(v) = self.vertices.read(trans, nid)? {
+                sum.0 += v.0;
+                sum.1 += v.1;
+                n += 1;
+            }
+        }
+        if n == 0 {
+            return Ok(None);
+        }
+        let n = T::from(n).unwrap();
+        Ok(Some(Vertex2(sum.0 / n, sum.1 / n)))
+    }
+
     #[allow(clippy::missing_errors_doc)]
     /// Replace the vertex associated to a given identifier and return its old value.
     ///
@@ -137,6 +219,238 @@ impl<T: CoordsFloat> CMap2<T> {
     pub fn force_remove_vertex(&self, vertex_id: VertexIdType) -> Option<Vertex2<T>> {
         self.vertices.force_remove(vertex_id)
     }
+
+    #[must_use = "unused return value"]
+    /// Return the axis-aligned bounding box of the map's vertices.
+    ///
+    /// # Return
+    ///
+    /// Return a `(min, max)` tuple of the box's corners, or `None` if no vertex is defined.
+    pub fn bounding_box(&self) -> Option<(Vertex2<T>, Vertex2<T>)> {
+        self.iter_vertices_with_coords()
+            .map(|(_, v)| (v, v))
+            .reduce(merge_boxes)
+    }
+
+    #[cfg(feature = "par-internals")]
+    #[must_use = "unused return value"]
+    /// Return the axis-aligned bounding box of the map's vertices, computed in parallel.
+    ///
+    /// This method yields the same result as `bounding_box`, but is intended for large maps.
+    pub fn par_bounding_box(&self) -> Option<(Vertex2<T>, Vertex2<T>)> {
+        (1..self.n_darts() as DartIdType)
+            .into_par_iter()
+            .filter(|d| !self.unused_darts[*d].read_atomic() && self.vertex_id(*d) == *d)
+            .filter_map(|vid| self.force_read_vertex(vid))
+            .map(|v| (v, v))
+            .reduce_with(merge_boxes)
+    }
+
+    #[must_use = "unused return value"]
+    /// Return the circumcenters of the map's triangular faces.
+    ///
+    /// When the `par-internals` feature is enabled, faces are processed in parallel.
+    ///
+    /// # Return
+    ///
+    /// Return a map associating each triangular face to its circumcenter. Faces that are not
+    /// triangles, that have an undefined vertex, or that are degenerate are not included.
+    pub fn face_circumcenters(&self) -> HashMap<FaceIdType, Vertex2<T>> {
+        #[cfg(feature = "par-internals")]
+        {
+            (1..self.n_darts() as DartIdType)
+                .into_par_iter()
+                .filter(|d| !self.unused_darts[*d].read_atomic() && self.face_id(*d) == *d)
+                .filter_map(|fid| Some((fid, self.triangle_circumcenter(fid)?)))
+                .collect()
+        }
+        #[cfg(not(feature = "par-internals"))]
+        {
+            self.iter_faces()
+                .filter_map(|fid| Some((fid, self.triangle_circumcenter(fid)?)))
+                .collect()
+        }
+    }
+
+    /// Return the circumcenter of the face containing `dart_id` if it is a valid triangle.
+    fn triangle_circumcenter(&self, dart_id: DartIdType) -> Option<Vertex2<T>> {
+        let d1 = self.beta::<1>(dart_id);
+        let d2 = self.beta::<1>(d1);
+        if [d1, d2].contains(&NULL_DART_ID) || self.beta::<1>(d2) != dart_id {
+            return None;
+        }
+        let a = self.force_read_vertex(self.vertex_id(dart_id))?;
+        let b = self.force_read_vertex(self.vertex_id(d1))?;
+        let c = self.force_read_vertex(self.vertex_id(d2))?;
+        Vertex2::circumcenter(&a, &b, &c)
+    }
+}
+
+/// **Vertex merge policy**
+impl<T: CoordsFloat> CMap2<T> {
+    /// Return the rule used to merge vertex values when sewing darts.
+    #[must_use = "unused return value"]
+    pub fn vertex_merge_policy(&self) -> VertexMergePolicy {
+        self.vertex_merge_policy
+    }
+
+    /// Set the rule used to merge vertex values when sewing darts.
+    pub fn set_vertex_merge_policy(&mut self, policy: VertexMergePolicy) {
+        self.vertex_merge_policy = policy;
+    }
+
+    /// Merge values of vertices `lhs_inp` and `rhs_inp` into `out`, following the map's policy.
+    pub(crate) fn merge_vertices(
+        &self,
+        trans: &mut Transaction,
+        out: VertexIdType,
+        lhs_inp: VertexIdType,
+        rhs_inp: VertexIdType,
+    ) -> StmClosureResult<()> {
+        if self.vertex_merge_policy == VertexMergePolicy::Average {
+            return self.vertices.merge(trans, out, lhs_inp, rhs_inp);
+        }
+        match (
+            self.vertices.read(trans, lhs_inp)?,
+            self.vertices.read(trans, rhs_inp)?,
+        ) {
+            (Some(v1), Some(v2)) => {
+                if self.vertex_merge_policy == VertexMergePolicy::ErrorOnConflict && v1 != v2 {
+                    eprintln!("W: merging vertices {lhs_inp} and {rhs_inp} with different values");
+                    eprintln!("   keeping the value of vertex {lhs_inp}");
+                }
+                self.vertices.remove(trans, lhs_inp)?;
+                self.vertices.remove(trans, rhs_inp)?;
+                self.vertices.write(trans, out, v1)?;
+                Ok(())
+            }
+            _ => self.vertices.merge(trans, out, lhs_inp, rhs_inp),
+        }
+    }
+
+    /// Merge values of vertices `lhs_inp` and `rhs_inp` into `out`, following the map's policy.
+    ///
+    /// Unlike [`Self::merge_vertices`], conflicts and missing values result in an error.
+    pub(crate) fn try_merge_vertices(
+        &self,
+        trans: &mut Transaction,
+        out: VertexIdType,
+        lhs_inp: VertexIdType,
+        rhs_inp: VertexIdType,
+    ) -> CMapResult<()> {
+        if self.vertex_merge_policy == VertexMergePolicy::Average {
+            return self.vertices.try_merge(trans, out, lhs_inp, rhs_inp);
+        }
+        match (
+            self.vertices.read(trans, lhs_inp)?,
+            self.vertices.read(trans, rhs_inp)?,
+        ) {
+            (Some(v1), Some(v2)) => {
+                if self.vertex_merge_policy == VertexMergePolicy::ErrorOnConflict && v1 != v2 {
+                    return Err(CMapError::IncorrectGeometry(
+                        "merged vertices do not coincide",
+                    ));
+                }
+                self.vertices.remove(trans, lhs_inp)?;
+                self.vertices.remove(trans, rhs_inp)?;
+                self.vertices.write(trans, out, v1)?;
+                Ok(())
+            }
+            _ => self.vertices.try_merge(trans, out, lhs_inp, rhs_inp),
+        }
+    }
+}
+
+/// **Boundary-related methods**
+impl<T: CoordsFloat> CMap2<T> {
+    /// Return an iterator over boundary darts of the map.
+    ///
+    /// A boundary dart is a used dart with no `β2` image. Darts are yielded in increasing order.
+    #[must_use = "unused return value"]
+    pub fn iter_boundary_darts(&self) -> impl Iterator<Item = DartIdType> + '_ {
+        (1..self.n_darts() as DartIdType).filter(|d| self.is_boundary_dart(*d))
+    }
+
+    #[must_use = "unused return value"]
+    /// Return the boundary loops of the map.
+    ///
+    /// Each loop is made of consecutive boundary darts (see [`Self::iter_boundary_darts`]), i.e.
+    /// the target vertex of a dart is the source vertex of the next one. Loops are listed in
+    /// increasing order of their first dart. A loop is cut short if it reaches a dart with no `β1`
+    /// image.
+    ///
+    /// Each boundary dart belongs to exactly one loop. If the boundary goes through a vertex more
+    /// than once, the next dart is searched among the darts adjacent to the incoming one, so that
+    /// loops do not cross each other at that vertex.
+    pub fn boundary_loops(&self) -> Vec<Vec<DartIdType>> {
+        let mut marked: HashSet<DartIdType> = HashSet::from([NULL_DART_ID]);
+        let mut loops = Vec::new();
+        for dart_id in self.iter_boundary_darts() {
+            let mut current = Vec::new();
+            let mut d = dart_id;
+            while marked.insert(d) {
+                current.push(d);
+                d = self.next_boundary_dart(d);
+            }
+            if !current.is_empty() {
+                loops.push(current);
+            }
+        }
+        loops
+    }
+
+    #[must_use = "unused return value"]
+    /// Return the total length of the map's boundary.
+    ///
+    /// Edges with undefined vertices are ignored.
+    pub fn boundary_length(&self) -> T {
+        self.iter_boundary_darts()
+            .filter_map(|d| self.dart_length(d))
+            .fold(T::zero(), |acc, l| acc + l)
+    }
+
+    #[must_use = "unused return value"]
+    /// Return the perimeter of each boundary loop of the map.
+    ///
+    /// Perimeters are given in the same order as loops of [`Self::boundary_loops`]. Edges with
+    /// undefined vertices are ignored.
+    pub fn boundary_loop_perimeters(&self) -> Vec<T> {
+        self.boundary_loops()
+            .iter()
+            .map(|darts| {
+                darts
+                    .iter()
+                    .filter_map(|d| self.dart_length(*d))
+                    .fold(T::zero(), |acc, l| acc + l)
+            })
+            .collect()
+    }
+
+    /// Return `true` if the dart is used and has no `β2` image.
+    fn is_boundary_dart(&self, dart_id: DartIdType) -> bool {
+        !self.unused_darts[dart_id].read_atomic() && self.beta::<2>(dart_id) == NULL_DART_ID
+    }
+
+    /// Return the boundary dart starting from the target vertex of `dart_id`.
+    fn next_boundary_dart(&self, dart_id: DartIdType) -> DartIdType {
+        let mut d = self.beta::<1>(dart_id);
+        // rotate around the vertex until reaching the boundary
+        while d != NULL_DART_ID && d != dart_id && self.beta::<2>(d) != NULL_DART_ID {
+            d = self.beta::<1>(self.beta::<2>(d));
+        }
+        d
+    }
+
+    /// Return the length of the edge going from the source to the target vertex of `dart_id`.
+    fn dart_length(&self, dart_id: DartIdType) -> Option<T> {
+        let b1 = self.beta::<1>(dart_id);
+        if b1 == NULL_DART_ID {
+            return None;
+        }
+        let v1 = self.force_read_vertex(self.vertex_id(dart_id))?;
+        let v2 = self.force_read_vertex(self.vertex_id(b1))?;
+        Some((v2 - v1).norm())
+    }
 }
 
 /// **Generic attribute-related methods**
@@ -264,6 +578,12 @@ impl<T: CoordsFloat> CMap2<T> {
 
     // --- big guns
 
+    /// Check whether the map holds a storage for the attribute `A`.
+    #[must_use = "unused return value"]
+    pub fn contains_attribute_storage<A: AttributeBind + AttributeUpdate>(&self) -> bool {
+        self.attributes.contains_storage::<A>()
+    }
+
     /// Remove the attribute `A`'s storage from the map.
     ///
     /// This method is useful when implementing routines that uses attributes to run; Those can
@@ -272,3 +592,14 @@ impl<T: CoordsFloat> CMap2<T> {
         self.attributes.remove_storage::<A>();
     }
 }
+
+/// Merge two `(min, max)` bounding boxes.
+fn merge_boxes<T: CoordsFloat>(
+    lhs: (Vertex2<T>, Vertex2<T>),
+    rhs: (Vertex2<T>, Vertex2<T>),
+) -> (Vertex2<T>, Vertex2<T>) {
+    (
+        Vertex2(lhs.0 .0.min(rhs.0 .0), lhs.0 .1.min(rhs.0 .1)),
+        Vertex2(lhs.1 .0.max(rhs.1 .0), lhs.1 .1.max(rhs.1 .1)),
+    )
+}
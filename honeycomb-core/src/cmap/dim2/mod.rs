@@ -7,7 +7,9 @@
 
 // ------ MODULE DECLARATIONS
 
+pub mod audit;
 pub mod basic_ops;
+pub mod diff;
 pub mod embed;
 pub mod links;
 pub mod orbits;
@@ -64,17 +64,18 @@ impl<'a, T: CoordsFloat> Orbit2<'a, T> {
     /// Currently, orbits use two dynamically allocated structures for computation: a `VecDeque`,
     /// and a `BTreeSet`. Allocations are made by the constructor since these structures aren't
     /// empty initially.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, this method panics if the policy is custom and invalid for 2-maps. See
+    /// [`OrbitPolicy::check_dim`].
     #[must_use = "unused return value"]
     pub fn new(map_handle: &'a CMap2<T>, orbit_policy: OrbitPolicy, dart: DartIdType) -> Self {
         let mut marked = BTreeSet::<DartIdType>::new();
         marked.insert(NULL_DART_ID); // we don't want to include the null dart in the orbit
         marked.insert(dart); // we're starting here, so we mark it beforehand
         let pending = VecDeque::from([dart]);
-        /*
-        if let OrbitPolicy::Custom(slice) = orbit_policy {
-            assert!(!slice.len().is_zero());
-        }
-        */
+        debug_assert_eq!(orbit_policy.check_dim(2), Ok(()));
         Self {
             map_handle,
             orbit_policy,
@@ -166,6 +167,21 @@ impl<T: CoordsFloat> Iterator for Orbit2<'_, T> {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // pending darts will be yielded, undiscovered darts may be
+        let n_pending = self.pending.len();
+        let n_undiscovered = self.map_handle.n_darts().saturating_sub(self.marked.len());
+        let upper = match self.orbit_policy {
+            OrbitPolicy::Edge => {
+                // marked darts, minus the null dart, are either pending or visited
+                let n_visited = self.marked.len() - 1 - n_pending;
+                2_usize.saturating_sub(n_visited)
+            }
+            _ => n_pending + n_undiscovered,
+        };
+        (n_pending, Some(upper))
+    }
 }
 
 // ------ TESTS
@@ -174,6 +190,33 @@ impl<T: CoordsFloat> Iterator for Orbit2<'_, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cmap::OrbitError;
+
+    #[test]
+    fn custom_policy() {
+        assert_eq!(
+            OrbitPolicy::custom(&[1, 2]),
+            Ok(OrbitPolicy::Custom(&[1, 2]))
+        );
+        assert_eq!(OrbitPolicy::custom(&[]), Err(OrbitError::EmptyPolicy));
+        assert_eq!(
+            OrbitPolicy::custom(&[1, 4]),
+            Err(OrbitError::InvalidBeta { beta: 4, dim: 3 })
+        );
+        // valid for 3-maps only
+        let policy = OrbitPolicy::custom(&[3, 1]).unwrap();
+        assert_eq!(policy.check_dim(3), Ok(()));
+        assert_eq!(
+            policy.check_dim(2),
+            Err(OrbitError::InvalidBeta { beta: 3, dim: 2 })
+        );
+        assert_eq!(
+            OrbitPolicy::Custom(&[]).check_dim(2),
+            Err(OrbitError::EmptyPolicy)
+        );
+        // built-in policies are always valid
+        assert_eq!(OrbitPolicy::Face.check_dim(2), Ok(()));
+    }
 
     fn simple_map() -> CMap2<f64> {
         let mut map: CMap2<f64> = CMap2::new(11);
@@ -274,6 +317,51 @@ mod tests {
     }
 
     #[test]
+    fn orbit_len() {
+        let map = simple_map();
+        for policy in [
+            OrbitPolicy::Vertex,
+            OrbitPolicy::VertexLinear,
+            OrbitPolicy::Edge,
+            OrbitPolicy::Face,
+            OrbitPolicy::FaceLinear,
+            OrbitPolicy::Custom(&[1, 2]),
+        ] {
+            for dart in 1..map.n_darts() as DartIdType {
+                assert_eq!(
+                    map.orbit_len(policy.clone(), dart),
+                    Orbit2::new(&map, policy.clone(), dart).count()
+                );
+            }
+        }
+        assert_eq!(map.orbit_len(OrbitPolicy::Face, 7), 5);
+        assert_eq!(map.orbit_len(OrbitPolicy::Vertex, 4), 3);
+        assert_eq!(map.orbit_len(OrbitPolicy::VertexLinear, 4), 2);
+    }
+
+    #[test]
+    fn orbit_size_hint() {
+        let map = simple_map();
+        for policy in [OrbitPolicy::Vertex, OrbitPolicy::Edge, OrbitPolicy::Face] {
+            for dart in 1..map.n_darts() as DartIdType {
+                let mut orbit = Orbit2::new(&map, policy.clone(), dart);
+                let mut n_remaining = map.orbit_len(policy.clone(), dart);
+                loop {
+                    let (lower, upper) = orbit.size_hint();
+                    assert!(lower <= n_remaining);
+                    assert!(upper.is_some_and(|upper| n_remaining <= upper));
+                    if orbit.next().is_none() {
+                        break;
+                    }
+                    n_remaining -= 1;
+                }
+                assert_eq!(n_remaining, 0);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "EmptyPolicy"))]
     fn empty_orbit_policy() {
         let map = simple_map();
         let darts: Vec<DartIdType> = Orbit2::new(&map, OrbitPolicy::Custom(&[]), 3).collect();
@@ -281,7 +369,11 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "assertion failed: i < 3")]
+    #[cfg_attr(debug_assertions, should_panic(expected = "InvalidBeta"))]
+    #[cfg_attr(
+        not(debug_assertions),
+        should_panic(expected = "assertion failed: i < 3")
+    )]
     fn invalid_orbit_policy() {
         let map = simple_map();
         let orbit = Orbit2::new(&map, OrbitPolicy::Custom(&[6]), 3);
@@ -66,6 +66,40 @@ impl<T: CoordsFloat + 'static> CMap2<T> {
             .write_legacy_ascii(writer)
             .expect("E: could not write data to writer");
     }
+
+    /// Generate a legacy VTK file describing the boundary of the map.
+    ///
+    /// The output uses the format expected by the `grisubal` kernel as input geometry:
+    /// - each boundary dart is written as a `Line` cell, following the dart's orientation,
+    /// - each corner of the boundary is written as a `Vertex` cell, i.e. a point of interest.
+    ///
+    /// A boundary vertex is considered a corner if its incoming and outgoing boundary segments
+    /// are not aligned, or if it is the end of an open boundary loop (see
+    /// [`Self::boundary_loops`]).
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the internal writing routine fails, i.e.:
+    /// - vertex coordinates cannot be cast to `f32` or `f64`,
+    /// - a vertex cannot be found.
+    pub fn write_boundary_vtk(&self, writer: impl std::io::Write) {
+        // build a Vtk structure
+        let vtk_struct = Vtk {
+            version: Version::Legacy { major: 2, minor: 0 },
+            title: "boundary".to_string(),
+            byte_order: ByteOrder::BigEndian,
+            data: DataSet::UnstructuredGrid {
+                meta: None,
+                pieces: vec![Piece::Inline(Box::new(build_boundary_piece(self)))],
+            },
+            file_path: None,
+        };
+
+        // write data to the created file
+        vtk_struct
+            .write_legacy(writer)
+            .expect("E: could not write data to writer");
+    }
 }
 
 /// Internal building routine for VTK serialization.
@@ -144,26 +178,7 @@ where
     });
 
     UnstructuredGridPiece {
-        points: if TypeId::of::<T>() == TypeId::of::<f32>() {
-            IOBuffer::F32(
-                vertices
-                    .map(|t| t.to_f32().expect("E: unreachable"))
-                    .collect(),
-            )
-        } else if TypeId::of::<T>() == TypeId::of::<f64>() {
-            IOBuffer::F64(
-                vertices
-                    .map(|t| t.to_f64().expect("E: unreachable"))
-                    .collect(),
-            )
-        } else {
-            println!("W: unrecognized coordinate type -- cast to f64 might fail");
-            IOBuffer::F64(
-                vertices
-                    .map(|t| t.to_f64().expect("E: unreachable"))
-                    .collect(),
-            )
-        },
+        points: build_points_buffer(vertices),
         cells: vtkio::model::Cells {
             cell_verts: VertexNumbers::Legacy {
                 num_cells: n_cells,
@@ -174,3 +189,114 @@ where
         data: vtkio::model::Attributes::default(),
     }
 }
+
+/// Internal building routine for VTK serialization of the boundary.
+fn build_boundary_piece<T>(map: &CMap2<T>) -> UnstructuredGridPiece
+where
+    T: CoordsFloat + 'static,
+{
+    let read_vertex = |vid: VertexIdType| {
+        map.force_read_vertex(vid)
+            .expect("E: found a topological vertex with no associated coordinates")
+    };
+    let tolerance = T::epsilon().sqrt();
+
+    let mut vertex_ids: Vec<VertexIdType> = Vec::new();
+    let mut id_map: BTreeMap<VertexIdType, u32> = BTreeMap::new();
+    let mut point_id = |vid: VertexIdType| {
+        *id_map.entry(vid).or_insert_with(|| {
+            vertex_ids.push(vid);
+            (vertex_ids.len() - 1) as u32
+        })
+    };
+
+    let mut cell_vertices: Vec<u32> = Vec::new();
+    let mut cell_types: Vec<CellType> = Vec::new();
+    let mut corners: Vec<u32> = Vec::new();
+
+    for darts in map.boundary_loops() {
+        // darts with no `β1` image do not make up a segment
+        let segments: Vec<(VertexIdType, VertexIdType)> = darts
+            .iter()
+            .filter(|d| map.beta::<1>(**d) != NULL_DART_ID)
+            .map(|d| (map.vertex_id(*d), map.vertex_id(map.beta::<1>(*d))))
+            .collect();
+        let Some((&first, &last)) = segments.first().zip(segments.last()) else {
+            continue;
+        };
+
+        // --- lines
+        for &(v1, v2) in &segments {
+            cell_types.push(CellType::Line);
+            cell_vertices.extend([2, point_id(v1), point_id(v2)]);
+        }
+
+        // --- corners
+        let closed = last.1 == first.0;
+        if !closed {
+            corners.extend([point_id(first.0), point_id(last.1)]);
+        }
+        let incoming = if closed { Some(&last) } else { None };
+        for (&(v_in, v), &(_, v_out)) in incoming
+            .into_iter()
+            .chain(&segments)
+            .zip(segments.iter().skip(usize::from(!closed)))
+        {
+            let (p_in, p, p_out) = (read_vertex(v_in), read_vertex(v), read_vertex(v_out));
+            let (u, w) = (p - p_in, p_out - p);
+            let cross = u.x() * w.y() - u.y() * w.x();
+            if cross.abs() > tolerance * u.norm() * w.norm() || u.dot(&w) <= T::zero() {
+                corners.push(point_id(v));
+            }
+        }
+    }
+
+    for id in corners {
+        cell_types.push(CellType::Vertex);
+        cell_vertices.extend([1, id]);
+    }
+
+    let vertices = vertex_ids
+        .into_iter()
+        .map(read_vertex)
+        .flat_map(|v| [v.x(), v.y(), T::zero()].into_iter());
+
+    UnstructuredGridPiece {
+        points: build_points_buffer(vertices),
+        cells: vtkio::model::Cells {
+            cell_verts: VertexNumbers::Legacy {
+                num_cells: cell_types.len() as u32,
+                vertices: cell_vertices,
+            },
+            types: cell_types,
+        },
+        data: vtkio::model::Attributes::default(),
+    }
+}
+
+/// Internal routine converting a sequence of coordinates to a VTK buffer.
+fn build_points_buffer<T>(coords: impl Iterator<Item = T>) -> IOBuffer
+where
+    T: CoordsFloat + 'static,
+{
+    if TypeId::of::<T>() == TypeId::of::<f32>() {
+        IOBuffer::F32(
+            coords
+                .map(|t| t.to_f32().expect("E: unreachable"))
+                .collect(),
+        )
+    } else if TypeId::of::<T>() == TypeId::of::<f64>() {
+        IOBuffer::F64(
+            coords
+                .map(|t| t.to_f64().expect("E: unreachable"))
+                .collect(),
+        )
+    } else {
+        println!("W: unrecognized coordinate type -- cast to f64 might fail");
+        IOBuffer::F64(
+            coords
+                .map(|t| t.to_f64().expect("E: unreachable"))
+                .collect(),
+        )
+    }
+}
@@ -4,7 +4,7 @@ mod two;
 use crate::stm::Transaction;
 
 use crate::{
-    cmap::{CMap2, CMapResult, DartIdType},
+    cmap::{try_atomically, CMap2, CMapResult, DartIdType},
     prelude::CoordsFloat,
 };
 
@@ -140,4 +140,59 @@ impl<T: CoordsFloat> CMap2<T> {
             _ => unreachable!(),
         }
     }
+
+    /// `I`-sew operator, applied to a batch of dart pairs.
+    ///
+    /// Sews are applied in the order of the slice, using a single transaction: either all of them
+    /// are applied, or none is. As for [`force_sew`][Self::force_sew], the transaction is retried
+    /// on conflicts; other errors abort the whole batch.
+    ///
+    /// Compared to successive calls to `force_sew`, this reduces the transactional overhead when
+    /// building maps, and prevents partially built maps in case of failure.
+    ///
+    /// # Arguments
+    ///
+    /// - `const I: u8` -- Sew dimension.
+    /// - `pairs: &[(DartIdType, DartIdType)]` -- Pairs of darts to sew.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the first error raised by a sew of the batch, e.g. a failed attribute
+    /// merge (see [`sew`][Self::sew]). In this case, the map is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// The method may panic if:
+    /// - `I >= 3` or `I == 0`,
+    /// - the two darts of a pair are not `I`-sewable.
+    pub fn force_sew_many<const I: u8>(
+        &self,
+        pairs: &[(DartIdType, DartIdType)],
+    ) -> CMapResult<()> {
+        try_atomically(|trans| {
+            for (ld, rd) in pairs {
+                self.sew::<I>(trans, *ld, *rd)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// 2-sew multiple pairs of darts.
+    ///
+    /// This method is equivalent to calling [`force_sew::<2>`][Self::force_sew] on each pair,
+    /// in order, but is intended for large batches of sews, e.g. when building grids: vertex
+    /// IDs are tracked across the batch instead of being recomputed for each sew.
+    ///
+    /// The map should not be modified concurrently while this method is running.
+    ///
+    /// # Arguments
+    ///
+    /// - `pairs: &[(DartIdType, DartIdType)]` -- Pairs of darts to 2-sew.
+    ///
+    /// # Panics
+    ///
+    /// The method may panic if two darts of a pair are not 2-sewable.
+    pub fn two_sew_pairs(&self, pairs: &[(DartIdType, DartIdType)]) {
+        self.force_two_sew_pairs(pairs);
+    }
 }
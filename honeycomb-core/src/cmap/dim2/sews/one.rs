@@ -27,8 +27,7 @@ impl<T: CoordsFloat> CMap2<T> {
 
             let new_vid = self.vertex_id_transac(trans, rhs_dart_id)?;
 
-            self.vertices
-                .try_merge(trans, new_vid, b2lhs_vid_old, rhs_vid_old)?;
+            self.try_merge_vertices(trans, new_vid, b2lhs_vid_old, rhs_vid_old)?;
             self.attributes.try_merge_vertex_attributes(
                 trans,
                 new_vid,
@@ -53,8 +52,7 @@ impl<T: CoordsFloat> CMap2<T> {
 
                 let new_vid = self.vertex_id_transac(trans, rhs_dart_id)?;
 
-                self.vertices
-                    .merge(trans, new_vid, b2lhs_vid_old, rhs_vid_old)?;
+                self.merge_vertices(trans, new_vid, b2lhs_vid_old, rhs_vid_old)?;
                 self.attributes.merge_vertex_attributes(
                     trans,
                     new_vid,
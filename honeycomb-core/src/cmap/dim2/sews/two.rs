@@ -1,8 +1,10 @@
+use std::collections::HashMap;
+
 use crate::stm::{atomically, Transaction};
 
 use crate::{
-    attributes::{AttributeStorage, UnknownAttributeStorage},
-    cmap::{CMap2, CMapResult, DartIdType, NULL_DART_ID},
+    attributes::AttributeStorage,
+    cmap::{CMap2, CMapResult, DartIdType, Orbit2, OrbitPolicy, VertexIdType, NULL_DART_ID},
     prelude::CoordsFloat,
 };
 
@@ -29,7 +31,7 @@ impl<T: CoordsFloat> CMap2<T> {
             (true, false) => {
                 // fetch vertices ID before topology update
                 let lhs_eid_old = self.edge_id_transac(trans, lhs_dart_id)?;
-                let rhs_eid_old = self.edge_id_transac(trans, b1rhs_dart_id)?;
+                let rhs_eid_old = self.edge_id_transac(trans, rhs_dart_id)?;
                 let lhs_vid_old = self.vertex_id_transac(trans, lhs_dart_id)?;
                 let b1rhs_vid_old = self.vertex_id_transac(trans, b1rhs_dart_id)?;
                 // update the topology
@@ -37,8 +39,7 @@ impl<T: CoordsFloat> CMap2<T> {
                 // merge vertices & attributes from the old IDs to the new one
                 let lhs_vid_new = self.vertex_id_transac(trans, lhs_dart_id)?;
                 let eid_new = self.edge_id_transac(trans, lhs_dart_id)?;
-                self.vertices
-                    .try_merge(trans, lhs_vid_new, lhs_vid_old, b1rhs_vid_old)?;
+                self.try_merge_vertices(trans, lhs_vid_new, lhs_vid_old, b1rhs_vid_old)?;
                 self.attributes.try_merge_vertex_attributes(
                     trans,
                     lhs_vid_new,
@@ -56,7 +57,7 @@ impl<T: CoordsFloat> CMap2<T> {
             (false, true) => {
                 // fetch vertices ID before topology update
                 let lhs_eid_old = self.edge_id_transac(trans, lhs_dart_id)?;
-                let rhs_eid_old = self.edge_id_transac(trans, b1rhs_dart_id)?;
+                let rhs_eid_old = self.edge_id_transac(trans, rhs_dart_id)?;
                 let b1lhs_vid_old = self.vertex_id_transac(trans, b1lhs_dart_id)?;
                 let rhs_vid_old = self.vertex_id_transac(trans, rhs_dart_id)?;
                 // update the topology
@@ -64,8 +65,7 @@ impl<T: CoordsFloat> CMap2<T> {
                 // merge vertices & attributes from the old IDs to the new one
                 let rhs_vid_new = self.vertex_id_transac(trans, rhs_dart_id)?;
                 let eid_new = self.edge_id_transac(trans, lhs_dart_id)?;
-                self.vertices
-                    .try_merge(trans, rhs_vid_new, b1lhs_vid_old, rhs_vid_old)?;
+                self.try_merge_vertices(trans, rhs_vid_new, b1lhs_vid_old, rhs_vid_old)?;
                 self.attributes.try_merge_vertex_attributes(
                     trans,
                     rhs_vid_new,
@@ -83,7 +83,7 @@ impl<T: CoordsFloat> CMap2<T> {
             (false, false) => {
                 // fetch vertices ID before topology update
                 let lhs_eid_old = self.edge_id_transac(trans, lhs_dart_id)?;
-                let rhs_eid_old = self.edge_id_transac(trans, b1rhs_dart_id)?;
+                let rhs_eid_old = self.edge_id_transac(trans, rhs_dart_id)?;
                 // (lhs/b1rhs) vertex
                 let lhs_vid_old = self.vertex_id_transac(trans, lhs_dart_id)?;
                 let b1rhs_vid_old = self.vertex_id_transac(trans, b1rhs_dart_id)?;
@@ -119,10 +119,8 @@ impl<T: CoordsFloat> CMap2<T> {
                 let lhs_vid_new = self.vertex_id_transac(trans, lhs_dart_id)?;
                 let rhs_vid_new = self.vertex_id_transac(trans, rhs_dart_id)?;
                 let eid_new = self.edge_id_transac(trans, lhs_dart_id)?;
-                self.vertices
-                    .try_merge(trans, lhs_vid_new, lhs_vid_old, b1rhs_vid_old)?;
-                self.vertices
-                    .try_merge(trans, rhs_vid_new, b1lhs_vid_old, rhs_vid_old)?;
+                self.try_merge_vertices(trans, lhs_vid_new, lhs_vid_old, b1rhs_vid_old)?;
+                self.try_merge_vertices(trans, rhs_vid_new, b1lhs_vid_old, rhs_vid_old)?;
                 self.attributes.try_merge_vertex_attributes(
                     trans,
                     lhs_vid_new,
@@ -162,7 +160,7 @@ impl<T: CoordsFloat> CMap2<T> {
                 (true, false) => {
                     // fetch vertices ID before topology update
                     let lhs_eid_old = self.edge_id_transac(trans, lhs_dart_id)?;
-                    let rhs_eid_old = self.edge_id_transac(trans, b1rhs_dart_id)?;
+                    let rhs_eid_old = self.edge_id_transac(trans, rhs_dart_id)?;
                     let lhs_vid_old = self.vertex_id_transac(trans, lhs_dart_id)?;
                     let b1rhs_vid_old = self.vertex_id_transac(trans, b1rhs_dart_id)?;
                     // update the topology
@@ -170,8 +168,7 @@ impl<T: CoordsFloat> CMap2<T> {
                     // merge vertices & attributes from the old IDs to the new one
                     let lhs_vid_new = self.vertex_id_transac(trans, lhs_dart_id)?;
                     let eid_new = self.edge_id_transac(trans, lhs_dart_id)?;
-                    self.vertices
-                        .merge(trans, lhs_vid_new, lhs_vid_old, b1rhs_vid_old)?;
+                    self.merge_vertices(trans, lhs_vid_new, lhs_vid_old, b1rhs_vid_old)?;
                     self.attributes.merge_vertex_attributes(
                         trans,
                         lhs_vid_new,
@@ -189,7 +186,7 @@ impl<T: CoordsFloat> CMap2<T> {
                 (false, true) => {
                     // fetch vertices ID before topology update
                     let lhs_eid_old = self.edge_id_transac(trans, lhs_dart_id)?;
-                    let rhs_eid_old = self.edge_id_transac(trans, b1rhs_dart_id)?;
+                    let rhs_eid_old = self.edge_id_transac(trans, rhs_dart_id)?;
                     let b1lhs_vid_old = self.vertex_id_transac(trans, b1lhs_dart_id)?;
                     let rhs_vid_old = self.vertex_id_transac(trans, rhs_dart_id)?;
                     // update the topology
@@ -197,8 +194,7 @@ impl<T: CoordsFloat> CMap2<T> {
                     // merge vertices & attributes from the old IDs to the new one
                     let rhs_vid_new = self.vertex_id_transac(trans, rhs_dart_id)?;
                     let eid_new = self.edge_id_transac(trans, lhs_dart_id)?;
-                    self.vertices
-                        .merge(trans, rhs_vid_new, b1lhs_vid_old, rhs_vid_old)?;
+                    self.merge_vertices(trans, rhs_vid_new, b1lhs_vid_old, rhs_vid_old)?;
                     self.attributes.merge_vertex_attributes(
                         trans,
                         rhs_vid_new,
@@ -216,7 +212,7 @@ impl<T: CoordsFloat> CMap2<T> {
                 (false, false) => {
                     // fetch vertices ID before topology update
                     let lhs_eid_old = self.edge_id_transac(trans, lhs_dart_id)?;
-                    let rhs_eid_old = self.edge_id_transac(trans, b1rhs_dart_id)?;
+                    let rhs_eid_old = self.edge_id_transac(trans, rhs_dart_id)?;
                     // (lhs/b1rhs) vertex
                     let lhs_vid_old = self.vertex_id_transac(trans, lhs_dart_id)?;
                     let b1rhs_vid_old = self.vertex_id_transac(trans, b1rhs_dart_id)?;
@@ -252,10 +248,8 @@ impl<T: CoordsFloat> CMap2<T> {
                     let lhs_vid_new = self.vertex_id_transac(trans, lhs_dart_id)?;
                     let rhs_vid_new = self.vertex_id_transac(trans, rhs_dart_id)?;
                     let eid_new = self.edge_id_transac(trans, lhs_dart_id)?;
-                    self.vertices
-                        .merge(trans, lhs_vid_new, lhs_vid_old, b1rhs_vid_old)?;
-                    self.vertices
-                        .merge(trans, rhs_vid_new, b1lhs_vid_old, rhs_vid_old)?;
+                    self.merge_vertices(trans, lhs_vid_new, lhs_vid_old, b1rhs_vid_old)?;
+                    self.merge_vertices(trans, rhs_vid_new, b1lhs_vid_old, rhs_vid_old)?;
                     self.attributes.merge_vertex_attributes(
                         trans,
                         lhs_vid_new,
@@ -281,6 +275,114 @@ impl<T: CoordsFloat> CMap2<T> {
     }
 }
 
+#[doc(hidden)]
+/// Bulk 2-sews
+impl<T: CoordsFloat> CMap2<T> {
+    /// Bulk 2-sew implementation.
+    ///
+    /// Vertex IDs are tracked across sews instead of being recomputed from orbits before and
+    /// after each link. Merges are applied with the same IDs, in the same order, as successive
+    /// calls to `force_two_sew`.
+    pub(super) fn force_two_sew_pairs(&self, pairs: &[(DartIdType, DartIdType)]) {
+        let mut vertices = VertexClasses::default();
+        for &(lhs_dart_id, rhs_dart_id) in pairs {
+            let b1lhs_dart_id = self.beta::<1>(lhs_dart_id);
+            let b1rhs_dart_id = self.beta::<1>(rhs_dart_id);
+            // (old, old, new) IDs of the (lhs/b1rhs) & (b1lhs/rhs) vertices
+            let lhs_merge = (b1rhs_dart_id != NULL_DART_ID)
+                .then(|| vertices.union(self, lhs_dart_id, b1rhs_dart_id));
+            let rhs_merge = (b1lhs_dart_id != NULL_DART_ID)
+                .then(|| vertices.union(self, b1lhs_dart_id, rhs_dart_id));
+            // both darts are 2-free, so they are the IDs of their edges
+            let eid_new = lhs_dart_id.min(rhs_dart_id);
+
+            atomically(|trans| {
+                // check orientation
+                if let (Some((l_vid, b1r_vid, _)), Some((b1l_vid, r_vid, _))) =
+                    (lhs_merge, rhs_merge)
+                {
+                    if let (Some(l_vertex), Some(b1r_vertex), Some(b1l_vertex), Some(r_vertex)) = (
+                        self.vertices.read(trans, l_vid)?,
+                        self.vertices.read(trans, b1r_vid)?,
+                        self.vertices.read(trans, b1l_vid)?,
+                        self.vertices.read(trans, r_vid)?,
+                    ) {
+                        let lhs_vector = b1l_vertex - l_vertex;
+                        let rhs_vector = b1r_vertex - r_vertex;
+                        assert!(
+                            lhs_vector.dot(&rhs_vector) < T::zero(),
+                            "{}",
+                            format!("Dart {lhs_dart_id} and {rhs_dart_id} do not have consistent orientation for 2-sewing"),
+                        );
+                    }
+                }
+
+                // update the topology
+                self.betas.two_link_core(trans, lhs_dart_id, rhs_dart_id)?;
+                // merge vertices & attributes from the old IDs to the new one
+                for (old1, old2, new) in [lhs_merge, rhs_merge].into_iter().flatten() {
+                    self.merge_vertices(trans, new, old1, old2)?;
+                    self.attributes
+                        .merge_vertex_attributes(trans, new, old1, old2)?;
+                }
+                if lhs_merge.is_some() || rhs_merge.is_some() {
+                    self.attributes.merge_edge_attributes(
+                        trans,
+                        eid_new,
+                        lhs_dart_id,
+                        rhs_dart_id,
+                    )?;
+                }
+                Ok(())
+            });
+        }
+    }
+}
+
+/// Vertex IDs of darts, tracked across successive 2-sews using a union-find structure.
+///
+/// Darts are added along with their whole vertex orbit, so that classes always match orbits of
+/// the map, as long as it is only modified through [`VertexClasses::union`].
+#[derive(Default)]
+struct VertexClasses(HashMap<DartIdType, DartIdType>);
+
+impl VertexClasses {
+    /// Return the vertex ID of `dart_id`.
+    fn find<T: CoordsFloat>(&mut self, map: &CMap2<T>, dart_id: DartIdType) -> VertexIdType {
+        if !self.0.contains_key(&dart_id) {
+            let orbit: Vec<DartIdType> = Orbit2::new(map, OrbitPolicy::Vertex, dart_id).collect();
+            let vid = orbit.iter().copied().min().unwrap_or(dart_id);
+            self.0.extend(orbit.into_iter().map(|d| (d, vid)));
+            return vid;
+        }
+        let mut root = dart_id;
+        while self.0[&root] != root {
+            root = self.0[&root];
+        }
+        // compress the path for later lookups
+        let mut d = dart_id;
+        while d != root {
+            d = self.0.insert(d, root).expect("E: unreachable");
+        }
+        root
+    }
+
+    /// Merge the vertices of `lhs` and `rhs`.
+    ///
+    /// Return the IDs of the two vertices before the merge, along with the ID of the new one.
+    fn union<T: CoordsFloat>(
+        &mut self,
+        map: &CMap2<T>,
+        lhs: DartIdType,
+        rhs: DartIdType,
+    ) -> (VertexIdType, VertexIdType, VertexIdType) {
+        let (lhs_vid, rhs_vid) = (self.find(map, lhs), self.find(map, rhs));
+        let new_vid = lhs_vid.min(rhs_vid);
+        self.0.insert(lhs_vid.max(rhs_vid), new_vid);
+        (lhs_vid, rhs_vid, new_vid)
+    }
+}
+
 #[doc(hidden)]
 /// 2-unsews
 impl<T: CoordsFloat> CMap2<T> {
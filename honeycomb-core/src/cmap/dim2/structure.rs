@@ -16,6 +16,25 @@ use crate::{
 
 // ------ CONTENT
 
+/// Rule used to merge vertex values when sewing darts.
+///
+/// The policy only applies when both merged vertices have a value; if only one of them is
+/// defined, it is kept as is.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum VertexMergePolicy {
+    /// Use the average of both values. This is the default behavior.
+    #[default]
+    Average,
+    /// Keep the value of the vertex carried by the left-hand side dart of the sew.
+    KeepFirst,
+    /// Treat values that don't coincide exactly as a failure.
+    ///
+    /// Fallible sew methods return a [`CMapError::IncorrectGeometry`][crate::cmap::CMapError]
+    /// error in that case. Infallible variants (`force_` prefix) print a warning and keep the
+    /// value of the left-hand side vertex.
+    ErrorOnConflict,
+}
+
 /// # 2D combinatorial map implementation
 ///
 /// Information regarding maps can be found in the [user guide][UG].
@@ -139,6 +158,8 @@ pub struct CMap2<T: CoordsFloat> {
     pub(super) betas: BetaFunctions<CMAP2_BETA>,
     /// Current number of darts
     pub(super) n_darts: usize,
+    /// Rule used to merge vertex values when sewing darts
+    pub(super) vertex_merge_policy: VertexMergePolicy,
 }
 
 unsafe impl<T: CoordsFloat> Send for CMap2<T> {}
@@ -157,6 +178,7 @@ impl<T: CoordsFloat> CMap2<T> {
             unused_darts: UnusedDarts::new(n_darts + 1),
             betas: BetaFunctions::new(n_darts + 1),
             n_darts: n_darts + 1,
+            vertex_merge_policy: VertexMergePolicy::default(),
         }
     }
 
@@ -177,6 +199,7 @@ impl<T: CoordsFloat> CMap2<T> {
             unused_darts: UnusedDarts::new(n_darts + 1),
             betas: BetaFunctions::new(n_darts + 1),
             n_darts: n_darts + 1,
+            vertex_merge_policy: VertexMergePolicy::default(),
         }
     }
 }
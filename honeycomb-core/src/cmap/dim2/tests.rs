@@ -3,8 +3,11 @@
 use crate::stm::{atomically, StmError};
 
 use crate::{
-    attributes::AttrSparseVec,
-    cmap::{CMapError, VertexIdType},
+    attributes::{AttrSparseVec, FaceBound, SumAttr},
+    cmap::{
+        CMapError, ConsistencyError, DartIdType, IntegrityError, MapDiff, VertexIdType,
+        VertexMergePolicy,
+    },
     prelude::{AttributeBind, AttributeUpdate, CMap2, CMapBuilder, Orbit2, OrbitPolicy, Vertex2},
 };
 
@@ -255,6 +258,610 @@ fn remove_dart_twice() {
     map.remove_free_dart(1); // this should panic
 }
 
+#[test]
+fn reserve_darts() {
+    let mut map: CMap2<f64> = CMapBuilder::default()
+        .n_darts(10)
+        .with_capacity(100)
+        .build()
+        .unwrap();
+    assert_eq!(map.n_darts(), 11);
+    let capacity = map.betas.capacity();
+    assert!(capacity >= 101);
+    // adding darts up to the capacity does not reallocate
+    (0..90).for_each(|_| {
+        map.add_free_dart();
+    });
+    assert_eq!(map.n_darts(), 101);
+    assert_eq!(map.betas.capacity(), capacity);
+
+    // the hint has no effect on larger maps
+    let map: CMap2<f64> = CMapBuilder::from_n_darts(10).n_darts(20).build().unwrap();
+    assert_eq!(map.n_darts(), 21);
+}
+
+#[test]
+fn remove_face() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    assert_eq!(map.iter_vertices().count(), 9);
+
+    map.force_remove_face(1).unwrap();
+    assert_eq!(map.n_unused_darts(), 4);
+    assert_eq!(map.iter_faces().count(), 3);
+    // the corner vertex is removed, the others are kept by the neighboring faces
+    assert_eq!(map.iter_vertices().count(), 8);
+    assert!(map
+        .iter_vertices()
+        .all(|vid| map.force_read_vertex(vid).is_some()));
+    assert_eq!(map.check_consistency(), Ok(()));
+
+    // the face was already removed
+    assert!(matches!(
+        map.force_remove_face(1),
+        Err(CMapError::InconsistentCell(_))
+    ));
+}
+
+#[test]
+fn remove_face_open() {
+    let map: CMap2<f64> = CMap2::new(3);
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    assert!(matches!(
+        map.force_remove_face(1),
+        Err(CMapError::InconsistentCell(_))
+    ));
+    assert_eq!(map.n_unused_darts(), 0);
+    assert_eq!(map.beta::<1>(2), 3);
+}
+
+#[test]
+fn check_integrity() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    assert_eq!(map.check_integrity(), Ok(()));
+    map.force_remove_face(1).unwrap();
+    assert_eq!(map.check_integrity(), Ok(()));
+
+    // all issues are reported
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    let _ = map.force_remove_vertex(3);
+    map.force_write_vertex(5, (1.0, 0.0)); // vertex_id(5) == 2
+    assert_eq!(
+        map.check_integrity(),
+        Err(vec![
+            IntegrityError::UndefinedVertex(3),
+            IntegrityError::MisplacedVertex(5),
+        ])
+    );
+
+    // one-way link
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    map.set_beta::<2>(1, 3);
+    let errors = map.check_integrity().unwrap_err();
+    assert!(errors.contains(&IntegrityError::Topology(
+        ConsistencyError::NonInvolutiveBeta { dim: 2, dart: 1 }
+    )));
+    assert!(errors
+        .iter()
+        .any(|e| matches!(e, IntegrityError::InconsistentCellId { .. })));
+}
+
+#[test]
+fn iter_cells_order() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(3).build().unwrap();
+    map.force_remove_face(5).unwrap();
+    map.force_unsew::<2>(3);
+
+    let vertices: Vec<_> = map.iter_vertices().collect();
+    let edges: Vec<_> = map.iter_edges().collect();
+    let faces: Vec<_> = map.iter_faces().collect();
+    // IDs are sorted & equal to the smallest dart of each cell
+    assert!(vertices.windows(2).all(|w| w[0] < w[1]));
+    assert!(edges.windows(2).all(|w| w[0] < w[1]));
+    assert!(faces.windows(2).all(|w| w[0] < w[1]));
+    assert!(vertices.iter().all(|vid| map.vertex_id(*vid) == *vid));
+    assert!(edges.iter().all(|eid| map.edge_id(*eid) == *eid));
+    assert!(faces.iter().all(|fid| map.face_id(*fid) == *fid));
+    assert_eq!(faces, vec![1, 9, 13, 17, 21, 25, 29, 33]);
+}
+
+#[test]
+fn face_neighbors() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(3).build().unwrap();
+    let sorted_neighbors = |fid| {
+        let mut neighbors: Vec<_> = map.face_neighbors(fid).collect();
+        neighbors.sort_unstable();
+        neighbors
+    };
+    // corner & center cells
+    assert_eq!(sorted_neighbors(1), vec![5, 13]);
+    assert_eq!(sorted_neighbors(17), vec![5, 13, 21, 29]);
+
+    // boundary edges are skipped
+    map.force_unsew::<2>(18);
+    assert_eq!(map.face_neighbors(17).count(), 3);
+    assert_eq!(map.face_neighbors(1).count(), 2);
+}
+
+#[test]
+fn merge_maps() {
+    let mut map: CMap2<f64> = CMapBuilder::unit_grid(1)
+        .add_attribute::<Weight>()
+        .build()
+        .unwrap();
+    let other: CMap2<f64> = CMapBuilder::unit_grid(1)
+        .add_attribute::<Weight>()
+        .build()
+        .unwrap();
+    // shift the second square to the right
+    for vid in other.iter_vertices().collect::<Vec<_>>() {
+        let v = other.force_read_vertex(vid).unwrap();
+        other.force_write_vertex(vid, (v.x() + 1.0, v.y()));
+    }
+    map.force_write_attribute(2, Weight(1));
+    other.force_write_attribute(1, Weight(2));
+
+    let offset = map.merge(other).unwrap();
+    assert_eq!(offset, 4);
+    assert_eq!(map.n_darts(), 9);
+    assert_eq!(map.iter_faces().collect::<Vec<_>>(), vec![1, 5]);
+    assert_eq!(map.beta::<1>(4 + offset), 1 + offset);
+    assert_eq!(map.force_read_vertex(1 + offset), Some(Vertex2(1.0, 0.0)));
+    assert_eq!(map.force_read_attribute::<Weight>(1 + offset).unwrap().0, 2);
+    assert_eq!(map.check_consistency(), Ok(()));
+
+    // right edge of the first square, left edge of the second
+    assert_eq!(map.force_read_vertex(2), Some(Vertex2(1.0, 0.0)));
+    assert_eq!(map.force_read_vertex(4 + offset), Some(Vertex2(1.0, 1.0)));
+    map.force_sew::<2>(2, 4 + offset);
+    assert_eq!(map.iter_edges().count(), 7);
+    assert_eq!(map.iter_vertices().count(), 6);
+    assert_eq!(map.force_read_vertex(2), Some(Vertex2(1.0, 0.0)));
+    assert_eq!(map.force_read_attribute::<Weight>(2).unwrap().0, 3);
+    assert_eq!(map.check_consistency(), Ok(()));
+}
+
+#[test]
+fn merge_maps_attribute_mismatch() {
+    let mut map: CMap2<f64> = CMapBuilder::unit_grid(1)
+        .add_attribute::<Weight>()
+        .build()
+        .unwrap();
+    let other: CMap2<f64> = CMapBuilder::unit_grid(1).build().unwrap();
+    assert!(matches!(
+        map.merge(other),
+        Err(CMapError::UnknownAttribute(_))
+    ));
+    assert_eq!(map.n_darts(), 5);
+}
+
+#[test]
+fn compact() {
+    type Flux = SumAttr<f64, FaceBound>;
+    let mut map: CMap2<f64> = CMapBuilder::unit_grid(2)
+        .add_attribute::<Flux>()
+        .build()
+        .unwrap();
+    let faces = map.iter_faces().collect::<Vec<_>>();
+    map.force_write_attribute(9, Flux::new(3.0));
+    map.force_remove_face(1).unwrap();
+    assert_eq!(map.n_unused_darts(), 4);
+    let vertices = map
+        .iter_vertices()
+        .map(|vid| (vid, map.force_read_vertex(vid).unwrap()))
+        .collect::<Vec<_>>();
+
+    let mapping = map.compact();
+    assert_eq!(mapping.len(), 17);
+    assert_eq!(map.n_darts(), 13);
+    assert_eq!(map.n_unused_darts(), 0);
+    assert!(mapping[..5].iter().all(|&d| d == 0));
+    assert_eq!(mapping[5..], (1..=12).collect::<Vec<_>>());
+    assert_eq!(map.check_consistency(), Ok(()));
+
+    // cells & their values are preserved up to renumbering
+    assert_eq!(
+        map.iter_faces().collect::<Vec<_>>(),
+        faces[1..]
+            .iter()
+            .map(|&f| mapping[f as usize])
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(map.iter_vertices().count(), vertices.len());
+    for (vid, v) in vertices {
+        assert_eq!(map.force_read_vertex(mapping[vid as usize]), Some(v));
+    }
+    assert_eq!(
+        map.force_read_attribute::<Flux>(mapping[9]),
+        Some(Flux::new(3.0))
+    );
+
+    // no unused darts, no change
+    let mapping = map.compact();
+    assert_eq!(mapping, (0..13).collect::<Vec<_>>());
+    assert_eq!(map.n_darts(), 13);
+}
+
+#[test]
+fn cell_counts() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    let counts = map.cell_counts();
+    assert_eq!(counts.quads, 4);
+    assert_eq!(counts.n_faces(), 4);
+    assert_eq!(counts.n_volumes(), 0);
+
+    let mut map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    // add a pentagon
+    let d = map.add_free_darts(5);
+    (0..5).for_each(|i| map.force_link::<1>(d + i, d + (i + 1) % 5));
+    let counts = map.cell_counts();
+    assert_eq!(counts.triangles, 8);
+    assert_eq!(counts.quads, 0);
+    assert_eq!(counts.polygons, 1);
+}
+
+#[test]
+fn read_vertex_interpolated() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    let center = map
+        .iter_vertices()
+        .find(|vid| map.force_read_vertex(*vid) == Some(Vertex2(1.0, 1.0)))
+        .unwrap();
+    // defined vertices are read as usual
+    atomically(|trans| {
+        assert_eq!(
+            map.read_vertex_or_interpolate(trans, center)?,
+            Some(Vertex2(1.0, 1.0))
+        );
+        Ok(())
+    });
+    // undefined vertices are interpolated from their neighbors
+    map.force_remove_vertex(center);
+    atomically(|trans| {
+        assert_eq!(
+            map.read_vertex_or_interpolate(trans, center)?,
+            Some(Vertex2(1.0, 1.0))
+        );
+        Ok(())
+    });
+    // the interpolated value isn't written
+    assert_eq!(map.force_read_vertex(center), None);
+    // undefined neighbors are ignored
+    let corner = map
+        .iter_vertices()
+        .find(|vid| map.force_read_vertex(*vid) == Some(Vertex2(0.0, 0.0)))
+        .unwrap();
+    map.force_remove_vertex(corner);
+    atomically(|trans| {
+        assert_eq!(
+            map.read_vertex_or_interpolate(trans, corner)?,
+            Some(Vertex2(0.5, 0.5))
+        );
+        Ok(())
+    });
+}
+
+#[test]
+fn read_vertex_interpolated_none() {
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(2).build().unwrap();
+    map.force_link::<1>(1, 2);
+    atomically(|trans| {
+        assert_eq!(map.read_vertex_or_interpolate(trans, 1)?, None);
+        Ok(())
+    });
+    map.force_write_vertex(2, (1.0, 0.0));
+    atomically(|trans| {
+        assert_eq!(
+            map.read_vertex_or_interpolate(trans, 1)?,
+            Some(Vertex2(1.0, 0.0))
+        );
+        Ok(())
+    });
+}
+
+#[test]
+fn bounding_box() {
+    let map: CMap2<f64> = CMap2::new(4);
+    assert!(map.bounding_box().is_none());
+    let map: CMap2<f64> = CMapBuilder::unit_grid(3).build().unwrap();
+    assert_eq!(
+        map.bounding_box(),
+        Some((Vertex2(0.0, 0.0), Vertex2(3.0, 3.0)))
+    );
+    let vid = map.vertex_id(1);
+    map.force_write_vertex(vid, (-1.0, 0.5));
+    assert_eq!(
+        map.bounding_box(),
+        Some((Vertex2(-1.0, 0.0), Vertex2(3.0, 3.0)))
+    );
+    #[cfg(feature = "par-internals")]
+    assert_eq!(map.par_bounding_box(), map.bounding_box());
+}
+
+#[test]
+fn face_circumcenters() {
+    // right triangle + a unit square
+    let map: CMap2<f64> = CMap2::new(7);
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 1);
+    map.force_link::<1>(4, 5);
+    map.force_link::<1>(5, 6);
+    map.force_link::<1>(6, 7);
+    map.force_link::<1>(7, 4);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (2.0, 0.0));
+    map.force_write_vertex(3, (0.0, 2.0));
+    map.force_write_vertex(4, (3.0, 0.0));
+    map.force_write_vertex(5, (4.0, 0.0));
+    map.force_write_vertex(6, (4.0, 1.0));
+    map.force_write_vertex(7, (3.0, 1.0));
+
+    // the circumcenter of a right triangle is the middle of its hypotenuse
+    let centers = map.face_circumcenters();
+    assert_eq!(centers.len(), 1);
+    assert_eq!(centers.get(&1), Some(&Vertex2(1.0, 1.0)));
+}
+
+#[test]
+fn unused_darts_audit() {
+    let mut map: CMap2<f64> = CMap2::new(10);
+    let report = map.unused_dart_audit();
+    assert_eq!(report.n_unused, 0);
+    assert!(report.is_contiguous());
+    assert!(report.is_trailing());
+
+    map.remove_free_dart(9);
+    map.remove_free_dart(10);
+    let report = map.unused_dart_audit();
+    assert_eq!(report.blocks, vec![(9, 10)]);
+    assert!(report.is_trailing());
+
+    map.remove_free_dart(3);
+    map.remove_free_dart(4);
+    map.remove_free_dart(6);
+    let report = map.unused_dart_audit();
+    assert_eq!(report.n_unused, 5);
+    assert_eq!(report.n_unused, map.n_unused_darts());
+    assert_eq!(report.blocks, vec![(3, 4), (6, 6), (9, 10)]);
+    assert!(!report.is_contiguous());
+    assert!(!report.is_trailing());
+}
+
+#[test]
+fn iter_vertices_coords() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    // leave one vertex undefined
+    let vid = map.vertex_id(1);
+    map.force_remove_vertex(vid);
+    let expected: Vec<(VertexIdType, Vertex2<f64>)> = map
+        .iter_vertices()
+        .filter_map(|vid| map.force_read_vertex(vid).map(|v| (vid, v)))
+        .collect();
+    assert_eq!(expected.len(), 8);
+    assert_eq!(
+        map.iter_vertices_with_coords().collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test]
+fn iter_faces_geometry() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    map.iter_faces_with_geometry().for_each(|(fid, geometry)| {
+        assert_eq!(geometry.len(), 4);
+        assert!(!geometry.spilled());
+        let mut d = fid as DartIdType;
+        for v in &geometry {
+            assert_eq!(map.force_read_vertex(map.vertex_id(d)), Some(*v));
+            d = map.beta::<1>(d);
+        }
+        assert_eq!(d, fid as DartIdType);
+    });
+    assert_eq!(
+        map.iter_faces_with_geometry().count(),
+        map.iter_faces().count()
+    );
+    // undefined vertices are skipped
+    map.force_remove_vertex(map.vertex_id(1));
+    let (fid, geometry) = map.iter_faces_with_geometry().next().unwrap();
+    assert_eq!(fid, 1);
+    assert_eq!(
+        geometry.as_slice(),
+        &[Vertex2(1.0, 0.0), Vertex2(1.0, 1.0), Vertex2(0.0, 1.0)]
+    );
+}
+
+#[test]
+fn valence_and_degree() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    let valence_at = |x: f64, y: f64| {
+        let (vid, _) = map
+            .iter_vertices_with_coords()
+            .find(|(_, v)| *v == Vertex2(x, y))
+            .unwrap();
+        map.vertex_valence(vid)
+    };
+    // interior, boundary & corner vertices
+    assert_eq!(valence_at(1.0, 1.0), 4);
+    assert_eq!(valence_at(1.0, 0.0), 3);
+    assert_eq!(valence_at(0.0, 2.0), 2);
+    assert_eq!(valence_at(2.0, 2.0), 2);
+    assert!(map.iter_faces().all(|fid| map.face_degree(fid) == 4));
+
+    // open path of two darts
+    let map: CMap2<f64> = CMap2::new(2);
+    map.force_link::<1>(1, 2);
+    assert_eq!(map.vertex_valence(1), 1);
+    assert_eq!(map.vertex_valence(2), 2);
+    assert_eq!(map.face_degree(1), 2);
+
+    // triangle
+    let map: CMap2<f64> = CMap2::new(3);
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 1);
+    assert_eq!(map.vertex_valence(1), 2);
+    assert_eq!(map.face_degree(1), 3);
+}
+
+#[cfg(feature = "par-internals")]
+#[test]
+fn par_iter_cells() {
+    use rayon::prelude::*;
+
+    let mut map: CMap2<f64> = CMapBuilder::unit_grid(10).build().unwrap();
+    // add a free dart & an unused dart
+    map.add_free_darts(2);
+    map.remove_free_dart(map.n_darts() as DartIdType - 1);
+
+    let sorted = |mut ids: Vec<DartIdType>| {
+        ids.sort_unstable();
+        ids
+    };
+    assert_eq!(
+        sorted(map.par_iter_vertices().collect()),
+        map.iter_vertices().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        sorted(map.par_iter_edges().collect()),
+        map.iter_edges().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        sorted(map.par_iter_faces().collect()),
+        map.iter_faces().collect::<Vec<_>>()
+    );
+    assert_eq!(map.par_iter_faces().count(), 101);
+}
+
+#[test]
+fn add_dart_range() {
+    let mut map: CMap2<f64> = CMap2::new(4);
+    let range = map.add_free_dart_range(6);
+    assert_eq!(map.n_darts(), 11);
+    assert_eq!(range.first(), 5);
+    assert_eq!(range.len(), 6);
+    assert_eq!(range.get(5), Some(10));
+    assert_eq!(range.get(6), None);
+    assert!(range.contains(7));
+    assert!(!range.contains(11));
+    assert!(range.into_iter().eq(5..11));
+    let chunks: Vec<Vec<DartIdType>> = range
+        .chunks_exact(4)
+        .map(|chunk| chunk.into_iter().collect())
+        .collect();
+    assert_eq!(chunks, vec![vec![5, 6, 7, 8]]);
+    assert!(map.add_free_dart_range(0).is_empty());
+}
+
+#[test]
+fn orbit_dart_tx() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    for policy in [
+        OrbitPolicy::Vertex,
+        OrbitPolicy::VertexLinear,
+        OrbitPolicy::Edge,
+        OrbitPolicy::Face,
+        OrbitPolicy::FaceLinear,
+        OrbitPolicy::Custom(&[1, 2]),
+    ] {
+        let darts = atomically(|trans| {
+            let mut darts = Vec::new();
+            map.for_each_orbit_dart_tx(trans, policy.clone(), 3, |_, d| {
+                darts.push(d);
+                Ok(())
+            })?;
+            Ok(darts)
+        });
+        assert_eq!(darts, Orbit2::new(&map, policy, 3).collect::<Vec<_>>());
+    }
+
+    // detach the first face from its neighbors
+    atomically(|trans| {
+        map.for_each_orbit_dart_tx(trans, OrbitPolicy::Face, 1, |t, d| {
+            if map.beta_transac::<2>(t, d)? != 0 {
+                map.unlink::<2>(t, d)?;
+            }
+            Ok(())
+        })
+    });
+    assert!(map.i_cell::<2>(1).all(|d| map.is_i_free::<2>(d)));
+    assert_eq!(map.iter_boundary_darts().count(), 12);
+    assert!(map.check_consistency().is_ok());
+}
+
+#[test]
+fn boundary_darts() {
+    let mut map: CMap2<f64> = CMapBuilder::unit_grid(3).build().unwrap();
+    assert_eq!(map.iter_boundary_darts().count(), 12);
+    assert!(map.iter_boundary_darts().all(|d| map.is_i_free::<2>(d)));
+
+    // remove the center face, creating a hole
+    let center: Vec<DartIdType> = map
+        .iter_faces()
+        .find(|fid| {
+            map.force_read_vertex(map.vertex_id(*fid as DartIdType)) == Some(Vertex2(1.0, 1.0))
+        })
+        .map(|fid| map.i_cell::<2>(fid as DartIdType).collect())
+        .unwrap();
+    let coords: Vec<(DartIdType, Vertex2<f64>)> = (1..map.n_darts() as DartIdType)
+        .map(|d| (d, map.force_read_vertex(map.vertex_id(d)).unwrap()))
+        .collect();
+    for d in &center {
+        map.force_unlink::<2>(*d);
+    }
+    for d in &center {
+        map.force_unlink::<1>(*d);
+    }
+    for d in &center {
+        map.remove_free_dart(*d);
+    }
+    // links are topology-only; restore values of vertices that changed ID
+    for (d, v) in coords {
+        if !center.contains(&d) && map.force_read_vertex(map.vertex_id(d)).is_none() {
+            map.force_write_vertex(map.vertex_id(d), v);
+        }
+    }
+    assert_eq!(map.iter_boundary_darts().count(), 16);
+    let loops = map.boundary_loops();
+    assert_eq!(loops.len(), 2);
+    let mut lengths: Vec<usize> = loops.iter().map(Vec::len).collect();
+    lengths.sort_unstable();
+    assert_eq!(lengths, [4, 12]);
+    // each boundary dart belongs to exactly one loop
+    let mut darts: Vec<DartIdType> = loops.concat();
+    darts.sort_unstable();
+    assert_eq!(darts, map.iter_boundary_darts().collect::<Vec<_>>());
+    assert!((map.boundary_length() - 16.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn boundary_lengths() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(3).build().unwrap();
+    assert!((map.boundary_length() - 12.0).abs() < f64::EPSILON);
+    let loops = map.boundary_loops();
+    assert_eq!(loops.len(), 1);
+    assert_eq!(loops[0].len(), 12);
+    assert_eq!(map.boundary_loop_perimeters().len(), 1);
+    assert!((map.boundary_loop_perimeters()[0] - 12.0).abs() < f64::EPSILON);
+
+    // two disjoint triangles
+    let vertices = [
+        Vertex2(0.0, 0.0),
+        Vertex2(3.0, 0.0),
+        Vertex2(0.0, 4.0),
+        Vertex2(5.0, 0.0),
+        Vertex2(6.0, 0.0),
+        Vertex2(5.0, 1.0),
+    ];
+    let map: CMap2<f64> = CMap2::from_triangle_soup(&vertices, &[[0, 1, 2], [3, 4, 5]]).unwrap();
+    assert_eq!(map.boundary_loops(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    let perimeters = map.boundary_loop_perimeters();
+    assert!((perimeters[0] - 12.0).abs() < f64::EPSILON);
+    assert!((perimeters[1] - (2.0 + 2.0_f64.sqrt())).abs() < f64::EPSILON);
+    assert!((map.boundary_length() - (14.0 + 2.0_f64.sqrt())).abs() < f64::EPSILON);
+}
+
 // --- (UN)SEW
 
 #[test]
@@ -271,6 +878,42 @@ fn two_sew_complete() {
     assert_eq!(map.force_read_vertex(2).unwrap(), Vertex2::from((0.5, 1.0)));
 }
 
+#[test]
+fn two_sew_pairs() {
+    // unsewn cells of a grid, with slightly perturbed vertices
+    let grid: CMap2<f64> = CMapBuilder::unit_grid(15).build().unwrap();
+    let unsewn = || {
+        let map: CMap2<f64> = CMap2::new(grid.n_darts() - 1);
+        for d in 1..grid.n_darts() as DartIdType {
+            map.force_link::<1>(d, grid.beta::<1>(d));
+            let v = grid.force_read_vertex(grid.vertex_id(d)).unwrap();
+            map.force_write_vertex(d, (v.x() + f64::from(d) * 1e-4, v.y()));
+        }
+        map
+    };
+    let pairs: Vec<(DartIdType, DartIdType)> = (1..grid.n_darts() as DartIdType)
+        .filter(|d| *d < grid.beta::<2>(*d))
+        .map(|d| (d, grid.beta::<2>(d)))
+        .collect();
+
+    let one_by_one = unsewn();
+    for (l, r) in &pairs {
+        one_by_one.force_sew::<2>(*l, *r);
+    }
+    let batched = unsewn();
+    batched.two_sew_pairs(&pairs);
+
+    for d in 1..grid.n_darts() as DartIdType {
+        assert_eq!(batched.beta::<2>(d), grid.beta::<2>(d));
+        assert_eq!(batched.vertex_id(d), one_by_one.vertex_id(d));
+    }
+    assert_eq!(
+        batched.iter_vertices_with_coords().collect::<Vec<_>>(),
+        one_by_one.iter_vertices_with_coords().collect::<Vec<_>>()
+    );
+    assert_eq!(batched.n_vertices(), grid.n_vertices());
+}
+
 #[test]
 fn two_sew_incomplete() {
     let mut map: CMap2<f64> = CMap2::new(3);
@@ -301,6 +944,46 @@ fn two_sew_no_b1() {
     assert_eq!(map.force_read_vertex(2).unwrap(), Vertex2::from((1.0, 1.0)));
 }
 
+#[test]
+fn two_sew_edge_attributes() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct EAttr(u32);
+
+    impl AttributeUpdate for EAttr {
+        fn merge(attr1: Self, attr2: Self) -> Self {
+            Self(attr1.0 + attr2.0)
+        }
+
+        fn split(attr: Self) -> (Self, Self) {
+            (attr, attr)
+        }
+    }
+
+    impl AttributeBind for EAttr {
+        type StorageType = AttrSparseVec<Self>;
+        type IdentifierType = crate::cmap::EdgeIdType;
+        const BIND_POLICY: OrbitPolicy = OrbitPolicy::Edge;
+    }
+
+    let mut map: CMap2<f64> = CMap2::new(4);
+    map.attributes.add_storage::<EAttr>(5);
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(3, 4);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (0.0, 1.0));
+    map.force_write_vertex(3, (1.0, 1.0));
+    map.force_write_vertex(4, (1.0, 0.0));
+    for (d, val) in [(1, 1), (2, 20), (3, 300), (4, 4000)] {
+        map.force_write_attribute(d, EAttr(val));
+    }
+    map.force_sew::<2>(1, 3);
+    // edges of darts 1 & 3 are merged, other edges are untouched
+    assert_eq!(map.edge_id(3), 1);
+    assert_eq!(map.force_read_attribute::<EAttr>(1), Some(EAttr(301)));
+    assert_eq!(map.force_read_attribute::<EAttr>(2), Some(EAttr(20)));
+    assert_eq!(map.force_read_attribute::<EAttr>(4), Some(EAttr(4000)));
+}
+
 #[test]
 // #[should_panic] // FIXME: find a way to test what's printed?
 fn two_sew_no_attributes() {
@@ -330,6 +1013,74 @@ fn two_sew_bad_orientation() {
     map.force_sew::<2>(1, 3); // panic
 }
 
+#[test]
+fn two_sew_merge_policy() {
+    let build = |policy| {
+        let map: CMap2<f64> = CMapBuilder::default()
+            .n_darts(4)
+            .vertex_merge_policy(policy)
+            .build()
+            .unwrap();
+        map.force_link::<1>(1, 2);
+        map.force_link::<1>(3, 4);
+        map.force_write_vertex(1, (0.0, 0.0));
+        map.force_write_vertex(2, (0.0, 1.0));
+        map.force_write_vertex(3, (1.0, 1.0));
+        map.force_write_vertex(4, (1.0, 0.0));
+        map
+    };
+
+    let map = build(VertexMergePolicy::Average);
+    assert_eq!(map.vertex_merge_policy(), VertexMergePolicy::Average);
+    map.force_sew::<2>(1, 3);
+    assert_eq!(map.force_read_vertex(1), Some(Vertex2(0.5, 0.0)));
+    assert_eq!(map.force_read_vertex(2), Some(Vertex2(0.5, 1.0)));
+
+    let map = build(VertexMergePolicy::KeepFirst);
+    map.force_sew::<2>(1, 3);
+    assert_eq!(map.force_read_vertex(1), Some(Vertex2(0.0, 0.0)));
+    assert_eq!(map.force_read_vertex(2), Some(Vertex2(0.0, 1.0)));
+    assert_eq!(map.n_vertices(), 2);
+
+    let map = build(VertexMergePolicy::ErrorOnConflict);
+    let res = atomically(|trans| Ok(map.sew::<2>(trans, 1, 3)));
+    assert!(matches!(res, Err(CMapError::IncorrectGeometry(_))));
+
+    // coinciding vertices are merged without issue
+    let mut map = build(VertexMergePolicy::ErrorOnConflict);
+    map.force_write_vertex(3, (0.0, 1.0));
+    map.force_write_vertex(4, (0.0, 0.0));
+    let res = atomically(|trans| Ok(map.sew::<2>(trans, 1, 3)));
+    assert!(res.is_ok());
+    assert_eq!(map.force_read_vertex(1), Some(Vertex2(0.0, 0.0)));
+    assert_eq!(map.force_read_vertex(2), Some(Vertex2(0.0, 1.0)));
+    map.set_vertex_merge_policy(VertexMergePolicy::KeepFirst);
+    assert_eq!(map.vertex_merge_policy(), VertexMergePolicy::KeepFirst);
+}
+
+#[test]
+fn force_sew_many() {
+    let map: CMap2<f64> = CMap2::new(8);
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(3, 4);
+    map.force_link::<1>(5, 6);
+    map.force_link::<1>(7, 8);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (1.0, 0.0));
+    map.force_write_vertex(3, (1.0, 0.0));
+    map.force_write_vertex(4, (0.0, 0.0));
+
+    // the second sew fails due to missing vertices; the first one is rolled back
+    assert!(map.force_sew_many::<2>(&[(1, 3), (5, 7)]).is_err());
+    assert_eq!(map.beta::<2>(1), 0);
+    assert_eq!(map.beta::<2>(5), 0);
+
+    assert!(map.force_sew_many::<2>(&[(1, 3)]).is_ok());
+    assert_eq!(map.beta::<2>(1), 3);
+    assert_eq!(map.beta::<2>(3), 1);
+    assert_eq!(map.vertex_id(1), map.vertex_id(4));
+}
+
 #[test]
 fn one_sew_complete() {
     let mut map: CMap2<f64> = CMap2::new(3);
@@ -374,6 +1125,193 @@ fn one_sew_no_attributes_bis() {
     map.force_sew::<1>(1, 3); // panic
 }
 
+// --- DIFF
+
+#[test]
+fn diff_split_edge() {
+    // build an isolated edge
+    let build = || {
+        let map: CMap2<f64> = CMapBuilder::default().n_darts(2).build().unwrap();
+        map.force_link::<2>(1, 2);
+        map.force_write_vertex(1, (0.0, 0.0));
+        map.force_write_vertex(2, (1.0, 0.0));
+        map
+    };
+    let old = build();
+    let mut new = build();
+    assert!(old.diff(&new).is_empty());
+
+    // split it in the middle
+    //
+    //  1 -> 2            1 -> 3 -> 2
+    //  <- 2 -     =>     <- 4 -<- 3 -
+    new.add_free_darts(2);
+    new.force_unlink::<2>(1);
+    new.force_link::<1>(1, 3);
+    new.force_link::<1>(2, 4);
+    new.force_link::<2>(1, 4);
+    new.force_link::<2>(2, 3);
+    new.force_write_vertex(3, (0.5, 0.0));
+
+    assert_eq!(
+        old.diff(&new),
+        MapDiff {
+            added_darts: vec![3, 4],
+            removed_darts: vec![],
+            changed_betas: vec![1, 2],
+            moved_vertices: vec![3],
+        }
+    );
+}
+
+#[test]
+fn diff_removed_darts() {
+    let old: CMap2<f64> = CMapBuilder::default().n_darts(3).build().unwrap();
+    old.force_write_vertex(3, (1.0, 1.0));
+    let mut new: CMap2<f64> = CMapBuilder::default().n_darts(3).build().unwrap();
+    new.force_write_vertex(3, (1.0, 2.0));
+    new.remove_free_dart(2);
+
+    let diff = old.diff(&new);
+    assert!(diff.added_darts.is_empty());
+    assert_eq!(&diff.removed_darts, &[2]);
+    assert!(diff.changed_betas.is_empty());
+    assert_eq!(&diff.moved_vertices, &[3]);
+    // the diff is symmetric
+    let diff = new.diff(&old);
+    assert_eq!(&diff.added_darts, &[2]);
+    assert!(diff.removed_darts.is_empty());
+}
+
+// --- MANIFOLD CHECK
+
+#[test]
+fn manifold_grid() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(3).build().unwrap();
+    assert!(map.is_manifold());
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(3).build().unwrap();
+    assert!(map.is_manifold());
+}
+
+#[test]
+fn manifold_bowtie() {
+    // two triangles sharing a single vertex O
+    //
+    //  Q        S
+    //  | \    / |
+    //  |  \  /  |
+    //  |    O   |
+    //  |  /  \  |
+    //  | /    \ |
+    //  P        R
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(6).build().unwrap();
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 1);
+    map.force_link::<1>(4, 5);
+    map.force_link::<1>(5, 6);
+    map.force_link::<1>(6, 4);
+    map.force_write_vertex(1, (1.0, 1.0));
+    map.force_write_vertex(2, (0.0, 0.0));
+    map.force_write_vertex(3, (0.0, 2.0));
+    map.force_write_vertex(4, (1.5, 1.0));
+    map.force_write_vertex(5, (2.0, 0.0));
+    map.force_write_vertex(6, (2.0, 2.0));
+    assert!(map.coincident_vertices().is_empty());
+    assert!(map.is_manifold());
+
+    // pinch both fans at O; each fan is still its own vertex
+    map.force_write_vertex(4, (1.0, 1.0));
+    assert_eq!(map.iter_vertices().count(), 6);
+    assert_eq!(map.vertex_fans(1).len(), 1);
+    assert_eq!(map.coincident_vertices(), vec![vec![1, 4]]);
+    assert!(!map.is_manifold_vertex(1));
+    assert!(!map.is_manifold_vertex(4));
+    assert!(map.is_manifold_vertex(2));
+    assert!(!map.is_manifold());
+}
+
+#[test]
+fn manifold_merged_fans() {
+    // same triangles, merged into a single vertex by a one-way link
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(6).build().unwrap();
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 1);
+    map.force_link::<1>(4, 5);
+    map.force_link::<1>(5, 6);
+    map.force_link::<1>(6, 4);
+    assert_eq!(map.vertex_fans(1), vec![vec![1]]);
+
+    map.set_beta::<2>(1, 6);
+    assert!(Orbit2::new(&map, OrbitPolicy::Vertex, 1).any(|d| d == 4));
+    assert_eq!(map.vertex_fans(1), vec![vec![1], vec![4]]);
+    assert!(!map.is_manifold_vertex(1));
+    assert!(!map.is_manifold());
+}
+
+#[test]
+fn manifold_oversewn_edge() {
+    // three triangles sharing the same edge
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(9).build().unwrap();
+    for d in [1, 4, 7] {
+        map.force_link::<1>(d, d + 1);
+        map.force_link::<1>(d + 1, d + 2);
+        map.force_link::<1>(d + 2, d);
+    }
+    map.force_link::<2>(1, 4);
+    assert!(map.is_manifold());
+    map.set_beta::<2>(7, 1);
+    assert!(!map.is_manifold());
+}
+
+#[test]
+fn orientable_checks() {
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(3).build().unwrap();
+    assert!(map.is_orientable());
+
+    // the check doesn't depend on geometry
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(9).build().unwrap();
+    for d in [1, 4, 7] {
+        map.force_link::<1>(d, d + 1);
+        map.force_link::<1>(d + 1, d + 2);
+        map.force_link::<1>(d + 2, d);
+    }
+    map.force_link::<2>(1, 4);
+    assert!(map.is_orientable());
+
+    // a third face claims the shared edge; the link is one-way
+    map.set_beta::<2>(7, 1);
+    assert!(!map.is_orientable());
+}
+
+#[test]
+fn orientable_folded_embedding() {
+    // two triangles sharing an edge
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(6).build().unwrap();
+    for d in [1, 4] {
+        map.force_link::<1>(d, d + 1);
+        map.force_link::<1>(d + 1, d + 2);
+        map.force_link::<1>(d + 2, d);
+    }
+    map.force_link::<2>(1, 4);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (1.0, 0.0));
+    map.force_write_vertex(3, (0.0, 1.0));
+    map.force_write_vertex(6, (1.0, -1.0));
+    assert!(map.is_orientable());
+
+    // fold the second triangle over the first; the topology is unchanged
+    map.force_write_vertex(6, (1.0, 1.0));
+    assert!(map.is_orientable());
+
+    // detach the start of dart 5 from the end of dart 1; both triangles now traverse the shared
+    // edge in the same direction
+    map.set_beta::<0>(5, 6);
+    assert_ne!(map.vertex_id(5), map.vertex_id(1));
+    assert!(!map.is_orientable());
+}
+
 // --- IO
 
 #[test]
@@ -558,6 +1496,7 @@ fn sew_ordering_with_transactions() {
                         CMapError::FailedAttributeMerge(_) => Err(StmError::Retry),
                         CMapError::FailedAttributeSplit(_)
                         | CMapError::IncorrectGeometry(_)
+                        | CMapError::InconsistentCell(_)
                         | CMapError::UnknownAttribute(_) => unreachable!(),
                     }
                 } else {
@@ -574,6 +1513,7 @@ fn sew_ordering_with_transactions() {
                         CMapError::FailedAttributeMerge(_) => Err(StmError::Retry),
                         CMapError::FailedAttributeSplit(_)
                         | CMapError::IncorrectGeometry(_)
+                        | CMapError::InconsistentCell(_)
                         | CMapError::UnknownAttribute(_) => unreachable!(),
                     }
                 } else {
@@ -706,6 +1646,7 @@ fn unsew_ordering_with_transactions() {
                         CMapError::FailedAttributeSplit(_) => Err(StmError::Retry),
                         CMapError::FailedAttributeMerge(_)
                         | CMapError::IncorrectGeometry(_)
+                        | CMapError::InconsistentCell(_)
                         | CMapError::UnknownAttribute(_) => unreachable!(),
                     }
                 } else {
@@ -722,6 +1663,7 @@ fn unsew_ordering_with_transactions() {
                         CMapError::FailedAttributeSplit(_) => Err(StmError::Retry),
                         CMapError::FailedAttributeMerge(_)
                         | CMapError::IncorrectGeometry(_)
+                        | CMapError::InconsistentCell(_)
                         | CMapError::UnknownAttribute(_) => unreachable!(),
                     }
                 } else {
@@ -2,9 +2,15 @@
 
 // ------ IMPORTS
 
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
+
 use super::CMAP2_BETA;
+use crate::cmap::{ConsistencyError, IntegrityError};
 use crate::geometry::CoordsFloat;
-use crate::prelude::{CMap2, DartIdType};
+use crate::prelude::{
+    CMap2, DartIdType, FaceIdType, Orbit2, OrbitPolicy, VertexIdType, NULL_DART_ID,
+};
 use crate::stm::atomically;
 
 // ------ CONTENT
@@ -33,3 +39,363 @@ impl<T: CoordsFloat> CMap2<T> {
         });
     }
 }
+
+/// **Checks**
+impl<T: CoordsFloat> CMap2<T> {
+    #[allow(clippy::cast_possible_truncation)]
+    /// Check the consistency of the map's topology.
+    ///
+    /// This method verifies that:
+    /// - `β0` and `β1` are inverse of each other,
+    /// - `β2` is an involution,
+    /// - no dart is 2-linked to its own successor in a face, which would fold the edge onto
+    ///   itself,
+    /// - used darts are only linked to used darts, and unused darts are free.
+    ///
+    /// This check reads the whole map; it is meant to be used in tests, or to validate maps
+    /// built from external data (see
+    /// [`CMapBuilder::validate_on_build`][crate::prelude::CMapBuilder::validate_on_build]).
+    ///
+    /// # Errors
+    ///
+    /// This method returns the list of all detected issues, in increasing order of darts. Refer
+    /// to [`ConsistencyError`]'s documentation for a description of each issue.
+    pub fn check_consistency(&self) -> Result<(), Vec<ConsistencyError>> {
+        let mut errors = Vec::new();
+        for d in 1..self.n_darts() as DartIdType {
+            let betas = [self.beta::<0>(d), self.beta::<1>(d), self.beta::<2>(d)];
+            if self.unused_darts[d].read_atomic() {
+                if betas.iter().any(|b| *b != NULL_DART_ID) {
+                    errors.push(ConsistencyError::LinkedUnusedDart(d));
+                }
+                continue;
+            }
+            for (dim, b) in betas.iter().enumerate() {
+                if *b != NULL_DART_ID && self.unused_darts[*b].read_atomic() {
+                    errors.push(ConsistencyError::LinkToUnusedDart {
+                        dim: dim as u8,
+                        dart: d,
+                    });
+                }
+            }
+            let [b0, b1, b2] = betas;
+            if (b1 != NULL_DART_ID && self.beta::<0>(b1) != d)
+                || (b0 != NULL_DART_ID && self.beta::<1>(b0) != d)
+            {
+                errors.push(ConsistencyError::NonInverseBeta01(d));
+            }
+            if b2 != NULL_DART_ID && self.beta::<2>(b2) != d {
+                errors.push(ConsistencyError::NonInvolutiveBeta { dim: 2, dart: d });
+            }
+            if b2 != NULL_DART_ID && b2 == b1 {
+                errors.push(ConsistencyError::FoldedEdge(d));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    /// Check the integrity of the map.
+    ///
+    /// In addition to the topological checks of [`CMap2::check_consistency`], this method
+    /// verifies that:
+    /// - every non-free dart belongs to a vertex with an associated value,
+    /// - vertex values are only stored at vertex identifiers,
+    /// - vertex, edge & face identifiers are consistent, i.e. the identifier of a cell is the
+    ///   same when computed from any of its darts.
+    ///
+    /// Unlike assertions, all checks are run to completion, so that every issue can be reported
+    /// at once. This is useful to debug maps resulting from failed or custom operations.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the list of all detected issues. Topological issues are listed first,
+    /// as returned by [`CMap2::check_consistency`], followed by other issues in increasing order
+    /// of darts. Refer to [`IntegrityError`]'s documentation for a description of each issue.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use honeycomb_core::prelude::{CMap2, CMapBuilder};
+    /// use honeycomb_core::cmap::IntegrityError;
+    ///
+    /// let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    /// assert_eq!(map.check_integrity(), Ok(()));
+    ///
+    /// let _ = map.force_remove_vertex(1);
+    /// assert_eq!(
+    ///     map.check_integrity(),
+    ///     Err(vec![IntegrityError::UndefinedVertex(1)]),
+    /// );
+    /// ```
+    pub fn check_integrity(&self) -> Result<(), Vec<IntegrityError>> {
+        let mut errors: Vec<IntegrityError> = self
+            .check_consistency()
+            .err()
+            .into_iter()
+            .flatten()
+            .map(IntegrityError::from)
+            .collect();
+        for d in 1..self.n_darts() as DartIdType {
+            let vid = self.vertex_id(d);
+            if self.unused_darts[d].read_atomic() || vid != d {
+                if self.force_read_vertex(d).is_some() {
+                    errors.push(IntegrityError::MisplacedVertex(d));
+                }
+                if self.unused_darts[d].read_atomic() {
+                    continue;
+                }
+            } else if !self.is_free(d) && self.force_read_vertex(vid).is_none() {
+                errors.push(IntegrityError::UndefinedVertex(d));
+            }
+            // cells must have the same identifier when computed from neighboring darts
+            let neighbors = [
+                (0, self.beta::<1>(self.beta::<2>(d))),
+                (0, self.beta::<2>(self.beta::<0>(d))),
+                (1, self.beta::<2>(d)),
+                (2, self.beta::<1>(d)),
+            ];
+            for (dim, e) in neighbors.into_iter().filter(|(_, e)| *e != NULL_DART_ID) {
+                let same_id = match dim {
+                    0 => vid == self.vertex_id(e),
+                    1 => self.edge_id(d) == self.edge_id(e),
+                    _ => self.face_id(d) == self.face_id(e),
+                };
+                if !same_id {
+                    errors.push(IntegrityError::InconsistentCellId { dim, dart: d });
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Check if the map models a manifold surface (with boundaries).
+    ///
+    /// The map is considered manifold if:
+    /// - links are consistent, i.e. β<sub>0</sub> is the inverse of β<sub>1</sub>, β<sub>2</sub>
+    ///   is an involution without fixed points, and no used dart is linked to an unused one. In
+    ///   particular, this ensures that no edge is shared by more than two faces.
+    /// - each vertex is made of a single fan (or cycle) of darts, and no two vertices are located
+    ///   at the same position, i.e. there are no "bowtie" vertices.
+    ///
+    /// Fans are only counted once links are known to be consistent, in which case the orbit of
+    /// each vertex is a single fan. Bowtie vertices of such maps are made of distinct vertices
+    /// pinched together by their geometry, which is detected using
+    /// [`CMap2::coincident_vertices`].
+    ///
+    /// Note that a map built exclusively using sew and link operations always has consistent
+    /// links. This method is meant to validate maps built or edited by other means, e.g. manual
+    /// beta assignments or external imports, as well as maps pinched by their geometry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use honeycomb_core::prelude::{CMap2, CMapBuilder};
+    ///
+    /// let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    /// assert!(map.is_manifold());
+    ///
+    /// // a third face claims the edge shared by the two first squares
+    /// map.set_beta::<2>(9, 2);
+    /// assert!(!map.is_manifold());
+    /// ```
+    #[must_use = "unused return value"]
+    pub fn is_manifold(&self) -> bool {
+        let is_used = |d: DartIdType| !self.unused_darts[d].read_atomic();
+
+        // links
+        let consistent_links = (1..self.n_darts as DartIdType)
+            .filter(|d| is_used(*d))
+            .all(|d| {
+                let [b0, b1, b2] = [0, 1, 2].map(|i| self.beta_rt(i, d));
+                [b0, b1, b2]
+                    .iter()
+                    .all(|b| *b == NULL_DART_ID || is_used(*b))
+                    && (b0 == NULL_DART_ID || self.beta::<1>(b0) == d)
+                    && (b1 == NULL_DART_ID || self.beta::<0>(b1) == d)
+                    && (b2 == NULL_DART_ID || (b2 != d && self.beta::<2>(b2) == d))
+            });
+        if !consistent_links {
+            return false;
+        }
+
+        // fans can only be merged by one-way links, which were rejected above
+        debug_assert!(self
+            .iter_vertices()
+            .all(|vid| self.vertex_fans(vid).len() == 1));
+
+        // vertices pinched together
+        self.coincident_vertices().is_empty()
+    }
+
+    /// Return groups of vertices located at the same position.
+    ///
+    /// Vertices are sorted by position in a single pass, so this method should be preferred over
+    /// repeated calls to [`CMap2::is_manifold_vertex`] when checking many vertices. Vertices
+    /// without an associated value are ignored.
+    ///
+    /// # Return
+    ///
+    /// Return each group of at least two vertices sharing a position. Vertices of a group are
+    /// sorted by increasing ID, and groups are sorted by their first vertex.
+    #[must_use = "unused return value"]
+    pub fn coincident_vertices(&self) -> Vec<Vec<VertexIdType>> {
+        let mut positions: Vec<_> = self
+            .iter_vertices()
+            .filter_map(|vid| self.force_read_vertex(vid).map(|v| (v, vid)))
+            .collect();
+        positions.sort_by(|(a, vid_a), (b, vid_b)| {
+            a.x()
+                .partial_cmp(&b.x())
+                .unwrap_or(Ordering::Equal)
+                .then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+                .then(vid_a.cmp(vid_b))
+        });
+        let mut groups: Vec<Vec<VertexIdType>> = positions
+            .chunk_by(|(a, _), (b, _)| a == b)
+            .filter(|chunk| chunk.len() > 1)
+            .map(|chunk| chunk.iter().map(|(_, vid)| *vid).collect())
+            .collect();
+        groups.sort_unstable_by_key(|group| group[0]);
+        groups
+    }
+
+    /// Check if faces of the map are consistently oriented.
+    ///
+    /// Faces are visited using a BFS across β<sub>2</sub>-adjacent faces, the first face of each
+    /// connected component giving the reference orientation. Each visited face propagates its
+    /// orientation to its neighbors, which must traverse their shared edge in the opposite
+    /// direction, i.e. each dart of the edge must go from the vertex at the end of the other.
+    /// The check stops on the first edge traversed in the same direction by both faces, or on
+    /// the first non-involutive β<sub>2</sub> link. It is purely topological: the position of
+    /// vertices is not used.
+    ///
+    /// As for [`CMap2::is_manifold`], a map built exclusively using sew and link operations is
+    /// always orientable; this method is meant to validate maps built or edited by other means.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use honeycomb_core::prelude::{CMap2, CMapBuilder};
+    ///
+    /// let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    /// assert!(map.is_orientable());
+    ///
+    /// // glue the bottom edge of the first square to the edge it shares with its neighbor
+    /// map.set_beta::<2>(1, 8);
+    /// assert!(!map.is_orientable());
+    /// ```
+    #[must_use = "unused return value"]
+    pub fn is_orientable(&self) -> bool {
+        let mut visited: HashSet<FaceIdType> = HashSet::new();
+        for fid in self.iter_faces() {
+            if !visited.insert(fid) {
+                continue;
+            }
+            let mut pending = VecDeque::from([fid]);
+            while let Some(face) = pending.pop_front() {
+                for d in Orbit2::new(self, OrbitPolicy::Face, face as DartIdType) {
+                    let e = self.beta::<2>(d);
+                    if e == NULL_DART_ID {
+                        continue;
+                    }
+                    if self.beta::<2>(e) != d || !self.is_reversed_edge(d, e) {
+                        return false;
+                    }
+                    let neighbor = self.face_id(e);
+                    if visited.insert(neighbor) {
+                        pending.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Return `true` if `e` goes from the end of `d` to its start.
+    fn is_reversed_edge(&self, d: DartIdType, e: DartIdType) -> bool {
+        let (b1d, b1e) = (self.beta::<1>(d), self.beta::<1>(e));
+        (b1d == NULL_DART_ID || self.vertex_id(e) == self.vertex_id(b1d))
+            && (b1e == NULL_DART_ID || self.vertex_id(b1e) == self.vertex_id(d))
+    }
+
+    /// Return the fans of darts making up a vertex.
+    ///
+    /// A fan is a set of darts of the vertex connected by β<sub>2</sub> links that are
+    /// reciprocated. In a map with consistent links, each vertex is made of a single fan; several
+    /// fans are merged into one vertex by one-way links.
+    ///
+    /// # Arguments
+    ///
+    /// - `vertex_id: VertexIdType` -- Identifier of the vertex.
+    ///
+    /// # Return
+    ///
+    /// Return the darts of each fan. The first fan contains `vertex_id`.
+    #[must_use = "unused return value"]
+    pub fn vertex_fans(&self, vertex_id: VertexIdType) -> Vec<Vec<DartIdType>> {
+        // rotations only follow reciprocated links
+        let linked = |d: DartIdType| {
+            let b2 = self.beta::<2>(d);
+            if b2 != NULL_DART_ID && self.beta::<2>(b2) == d {
+                b2
+            } else {
+                NULL_DART_ID
+            }
+        };
+        let mut fans = Vec::new();
+        let mut marked = HashSet::from([NULL_DART_ID]);
+        for start in Orbit2::new(self, OrbitPolicy::Vertex, vertex_id as DartIdType) {
+            if !marked.insert(start) {
+                continue;
+            }
+            let mut fan = vec![start];
+            let mut pending = VecDeque::from([start]);
+            while let Some(d) = pending.pop_front() {
+                let next = self.beta::<1>(linked(d));
+                let prev = linked(self.beta::<0>(d));
+                for image in [next, prev] {
+                    if marked.insert(image) {
+                        fan.push(image);
+                        pending.push_back(image);
+                    }
+                }
+            }
+            fans.push(fan);
+        }
+        fans
+    }
+
+    /// Check if a vertex is manifold.
+    ///
+    /// This is the vertex criterion used by [`CMap2::is_manifold`]: the vertex must be made of a
+    /// single fan of darts (see [`CMap2::vertex_fans`]), and no other vertex may be located at
+    /// the same position.
+    ///
+    /// Note that this method iterates over all vertices of the map to find coinciding positions,
+    /// making it linear in the size of the map. Prefer [`CMap2::is_manifold`] to check the
+    /// entire map, or [`CMap2::coincident_vertices`] to find all pinched vertices at once.
+    ///
+    /// # Arguments
+    ///
+    /// - `vertex_id: VertexIdType` -- Identifier of the checked vertex.
+    #[must_use = "unused return value"]
+    pub fn is_manifold_vertex(&self, vertex_id: VertexIdType) -> bool {
+        if self.vertex_fans(vertex_id).len() != 1 {
+            return false;
+        }
+        self.force_read_vertex(vertex_id).is_none_or(|v| {
+            self.iter_vertices()
+                .filter(|vid| *vid != vertex_id)
+                .all(|vid| self.force_read_vertex(vid) != Some(v))
+        })
+    }
+}
@@ -13,11 +13,13 @@ use crate::stm::{atomically, StmClosureResult, StmError, Transaction};
 
 use std::collections::{HashSet, VecDeque};
 
+use smallvec::SmallVec;
+
 use crate::{
     attributes::UnknownAttributeStorage,
     cmap::{
-        CMap3, DartIdType, EdgeIdType, FaceIdType, Orbit3, OrbitPolicy, VertexIdType, VolumeIdType,
-        NULL_DART_ID,
+        CMap3, CMapError, CMapResult, CellCounts, DartIdType, DartRange, EdgeIdType, FaceIdType,
+        Orbit3, OrbitPolicy, VertexIdType, VolumeIdType, NULL_DART_ID,
     },
     geometry::CoordsFloat,
 };
@@ -70,6 +72,18 @@ impl<T: CoordsFloat> CMap3<T> {
         new_id
     }
 
+    /// Add `n_darts` new free darts to the map.
+    ///
+    /// This is equivalent to [`Self::add_free_darts`], but the new darts are returned as a
+    /// [`DartRange`] instead of the first ID.
+    ///
+    /// # Return
+    ///
+    /// Returns the contiguous range of new darts.
+    pub fn add_free_dart_range(&mut self, n_darts: usize) -> DartRange {
+        DartRange::new(self.add_free_darts(n_darts), n_darts)
+    }
+
     /// Insert a new free dart into the map.
     ///
     /// This method attempts to reuse an unused dart slot if available; otherwise, it adds a new one.
@@ -113,6 +127,146 @@ impl<T: CoordsFloat> CMap3<T> {
             Ok(())
         });
     }
+
+    /// Remove a free dart from the map.
+    ///
+    /// This variant is equivalent to [`remove_free_dart`][Self::remove_free_dart], but uses a
+    /// transaction instead of requiring exclusive access to the map. This allows darts to be
+    /// released as part of a larger transactional operation.
+    ///
+    /// # Arguments
+    ///
+    /// - `trans: &mut Transaction` -- Transaction associated to the operation.
+    /// - `dart_id: DartIdType` -- Identifier of the dart to remove.
+    ///
+    /// # Errors
+    ///
+    /// This method is meant to be called in a context where the returned `Result` is used to
+    /// validate the transaction passed as argument. Errors should not be processed manually,
+    /// only processed via the `?` operator.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if:
+    /// - the dart is not free for all *i*,
+    /// - the dart is already marked as unused.
+    pub fn remove_free_dart_transac(
+        &self,
+        trans: &mut Transaction,
+        dart_id: DartIdType,
+    ) -> StmClosureResult<()> {
+        assert_eq!(self.beta_transac::<0>(trans, dart_id)?, NULL_DART_ID);
+        assert_eq!(self.beta_transac::<1>(trans, dart_id)?, NULL_DART_ID);
+        assert_eq!(self.beta_transac::<2>(trans, dart_id)?, NULL_DART_ID);
+        assert_eq!(self.beta_transac::<3>(trans, dart_id)?, NULL_DART_ID);
+        assert!(!self.unused_darts[dart_id].replace(trans, true)?);
+        Ok(())
+    }
+}
+
+/// **Cell removal methods**
+impl<T: CoordsFloat> CMap3<T> {
+    /// Remove a volume from the map.
+    ///
+    /// The volume is first 3-unsewn from its neighbors, which keep their own copy of the
+    /// attributes previously shared with the volume. Its darts are then 2-unlinked and
+    /// 1-unlinked, their vertices removed, and they are finally released as unused darts.
+    ///
+    /// # Arguments
+    ///
+    /// - `trans: &mut Transaction` -- Transaction associated to the operation.
+    /// - `volume_id: VolumeIdType` -- Identifier of the volume to remove.
+    ///
+    /// # Errors
+    ///
+    /// This method is meant to be called in a context where the returned `Result` is used to
+    /// validate the transaction passed as argument. The returned error may be:
+    /// - [`CMapError::FailedTransaction`] -- should be processed via the `?` operator,
+    /// - [`CMapError::InconsistentCell`] -- if the volume isn't closed, or contains unused darts,
+    /// - [`CMapError::FailedAttributeSplit`] -- if the volume couldn't be unsewn from its
+    ///   neighbors.
+    ///
+    /// `InconsistentCell` errors are detected before the map is altered.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if the volume contains darts that do not exist in the map.
+    pub fn remove_volume_tx(
+        &self,
+        trans: &mut Transaction,
+        volume_id: VolumeIdType,
+    ) -> CMapResult<()> {
+        if volume_id == NULL_DART_ID {
+            return Err(CMapError::InconsistentCell("null volume"));
+        }
+        let mut darts: Vec<DartIdType> = Vec::new();
+        let mut marked: HashSet<DartIdType> = HashSet::from([volume_id as DartIdType]);
+        let mut pending: VecDeque<DartIdType> = VecDeque::from([volume_id as DartIdType]);
+        while let Some(d) = pending.pop_front() {
+            if self.unused_darts[d].read(trans)? {
+                return Err(CMapError::InconsistentCell(
+                    "volume contains an unused dart",
+                ));
+            }
+            darts.push(d);
+            for next in [
+                self.beta_transac::<1>(trans, d)?,
+                self.beta_transac::<0>(trans, d)?,
+                self.beta_transac::<2>(trans, d)?,
+            ] {
+                if next == NULL_DART_ID {
+                    return Err(CMapError::InconsistentCell("volume isn't closed"));
+                }
+                if marked.insert(next) {
+                    pending.push_back(next);
+                }
+            }
+        }
+
+        for &d in &darts {
+            // 3-unsews operate on whole faces, so some darts are already free
+            if self.beta_transac::<3>(trans, d)? != NULL_DART_ID {
+                self.unsew::<3>(trans, d)?;
+            }
+        }
+        // the volume is isolated: its vertices are only made of its own darts
+        for &d in &darts {
+            let vid = self.vertex_id_transac(trans, d)?;
+            self.remove_vertex(trans, vid)?;
+        }
+        for &d in &darts {
+            if self.beta_transac::<2>(trans, d)? != NULL_DART_ID {
+                self.unlink::<2>(trans, d)?;
+            }
+        }
+        for &d in &darts {
+            if self.beta_transac::<1>(trans, d)? != NULL_DART_ID {
+                self.unlink::<1>(trans, d)?;
+            }
+        }
+        for &d in &darts {
+            self.remove_free_dart_transac(trans, d)?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove a volume from the map.
+    ///
+    /// This variant is equivalent to [`remove_volume_tx`][Self::remove_volume_tx], but internally
+    /// uses a transaction that will be retried until validated.
+    ///
+    /// # Errors
+    ///
+    /// This method may fail, returning an error, for the same reasons as the transactional
+    /// variant, `FailedTransaction` excluded.
+    pub fn force_remove_volume(&self, volume_id: VolumeIdType) -> CMapResult<()> {
+        atomically(|trans| match self.remove_volume_tx(trans, volume_id) {
+            Ok(()) => Ok(Ok(())),
+            Err(CMapError::FailedTransaction(e)) => Err(e),
+            Err(e) => Ok(Err(e)),
+        })
+    }
 }
 
 /// **Beta-related methods**
@@ -537,7 +691,95 @@ impl<T: CoordsFloat> CMap3<T> {
         }
     }
 
+    #[allow(clippy::needless_pass_by_value)]
+    /// Apply a closure to each dart of an orbit, inside a transaction.
+    ///
+    /// Darts are visited in the same order as [`Orbit3`] would yield them, but the orbit is
+    /// computed using transactional reads, which makes the traversal part of the transaction.
+    /// This removes the need to collect darts of the orbit in a buffer before mutating the map.
+    ///
+    /// Images of a dart are read before applying the closure to it, so `f` may update links of
+    /// the dart it is given. Updates to links of darts that are yet to be visited will be
+    /// reflected in the traversal.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if a read fails, or as soon as `f` returns one. In both
+    /// cases, the error should be passed up to the transaction control method.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a custom policy refers to an invalid beta function.
+    pub fn for_each_orbit_dart_tx(
+        &self,
+        trans: &mut Transaction,
+        orbit_policy: OrbitPolicy,
+        dart_id: DartIdType,
+        mut f: impl FnMut(&mut Transaction, DartIdType) -> StmClosureResult<()>,
+    ) -> StmClosureResult<()> {
+        debug_assert_eq!(orbit_policy.check_dim(3), Ok(()));
+        let mut marked = HashSet::from([NULL_DART_ID, dart_id]);
+        let mut pending = VecDeque::from([dart_id]);
+
+        while let Some(d) = pending.pop_front() {
+            let mut images: SmallVec<[DartIdType; 5]> = SmallVec::new();
+            match orbit_policy {
+                OrbitPolicy::Vertex | OrbitPolicy::VertexLinear => {
+                    let (b2, b3) = (
+                        self.beta_transac::<2>(trans, d)?,
+                        self.beta_transac::<3>(trans, d)?,
+                    );
+                    images.push(self.beta_transac::<3>(trans, b2)?);
+                    images.push(self.beta_transac::<1>(trans, b3)?);
+                    images.push(self.beta_transac::<1>(trans, b2)?);
+                    if orbit_policy == OrbitPolicy::Vertex {
+                        let b0 = self.beta_transac::<0>(trans, d)?;
+                        images.push(self.beta_transac::<3>(trans, b0)?);
+                        images.push(self.beta_transac::<2>(trans, b0)?);
+                    }
+                }
+                OrbitPolicy::Edge => {
+                    images.push(self.beta_transac::<2>(trans, d)?);
+                    images.push(self.beta_transac::<3>(trans, d)?);
+                }
+                OrbitPolicy::Face => {
+                    images.push(self.beta_transac::<1>(trans, d)?);
+                    images.push(self.beta_transac::<0>(trans, d)?);
+                    images.push(self.beta_transac::<3>(trans, d)?);
+                }
+                OrbitPolicy::FaceLinear => {
+                    images.push(self.beta_transac::<1>(trans, d)?);
+                    images.push(self.beta_transac::<3>(trans, d)?);
+                }
+                OrbitPolicy::Volume => {
+                    images.push(self.beta_transac::<1>(trans, d)?);
+                    images.push(self.beta_transac::<0>(trans, d)?);
+                    images.push(self.beta_transac::<2>(trans, d)?);
+                }
+                OrbitPolicy::VolumeLinear => {
+                    images.push(self.beta_transac::<1>(trans, d)?);
+                    images.push(self.beta_transac::<2>(trans, d)?);
+                }
+                OrbitPolicy::Custom(beta_slice) => {
+                    for beta_id in beta_slice {
+                        images.push(self.beta_rt_transac(trans, *beta_id, d)?);
+                    }
+                }
+            }
+            for image in images {
+                if marked.insert(image) {
+                    pending.push_back(image);
+                }
+            }
+            f(trans, d)?;
+        }
+
+        Ok(())
+    }
+
     /// Return an iterator over IDs of all the map's vertices.
+    ///
+    /// IDs are yielded in increasing order.
     pub fn iter_vertices(&self) -> impl Iterator<Item = VertexIdType> + '_ {
         (1..self.n_darts() as DartIdType)
             .zip(self.unused_darts.iter().skip(1))
@@ -561,6 +803,8 @@ impl<T: CoordsFloat> CMap3<T> {
     }
 
     /// Return an iterator over IDs of all the map's edges.
+    ///
+    /// IDs are yielded in increasing order.
     pub fn iter_edges(&self) -> impl Iterator<Item = EdgeIdType> + '_ {
         (1..self.n_darts() as DartIdType)
             .zip(self.unused_darts.iter().skip(1))
@@ -584,6 +828,8 @@ impl<T: CoordsFloat> CMap3<T> {
     }
 
     /// Return an iterator over IDs of all the map's faces.
+    ///
+    /// IDs are yielded in increasing order.
     pub fn iter_faces(&self) -> impl Iterator<Item = FaceIdType> + '_ {
         (1..self.n_darts() as DartIdType)
             .zip(self.unused_darts.iter().skip(1))
@@ -606,7 +852,73 @@ impl<T: CoordsFloat> CMap3<T> {
             })
     }
 
+    /// Count volumes of the map by shape.
+    ///
+    /// Faces of each volume are computed as the β<sub>1</sub> cycles of its darts. A volume is
+    /// counted as a tetrahedron if it is made up of four triangles, as a hexahedron if it is made
+    /// up of six quadrilaterals, and as a general polyhedron otherwise (including volumes with
+    /// open faces).
+    ///
+    /// # Return
+    ///
+    /// Return a [`CellCounts`] where volumes are classified. Face counts are left to zero.
+    #[must_use = "unused return value"]
+    pub fn cell_counts(&self) -> CellCounts {
+        let mut counts = CellCounts::default();
+        for vid in self.iter_volumes() {
+            let mut visited = HashSet::new();
+            let mut degrees: SmallVec<[usize; 6]> = SmallVec::new();
+            for d in Orbit3::new(self, OrbitPolicy::Volume, vid as DartIdType) {
+                if !visited.insert(d) {
+                    continue;
+                }
+                let mut n = 1;
+                let mut dd = self.beta::<1>(d);
+                while dd != d && dd != NULL_DART_ID && visited.insert(dd) {
+                    n += 1;
+                    dd = self.beta::<1>(dd);
+                }
+                degrees.push(n);
+            }
+            match degrees.as_slice() {
+                [3, 3, 3, 3] => counts.tetrahedra += 1,
+                [4, 4, 4, 4, 4, 4] => counts.hexahedra += 1,
+                _ => counts.polyhedra += 1,
+            }
+        }
+        counts
+    }
+
+    /// Return an iterator over IDs of the volumes adjacent to a volume.
+    ///
+    /// As in [`CMap3::cell_counts`], faces of the volume are computed as the β<sub>1</sub> cycles
+    /// of its darts. A volume ID is yielded for each face that isn't 3-free, in the order darts
+    /// of the volume are visited. A neighbor sharing multiple faces with the volume is yielded
+    /// multiple times.
+    #[must_use = "unused return value"]
+    pub fn volume_neighbors(
+        &self,
+        volume_id: VolumeIdType,
+    ) -> impl Iterator<Item = VolumeIdType> + '_ {
+        Orbit3::new(self, OrbitPolicy::Volume, volume_id as DartIdType)
+            .filter(|d| !self.is_i_free::<3>(*d))
+            // keep a single dart per face, i.e. the smallest of its cycle
+            .filter(|d| {
+                let mut dd = self.beta::<1>(*d);
+                while dd != *d && dd != NULL_DART_ID {
+                    if dd < *d {
+                        return false;
+                    }
+                    dd = self.beta::<1>(dd);
+                }
+                true
+            })
+            .map(|d| self.volume_id(self.beta::<3>(d)))
+    }
+
     /// Return an iterator over IDs of all the map's volumes.
+    ///
+    /// IDs are yielded in increasing order.
     pub fn iter_volumes(&self) -> impl Iterator<Item = VolumeIdType> + '_ {
         (1..self.n_darts() as DartIdType)
             .zip(self.unused_darts.iter().skip(1))
@@ -15,6 +15,11 @@ use crate::{
     geometry::{CoordsFloat, Vertex3},
 };
 
+#[cfg(feature = "par-internals")]
+use crate::prelude::DartIdType;
+#[cfg(feature = "par-internals")]
+use rayon::prelude::*;
+
 // ------ CONTENT
 
 /// ## **Built-in vertex-related methods**
@@ -139,6 +144,33 @@ impl<T: CoordsFloat> CMap3<T> {
     pub fn force_remove_vertex(&self, vertex_id: VertexIdType) -> Option<Vertex3<T>> {
         self.vertices.force_remove(vertex_id)
     }
+
+    #[must_use = "unused return value"]
+    /// Return the axis-aligned bounding box of the map's vertices.
+    ///
+    /// # Return
+    ///
+    /// Return a `(min, max)` tuple of the box's corners, or `None` if no vertex is defined.
+    pub fn bounding_box(&self) -> Option<(Vertex3<T>, Vertex3<T>)> {
+        self.iter_vertices()
+            .filter_map(|vid| self.force_read_vertex(vid))
+            .map(|v| (v, v))
+            .reduce(merge_boxes)
+    }
+
+    #[cfg(feature = "par-internals")]
+    #[must_use = "unused return value"]
+    /// Return the axis-aligned bounding box of the map's vertices, computed in parallel.
+    ///
+    /// This method yields the same result as `bounding_box`, but is intended for large maps.
+    pub fn par_bounding_box(&self) -> Option<(Vertex3<T>, Vertex3<T>)> {
+        (1..self.n_darts() as DartIdType)
+            .into_par_iter()
+            .filter(|d| !self.unused_darts[*d].read_atomic() && self.vertex_id(*d) == *d)
+            .filter_map(|vid| self.force_read_vertex(vid))
+            .map(|v| (v, v))
+            .reduce_with(merge_boxes)
+    }
 }
 
 /// ## **Generic attribute-related methods**
@@ -273,3 +305,22 @@ impl<T: CoordsFloat> CMap3<T> {
         self.attributes.remove_storage::<A>();
     }
 }
+
+/// Merge two `(min, max)` bounding boxes.
+fn merge_boxes<T: CoordsFloat>(
+    lhs: (Vertex3<T>, Vertex3<T>),
+    rhs: (Vertex3<T>, Vertex3<T>),
+) -> (Vertex3<T>, Vertex3<T>) {
+    (
+        Vertex3(
+            lhs.0 .0.min(rhs.0 .0),
+            lhs.0 .1.min(rhs.0 .1),
+            lhs.0 .2.min(rhs.0 .2),
+        ),
+        Vertex3(
+            lhs.1 .0.max(rhs.1 .0),
+            lhs.1 .1.max(rhs.1 .1),
+            lhs.1 .2.max(rhs.1 .2),
+        ),
+    )
+}
@@ -2,7 +2,7 @@ mod one;
 mod three;
 mod two;
 
-use crate::stm::{StmClosureResult, Transaction};
+use crate::stm::{atomically, StmClosureResult, Transaction};
 
 use crate::{
     cmap::{CMap3, DartIdType},
@@ -134,4 +134,29 @@ impl<T: CoordsFloat> CMap3<T> {
             _ => unreachable!(),
         }
     }
+
+    /// `I`-link operator, applied to a batch of dart pairs.
+    ///
+    /// This method is equivalent to calling [`force_link`][Self::force_link] on each pair, but
+    /// uses a single transaction that will be retried until validated. Links are applied in the
+    /// order of the slice.
+    ///
+    /// # Arguments
+    ///
+    /// - `const I: u8` -- Link dimension.
+    /// - `pairs: &[(DartIdType, DartIdType)]` -- Pairs of darts to link.
+    ///
+    /// # Panics
+    ///
+    /// The method may panic if:
+    /// - `I >= 4` or `I == 0`,
+    /// - the two darts of a pair are not `I`-linkable.
+    pub fn force_link_many<const I: u8>(&self, pairs: &[(DartIdType, DartIdType)]) {
+        atomically(|trans| {
+            for (lhs_dart_id, rhs_dart_id) in pairs {
+                self.link::<I>(trans, *lhs_dart_id, *rhs_dart_id)?;
+            }
+            Ok(())
+        });
+    }
 }
@@ -28,6 +28,7 @@ impl<'a, T: CoordsFloat> Orbit3<'a, T> {
         marked.insert(NULL_DART_ID); // we don't want to include the null dart in the orbit
         marked.insert(dart); // we're starting here, so we mark it beforehand
         let pending = VecDeque::from([dart]);
+        debug_assert_eq!(orbit_policy.check_dim(3), Ok(()));
 
         Self {
             map_handle,
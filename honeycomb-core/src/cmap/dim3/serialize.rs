@@ -0,0 +1,138 @@
+use crate::cmap::{CMapError, CMapResult, DartIdType, VertexIdType, NULL_DART_ID};
+use crate::geometry::{CoordsFloat, Vertex3};
+use crate::prelude::CMap3;
+
+use std::collections::BTreeMap;
+use std::io::Write;
+
+// --- MEDIT
+
+/// **Serialization methods**
+impl<T: CoordsFloat> CMap3<T> {
+    #[allow(clippy::missing_errors_doc)]
+    /// Generate an ASCII MEDIT (`.mesh`) file from the map.
+    ///
+    /// The file contains the vertices & tetrahedra of the map, as returned by
+    /// [`Self::to_tet_soup`]. All references are set to `0`.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method will return a `CMapError::IncorrectGeometry` if the map cannot be exported as
+    /// a tetrahedron soup. In this case, nothing is written.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the internal writing routine fails, i.e.:
+    /// - vertex coordinates cannot be cast to `f64`,
+    /// - data cannot be written to the writer.
+    pub fn to_medit(&self, writer: impl Write) -> CMapResult<()> {
+        let (vertices, tets) = self.to_tet_soup()?;
+        write_medit(writer, &vertices, &tets).expect("E: could not write data to writer");
+        Ok(())
+    }
+}
+
+/// Internal writing routine for MEDIT serialization.
+fn write_medit<T: CoordsFloat>(
+    mut writer: impl Write,
+    vertices: &[Vertex3<T>],
+    tets: &[[u32; 4]],
+) -> std::io::Result<()> {
+    writeln!(writer, "MeshVersionFormatted 2")?;
+    writeln!(writer, "Dimension 3")?;
+    writeln!(writer, "\nVertices\n{}", vertices.len())?;
+    for v in vertices {
+        let [x, y, z] = [v.x(), v.y(), v.z()].map(|c| c.to_f64().unwrap());
+        writeln!(writer, "{x} {y} {z} 0")?;
+    }
+    // MEDIT indices start at 1
+    writeln!(writer, "\nTetrahedra\n{}", tets.len())?;
+    for [a, b, c, d] in tets {
+        writeln!(writer, "{} {} {} {} 0", a + 1, b + 1, c + 1, d + 1)?;
+    }
+    writeln!(writer, "\nEnd")
+}
+
+// --- OBJ
+
+/// **Serialization methods**
+impl<T: CoordsFloat> CMap3<T> {
+    #[allow(clippy::missing_errors_doc)]
+    /// Generate an OBJ (`.obj`) file describing the boundary of the map.
+    ///
+    /// Only boundary faces, i.e. faces that are 3-free, are written, as polygons. Interior faces
+    /// are skipped, as well as vertices which do not belong to the boundary. Faces are written
+    /// in reverse `β1` order, so that their normals point outward for positively oriented
+    /// volumes.
+    ///
+    /// # Return / Errors
+    ///
+    /// This method will return a `CMapError::IncorrectGeometry` if a boundary face isn't closed,
+    /// or if one of its vertices is undefined. In this case, nothing is written.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the internal writing routine fails, i.e.:
+    /// - vertex coordinates cannot be cast to `f64`,
+    /// - data cannot be written to the writer.
+    pub fn to_obj(&self, writer: impl Write) -> CMapResult<()> {
+        let mut indices: BTreeMap<VertexIdType, usize> = BTreeMap::new();
+        let mut vertices = Vec::new();
+        let mut faces = Vec::new();
+        for fid in self.iter_faces() {
+            let d0 = fid as DartIdType;
+            if self.beta::<3>(d0) != NULL_DART_ID {
+                continue;
+            }
+            let mut face = Vec::new();
+            let mut d = d0;
+            loop {
+                let vid = self.vertex_id(d);
+                let idx = if let Some(idx) = indices.get(&vid) {
+                    *idx
+                } else {
+                    vertices.push(
+                        self.force_read_vertex(vid)
+                            .ok_or(CMapError::IncorrectGeometry("undefined vertex"))?,
+                    );
+                    indices.insert(vid, vertices.len() - 1);
+                    vertices.len() - 1
+                };
+                face.push(idx);
+                d = self.beta::<1>(d);
+                if d == d0 {
+                    break;
+                }
+                if d == NULL_DART_ID {
+                    return Err(CMapError::IncorrectGeometry("open boundary face"));
+                }
+            }
+            face.reverse();
+            faces.push(face);
+        }
+
+        write_obj(writer, &vertices, &faces).expect("E: could not write data to writer");
+        Ok(())
+    }
+}
+
+/// Internal writing routine for OBJ serialization.
+fn write_obj<T: CoordsFloat>(
+    mut writer: impl Write,
+    vertices: &[Vertex3<T>],
+    faces: &[Vec<usize>],
+) -> std::io::Result<()> {
+    for v in vertices {
+        let [x, y, z] = [v.x(), v.y(), v.z()].map(|c| c.to_f64().unwrap());
+        writeln!(writer, "v {x} {y} {z}")?;
+    }
+    // OBJ indices start at 1
+    for face in faces {
+        write!(writer, "f")?;
+        for idx in face {
+            write!(writer, " {}", idx + 1)?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
@@ -5,7 +5,7 @@ mod two;
 use crate::stm::Transaction;
 
 use crate::{
-    cmap::{CMap3, CMapResult, DartIdType},
+    cmap::{try_atomically, CMap3, CMapResult, DartIdType},
     prelude::CoordsFloat,
 };
 
@@ -127,6 +127,34 @@ impl<T: CoordsFloat> CMap3<T> {
         }
     }
 
+    /// `I`-sew operator, applied to a batch of dart pairs.
+    ///
+    /// Sews are applied in the order of the slice, using a single transaction: either all of them
+    /// are applied, or none is. The transaction is retried on conflicts; other errors abort the
+    /// whole batch.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the first error raised by a sew of the batch (see [`sew`][Self::sew]).
+    /// In this case, the map is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// The method may panic if:
+    /// - `I >= 4` or `I == 0`,
+    /// - the two darts of a pair are not `I`-sewable.
+    pub fn force_sew_many<const I: u8>(
+        &self,
+        pairs: &[(DartIdType, DartIdType)],
+    ) -> CMapResult<()> {
+        try_atomically(|trans| {
+            for (ld, rd) in pairs {
+                self.sew::<I>(trans, *ld, *rd)?;
+            }
+            Ok(())
+        })
+    }
+
     /// `I`-unsew operator.
     ///
     /// This variant is equivalent to [`unsew`][Self::unsew], but internally uses a transaction that
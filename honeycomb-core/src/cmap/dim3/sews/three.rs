@@ -204,7 +204,7 @@ impl<T: CoordsFloat> CMap3<T> {
             let l_face = l_side.clone().min().expect("E: unreachable");
             let r_face = r_side.clone().min().expect("E: unreachable");
             self.attributes
-                .try_split_face_attributes(trans, l_face, r_face, l_face.max(r_face))?;
+                .try_split_face_attributes(trans, l_face, r_face, l_face.min(r_face))?;
 
             for (l, r) in l_side.zip(r_side) {
                 // edge
@@ -213,7 +213,7 @@ impl<T: CoordsFloat> CMap3<T> {
                     self.edge_id_transac(trans, r)?,
                 );
                 self.attributes
-                    .try_split_edge_attributes(trans, eid_l, eid_r, eid_l.max(eid_r))?;
+                    .try_split_edge_attributes(trans, eid_l, eid_r, eid_l.min(eid_r))?;
 
                 // vertices
                 let b1l = self.beta_transac::<1>(trans, l)?;
@@ -223,12 +223,12 @@ impl<T: CoordsFloat> CMap3<T> {
                     self.vertex_id_transac(trans, r)?,
                 );
                 self.vertices
-                    .try_split(trans, vid_l, vid_r, vid_l.max(vid_r))?;
+                    .try_split(trans, vid_l, vid_r, vid_l.min(vid_r))?;
                 self.attributes.try_split_vertex_attributes(
                     trans,
                     vid_l,
                     vid_r,
-                    vid_l.max(vid_r),
+                    vid_l.min(vid_r),
                 )?;
                 if self.beta_transac::<0>(trans, l)? == NULL_DART_ID {
                     let b1r = self.beta_transac::<1>(trans, r)?;
@@ -238,12 +238,12 @@ impl<T: CoordsFloat> CMap3<T> {
                         self.vertex_id_transac(trans, if b1r == NULL_DART_ID { b2r } else { b1r })?,
                     );
                     self.vertices
-                        .try_split(trans, lvid_l, lvid_r, lvid_l.max(lvid_r))?;
+                        .try_split(trans, lvid_l, lvid_r, lvid_l.min(lvid_r))?;
                     self.attributes.try_split_vertex_attributes(
                         trans,
                         lvid_l,
                         lvid_r,
-                        lvid_l.max(lvid_r),
+                        lvid_l.min(lvid_r),
                     )?;
                 }
             }
@@ -268,7 +268,7 @@ impl<T: CoordsFloat> CMap3<T> {
                 let l_face = l_side.clone().min().expect("E: unreachable");
                 let r_face = r_side.clone().min().expect("E: unreachable");
                 self.attributes
-                    .split_face_attributes(trans, l_face, r_face, l_face.max(r_face))?;
+                    .split_face_attributes(trans, l_face, r_face, l_face.min(r_face))?;
 
                 for (l, r) in l_side.zip(r_side) {
                     // edge
@@ -277,7 +277,7 @@ impl<T: CoordsFloat> CMap3<T> {
                         self.edge_id_transac(trans, r)?,
                     );
                     self.attributes
-                        .split_edge_attributes(trans, eid_l, eid_r, eid_l.max(eid_r))?;
+                        .split_edge_attributes(trans, eid_l, eid_r, eid_l.min(eid_r))?;
 
                     // vertices
                     let b1l = self.beta_transac::<1>(trans, l)?;
@@ -286,12 +286,12 @@ impl<T: CoordsFloat> CMap3<T> {
                         self.vertex_id_transac(trans, if b1l == NULL_DART_ID { b2l } else { b1l })?,
                         self.vertex_id_transac(trans, r)?,
                     );
-                    self.vertices.split(trans, vid_l, vid_r, vid_l.max(vid_r))?;
+                    self.vertices.split(trans, vid_l, vid_r, vid_l.min(vid_r))?;
                     self.attributes.split_vertex_attributes(
                         trans,
                         vid_l,
                         vid_r,
-                        vid_l.max(vid_r),
+                        vid_l.min(vid_r),
                     )?;
                     if self.beta_transac::<0>(trans, l)? == NULL_DART_ID {
                         let b1r = self.beta_transac::<1>(trans, r)?;
@@ -304,12 +304,12 @@ impl<T: CoordsFloat> CMap3<T> {
                             )?,
                         );
                         self.vertices
-                            .split(trans, lvid_l, lvid_r, lvid_l.max(lvid_r))?;
+                            .split(trans, lvid_l, lvid_r, lvid_l.min(lvid_r))?;
                         self.attributes.split_vertex_attributes(
                             trans,
                             lvid_l,
                             lvid_r,
-                            lvid_l.max(lvid_r),
+                            lvid_l.min(lvid_r),
                         )?;
                     }
                 }
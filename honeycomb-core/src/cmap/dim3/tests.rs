@@ -4,8 +4,11 @@ use crate::stm::{atomically, StmError, TVar};
 
 use crate::{
     attributes::{AttrSparseVec, AttributeBind, AttributeUpdate},
-    cmap::{CMap3, CMapError, DartIdType, Orbit3, OrbitPolicy, VertexIdType},
-    geometry::Vertex3,
+    cmap::{
+        CMap3, CMapError, ConsistencyError, DartIdType, IntegrityError, Orbit3, OrbitPolicy,
+        VertexIdType,
+    },
+    geometry::{Vertex2, Vertex3},
 };
 
 // ------ CONTENT
@@ -419,6 +422,421 @@ fn remove_dart_twice() {
     map.remove_free_dart(1); // this should panic
 }
 
+#[test]
+fn bounding_box() {
+    let map: CMap3<f64> = CMap3::new(4);
+    assert!(map.bounding_box().is_none());
+    map.force_write_vertex(1, (1.0, -1.0, 0.5));
+    assert_eq!(
+        map.bounding_box(),
+        Some((Vertex3(1.0, -1.0, 0.5), Vertex3(1.0, -1.0, 0.5)))
+    );
+    map.force_write_vertex(2, (0.0, 2.0, 0.0));
+    map.force_write_vertex(4, (3.0, 0.0, -1.0));
+    assert_eq!(
+        map.bounding_box(),
+        Some((Vertex3(0.0, -1.0, -1.0), Vertex3(3.0, 2.0, 0.5)))
+    );
+    #[cfg(feature = "par-internals")]
+    assert_eq!(map.par_bounding_box(), map.bounding_box());
+}
+
+// --- LINK
+
+/// Build a tetrahedron `ABCD` using darts `d0+1..=d0+12`.
+fn build_tet(map: &CMap3<f64>, d0: DartIdType) {
+    // faces: (A, B, C), (B, A, D), (A, C, D), (C, B, D)
+    let one_links: Vec<(DartIdType, DartIdType)> = [1, 4, 7, 10]
+        .into_iter()
+        .flat_map(|f| {
+            let d = d0 + f;
+            [(d, d + 1), (d + 1, d + 2), (d + 2, d)]
+        })
+        .collect();
+    let two_links =
+        [(1, 4), (3, 7), (5, 9), (2, 10), (6, 11), (8, 12)].map(|(l, r)| (d0 + l, d0 + r));
+    map.force_link_many::<1>(&one_links);
+    map.force_link_many::<2>(&two_links);
+}
+
+#[test]
+fn link_many_two_tets() {
+    let map: CMap3<f64> = CMap3::new(24);
+    // first tet: (A, B, C, D); second tet: (B, A, C, E)
+    build_tet(&map, 0);
+    build_tet(&map, 12);
+    assert_eq!(map.iter_volumes().count(), 2);
+    assert_eq!(map.iter_vertices().count(), 8);
+
+    // glue both tets along face (A, B, C)
+    map.force_link_many::<3>(&[(1, 13)]);
+    assert_eq!(map.beta::<3>(1), 13);
+    assert_eq!(map.beta::<3>(2), 15);
+    assert_eq!(map.beta::<3>(3), 14);
+    assert_eq!(map.iter_volumes().count(), 2);
+    assert_eq!(map.iter_faces().count(), 7);
+    assert_eq!(map.iter_vertices().count(), 5);
+}
+
+#[test]
+fn cell_counts() {
+    // two tets, a hex, and a lone triangle
+    let map: CMap3<f64> = CMap3::new(24 + 24 + 3);
+    build_tet(&map, 0);
+    build_tet(&map, 12);
+    map.force_link_many::<3>(&[(1, 13)]);
+
+    // hex faces, as outward-oriented cycles of cube corners
+    let faces: [[usize; 4]; 6] = [
+        [0, 3, 2, 1],
+        [4, 5, 6, 7],
+        [0, 1, 5, 4],
+        [1, 2, 6, 5],
+        [2, 3, 7, 6],
+        [3, 0, 4, 7],
+    ];
+    let dart = |f: usize, i: usize| (25 + 4 * f + i) as DartIdType;
+    let mut one_links = Vec::new();
+    let mut two_links = Vec::new();
+    for (f, face) in faces.iter().enumerate() {
+        for i in 0..4 {
+            one_links.push((dart(f, i), dart(f, (i + 1) % 4)));
+            let (a, b) = (face[i], face[(i + 1) % 4]);
+            // link with the dart of the opposite edge, once per pair
+            for (g, other) in faces.iter().enumerate().skip(f + 1) {
+                if let Some(j) = (0..4).find(|&j| other[j] == b && other[(j + 1) % 4] == a) {
+                    two_links.push((dart(f, i), dart(g, j)));
+                }
+            }
+        }
+    }
+    map.force_link_many::<1>(&one_links);
+    map.force_link_many::<2>(&two_links);
+
+    map.force_link::<1>(49, 50);
+    map.force_link::<1>(50, 51);
+    map.force_link::<1>(51, 49);
+
+    let counts = map.cell_counts();
+    assert_eq!(counts.tetrahedra, 2);
+    assert_eq!(counts.hexahedra, 1);
+    assert_eq!(counts.polyhedra, 1);
+    assert_eq!(counts.n_volumes(), 4);
+    assert_eq!(counts.n_faces(), 0);
+}
+
+#[test]
+fn check_consistency() {
+    let map: CMap3<f64> = CMap3::new(24);
+    build_tet(&map, 0);
+    build_tet(&map, 12);
+    map.force_link_many::<3>(&[(1, 13)]);
+    assert_eq!(map.check_consistency(), Ok(()));
+
+    // break the 3-link of dart 2
+    map.set_beta::<3>(2, 14);
+    let errors = map.check_consistency().unwrap_err();
+    assert!(errors.contains(&ConsistencyError::NonInvolutiveBeta { dim: 3, dart: 2 }));
+    assert!(errors.contains(&ConsistencyError::IncoherentBeta3(1)));
+    assert!(errors
+        .iter()
+        .all(|e| !matches!(e, ConsistencyError::NonInverseBeta01(_))));
+}
+
+#[test]
+fn check_integrity() {
+    let map: CMap3<f64> = CMap3::new(24);
+    build_tet(&map, 0);
+    build_tet(&map, 12);
+    // A, B, C, D for the first tet, B, A, C, E for the second
+    for (d, v) in [
+        (1, (0.0, 0.0, 0.0)),
+        (2, (1.0, 0.0, 0.0)),
+        (3, (0.0, 1.0, 0.0)),
+        (6, (0.0, 0.0, 1.0)),
+        (13, (1.0, 0.0, 0.0)),
+        (14, (0.0, 0.0, 0.0)),
+        (15, (0.0, 1.0, 0.0)),
+        (18, (0.0, 0.0, -1.0)),
+    ] {
+        map.force_write_vertex(map.vertex_id(d), v);
+    }
+    map.force_sew::<3>(1, 13);
+    assert_eq!(map.check_integrity(), Ok(()));
+
+    // vertex E loses its value
+    let vid = map.vertex_id(18);
+    let _ = map.force_remove_vertex(vid);
+    assert_eq!(
+        map.check_integrity(),
+        Err(vec![IntegrityError::UndefinedVertex(vid)])
+    );
+    map.force_write_vertex(vid, (0.0, 0.0, -1.0));
+
+    // dart 1 is 2-unlinked, but its 3-neighbor isn't
+    let b2 = map.beta::<2>(1);
+    map.set_beta::<2>(1, 0);
+    map.set_beta::<2>(b2, 0);
+    let errors = map.check_integrity().unwrap_err();
+    assert!(errors.contains(&IntegrityError::OpenLinkedFace(1)));
+    assert!(errors
+        .iter()
+        .all(|e| !matches!(e, IntegrityError::Topology(_))));
+}
+
+#[test]
+fn iter_cells_order() {
+    let map: CMap3<f64> = CMap3::new(24);
+    build_tet(&map, 12);
+    build_tet(&map, 0);
+    map.force_link_many::<3>(&[(13, 1)]);
+
+    let vertices: Vec<_> = map.iter_vertices().collect();
+    let edges: Vec<_> = map.iter_edges().collect();
+    let faces: Vec<_> = map.iter_faces().collect();
+    let volumes: Vec<_> = map.iter_volumes().collect();
+    assert!(vertices.windows(2).all(|w| w[0] < w[1]));
+    assert!(edges.windows(2).all(|w| w[0] < w[1]));
+    assert!(faces.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(volumes, vec![1, 13]);
+    assert_eq!(faces.len(), 7);
+    assert!(faces.iter().all(|fid| map.face_id(*fid) == *fid));
+}
+
+#[test]
+fn volume_neighbors() {
+    let map: CMap3<f64> = CMap3::new(36);
+    build_tet(&map, 0);
+    build_tet(&map, 12);
+    build_tet(&map, 24);
+    assert_eq!(map.volume_neighbors(1).count(), 0);
+
+    // glue the second & third tets to two faces of the first one
+    map.force_link_many::<3>(&[(1, 13), (4, 25)]);
+    let mut neighbors: Vec<_> = map.volume_neighbors(1).collect();
+    neighbors.sort_unstable();
+    assert_eq!(neighbors, vec![13, 25]);
+    assert_eq!(map.volume_neighbors(13).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(map.volume_neighbors(25).collect::<Vec<_>>(), vec![1]);
+}
+
+#[test]
+fn extract_surface() {
+    let map: CMap3<f64> = CMap3::new(24);
+    build_tet(&map, 0);
+    build_tet(&map, 12);
+    // A, B, C, D for the first tet, B, A, C, E for the second
+    for (d, v) in [
+        (1, (0.0, 0.0, 0.0)),
+        (2, (1.0, 0.0, 0.0)),
+        (3, (0.0, 1.0, 0.0)),
+        (6, (0.0, 0.0, 1.0)),
+        (13, (1.0, 0.0, 0.0)),
+        (14, (0.0, 0.0, 0.0)),
+        (15, (0.0, 1.0, 0.0)),
+        (18, (0.0, 0.0, -1.0)),
+    ] {
+        map.force_write_vertex(map.vertex_id(d), v);
+    }
+    map.force_sew::<3>(1, 13);
+
+    // the shared face is dropped, the surface is closed
+    let surface = map.extract_surface(|v| Vertex2(v.x(), v.y()));
+    assert_eq!(surface.n_darts(), 19);
+    assert_eq!(surface.iter_faces().count(), 6);
+    assert_eq!(surface.iter_edges().count(), 9);
+    assert_eq!(surface.iter_vertices().count(), 5);
+    assert!((1..19).all(|d| !surface.is_i_free::<2>(d)));
+    assert_eq!(surface.check_integrity(), Ok(()));
+    let coords: Vec<_> = surface
+        .iter_vertices()
+        .map(|vid| surface.force_read_attribute::<Vertex3<f64>>(vid).unwrap())
+        .collect();
+    assert!(coords.contains(&Vertex3(0.0, 0.0, 1.0)));
+    assert!(coords.contains(&Vertex3(0.0, 0.0, -1.0)));
+
+    // the boundary of a single tet has one face per tet face
+    let map: CMap3<f64> = CMap3::new(12);
+    build_tet(&map, 0);
+    let surface = map.extract_surface(|v| Vertex2(v.x(), v.y()));
+    assert_eq!(surface.iter_faces().count(), 4);
+    assert_eq!(surface.iter_vertices().count(), 4);
+}
+
+#[test]
+fn remove_volume() {
+    let map: CMap3<f64> = CMap3::new(24);
+    build_tet(&map, 0);
+    build_tet(&map, 12);
+    // A, B, C, D for the first tet, B, A, C, E for the second
+    for (d, v) in [
+        (1, (0.0, 0.0, 0.0)),
+        (2, (1.0, 0.0, 0.0)),
+        (3, (0.0, 1.0, 0.0)),
+        (6, (0.0, 0.0, 1.0)),
+        (13, (1.0, 0.0, 0.0)),
+        (14, (0.0, 0.0, 0.0)),
+        (15, (0.0, 1.0, 0.0)),
+        (18, (0.0, 0.0, -1.0)),
+    ] {
+        map.force_write_vertex(map.vertex_id(d), v);
+    }
+    map.force_sew::<3>(1, 13);
+    assert_eq!(map.iter_vertices().count(), 5);
+
+    map.force_remove_volume(13).unwrap();
+    assert_eq!(map.n_unused_darts(), 12);
+    assert_eq!(map.iter_volumes().collect::<Vec<_>>(), vec![1]);
+    assert_eq!(map.iter_vertices().count(), 4);
+    assert!(map
+        .iter_vertices()
+        .all(|vid| map.force_read_vertex(vid).is_some()));
+    assert!((1..=12).all(|d| map.beta::<3>(d) == 0));
+    assert_eq!(map.check_consistency(), Ok(()));
+
+    // the volume was already removed
+    assert!(matches!(
+        map.force_remove_volume(13),
+        Err(CMapError::InconsistentCell(_))
+    ));
+}
+
+#[test]
+fn remove_volume_open() {
+    let map: CMap3<f64> = CMap3::new(3);
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 1);
+    assert!(matches!(
+        map.force_remove_volume(1),
+        Err(CMapError::InconsistentCell(_))
+    ));
+    assert_eq!(map.n_unused_darts(), 0);
+    assert_eq!(map.beta::<1>(1), 2);
+}
+
+#[test]
+fn orbit_dart_tx() {
+    let map: CMap3<f64> = CMap3::new(24);
+    build_tet(&map, 0);
+    build_tet(&map, 12);
+    map.force_link_many::<3>(&[(1, 13)]);
+    for policy in [
+        OrbitPolicy::Vertex,
+        OrbitPolicy::VertexLinear,
+        OrbitPolicy::Edge,
+        OrbitPolicy::Face,
+        OrbitPolicy::FaceLinear,
+        OrbitPolicy::Volume,
+        OrbitPolicy::VolumeLinear,
+        OrbitPolicy::Custom(&[1, 3]),
+    ] {
+        let darts = atomically(|trans| {
+            let mut darts = Vec::new();
+            map.for_each_orbit_dart_tx(trans, policy.clone(), 2, |_, d| {
+                darts.push(d);
+                Ok(())
+            })?;
+            Ok(darts)
+        });
+        assert_eq!(darts, Orbit3::new(&map, policy, 2).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn obj_boundary_write() {
+    let map: CMap3<f64> = CMap3::new(24);
+    build_tet(&map, 0);
+    build_tet(&map, 12);
+    map.force_link_many::<3>(&[(1, 13)]);
+    // A, B, C, D, E
+    map.force_write_vertex(map.vertex_id(1), (0.0, 0.0, 0.0));
+    map.force_write_vertex(map.vertex_id(2), (1.0, 0.0, 0.0));
+    map.force_write_vertex(map.vertex_id(3), (0.0, 1.0, 0.0));
+    map.force_write_vertex(map.vertex_id(6), (0.0, 0.0, 1.0));
+    map.force_write_vertex(map.vertex_id(18), (0.0, 0.0, -1.0));
+
+    let mut buffer = Vec::new();
+    map.to_obj(&mut buffer).unwrap();
+    let obj = String::from_utf8(buffer).unwrap();
+    let vertices: Vec<Vertex3<f64>> = obj
+        .lines()
+        .filter_map(|l| l.strip_prefix("v "))
+        .map(|l| {
+            let c: Vec<f64> = l.split_whitespace().map(|c| c.parse().unwrap()).collect();
+            Vertex3(c[0], c[1], c[2])
+        })
+        .collect();
+    let faces: Vec<Vec<usize>> = obj
+        .lines()
+        .filter_map(|l| l.strip_prefix("f "))
+        .map(|l| l.split_whitespace().map(|i| i.parse().unwrap()).collect())
+        .collect();
+    // the shared face (A, B, C) is skipped
+    assert_eq!(vertices.len(), 5);
+    assert_eq!(faces.len(), 6);
+    assert!(faces.iter().all(|f| f.len() == 3));
+    // all normals point outward
+    let center = Vertex3(0.2, 0.2, 0.0);
+    assert!(faces.iter().all(|f| {
+        let [a, b, c] = [f[0], f[1], f[2]].map(|i| vertices[i - 1]);
+        let normal = (b - a).cross(&(c - a));
+        normal.dot(&(a - center)) > 0.0
+    }));
+
+    // undefined vertex
+    map.force_remove_vertex(map.vertex_id(18));
+    let mut buffer = Vec::new();
+    assert!(map.to_obj(&mut buffer).is_err());
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn tet_soup_write() {
+    let map: CMap3<f64> = CMap3::new(24);
+    // first tet: (A, B, C, D); second tet: (B, A, C, E)
+    build_tet(&map, 0);
+    build_tet(&map, 12);
+    map.force_link_many::<3>(&[(1, 13)]);
+    // A, B, C, D, E
+    map.force_write_vertex(map.vertex_id(1), (0.0, 0.0, 0.0));
+    map.force_write_vertex(map.vertex_id(2), (1.0, 0.0, 0.0));
+    map.force_write_vertex(map.vertex_id(3), (0.0, 1.0, 0.0));
+    map.force_write_vertex(map.vertex_id(6), (0.0, 0.0, 1.0));
+    map.force_write_vertex(map.vertex_id(18), (0.0, 0.0, -1.0));
+
+    let (vertices, tets) = map.to_tet_soup().unwrap();
+    assert_eq!(vertices.len(), 5);
+    assert_eq!(tets.len(), 2);
+    assert!(tets
+        .iter()
+        .all(|tet| tet.iter().all(|id| (*id as usize) < vertices.len())));
+    let [a, b, c, d] = tets[0].map(|id| vertices[id as usize]);
+    assert_eq!(
+        (a, b, c, d),
+        (
+            Vertex3(0.0, 0.0, 0.0),
+            Vertex3(1.0, 0.0, 0.0),
+            Vertex3(0.0, 1.0, 0.0),
+            Vertex3(0.0, 0.0, 1.0)
+        )
+    );
+    // both tets share face (A, B, C), with opposite orientations
+    assert_eq!(&tets[1][..3], &[tets[0][1], tets[0][0], tets[0][2]]);
+    assert_eq!(vertices[tets[1][3] as usize], Vertex3(0.0, 0.0, -1.0));
+
+    // open volumes cannot be exported
+    let map: CMap3<f64> = CMap3::new(3);
+    map.force_link_many::<1>(&[(1, 2), (2, 3), (3, 1)]);
+    (1..=3).for_each(|d| {
+        map.force_write_vertex(d, (0.0, 0.0, 0.0));
+    });
+    assert!(matches!(
+        map.to_tet_soup(),
+        Err(CMapError::IncorrectGeometry(_))
+    ));
+}
+
 // --- (UN)SEW
 
 #[test]
@@ -484,6 +902,35 @@ fn three_sew() {
     assert_eq!(map.force_read_vertex(4).unwrap(), Vertex3(0.0, 1.0, 0.5));
 }
 
+#[test]
+fn force_sew_many() {
+    let map: CMap3<f64> = CMap3::new(16);
+    for d0 in [0, 4, 8, 12] {
+        map.force_link::<1>(d0 + 1, d0 + 2);
+        map.force_link::<1>(d0 + 2, d0 + 3);
+        map.force_link::<1>(d0 + 3, d0 + 4);
+        map.force_link::<1>(d0 + 4, d0 + 1);
+    }
+    map.force_write_vertex(1, Vertex3(0.0, 0.0, 0.0));
+    map.force_write_vertex(2, Vertex3(1.0, 0.0, 0.0));
+    map.force_write_vertex(3, Vertex3(1.0, 1.0, 0.0));
+    map.force_write_vertex(4, Vertex3(0.0, 1.0, 0.0));
+    map.force_write_vertex(5, Vertex3(0.0, 0.0, 1.0));
+    map.force_write_vertex(6, Vertex3(0.0, 1.0, 1.0));
+    map.force_write_vertex(7, Vertex3(1.0, 1.0, 1.0));
+    map.force_write_vertex(8, Vertex3(1.0, 0.0, 1.0));
+
+    // the second sew fails due to missing vertices; the first one is rolled back
+    assert!(map.force_sew_many::<3>(&[(1, 8), (9, 16)]).is_err());
+    assert_eq!(map.beta::<3>(1), 0);
+    assert_eq!(map.beta::<3>(9), 0);
+    assert_eq!(map.force_read_vertex(1).unwrap(), Vertex3(0.0, 0.0, 0.0));
+
+    assert!(map.force_sew_many::<3>(&[(1, 8)]).is_ok());
+    assert_eq!(map.beta::<3>(1), 8);
+    assert_eq!(map.force_read_vertex(1).unwrap(), Vertex3(0.0, 0.0, 0.5));
+}
+
 #[test]
 #[should_panic(expected = "Dart 1 and 5 do not have consistent orientation for 2-sewing")]
 fn two_sew_bad_orientation() {
@@ -530,6 +977,94 @@ fn three_sew_bad_orientation() {
     map.force_sew::<3>(1, 5); // panic due to inconsistent orientation
 }
 
+#[test]
+fn three_unsew_attributes() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct VAttr(u32);
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct EAttr(u32);
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct FAttr(u32);
+    macro_rules! impl_attr {
+        ($t: ident, $id: ty, $policy: expr) => {
+            impl AttributeUpdate for $t {
+                fn merge(attr1: Self, attr2: Self) -> Self {
+                    Self(attr1.0 + attr2.0)
+                }
+
+                fn split(attr: Self) -> (Self, Self) {
+                    (attr, attr)
+                }
+            }
+
+            impl AttributeBind for $t {
+                type StorageType = AttrSparseVec<Self>;
+                type IdentifierType = $id;
+                const BIND_POLICY: OrbitPolicy = $policy;
+            }
+        };
+    }
+    impl_attr!(VAttr, VertexIdType, OrbitPolicy::Vertex);
+    impl_attr!(EAttr, crate::cmap::EdgeIdType, OrbitPolicy::Edge);
+    impl_attr!(FAttr, crate::cmap::FaceIdType, OrbitPolicy::Face);
+
+    let mut map: CMap3<f64> = CMap3::new(8);
+    map.attributes.add_storage::<VAttr>(9);
+    map.attributes.add_storage::<EAttr>(9);
+    map.attributes.add_storage::<FAttr>(9);
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 4);
+    map.force_link::<1>(4, 1);
+    map.force_link::<1>(5, 6);
+    map.force_link::<1>(6, 7);
+    map.force_link::<1>(7, 8);
+    map.force_link::<1>(8, 5);
+    map.force_write_vertex(1, Vertex3(0.0, 0.0, 0.0));
+    map.force_write_vertex(2, Vertex3(1.0, 0.0, 0.0));
+    map.force_write_vertex(3, Vertex3(1.0, 1.0, 0.0));
+    map.force_write_vertex(4, Vertex3(0.0, 1.0, 0.0));
+    map.force_write_vertex(5, Vertex3(0.0, 0.0, 1.0));
+    map.force_write_vertex(6, Vertex3(0.0, 1.0, 1.0));
+    map.force_write_vertex(7, Vertex3(1.0, 1.0, 1.0));
+    map.force_write_vertex(8, Vertex3(1.0, 0.0, 1.0));
+    for d in 1..=8 {
+        map.force_write_attribute(d, VAttr(d));
+        map.force_write_attribute(d, EAttr(10 * d));
+    }
+    map.force_write_attribute(1, FAttr(100));
+    map.force_write_attribute(5, FAttr(200));
+
+    map.force_sew::<3>(1, 8);
+    // merged values live at the smallest ID of each cell
+    assert_eq!(map.force_read_attribute::<VAttr>(1), Some(VAttr(1 + 5)));
+    assert_eq!(map.force_read_attribute::<EAttr>(1), Some(EAttr(10 + 80)));
+    assert_eq!(map.force_read_attribute::<FAttr>(1), Some(FAttr(300)));
+
+    map.force_unsew::<3>(1);
+    // both sides get the value back, at the smallest ID of their cell
+    for (l, r) in [(1, 5), (2, 8), (3, 7), (4, 6)] {
+        assert_eq!(map.vertex_id(l), l);
+        assert_eq!(map.vertex_id(r), r);
+        assert_eq!(map.force_read_attribute::<VAttr>(l), Some(VAttr(l + r)));
+        assert_eq!(map.force_read_attribute::<VAttr>(r), Some(VAttr(l + r)));
+    }
+    for (l, r) in [(1, 8), (2, 7), (3, 6), (4, 5)] {
+        assert_eq!(map.edge_id(l), l);
+        assert_eq!(map.edge_id(r), r);
+        assert_eq!(
+            map.force_read_attribute::<EAttr>(l),
+            Some(EAttr(10 * (l + r)))
+        );
+        assert_eq!(
+            map.force_read_attribute::<EAttr>(r),
+            Some(EAttr(10 * (l + r)))
+        );
+    }
+    assert_eq!(map.force_read_attribute::<FAttr>(1), Some(FAttr(300)));
+    assert_eq!(map.force_read_attribute::<FAttr>(5), Some(FAttr(300)));
+}
+
 // --- PARALLEL
 
 #[test]
@@ -613,6 +1148,7 @@ fn sew_ordering_with_transactions() {
                         CMapError::FailedAttributeMerge(_) => Err(StmError::Retry),
                         CMapError::FailedAttributeSplit(_)
                         | CMapError::IncorrectGeometry(_)
+                        | CMapError::InconsistentCell(_)
                         | CMapError::UnknownAttribute(_) => unreachable!(),
                     }
                 } else {
@@ -633,6 +1169,7 @@ fn sew_ordering_with_transactions() {
                         CMapError::FailedAttributeMerge(_) => Err(StmError::Retry),
                         CMapError::FailedAttributeSplit(_)
                         | CMapError::IncorrectGeometry(_)
+                        | CMapError::InconsistentCell(_)
                         | CMapError::UnknownAttribute(_) => unreachable!(),
                     }
                 } else {
@@ -767,6 +1304,7 @@ fn unsew_ordering_with_transactions() {
                         CMapError::FailedAttributeSplit(_) => Err(StmError::Retry),
                         CMapError::FailedAttributeMerge(_)
                         | CMapError::IncorrectGeometry(_)
+                        | CMapError::InconsistentCell(_)
                         | CMapError::UnknownAttribute(_) => unreachable!(),
                     }
                 } else {
@@ -783,6 +1321,7 @@ fn unsew_ordering_with_transactions() {
                         CMapError::FailedAttributeSplit(_) => Err(StmError::Retry),
                         CMapError::FailedAttributeMerge(_)
                         | CMapError::IncorrectGeometry(_)
+                        | CMapError::InconsistentCell(_)
                         | CMapError::UnknownAttribute(_) => unreachable!(),
                     }
                 } else {
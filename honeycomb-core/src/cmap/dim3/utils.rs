@@ -5,8 +5,9 @@
 // ------ IMPORTS
 
 use super::CMAP3_BETA;
-use crate::geometry::CoordsFloat;
-use crate::prelude::{CMap3, DartIdType};
+use crate::cmap::{ConsistencyError, IntegrityError, NULL_DART_ID, NULL_VOLUME_ID};
+use crate::geometry::{CoordsFloat, Vertex2, Vertex3};
+use crate::prelude::{CMap2, CMap3, CMapBuilder, DartIdType};
 use crate::stm::atomically;
 
 // ------ CONTENT
@@ -43,3 +44,216 @@ impl<T: CoordsFloat> CMap3<T> {
         });
     }
 }
+
+/// **Conversions**
+impl<T: CoordsFloat> CMap3<T> {
+    #[allow(clippy::cast_possible_truncation)]
+    /// Extract the boundary of the map as a standalone 2-map.
+    ///
+    /// Boundary faces, i.e. 3-free faces, are copied into a new [`CMap2`], keeping their
+    /// orientation. Two boundary darts are 2-sewn in the new map if they are adjacent on the
+    /// surface, i.e. if one can be reached from the other by turning around their edge through
+    /// the volumes of the map. Darts are renumbered, following the order of the original IDs.
+    ///
+    /// # Coordinates
+    ///
+    /// Since a [`CMap2`] uses 2D vertices, the surface is flattened using `project`. The
+    /// original 3D coordinates are kept in the new map using a [`Vertex3`] attribute, bound to
+    /// its vertices. Undefined vertices of the original map are left undefined.
+    ///
+    /// # Arguments
+    ///
+    /// - `project: impl Fn(Vertex3<T>) -> Vertex2<T>` -- Projection used to compute 2D
+    ///   coordinates of the surface, e.g. `|v| Vertex2(v.x(), v.y())`.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if the map contains more darts than a [`CMap2`] can hold.
+    #[must_use = "unused return value"]
+    pub fn extract_surface(&self, project: impl Fn(Vertex3<T>) -> Vertex2<T>) -> CMap2<T> {
+        let boundary: Vec<DartIdType> = (1..self.n_darts() as DartIdType)
+            .filter(|d| !self.unused_darts[*d].read_atomic() && self.is_i_free::<3>(*d))
+            .collect();
+        // new IDs of boundary darts, the null dart being mapped to itself
+        let mut new_ids = vec![NULL_DART_ID; self.n_darts()];
+        for (i, d) in boundary.iter().enumerate() {
+            new_ids[*d as usize] = i as DartIdType + 1;
+        }
+
+        let surface: CMap2<T> = CMapBuilder::from_n_darts(boundary.len())
+            .add_attribute::<Vertex3<T>>()
+            .build()
+            .expect("E: unreachable");
+        for d in &boundary {
+            // turn around the edge until reaching the next boundary face
+            let mut b2 = self.beta::<2>(*d);
+            let mut n = 0;
+            while !self.is_i_free::<3>(b2) && n < self.n_darts() {
+                b2 = self.beta::<2>(self.beta::<3>(b2));
+                n += 1;
+            }
+            surface.set_betas(
+                new_ids[*d as usize],
+                [
+                    new_ids[self.beta::<0>(*d) as usize],
+                    new_ids[self.beta::<1>(*d) as usize],
+                    new_ids[b2 as usize],
+                ],
+            );
+        }
+        for d in &boundary {
+            let (vid, new_vid) = (self.vertex_id(*d), surface.vertex_id(new_ids[*d as usize]));
+            if new_vid == new_ids[*d as usize] {
+                if let Some(v) = self.force_read_vertex(vid) {
+                    surface.force_write_vertex(new_vid, project(v));
+                    surface.force_write_attribute(new_vid, v);
+                }
+            }
+        }
+        surface
+    }
+}
+
+/// **Checks**
+impl<T: CoordsFloat> CMap3<T> {
+    #[allow(clippy::cast_possible_truncation)]
+    /// Check the consistency of the map's topology.
+    ///
+    /// This method verifies that:
+    /// - `β0` and `β1` are inverse of each other,
+    /// - `β2` and `β3` are involutions,
+    /// - 3-links reverse the orientation of linked faces, i.e. `β3∘β1` is an involution,
+    /// - used darts are only linked to used darts, and unused darts are free,
+    /// - used darts resolve to a valid volume identifier.
+    ///
+    /// This check reads the whole map; it is meant to be used in tests, or in debug builds to
+    /// validate the result of complex operations.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the list of all detected issues, in increasing order of darts. Refer
+    /// to [`ConsistencyError`]'s documentation for a description of each issue.
+    pub fn check_consistency(&self) -> Result<(), Vec<ConsistencyError>> {
+        let mut errors = Vec::new();
+        for d in 1..self.n_darts() as DartIdType {
+            let betas = [
+                self.beta::<0>(d),
+                self.beta::<1>(d),
+                self.beta::<2>(d),
+                self.beta::<3>(d),
+            ];
+            if self.unused_darts[d].read_atomic() {
+                if betas.iter().any(|b| *b != NULL_DART_ID) {
+                    errors.push(ConsistencyError::LinkedUnusedDart(d));
+                }
+                continue;
+            }
+            for (dim, b) in betas.iter().enumerate() {
+                if *b != NULL_DART_ID && self.unused_darts[*b].read_atomic() {
+                    errors.push(ConsistencyError::LinkToUnusedDart {
+                        dim: dim as u8,
+                        dart: d,
+                    });
+                }
+            }
+            let [b0, b1, b2, b3] = betas;
+            if (b1 != NULL_DART_ID && self.beta::<0>(b1) != d)
+                || (b0 != NULL_DART_ID && self.beta::<1>(b0) != d)
+            {
+                errors.push(ConsistencyError::NonInverseBeta01(d));
+            }
+            if b2 != NULL_DART_ID && self.beta::<2>(b2) != d {
+                errors.push(ConsistencyError::NonInvolutiveBeta { dim: 2, dart: d });
+            }
+            if b3 != NULL_DART_ID && self.beta::<3>(b3) != d {
+                errors.push(ConsistencyError::NonInvolutiveBeta { dim: 3, dart: d });
+            }
+            if b1 != NULL_DART_ID && b3 != NULL_DART_ID && self.beta::<3>(b1) != self.beta::<0>(b3)
+            {
+                errors.push(ConsistencyError::IncoherentBeta3(d));
+            }
+            if self.volume_id(d) == NULL_VOLUME_ID {
+                errors.push(ConsistencyError::UndefinedVolume(d));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    /// Check the integrity of the map.
+    ///
+    /// In addition to the topological checks of [`CMap3::check_consistency`], this method
+    /// verifies that:
+    /// - 3-linked faces have the same edges, i.e. a 2-free dart isn't 3-linked to a dart which
+    ///   isn't 2-free,
+    /// - every non-free dart belongs to a vertex with an associated value,
+    /// - vertex values are only stored at vertex identifiers,
+    /// - vertex, edge, face & volume identifiers are consistent, i.e. the identifier of a cell
+    ///   is the same when computed from any of its darts.
+    ///
+    /// All checks are run to completion, so that every issue can be reported at once. This is
+    /// useful to verify maps built by hand, where a single misplaced link is hard to spot.
+    ///
+    /// # Errors
+    ///
+    /// This method returns the list of all detected issues. Topological issues are listed first,
+    /// as returned by [`CMap3::check_consistency`], followed by other issues in increasing order
+    /// of darts. Refer to [`IntegrityError`]'s documentation for a description of each issue.
+    pub fn check_integrity(&self) -> Result<(), Vec<IntegrityError>> {
+        let mut errors: Vec<IntegrityError> = self
+            .check_consistency()
+            .err()
+            .into_iter()
+            .flatten()
+            .map(IntegrityError::from)
+            .collect();
+        for d in 1..self.n_darts() as DartIdType {
+            let vid = self.vertex_id(d);
+            if self.unused_darts[d].read_atomic() || vid != d {
+                if self.force_read_vertex(d).is_some() {
+                    errors.push(IntegrityError::MisplacedVertex(d));
+                }
+                if self.unused_darts[d].read_atomic() {
+                    continue;
+                }
+            } else if !self.is_free(d) && self.force_read_vertex(vid).is_none() {
+                errors.push(IntegrityError::UndefinedVertex(d));
+            }
+            let (b2, b3) = (self.beta::<2>(d), self.beta::<3>(d));
+            if b2 == NULL_DART_ID && b3 != NULL_DART_ID && self.beta::<2>(b3) != NULL_DART_ID {
+                errors.push(IntegrityError::OpenLinkedFace(d));
+            }
+            // cells must have the same identifier when computed from neighboring darts
+            let neighbors = [
+                (0, self.beta::<1>(b2)),
+                (0, self.beta::<1>(b3)),
+                (1, b2),
+                (1, b3),
+                (2, self.beta::<1>(d)),
+                (2, b3),
+                (3, self.beta::<1>(d)),
+                (3, b2),
+            ];
+            for (dim, e) in neighbors.into_iter().filter(|(_, e)| *e != NULL_DART_ID) {
+                let same_id = match dim {
+                    0 => vid == self.vertex_id(e),
+                    1 => self.edge_id(d) == self.edge_id(e),
+                    2 => self.face_id(d) == self.face_id(e),
+                    _ => self.volume_id(d) == self.volume_id(e),
+                };
+                if !same_id {
+                    errors.push(IntegrityError::InconsistentCellId { dim, dart: d });
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! Main error type
 
-use crate::stm::StmError;
+use std::cell::Cell;
+
+use crate::cmap::DartIdType;
+use crate::stm::{StmError, Transaction, TransactionControl};
 
 /// Convenience type alias
 pub type CMapResult<T> = Result<T, CMapError>;
@@ -23,4 +26,106 @@ pub enum CMapError {
     /// Accessed attribute isn't in the map storage.
     #[error("unknown attribute: {0}")]
     UnknownAttribute(&'static str),
+    /// Removed cell is incomplete, or contains darts that were already removed.
+    #[error("cell is inconsistent or partially removed: {0}")]
+    InconsistentCell(&'static str),
+}
+
+/// # Map consistency error enum.
+///
+/// Each variant describes an invalid configuration of the map's topology, at a given dart.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConsistencyError {
+    /// `β0` and `β1` are not inverse of each other.
+    #[error("beta0 & beta1 aren't inverse of each other at dart {0}")]
+    NonInverseBeta01(DartIdType),
+    /// A beta function that should be an involution isn't.
+    #[error("beta{dim} isn't an involution at dart {dart}")]
+    NonInvolutiveBeta {
+        /// Dimension of the beta function.
+        dim: u8,
+        /// Dart at which the check failed.
+        dart: DartIdType,
+    },
+    /// The 3-link of a dart doesn't reverse the orientation of its face, i.e. `β3∘β1` isn't an
+    /// involution.
+    #[error("beta3 is incoherent with the orientation of faces at dart {0}")]
+    IncoherentBeta3(DartIdType),
+    /// A used dart is linked to an unused dart.
+    #[error("dart {dart} is linked to an unused dart by beta{dim}")]
+    LinkToUnusedDart {
+        /// Dimension of the beta function.
+        dim: u8,
+        /// Dart at which the check failed.
+        dart: DartIdType,
+    },
+    /// An unused dart still has beta images.
+    #[error("unused dart {0} isn't free")]
+    LinkedUnusedDart(DartIdType),
+    /// A dart is 2-linked to the next dart of its own face, folding the edge onto itself.
+    #[error("edge is folded onto itself at dart {0}")]
+    FoldedEdge(DartIdType),
+    /// A used dart does not resolve to a valid cell identifier.
+    #[error("dart {0} doesn't resolve to a valid volume")]
+    UndefinedVolume(DartIdType),
+}
+
+/// # Map integrity error enum.
+///
+/// Each variant describes an invalid configuration of the map, either of its topology or of the
+/// data associated to its cells, at a given dart.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityError {
+    /// The topology of the map is inconsistent.
+    #[error(transparent)]
+    Topology(#[from] ConsistencyError),
+    /// A non-free dart is the representative of a vertex with no associated value.
+    #[error("vertex {0} has no associated value")]
+    UndefinedVertex(DartIdType),
+    /// A vertex value is stored at an identifier which doesn't correspond to a vertex.
+    #[error("vertex value stored at dart {0}, which isn't a vertex identifier")]
+    MisplacedVertex(DartIdType),
+    /// A 2-free dart is 3-linked to a dart which isn't 2-free, i.e. 3-linked faces don't have
+    /// the same edges.
+    #[error("dart {0} is 2-free, but 3-linked to a dart which isn't")]
+    OpenLinkedFace(DartIdType),
+    /// A cell has different identifiers depending on the dart used to compute it.
+    #[error("inconsistent {dim}-cell identifier at dart {dart}")]
+    InconsistentCellId {
+        /// Dimension of the cell.
+        dim: u8,
+        /// Dart at which the check failed.
+        dart: DartIdType,
+    },
+}
+
+/// Run a fallible transaction, retrying it on conflicts and aborting it on other errors.
+///
+/// `CMapError::FailedTransaction` errors returned by the closure are handled by the transaction
+/// control, while any other error aborts the transaction, so that none of its writes are
+/// committed. The error is then returned to the caller.
+pub(crate) fn try_atomically<T, F>(f: F) -> CMapResult<T>
+where
+    F: Fn(&mut Transaction) -> CMapResult<T>,
+{
+    let error: Cell<Option<CMapError>> = Cell::new(None);
+    Transaction::with_control(
+        |err| match err {
+            StmError::Failure => TransactionControl::Abort,
+            StmError::Retry => TransactionControl::Retry,
+        },
+        |trans| match f(trans) {
+            Ok(val) => Ok(val),
+            Err(CMapError::FailedTransaction(err)) => Err(err),
+            Err(err) => {
+                error.set(Some(err));
+                Err(StmError::Failure)
+            }
+        },
+    )
+    .ok_or_else(|| {
+        error
+            .take()
+            .unwrap_or(CMapError::FailedTransaction(StmError::Failure))
+    })
 }
@@ -7,14 +7,22 @@ mod dim2; // FIXME:simplify docs
 mod dim3;
 mod error;
 
-pub use builder::{BuilderError, CMapBuilder, GridDescriptor};
+pub use builder::{BuilderError, CMapBuilder, CellShape, GridDescriptor, PhysicalGroup};
 pub use components::{
+    census::CellCounts,
     identifiers::{
-        DartIdType, EdgeIdType, FaceIdType, VertexIdType, VolumeIdType, NULL_DART_ID, NULL_EDGE_ID,
-        NULL_FACE_ID, NULL_VERTEX_ID, NULL_VOLUME_ID,
+        DartIdType, DartRange, EdgeIdType, FaceIdType, VertexIdType, VolumeIdType, NULL_DART_ID,
+        NULL_EDGE_ID, NULL_FACE_ID, NULL_VERTEX_ID, NULL_VOLUME_ID,
     },
-    orbits::OrbitPolicy,
+    orbits::{OrbitError, OrbitPolicy},
+};
+pub use dim2::{
+    audit::UnusedDartReport,
+    diff::MapDiff,
+    orbits::Orbit2,
+    structure::{CMap2, VertexMergePolicy},
 };
-pub use dim2::{orbits::Orbit2, structure::CMap2};
 pub use dim3::{orbits::Orbit3, structure::CMap3};
-pub use error::{CMapError, CMapResult};
+pub use error::{CMapError, CMapResult, ConsistencyError, IntegrityError};
+
+pub(crate) use error::try_atomically;
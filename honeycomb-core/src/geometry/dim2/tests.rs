@@ -108,6 +108,29 @@ mod vector {
             }
         };
     }
+    macro_rules! generate_angle_test {
+        ($id: ident, $t: ty) => {
+            #[test]
+            fn $id() {
+                use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+                let v = Vector2::<$t>(2.0, 0.0);
+                assert!(almost_equal(v.angle_between(&v).unwrap(), 0.0));
+                assert!(almost_equal(
+                    v.angle_between(&Vector2(0.0, -3.0)).unwrap(),
+                    FRAC_PI_2 as $t
+                ));
+                assert!(almost_equal(
+                    v.angle_between(&Vector2(1.0, 1.0)).unwrap(),
+                    FRAC_PI_4 as $t
+                ));
+                assert!(almost_equal(v.angle_between(&-v).unwrap(), PI as $t));
+                let origin: Vector2<$t> = Vector2::default();
+                assert_eq!(v.angle_between(&origin), Err(CoordsError::InvalidUnitDir));
+                assert_eq!(origin.normalize(), Err(CoordsError::InvalidUnitDir));
+                assert_eq!(v.normalize(), v.unit_dir());
+            }
+        };
+    }
     // generation
     generate_dot_prod_test!(dot_product_simple, f32);
     generate_dot_prod_test!(dot_product_double, f64);
@@ -117,11 +140,15 @@ mod vector {
 
     generate_normal_dir_test!(normal_dir_simple, f32);
     generate_normal_dir_test!(normal_dir_double, f64);
+
+    generate_angle_test!(angle_simple, f32);
+    generate_angle_test!(angle_double, f64);
 }
 
 // --- vertex
 
 mod vertex {
+    use crate::geometry::Orientation;
     use crate::prelude::{Vector2, Vertex2};
     // tests
     #[test]
@@ -172,6 +199,83 @@ mod vertex {
         }
     }
 
+    #[test]
+    fn midpoint_lerp() {
+        let v1: Vertex2<f64> = Vertex2(0.0, 1.0);
+        let v2 = Vertex2(4.0, 3.0);
+        assert_eq!(v1.midpoint(&v2), Vertex2::average(&v1, &v2));
+        assert_eq!(v1.lerp(&v2, 0.0), v1);
+        assert_eq!(v1.lerp(&v2, 1.0), v2);
+        assert_eq!(v1.lerp(&v2, 0.5), v1.midpoint(&v2));
+        assert_eq!(v1.lerp(&v2, 1.5), Vertex2(6.0, 4.0));
+    }
+
+    #[test]
+    fn centroid() {
+        assert_eq!(Vertex2::<f32>::centroid(&[]), None);
+        let v = Vertex2(1.0, 2.0);
+        assert_eq!(Vertex2::centroid(&[v]), Some(v));
+        let triangle: [Vertex2<f64>; 3] = [Vertex2(0.0, 0.0), Vertex2(3.0, 0.0), Vertex2(0.0, 3.0)];
+        assert_eq!(Vertex2::centroid(&triangle), Some(Vertex2(1.0, 1.0)));
+    }
+
+    #[test]
+    fn orient() {
+        let (a, b) = (Vertex2(0.0, 0.0), Vertex2(2.0, 1.0));
+        assert_eq!(
+            Vertex2::orient(&a, &b, &Vertex2(0.0, 1.0)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            Vertex2::orient(&b, &a, &Vertex2(0.0, 1.0)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            Vertex2::orient(&a, &b, &Vertex2(-4.0, -2.0)),
+            Orientation::Collinear
+        );
+        // the tolerance is relative to the size of the triangle
+        assert_eq!(
+            Vertex2::orient(&a, &Vertex2(2e-12, 1e-12), &Vertex2(0.0, 1e-12)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            Vertex2::orient(&a, &b, &Vertex2(4.0, 2.0 + 1e-16)),
+            Orientation::Collinear
+        );
+        // degenerate triangle
+        assert_eq!(Vertex2::orient(&a, &a, &b), Orientation::Collinear);
+    }
+
+    #[cfg(feature = "exact-predicates")]
+    #[test]
+    fn orient_exact() {
+        let (a, b) = (Vertex2(0.0, 0.0), Vertex2(2.0, 1.0));
+        assert_eq!(
+            Vertex2::orient_exact(&a, &b, &Vertex2(0.0, 1.0)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            Vertex2::orient_exact(&b, &a, &Vertex2(0.0, 1.0)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            Vertex2::orient_exact(&a, &b, &Vertex2(-4.0, -2.0)),
+            Orientation::Collinear
+        );
+        // rounded differences cancel out, but these aren't exactly aligned
+        let c: Vertex2<f64> = Vertex2(24.0, 24.0 + 2f64.powi(-48));
+        let (a, b) = (Vertex2(0.5, 0.5), Vertex2(12.0, 12.0));
+        assert_eq!(
+            Vertex2::orient_exact(&a, &b, &c),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(Vertex2::orient_exact(&b, &a, &c), Orientation::Clockwise);
+        // these are, in the sense of the float values
+        let (a, b, c) = (Vertex2(0.1, 0.1), Vertex2(0.2, 0.2), Vertex2(0.3, 0.3));
+        assert_eq!(Vertex2::orient_exact(&a, &b, &c), Orientation::Collinear);
+    }
+
     #[test]
     fn sub_vertex_vertex() {
         {
@@ -84,6 +84,33 @@ impl<T: CoordsFloat> Vector2<T> {
         }
     }
 
+    /// Compute the direction of `self` as a unit vector.
+    ///
+    /// This is equivalent to [`Vector2::unit_dir`].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if called on a null `Vector2`.
+    pub fn normalize(&self) -> Result<Self, CoordsError> {
+        self.unit_dir()
+    }
+
+    /// Compute the angle between `self` and `other`, in radians.
+    ///
+    /// The returned value is in the `[0; π]` range, i.e. the orientation of the pair of vectors
+    /// is not taken into account.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if `self` or `other` is a null `Vector2`.
+    pub fn angle_between(&self, other: &Vector2<T>) -> Result<T, CoordsError> {
+        if self.norm().is_zero() || other.norm().is_zero() {
+            return Err(CoordsError::InvalidUnitDir);
+        }
+        let cross = self.0 * other.1 - self.1 * other.0;
+        Ok(cross.abs().atan2(self.dot(other)))
+    }
+
     /// Compute the direction of the normal vector to `self` as a unit vector.
     ///
     /// # Errors
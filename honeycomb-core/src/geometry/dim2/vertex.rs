@@ -2,8 +2,9 @@
 //!
 //! This module contains all code used to model vertices.
 
+use crate::attributes::AttrSparseVec;
+use crate::geometry::{CoordsFloat, Orientation};
 use crate::prelude::{AttributeBind, AttributeUpdate, OrbitPolicy, Vector2, VertexIdType};
-use crate::{attributes::AttrSparseVec, geometry::CoordsFloat};
 
 /// # 2D vertex structure
 ///
@@ -91,6 +92,220 @@ impl<T: CoordsFloat> Vertex2<T> {
         let two = T::from(2.0).unwrap();
         Vertex2((lhs.0 + rhs.0) / two, (lhs.1 + rhs.1) / two)
     }
+
+    /// Compute the mid-point between `self` and another vertex.
+    ///
+    /// This is equivalent to [`Vertex2::average`].
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if it cannot initialize an object `T: CoordsFloat` from the value
+    /// `2.0`. The chance of this happening when using `T = f64` or `T = f32` is most likely zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use honeycomb_core::prelude::Vertex2;
+    ///
+    /// let a: Vertex2<f64> = Vertex2(0.0, 1.0);
+    /// let b: Vertex2<f64> = Vertex2(2.0, 3.0);
+    ///
+    /// assert_eq!(a.midpoint(&b), Vertex2(1.0, 2.0));
+    /// ```
+    #[must_use = "unused return value"]
+    pub fn midpoint(&self, other: &Vertex2<T>) -> Vertex2<T> {
+        Self::average(self, other)
+    }
+
+    /// Linearly interpolate between `self` and another vertex.
+    ///
+    /// The result is `self` for `t == 0` and `other` for `t == 1`; values outside of `[0; 1]`
+    /// extrapolate along the line going through both vertices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use honeycomb_core::prelude::Vertex2;
+    ///
+    /// let a: Vertex2<f64> = Vertex2(0.0, 0.0);
+    /// let b: Vertex2<f64> = Vertex2(4.0, 2.0);
+    ///
+    /// assert_eq!(a.lerp(&b, 0.25), Vertex2(1.0, 0.5));
+    /// ```
+    #[must_use = "unused return value"]
+    pub fn lerp(&self, other: &Vertex2<T>, t: T) -> Vertex2<T> {
+        *self + (*other - *self) * t
+    }
+
+    /// Compute the centroid of a set of vertices, i.e. the average of their coordinates.
+    ///
+    /// # Return
+    ///
+    /// Return `None` if the slice is empty.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the number of vertices cannot be converted to `T`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use honeycomb_core::prelude::Vertex2;
+    ///
+    /// let square: [Vertex2<f64>; 4] = [
+    ///     Vertex2(0.0, 0.0),
+    ///     Vertex2(2.0, 0.0),
+    ///     Vertex2(2.0, 2.0),
+    ///     Vertex2(0.0, 2.0),
+    /// ];
+    ///
+    /// assert_eq!(Vertex2::centroid(&square), Some(Vertex2(1.0, 1.0)));
+    /// assert_eq!(Vertex2::<f64>::centroid(&[]), None);
+    /// ```
+    pub fn centroid(verts: &[Vertex2<T>]) -> Option<Vertex2<T>> {
+        if verts.is_empty() {
+            return None;
+        }
+        let n = T::from(verts.len()).unwrap();
+        let (sx, sy) = verts
+            .iter()
+            .fold((T::zero(), T::zero()), |(sx, sy), v| (sx + v.0, sy + v.1));
+        Some(Vertex2(sx / n, sy / n))
+    }
+
+    /// Compute the circumcenter of the triangle `(a, b, c)`.
+    ///
+    /// # Return
+    ///
+    /// Return `None` if the triangle is degenerate, i.e. if its vertices are (nearly) collinear.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use honeycomb_core::prelude::Vertex2;
+    ///
+    /// let a: Vertex2<f64> = Vertex2(0.0, 0.0);
+    /// let b: Vertex2<f64> = Vertex2(2.0, 0.0);
+    /// let c: Vertex2<f64> = Vertex2(0.0, 2.0);
+    ///
+    /// assert_eq!(Vertex2::circumcenter(&a, &b, &c), Some(Vertex2(1.0, 1.0)));
+    /// assert_eq!(Vertex2::circumcenter(&a, &b, &Vertex2(1.0, 0.0)), None);
+    /// ```
+    pub fn circumcenter(a: &Vertex2<T>, b: &Vertex2<T>, c: &Vertex2<T>) -> Option<Vertex2<T>> {
+        let (ab, ac) = (*b - *a, *c - *a);
+        let det = (ab.x() * ac.y() - ab.y() * ac.x()) * (T::one() + T::one());
+        if det.abs() < T::epsilon() {
+            return None;
+        }
+        let (ab2, ac2) = (ab.dot(&ab), ac.dot(&ac));
+        Some(
+            *a + Vector2(
+                (ac.y() * ab2 - ab.y() * ac2) / det,
+                (ab.x() * ac2 - ac.x() * ab2) / det,
+            ),
+        )
+    }
+
+    /// Compute the orientation of the triplet of vertices `(a, b, c)`.
+    ///
+    /// The classification uses the sign of the cross product `ab × ac`, i.e. twice the signed
+    /// area of the triangle. The triplet is considered collinear if
+    /// `|ab × ac| <= ε * |ab| * |ac|`, with `ε = T::epsilon()`. Since the tolerance is relative
+    /// to the length of the edges, the result doesn't depend on the scale of the coordinates.
+    ///
+    /// Nearly degenerate triplets may be misclassified because of rounding errors; see
+    /// `Vertex2::orient_exact` (requires the `exact-predicates` feature) for a robust variant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use honeycomb_core::geometry::Orientation;
+    /// use honeycomb_core::prelude::Vertex2;
+    ///
+    /// let a: Vertex2<f64> = Vertex2(0.0, 0.0);
+    /// let b: Vertex2<f64> = Vertex2(1.0, 0.0);
+    ///
+    /// assert_eq!(Vertex2::orient(&a, &b, &Vertex2(0.0, 1.0)), Orientation::CounterClockwise);
+    /// assert_eq!(Vertex2::orient(&a, &b, &Vertex2(0.0, -1.0)), Orientation::Clockwise);
+    /// assert_eq!(Vertex2::orient(&a, &b, &Vertex2(2.0, 0.0)), Orientation::Collinear);
+    /// ```
+    pub fn orient(a: &Vertex2<T>, b: &Vertex2<T>, c: &Vertex2<T>) -> Orientation {
+        let (ab, ac) = (*b - *a, *c - *a);
+        let det = ab.x() * ac.y() - ab.y() * ac.x();
+        if det.abs() <= T::epsilon() * ab.norm() * ac.norm() {
+            Orientation::Collinear
+        } else if det > T::zero() {
+            Orientation::CounterClockwise
+        } else {
+            Orientation::Clockwise
+        }
+    }
+
+    #[cfg(feature = "exact-predicates")]
+    /// Compute the orientation of the triplet of vertices `(a, b, c)` using exact arithmetic.
+    ///
+    /// Unlike [`Vertex2::orient`], no tolerance is used: the sign of the cross product is computed
+    /// exactly, using error-free transformations of floating-point sums & products. The triplet
+    /// is only considered collinear if the vertices are exactly aligned. This is slower, but
+    /// yields consistent results for nearly degenerate inputs, as required by e.g. Delaunay
+    /// algorithms.
+    ///
+    /// The result is exact as long as no intermediate product overflows or underflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use honeycomb_core::geometry::Orientation;
+    /// use honeycomb_core::prelude::Vertex2;
+    ///
+    /// let a: Vertex2<f64> = Vertex2(0.5, 0.5);
+    /// let b: Vertex2<f64> = Vertex2(12.0, 12.0);
+    /// // 24 + 2^-48 is the float following 24
+    /// let c: Vertex2<f64> = Vertex2(24.0, 24.0 + 2f64.powi(-48));
+    ///
+    /// assert_eq!(Vertex2::orient(&a, &b, &c), Orientation::Collinear);
+    /// assert_eq!(Vertex2::orient_exact(&a, &b, &c), Orientation::CounterClockwise);
+    /// ```
+    pub fn orient_exact(a: &Vertex2<T>, b: &Vertex2<T>, c: &Vertex2<T>) -> Orientation {
+        // expanded cross product; each product is split into its rounded value & its error
+        let products = [
+            (b.0, c.1),
+            (-b.0, a.1),
+            (-a.0, c.1),
+            (-b.1, c.0),
+            (b.1, a.0),
+            (a.1, c.0),
+        ];
+        // sum everything into a non-overlapping expansion, sorted by increasing magnitude
+        let mut expansion: Vec<T> = Vec::with_capacity(12);
+        for (lhs, rhs) in products {
+            let product = lhs * rhs;
+            for term in [lhs.mul_add(rhs, -product), product] {
+                let mut acc = term;
+                for component in &mut expansion {
+                    let (sum, err) = two_sum(acc, *component);
+                    *component = err;
+                    acc = sum;
+                }
+                expansion.push(acc);
+            }
+        }
+        // the sign of the expansion is the sign of its largest non-zero component
+        match expansion.iter().rev().find(|v| !v.is_zero()) {
+            Some(v) if *v > T::zero() => Orientation::CounterClockwise,
+            Some(_) => Orientation::Clockwise,
+            None => Orientation::Collinear,
+        }
+    }
+}
+
+#[cfg(feature = "exact-predicates")]
+/// Compute `a + b` and the rounding error of the operation, so that `a + b == sum + err` exactly.
+fn two_sum<T: CoordsFloat>(a: T, b: T) -> (T, T) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
 }
 
 // Building trait
@@ -119,6 +119,30 @@ mod vector {
         };
     }
 
+    macro_rules! generate_angle_test {
+        ($id:ident, $t:ty) => {
+            #[test]
+            fn $id() {
+                use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+                let v = Vector3::<$t>(2.0, 0.0, 0.0);
+                assert!(almost_equal(v.angle_between(&v).unwrap(), 0.0));
+                assert!(almost_equal(
+                    v.angle_between(&Vector3(0.0, 0.0, 3.0)).unwrap(),
+                    FRAC_PI_2 as $t
+                ));
+                assert!(almost_equal(
+                    v.angle_between(&Vector3(1.0, 1.0, 0.0)).unwrap(),
+                    FRAC_PI_4 as $t
+                ));
+                assert!(almost_equal(v.angle_between(&-v).unwrap(), PI as $t));
+                let origin: Vector3<$t> = Vector3::default();
+                assert_eq!(v.angle_between(&origin), Err(CoordsError::InvalidUnitDir));
+                assert_eq!(origin.normalize(), Err(CoordsError::InvalidUnitDir));
+                assert_eq!(v.normalize(), v.unit_dir());
+            }
+        };
+    }
+
     // generation
     generate_dot_prod_test!(dot_product_simple, f32);
     generate_dot_prod_test!(dot_product_double, f64);
@@ -126,6 +150,8 @@ mod vector {
     generate_unit_dir_test!(unit_dir_double, f64);
     generate_cross_product_test!(cross_product_simple, f32);
     generate_cross_product_test!(cross_product_double, f64);
+    generate_angle_test!(angle_simple, f32);
+    generate_angle_test!(angle_double, f64);
 
     #[test]
     fn test_into_inner() {
@@ -184,6 +210,28 @@ mod vertex {
         assert_eq!(avg, Vertex3(1.0_f32, 2.0_f32, 3.0_f32));
     }
 
+    #[test]
+    fn test_midpoint_lerp() {
+        let v1: Vertex3<f64> = Vertex3(0.0, 1.0, -2.0);
+        let v2 = Vertex3(4.0, 3.0, 2.0);
+        assert_eq!(v1.midpoint(&v2), Vertex3::average(&v1, &v2));
+        assert_eq!(v1.lerp(&v2, 0.0), v1);
+        assert_eq!(v1.lerp(&v2, 1.0), v2);
+        assert_eq!(v1.lerp(&v2, 0.5), v1.midpoint(&v2));
+        assert_eq!(v1.lerp(&v2, -0.5), Vertex3(-2.0, 0.0, -4.0));
+    }
+
+    #[test]
+    fn test_centroid() {
+        assert_eq!(Vertex3::<f64>::centroid(&[]), None);
+        let v = Vertex3(1.0, 2.0, 3.0);
+        assert_eq!(Vertex3::centroid(&[v]), Some(v));
+        let cube: Vec<Vertex3<f64>> = (0..8)
+            .map(|i| Vertex3(f64::from(i & 1), f64::from((i >> 1) & 1), f64::from(i >> 2)))
+            .collect();
+        assert_eq!(Vertex3::centroid(&cube), Some(Vertex3(0.5, 0.5, 0.5)));
+    }
+
     // tests
     #[test]
     fn add_vertex_vector() {
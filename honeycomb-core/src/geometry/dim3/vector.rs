@@ -97,7 +97,33 @@ impl<T: CoordsFloat> Vector3<T> {
             Ok(*self / norm)
         }
     }
-    ///Return the dot product between `self` and `other`.
+
+    /// Compute the direction of `self` as a unit vector.
+    ///
+    /// This is equivalent to [`Vector3::unit_dir`].
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if called on a null `Vector3`.
+    pub fn normalize(&self) -> Result<Self, CoordsError> {
+        self.unit_dir()
+    }
+
+    /// Compute the angle between `self` and `other`, in radians.
+    ///
+    /// The returned value is in the `[0; π]` range.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if `self` or `other` is a null `Vector3`.
+    pub fn angle_between(&self, other: &Vector3<T>) -> Result<T, CoordsError> {
+        if self.norm().is_zero() || other.norm().is_zero() {
+            return Err(CoordsError::InvalidUnitDir);
+        }
+        Ok(self.cross(other).norm().atan2(self.dot(other)))
+    }
+
+    /// Return the dot product between `self` and `other`.
     pub fn dot(&self, other: &Vector3<T>) -> T {
         self.0 * other.0 + self.1 * other.1 + self.2 * other.2
     }
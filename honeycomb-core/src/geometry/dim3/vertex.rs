@@ -103,6 +103,88 @@ impl<T: CoordsFloat> Vertex3<T> {
             (lhs.2 + rhs.2) / two,
         )
     }
+
+    /// Compute the mid-point between `self` and another vertex.
+    ///
+    /// This is equivalent to [`Vertex3::average`].
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if it cannot initialize an object `T: CoordsFloat` from the value
+    /// `2.0`. The chance of this happening when using `T = f64` or `T = f32` is most likely zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use honeycomb_core::geometry::Vertex3;
+    ///
+    /// let a: Vertex3<f64> = Vertex3(0.0, 1.0, 2.0);
+    /// let b: Vertex3<f64> = Vertex3(2.0, 3.0, 4.0);
+    ///
+    /// assert_eq!(a.midpoint(&b), Vertex3(1.0, 2.0, 3.0));
+    /// ```
+    #[must_use = "unused return value"]
+    pub fn midpoint(&self, other: &Vertex3<T>) -> Vertex3<T> {
+        Self::average(self, other)
+    }
+
+    /// Linearly interpolate between `self` and another vertex.
+    ///
+    /// The result is `self` for `t == 0` and `other` for `t == 1`; values outside of `[0; 1]`
+    /// extrapolate along the line going through both vertices.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use honeycomb_core::geometry::Vertex3;
+    ///
+    /// let a: Vertex3<f64> = Vertex3(0.0, 0.0, 0.0);
+    /// let b: Vertex3<f64> = Vertex3(4.0, 2.0, -4.0);
+    ///
+    /// assert_eq!(a.lerp(&b, 0.25), Vertex3(1.0, 0.5, -1.0));
+    /// ```
+    #[must_use = "unused return value"]
+    pub fn lerp(&self, other: &Vertex3<T>, t: T) -> Vertex3<T> {
+        *self + (*other - *self) * t
+    }
+
+    /// Compute the centroid of a set of vertices, i.e. the average of their coordinates.
+    ///
+    /// # Return
+    ///
+    /// Return `None` if the slice is empty.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the number of vertices cannot be converted to `T`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use honeycomb_core::geometry::Vertex3;
+    ///
+    /// let tet: [Vertex3<f64>; 4] = [
+    ///     Vertex3(0.0, 0.0, 0.0),
+    ///     Vertex3(4.0, 0.0, 0.0),
+    ///     Vertex3(0.0, 4.0, 0.0),
+    ///     Vertex3(0.0, 0.0, 4.0),
+    /// ];
+    ///
+    /// assert_eq!(Vertex3::centroid(&tet), Some(Vertex3(1.0, 1.0, 1.0)));
+    /// assert_eq!(Vertex3::<f64>::centroid(&[]), None);
+    /// ```
+    pub fn centroid(verts: &[Vertex3<T>]) -> Option<Vertex3<T>> {
+        if verts.is_empty() {
+            return None;
+        }
+        let n = T::from(verts.len()).unwrap();
+        let (sx, sy, sz) = verts
+            .iter()
+            .fold((T::zero(), T::zero(), T::zero()), |(sx, sy, sz), v| {
+                (sx + v.0, sy + v.1, sz + v.2)
+            });
+        Some(Vertex3(sx / n, sy / n, sz / n))
+    }
 }
 
 // Building trait
@@ -18,7 +18,8 @@ pub use dim3::{vector::Vector3, vertex::Vertex3};
 /// # Coordinates-level error enum
 #[derive(Error, Debug, PartialEq)]
 pub enum CoordsError {
-    /// Error returned when trying to compute the unit vector of a null [`Vector2`].
+    /// Error returned when trying to compute the unit vector of a null vector, or an angle
+    /// involving a null vector.
     #[error("cannot compute unit direction of a null vector")]
     InvalidUnitDir,
     /// Error returned when trying to compute the normal to a null [`Vector2`].
@@ -26,6 +27,20 @@ pub enum CoordsError {
     InvalidNormDir,
 }
 
+/// # Orientation of a triplet of vertices
+///
+/// This is the result of [`Vertex2::orient`], which classifies the turn made when going from a
+/// vertex `a` to `b`, then `c`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// `c` is on the left side of `(a, b)`, i.e. `(a, b, c)` is a counterclockwise triangle.
+    CounterClockwise,
+    /// `c` is on the right side of `(a, b)`, i.e. `(a, b, c)` is a clockwise triangle.
+    Clockwise,
+    /// `a`, `b` & `c` are (nearly) aligned.
+    Collinear,
+}
+
 /// # Generic FP type trait
 ///
 /// This trait is used for vertex & vector values. The static lifetime is a requirements induced
@@ -29,8 +29,7 @@ pub mod cmap;
 
 pub mod geometry;
 
-// re-export since we use their items in the API
-pub use fast_stm as stm;
+pub mod stm;
 
 /// commonly used items
 pub mod prelude;
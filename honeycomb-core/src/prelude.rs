@@ -2,8 +2,8 @@
 
 pub use crate::attributes::{AttributeBind, AttributeUpdate};
 pub use crate::cmap::{
-    BuilderError, CMap2, CMap3, CMapBuilder, CMapResult, DartIdType, EdgeIdType, FaceIdType,
-    GridDescriptor, Orbit2, OrbitPolicy, VertexIdType, VolumeIdType, NULL_DART_ID, NULL_EDGE_ID,
-    NULL_FACE_ID, NULL_VERTEX_ID, NULL_VOLUME_ID,
+    BuilderError, CMap2, CMap3, CMapBuilder, CMapResult, CellShape, DartIdType, EdgeIdType,
+    FaceIdType, GridDescriptor, Orbit2, OrbitPolicy, VertexIdType, VolumeIdType, NULL_DART_ID,
+    NULL_EDGE_ID, NULL_FACE_ID, NULL_VERTEX_ID, NULL_VOLUME_ID,
 };
 pub use crate::geometry::{CoordsError, CoordsFloat, Vector2, Vertex2};
@@ -0,0 +1,76 @@
+//! STM utilities
+//!
+//! This module re-exports the content of the `fast-stm` crate, since we use its items in the API,
+//! along with helpers built on top of it.
+
+// ------ RE-EXPORTS
+
+pub use fast_stm::*;
+
+// ------ IMPORTS
+
+use std::cell::Cell;
+
+// ------ CONTENT
+
+/// Run a transaction until it is validated, and count the number of retries.
+///
+/// This is equivalent to [`atomically`], but additionally returns the number of times the
+/// closure was re-executed before the transaction was committed, whatever the cause (an error
+/// returned by the closure, or a conflict detected at commit time). This can be used to
+/// aggregate contention metrics, e.g. a histogram of retries over a parallel loop.
+///
+/// # Arguments
+///
+/// - `f: F` -- Transaction closure, as passed to [`atomically`].
+///
+/// # Return
+///
+/// Return the value produced by the validated transaction, along with the number of retries.
+/// A transaction validated on its first execution yields `0`.
+///
+/// # Example
+///
+/// ```
+/// use honeycomb_core::stm::{atomically_with_stats, TVar};
+///
+/// let var = TVar::new(0);
+/// let (old, n_retry) = atomically_with_stats(|trans| var.replace(trans, 1));
+/// assert_eq!(old, 0);
+/// assert_eq!(n_retry, 0);
+/// ```
+pub fn atomically_with_stats<T, F>(f: F) -> (T, usize)
+where
+    F: Fn(&mut Transaction) -> StmClosureResult<T>,
+{
+    let n_runs = Cell::new(0_usize);
+    let res = atomically(|trans| {
+        n_runs.set(n_runs.get() + 1);
+        f(trans)
+    });
+    (res, n_runs.get() - 1)
+}
+
+// ------ TESTS
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_count() {
+        let var = TVar::new(0);
+        let n_fail = Cell::new(3);
+        let (res, n_retry) = atomically_with_stats(|trans| {
+            var.write(trans, 1)?;
+            if n_fail.get() > 0 {
+                n_fail.set(n_fail.get() - 1);
+                return Err(StmError::Failure);
+            }
+            var.read(trans)
+        });
+        assert_eq!(res, 1);
+        assert_eq!(n_retry, 3);
+        assert_eq!(var.read_atomic(), 1);
+    }
+}
@@ -0,0 +1,148 @@
+//! 2D dual construction functions
+
+// ------ IMPORTS
+
+use crate::dual::DualBoundary;
+use honeycomb_core::cmap::{CMap2, CMapBuilder, DartIdType, Orbit2, OrbitPolicy, NULL_DART_ID};
+use honeycomb_core::geometry::{CoordsFloat, Vertex2};
+
+// ------ CONTENT
+
+#[allow(clippy::cast_possible_truncation, clippy::missing_panics_doc)]
+/// Build the dual of a 2D map.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// The dual map contains a dart for each dart of the original map. The dart `d'` associated to
+/// `d` crosses the edge of `d`, going from the face on its right to the face on its left, i.e.
+/// from the face of `β2(d)` to the face of `d`. Faces of the dual are made of darts
+/// corresponding to darts starting from the same vertex, and are oriented consistently with the
+/// original map. Dual vertices are placed at the barycenter of their original face.
+///
+/// The boundary of the original map is handled according to the `boundary` argument:
+/// - using [`DualBoundary::Drop`], the dual only contains faces associated to interior vertices
+/// - using [`DualBoundary::PointAtInfinity`], each boundary loop of the original map is closed
+///   using a virtual face; the associated dual vertex has no value. Additional darts are created
+///   for the boundary edges.
+///
+/// Dart IDs of the dual map are assigned following the order of original darts, virtual darts
+/// coming last. For a map without boundary, they are identical to original IDs. Applying the
+/// construction twice to such a map yields a map isomorphic to the original one, `d` being
+/// associated to `β2(d)`.
+///
+/// Faces of the original map are expected to be closed; unused & free darts are ignored.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the original map.
+/// - `boundary: DualBoundary` -- Treatment of the boundary.
+///
+/// # Return
+///
+/// Return the dual map.
+#[must_use = "unused return value"]
+pub fn dual_2d<T: CoordsFloat>(map: &CMap2<T>, boundary: DualBoundary) -> CMap2<T> {
+    let n_darts = map.n_darts();
+
+    // close the map by adding a virtual dart for each boundary dart
+    let mut b0: Vec<DartIdType> = (0..n_darts as DartIdType)
+        .map(|d| map.beta::<0>(d))
+        .collect();
+    let mut b1: Vec<DartIdType> = (0..n_darts as DartIdType)
+        .map(|d| map.beta::<1>(d))
+        .collect();
+    let mut b2: Vec<DartIdType> = (0..n_darts as DartIdType)
+        .map(|d| map.beta::<2>(d))
+        .collect();
+    let used = |d: DartIdType| d != NULL_DART_ID && !map.is_free(d);
+    let boundary_darts: Vec<DartIdType> = (1..n_darts as DartIdType)
+        .filter(|d| used(*d) && map.is_i_free::<2>(*d))
+        .collect();
+    for (i, d) in boundary_darts.iter().enumerate() {
+        let virt = (n_darts + i) as DartIdType;
+        b2[*d as usize] = virt;
+        b2.push(*d);
+    }
+    b0.resize(b2.len(), NULL_DART_ID);
+    b1.resize(b2.len(), NULL_DART_ID);
+    for (i, d) in boundary_darts.iter().enumerate() {
+        // the virtual face goes along the boundary loop backward; the next virtual dart is the
+        // one of the boundary dart ending at the source of `d`
+        let mut prev = map.beta::<0>(*d);
+        while !map.is_i_free::<2>(prev) {
+            prev = map.beta::<0>(map.beta::<2>(prev));
+        }
+        let virt = n_darts + i;
+        b1[virt] = b2[prev as usize];
+        b0[b2[prev as usize] as usize] = virt as DartIdType;
+    }
+    let is_virtual = |d: DartIdType| d as usize >= n_darts;
+
+    // select darts of the dual, and assign their new IDs
+    let rotate = |d: DartIdType| b2[b0[d as usize] as usize];
+    let mut new_ids = vec![NULL_DART_ID; b2.len()];
+    let mut n_new = 0;
+    for d in (1..b2.len() as DartIdType).filter(|d| is_virtual(*d) || used(*d)) {
+        let keep = match boundary {
+            DualBoundary::PointAtInfinity => true,
+            // the vertex of `d` is interior iff none of its darts are virtual
+            DualBoundary::Drop => {
+                let mut curr = d;
+                loop {
+                    if curr == NULL_DART_ID || is_virtual(curr) {
+                        break false;
+                    }
+                    curr = rotate(curr);
+                    if curr == d {
+                        break true;
+                    }
+                }
+            }
+        };
+        if keep {
+            n_new += 1;
+            new_ids[d as usize] = n_new;
+        }
+    }
+
+    // build the dual topology
+    let dual: CMap2<T> = CMapBuilder::default()
+        .n_darts(n_new as usize)
+        .build()
+        .expect("E: unreachable");
+    for d in 1..b2.len() as DartIdType {
+        let new_d = new_ids[d as usize];
+        if new_d == NULL_DART_ID {
+            continue;
+        }
+        // faces turn counterclockwise around the original vertex
+        dual.force_link::<1>(new_d, new_ids[rotate(d) as usize]);
+        let new_e = new_ids[b2[d as usize] as usize];
+        if new_e != NULL_DART_ID && new_d < new_e {
+            dual.force_link::<2>(new_d, new_e);
+        }
+    }
+
+    // place dual vertices at the barycenter of original faces
+    for d in 1..b2.len() as DartIdType {
+        let new_d = new_ids[d as usize];
+        let opposite = b2[d as usize];
+        if new_d == NULL_DART_ID || is_virtual(opposite) {
+            continue;
+        }
+        let vid = dual.vertex_id(new_d);
+        if dual.force_read_vertex(vid).is_some() {
+            continue;
+        }
+        let vertices: Vec<Vertex2<T>> = Orbit2::new(map, OrbitPolicy::Face, opposite)
+            .filter_map(|dart| map.force_read_vertex(map.vertex_id(dart)))
+            .collect();
+        if let Some(centroid) = Vertex2::centroid(&vertices) {
+            dual.force_write_vertex(vid, centroid);
+        }
+    }
+
+    dual
+}
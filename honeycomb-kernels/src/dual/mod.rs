@@ -0,0 +1,32 @@
+//! Dual map construction
+//!
+//! This module contains implementations of dual constructions. The dual of a map has a vertex for
+//! each face of the original map, a face for each vertex, and an edge crossing each original
+//! edge. We currently define:
+//! - 2D dual -- each dual vertex is placed at the barycenter of its original face
+
+// ------ MODULE DECLARATIONS
+
+mod dual_2d;
+
+// ------ PUBLIC RE-EXPORTS
+
+pub use dual_2d::dual_2d;
+
+// ------ CONTENT
+
+/// Treatment of the boundary when building a dual map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DualBoundary {
+    /// Vertices on the boundary have no dual face; edges adjacent to a single face have no dual
+    /// edge.
+    Drop,
+    /// Each boundary loop is closed by a virtual face, resulting in a dual vertex without an
+    /// associated value. Dual faces of boundary vertices form a fan around these points.
+    PointAtInfinity,
+}
+
+// ------ TESTS
+
+#[cfg(test)]
+mod tests;
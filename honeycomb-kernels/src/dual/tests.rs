@@ -0,0 +1,87 @@
+use super::*;
+use honeycomb_core::cmap::{CMap2, CMapBuilder, DartIdType, NULL_DART_ID};
+use honeycomb_core::geometry::Vertex2;
+
+/// Return the surface of a tetrahedron, i.e. a closed map made of four triangles.
+fn tetrahedron() -> CMap2<f64> {
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(12).build().unwrap();
+    for start in [1, 4, 7, 10] {
+        map.force_link::<1>(start, start + 1);
+        map.force_link::<1>(start + 1, start + 2);
+        map.force_link::<1>(start + 2, start);
+    }
+    for (d1, d2) in [(1, 4), (3, 7), (5, 9), (2, 10), (6, 11), (8, 12)] {
+        map.force_link::<2>(d1, d2);
+    }
+    // A, B, C, D
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (3.0, 0.0));
+    map.force_write_vertex(3, (0.0, 3.0));
+    map.force_write_vertex(6, (1.0, 1.0));
+    map
+}
+
+#[test]
+fn dual_closed() {
+    let map = tetrahedron();
+    let dual = dual_2d(&map, DualBoundary::Drop);
+    assert_eq!(dual.n_darts(), 13);
+    assert_eq!(dual.iter_vertices().count(), 4);
+    assert_eq!(dual.iter_edges().count(), 6);
+    assert_eq!(dual.iter_faces().count(), 4);
+    // the dual vertex of face (A, B, C) is shared by the darts crossing its edges
+    let vid = dual.vertex_id(map.beta::<2>(1));
+    assert_eq!(dual.force_read_vertex(vid), Some(Vertex2(1.0, 1.0)));
+
+    // the dual of the dual is the original map, up to the β2 relabeling
+    let ddual = dual_2d(&dual, DualBoundary::PointAtInfinity);
+    assert_eq!(ddual.n_darts(), 13);
+    for d in 1..13 as DartIdType {
+        assert_eq!(ddual.beta::<2>(d), map.beta::<2>(d));
+        assert_eq!(
+            ddual.beta::<1>(map.beta::<2>(d)),
+            map.beta::<2>(map.beta::<1>(d))
+        );
+    }
+    assert_eq!(ddual.iter_vertices().count(), 4);
+    assert_eq!(ddual.iter_faces().count(), 4);
+}
+
+#[test]
+fn dual_boundary_drop() {
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    let dual = dual_2d(&map, DualBoundary::Drop);
+    // the only interior vertex, (1, 1), results in an hexagon
+    assert_eq!(dual.n_darts(), 7);
+    assert_eq!(dual.iter_faces().count(), 1);
+    assert_eq!(dual.iter_vertices().count(), 6);
+    assert!((1..7).all(|d| dual.beta::<2>(d) == NULL_DART_ID));
+    let area = crate::quality::signed_face_area(&dual, 1);
+    assert!(area > 0.0);
+    // vertices are placed at triangle barycenters
+    assert!(dual.iter_vertices().all(|vid| {
+        let v = dual.force_read_vertex(vid).unwrap();
+        let (x, y) = (v.x() * 3.0, v.y() * 3.0);
+        (x - x.round()).abs() < 1e-10 && (y - y.round()).abs() < 1e-10
+    }));
+}
+
+#[test]
+fn dual_boundary_infinity() {
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    let dual = dual_2d(&map, DualBoundary::PointAtInfinity);
+    // 24 darts + 8 virtual ones
+    assert_eq!(dual.n_darts(), 33);
+    assert_eq!(dual.iter_faces().count(), 9);
+    assert_eq!(dual.iter_edges().count(), 16);
+    assert_eq!(dual.iter_vertices().count(), 9);
+    // the dual is closed
+    assert!((1..33).all(|d| dual.beta::<2>(d) != NULL_DART_ID));
+    // a single vertex models the point at infinity
+    assert_eq!(
+        dual.iter_vertices()
+            .filter(|vid| dual.force_read_vertex(*vid).is_none())
+            .count(),
+        1
+    );
+}
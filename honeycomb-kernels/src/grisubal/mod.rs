@@ -27,6 +27,9 @@
 //! 2. Remove "redundant" Points of Interest to avoid duplicated vertices.
 //! 3. Check for obvious orientation issues (open geometry & orientation per boundary).
 //!
+//! Distances used to detect overlaps during the first two steps can be set using
+//! [`GrisubalParams`].
+//!
 //! ### Main kernel
 //!
 //! 1. Compute intersection vertices between the geometry's segments and the grid.
@@ -48,7 +51,17 @@
 //!    across distinct boundaries.
 //! 3. Delete all darts making up the marked faces.
 //!
-//! The `Boundary` attribute is then removed from the map before return.
+//! When the `par-internals` feature is enabled, faces are colored in parallel, starting from all
+//! boundary darts at once, and marked faces are deleted concurrently. The result is identical to
+//! the sequential version.
+//!
+//! Using [`Clip::Region`], only the interior of a single boundary loop is kept. The coloring
+//! starts from darts of the selected loop located on the side of its interior, and only stops at
+//! this loop, i.e. other boundaries inside of it are crossed. All faces that weren't reached are
+//! deleted. This variant is always executed sequentially.
+//!
+//! The `Boundary` attribute is then removed from the map before return, unless the kernel is
+//! called with [`GrisubalParams::keep_boundary`] set.
 
 // ------ MODULE DECLARATIONS
 
@@ -56,22 +69,29 @@ pub(crate) mod model;
 pub(crate) mod routines;
 pub(crate) mod timers;
 
+// ------ PUBLIC RE-EXPORTS
+
+pub use model::{Boundary, Geometry2};
+
 // ------ IMPORTS
 
+#[cfg(not(feature = "par-internals"))]
+use crate::grisubal::routines::{clip_left, clip_right};
+#[cfg(feature = "par-internals")]
+use crate::grisubal::routines::{clip_left_par, clip_right_par};
 use crate::grisubal::{
-    model::{Boundary, Geometry2},
     routines::{
-        clip_left, clip_right, compute_intersection_ids, compute_overlapping_grid,
-        detect_orientation_issue, generate_edge_data, generate_intersection_data,
-        group_intersections_per_edge, insert_edges_in_map, insert_intersections,
-        remove_redundant_poi,
+        clip_region, compute_intersection_ids, compute_overlapping_grid, detect_orientation_issue,
+        generate_edge_data, generate_intersection_data, group_intersections_per_edge,
+        insert_edges_in_map, insert_intersections, remove_redundant_poi,
     },
     timers::{finish, start_timer, unsafe_time_section},
 };
 use honeycomb_core::{
     cmap::{CMapBuilder, GridDescriptor},
-    prelude::{CMap2, CoordsFloat},
+    prelude::{CMap2, CoordsFloat, EdgeIdType, Vertex2},
 };
+use std::collections::HashMap;
 use thiserror::Error;
 use vtkio::Vtk;
 
@@ -90,6 +110,15 @@ pub enum Clip {
     /// Keep all elements. Default value.
     #[default]
     None,
+    /// Keep elements enclosed by the specified boundary loop.
+    ///
+    /// Loops are connected sets of segments, indexed by the order in which they appear in the
+    /// input geometry. The interior of the loop is deduced from its orientation, so boundaries
+    /// nested inside of it are kept.
+    ///
+    /// The selected loop must be closed; if it isn't, or if the index doesn't correspond to a
+    /// loop, the kernel returns a [`GrisubalError::InvalidShape`] error instead of clipping.
+    Region(usize),
 }
 
 #[derive(Error, Debug)]
@@ -112,6 +141,76 @@ pub enum GrisubalError {
     UnsupportedVtkData(&'static str),
 }
 
+/// Intermediate data computed by the `grisubal` kernel.
+///
+/// This structure is returned by [`grisubal_with_report`] and is meant to help understand how a
+/// given mesh was produced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrisubalReport<T: CoordsFloat> {
+    /// Number of cells of the overlapping grid along the X/Y axes.
+    pub grid_dims: [usize; 2],
+    /// Origin of the overlapping grid.
+    pub origin: Vertex2<T>,
+    /// Number of intersections with the geometry, per edge of the overlapping grid.
+    ///
+    /// Edges are identified using their ID in the grid, i.e. before intersections are inserted.
+    /// Edges that are not intersected do not appear in the map.
+    pub intersections_per_edge: HashMap<EdgeIdType, usize>,
+    /// Indices of the vertices of the geometry that were removed from points of interest because
+    /// they landed on the overlapping grid.
+    pub removed_poi: Vec<usize>,
+}
+
+/// Numerical parameters of the `grisubal` kernel.
+///
+/// The default value of each field reproduces the behavior of [`grisubal`], i.e. only exact
+/// overlaps between the grid and the geometry are detected. Tolerances are absolute distances;
+/// negative values are treated as their absolute value.
+///
+/// # Example
+///
+/// ```
+/// # use honeycomb_kernels::grisubal::GrisubalParams;
+/// let params = GrisubalParams {
+///     snap_tolerance: 1e-6,
+///     ..Default::default()
+/// };
+/// assert_eq!(params.poi_merge_distance, 0.0);
+/// assert!(!params.keep_boundary);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrisubalParams<T: CoordsFloat> {
+    /// Distance under which a vertex of the geometry is considered to lie on a line of the
+    /// overlapping grid.
+    ///
+    /// The origin of the grid is shifted until no vertex lands on a grid corner, or lies on a
+    /// grid line in a way that would create a degenerate intersection. A tolerance larger than a
+    /// fraction of the cell size may prevent the grid from being placed, in which case the kernel
+    /// returns a [`GrisubalError::InvalidShape`] error.
+    pub snap_tolerance: T,
+    /// Distance under which a point of interest is considered redundant with the grid line it is
+    /// close to, and removed.
+    pub poi_merge_distance: T,
+    /// If `true`, the [`Boundary`] attribute used to mark darts of the captured geometry isn't
+    /// removed from the map before return.
+    ///
+    /// The side of the boundary a dart belongs to can then be queried using
+    /// `map.force_read_attribute::<Boundary>(dart_id)`; refer to [`Boundary`]'s documentation for
+    /// the meaning of each value. Note that if a side of the boundary is clipped, its darts are
+    /// removed along with the rest of the clipped faces.
+    pub keep_boundary: bool,
+}
+
+impl<T: CoordsFloat> Default for GrisubalParams<T> {
+    fn default() -> Self {
+        Self {
+            snap_tolerance: T::zero(),
+            poi_merge_distance: T::zero(),
+            keep_boundary: false,
+        }
+    }
+}
+
 #[allow(clippy::missing_errors_doc)]
 /// Main algorithm call function.
 ///
@@ -123,7 +222,8 @@ pub enum GrisubalError {
 ///   the post-processing phase.
 ///
 ///
-/// At the moment, the input geometry should be specified via a file under the VTK Legacy format.
+/// The input geometry should be specified via a file under the VTK Legacy format. Geometries
+/// built in memory can be used with [`grisubal_from_geometry`] instead.
 /// Just like the `io` feature provided in the core crate, there are a few additional requirements
 /// for the geometry to be loaded correctly:
 /// - The geometry should have a consistent orientation, i.e. the order in which the points are
@@ -154,12 +254,164 @@ pub enum GrisubalError {
 /// # Ok(())
 /// # }
 /// ```
-#[allow(clippy::needless_pass_by_value)]
 pub fn grisubal<T: CoordsFloat>(
     file_path: impl AsRef<std::path::Path>,
     grid_cell_sizes: [T; 2],
     clip: Clip,
 ) -> Result<CMap2<T>, GrisubalError> {
+    grisubal_with_params(file_path, grid_cell_sizes, clip, GrisubalParams::default())
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Main algorithm call function, also returning intermediate data.
+///
+/// This function behaves exactly like [`grisubal`], and additionally returns a
+/// [`GrisubalReport`] describing the overlapping grid and its intersections with the geometry.
+/// See [`grisubal`] for a description of arguments and requirements on the input geometry.
+///
+/// # Return / Errors
+///
+/// This function returns a `Result` taking the following values:
+/// - `Ok((CMap2, GrisubalReport))` -- Algorithm ran successfully.
+/// - `Err(GrisubalError)` -- Algorithm encountered an issue. See [`GrisubalError`] for all
+///   possible errors.
+///
+/// # Panics
+///
+/// This function may panic if the specified file cannot be opened.
+///
+/// # Example
+///
+/// ```no_run
+/// # use honeycomb_core::prelude::CMap2;
+/// # use honeycomb_kernels::grisubal::*;
+/// # fn main() -> Result<(), GrisubalError>{
+/// let (cmap, report): (CMap2<f64>, _) =
+///     grisubal_with_report("some/path/to/geometry.vtk", [1., 1.], Clip::default())?;
+/// println!("overlapping grid: {:?} cells", report.grid_dims);
+/// # Ok(())
+/// # }
+/// ```
+#[allow(clippy::needless_pass_by_value)]
+pub fn grisubal_with_report<T: CoordsFloat>(
+    file_path: impl AsRef<std::path::Path>,
+    grid_cell_sizes: [T; 2],
+    clip: Clip,
+) -> Result<(CMap2<T>, GrisubalReport<T>), GrisubalError> {
+    grisubal_file(file_path, grid_cell_sizes, clip, &GrisubalParams::default())
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Main algorithm call function, using custom parameters.
+///
+/// This function behaves exactly like [`grisubal`], but uses the tolerances specified in
+/// `params` when placing the overlapping grid & removing redundant points of interest, and keeps
+/// the [`Boundary`] attribute if requested. See [`grisubal`] for a description of the other
+/// arguments and requirements on the input geometry.
+///
+/// # Return / Errors
+///
+/// This function returns a `Result` taking the following values:
+/// - `Ok(CMap2)` -- Algorithm ran successfully.
+/// - `Err(GrisubalError)` -- Algorithm encountered an issue. See [`GrisubalError`] for all
+///   possible errors.
+///
+/// # Panics
+///
+/// This function may panic if the specified file cannot be opened.
+///
+/// # Example
+///
+/// ```no_run
+/// # use honeycomb_core::prelude::CMap2;
+/// # use honeycomb_kernels::grisubal::*;
+/// # fn main() -> Result<(), GrisubalError>{
+/// let params = GrisubalParams {
+///     snap_tolerance: 1e-3,
+///     poi_merge_distance: 1e-3,
+///     keep_boundary: true,
+/// };
+/// let cmap: CMap2<f64> = grisubal_with_params(
+///     "some/path/to/geometry.vtk",
+///     [1., 1.],
+///     Clip::default(),
+///     params,
+/// )?;
+/// let n_left = (1..cmap.n_darts() as u32)
+///     .filter(|d| cmap.force_read_attribute::<Boundary>(*d) == Some(Boundary::Left))
+///     .count();
+/// # Ok(())
+/// # }
+/// ```
+#[allow(clippy::needless_pass_by_value)]
+pub fn grisubal_with_params<T: CoordsFloat>(
+    file_path: impl AsRef<std::path::Path>,
+    grid_cell_sizes: [T; 2],
+    clip: Clip,
+    params: GrisubalParams<T>,
+) -> Result<CMap2<T>, GrisubalError> {
+    grisubal_file(file_path, grid_cell_sizes, clip, &params).map(|(cmap, _)| cmap)
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Main algorithm call function, using an in-memory geometry.
+///
+/// This function behaves exactly like [`grisubal`], but takes a [`Geometry2`] built by the caller
+/// instead of importing it from a VTK file. See [`grisubal`] for a description of the other
+/// arguments and requirements on the input geometry.
+///
+/// # Return / Errors
+///
+/// This function returns a `Result` taking the following values:
+/// - `Ok(CMap2)` -- Algorithm ran successfully.
+/// - `Err(GrisubalError)` -- Algorithm encountered an issue. See [`GrisubalError`] for all
+///   possible errors.
+///
+/// # Example
+///
+/// ```
+/// # use honeycomb_core::prelude::{CMap2, Vertex2};
+/// # use honeycomb_kernels::grisubal::*;
+/// # fn main() -> Result<(), GrisubalError>{
+/// // counterclockwise square
+/// let geometry = Geometry2 {
+///     vertices: vec![
+///         Vertex2(0.5, 0.5),
+///         Vertex2(2.5, 0.5),
+///         Vertex2(2.5, 2.5),
+///         Vertex2(0.5, 2.5),
+///     ],
+///     segments: vec![(0, 1), (1, 2), (2, 3), (3, 0)],
+///     poi: vec![0, 1, 2, 3],
+/// };
+/// let cmap: CMap2<f64> = grisubal_from_geometry(geometry, [1., 1.], Clip::Right)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn grisubal_from_geometry<T: CoordsFloat>(
+    geometry: Geometry2<T>,
+    grid_cell_sizes: [T; 2],
+    clip: Clip,
+) -> Result<CMap2<T>, GrisubalError> {
+    // INIT TIMER
+    start_timer!(instant);
+
+    // --- NOTHING TO IMPORT
+    unsafe_time_section!(instant, timers::Section::ImportVTK);
+    unsafe_time_section!(instant, timers::Section::BuildGeometry);
+    //----/
+
+    grisubal_kernel(geometry, grid_cell_sizes, clip, &GrisubalParams::default())
+        .map(|(cmap, _)| cmap)
+}
+
+/// Internal routine importing the geometry from a VTK file before running the algorithm.
+fn grisubal_file<T: CoordsFloat>(
+    file_path: impl AsRef<std::path::Path>,
+    grid_cell_sizes: [T; 2],
+    clip: Clip,
+    params: &GrisubalParams<T>,
+) -> Result<(CMap2<T>, GrisubalReport<T>), GrisubalError> {
     // INIT TIMER
     start_timer!(instant);
 
@@ -172,17 +424,35 @@ pub fn grisubal<T: CoordsFloat>(
     //----/
 
     // --- BUILD OUR MODEL FROM THE VTK IMPORT
-    let mut geometry = Geometry2::try_from(geometry_vtk)?;
+    let geometry = Geometry2::try_from(geometry_vtk)?;
     unsafe_time_section!(instant, timers::Section::BuildGeometry);
     //----/
 
+    grisubal_kernel(geometry, grid_cell_sizes, clip, params)
+}
+
+/// Internal routine running all steps of the algorithm following the geometry's construction.
+///
+/// The `Boundary` attribute is removed from the map before return, unless `params.keep_boundary`
+/// is `true`. Tolerances used during pre-processing are read from `params`.
+#[allow(clippy::needless_pass_by_value)]
+fn grisubal_kernel<T: CoordsFloat>(
+    mut geometry: Geometry2<T>,
+    grid_cell_sizes: [T; 2],
+    clip: Clip,
+    params: &GrisubalParams<T>,
+) -> Result<(CMap2<T>, GrisubalReport<T>), GrisubalError> {
+    // INIT TIMER
+    start_timer!(instant);
+
     // --- FIRST DETECTION OF ORIENTATION ISSUES
     detect_orientation_issue(&geometry)?;
     unsafe_time_section!(instant, timers::Section::DetectOrientation);
     //----/
 
     // --- FIND AN OVERLAPPING GRID
-    let ([nx, ny], origin) = compute_overlapping_grid(&geometry, grid_cell_sizes)?;
+    let ([nx, ny], origin) =
+        compute_overlapping_grid(&geometry, grid_cell_sizes, params.snap_tolerance)?;
     let [cx, cy] = grid_cell_sizes;
     let ogrid = GridDescriptor::default()
         .n_cells_x(nx)
@@ -194,7 +464,14 @@ pub fn grisubal<T: CoordsFloat>(
     //----/
 
     // --- REMOVE REDUNDANT PoIs
-    remove_redundant_poi(&mut geometry, grid_cell_sizes, origin);
+    let mut removed_poi = geometry.poi.clone();
+    remove_redundant_poi(
+        &mut geometry,
+        grid_cell_sizes,
+        origin,
+        params.poi_merge_distance,
+    );
+    removed_poi.retain(|idx| !geometry.poi.contains(idx));
     unsafe_time_section!(instant, timers::Section::RemoveRedundantPoi);
     //----/
 
@@ -221,6 +498,10 @@ pub fn grisubal<T: CoordsFloat>(
     let (edge_intersec, dart_slices) =
         group_intersections_per_edge(&mut cmap, intersection_metadata);
     let intersection_darts = compute_intersection_ids(n_intersec, &edge_intersec, &dart_slices);
+    let intersections_per_edge = edge_intersec
+        .iter()
+        .map(|(edge_id, vs)| (*edge_id, vs.len()))
+        .collect();
     unsafe_time_section!(instant, timers::Section::BuildMeshIntersecData);
     //----/
 
@@ -243,20 +524,143 @@ pub fn grisubal<T: CoordsFloat>(
     //-------/
 
     // --- CLIP
+    #[cfg(not(feature = "par-internals"))]
     match clip {
         Clip::Left => clip_left(&mut cmap)?,
         Clip::Right => clip_right(&mut cmap)?,
+        Clip::Region(idx) => clip_region(&mut cmap, &geometry, idx)?,
+        Clip::None => {}
+    }
+    #[cfg(feature = "par-internals")]
+    match clip {
+        Clip::Left => clip_left_par(&mut cmap)?,
+        Clip::Right => clip_right_par(&mut cmap)?,
+        Clip::Region(idx) => clip_region(&mut cmap, &geometry, idx)?,
         Clip::None => {}
     }
     unsafe_time_section!(instant, timers::Section::Clip);
     //----/
 
     // CLEANUP
-    cmap.remove_attribute_storage::<Boundary>();
+    if !params.keep_boundary {
+        cmap.remove_attribute_storage::<Boundary>();
+    }
     finish!(instant);
     //-/
 
-    Ok(cmap)
+    Ok((
+        cmap,
+        GrisubalReport {
+            grid_dims: [nx, ny],
+            origin,
+            intersections_per_edge,
+            removed_poi,
+        },
+    ))
+}
+
+/// Ratio between the shortest segment of a geometry and the cell size suggested for it.
+const SEGMENT_TO_CELL_SIZE_RATIO: f64 = 4.0;
+
+/// Suggest a grid cell size for a given geometry.
+///
+/// The suggested size is a fraction of the length of the geometry's shortest segment, so that the
+/// overlapping grid resolves all features of the geometry. This is meant to be used as a starting
+/// point for the `grid_cell_sizes` argument of [`grisubal`] and [`grisubal_from_geometry`].
+///
+/// # Arguments
+///
+/// - `geometry: &Geometry2<T>` -- Input geometry.
+///
+/// # Return
+///
+/// Return the suggested cell size, to use along both axes.
+///
+/// # Panics
+///
+/// This function panics if the geometry doesn't contain any non-degenerate segment. Refer to
+/// [`Geometry2::min_segment_length`] for other panic conditions.
+///
+/// # Example
+///
+/// ```
+/// # use honeycomb_core::prelude::{CMap2, Vertex2};
+/// # use honeycomb_kernels::grisubal::*;
+/// # fn main() -> Result<(), GrisubalError>{
+/// // counterclockwise rectangle
+/// let geometry = Geometry2 {
+///     vertices: vec![
+///         Vertex2(0.5, 0.5),
+///         Vertex2(4.5, 0.5),
+///         Vertex2(4.5, 2.5),
+///         Vertex2(0.5, 2.5),
+///     ],
+///     segments: vec![(0, 1), (1, 2), (2, 3), (3, 0)],
+///     poi: vec![0, 1, 2, 3],
+/// };
+/// let size: f64 = suggest_cell_size(&geometry);
+/// assert_eq!(size, 0.5);
+/// let cmap: CMap2<f64> = grisubal_from_geometry(geometry, [size, size], Clip::Right)?;
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "unused return value"]
+pub fn suggest_cell_size<T: CoordsFloat>(geometry: &Geometry2<T>) -> T {
+    cell_size_from_segments(geometry).expect("E: geometry contains no non-degenerate segment")
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Suggest a grid cell size for the geometry described by a VTK file.
+///
+/// This function builds the geometry from the file, as [`grisubal`] does, then behaves like
+/// [`suggest_cell_size`].
+///
+/// # Arguments
+///
+/// - `file_path: impl AsRef<Path>` -- Path to a VTK file describing input geometry. See
+///   [`grisubal`] for requirements on the file.
+///
+/// # Return / Errors
+///
+/// This function returns a `Result` taking the following values:
+/// - `Ok(T)` -- The suggested cell size, to use along both axes.
+/// - `Err(GrisubalError)` -- The geometry couldn't be built from the file, or it doesn't contain
+///   any non-degenerate segment.
+///
+/// # Panics
+///
+/// This function may panic if the specified file cannot be opened.
+///
+/// # Example
+///
+/// ```no_run
+/// # use honeycomb_core::prelude::CMap2;
+/// # use honeycomb_kernels::grisubal::*;
+/// # fn main() -> Result<(), GrisubalError>{
+/// let path = "some/path/to/geometry.vtk";
+/// let size: f64 = suggest_cell_size_from_file(path)?;
+/// let cmap: CMap2<f64> = grisubal(path, [size, size], Clip::default())?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn suggest_cell_size_from_file<T: CoordsFloat>(
+    file_path: impl AsRef<std::path::Path>,
+) -> Result<T, GrisubalError> {
+    let geometry_vtk = match Vtk::import(file_path) {
+        Ok(vtk) => vtk,
+        Err(e) => panic!("E: could not open specified vtk file - {e}"),
+    };
+    let geometry = Geometry2::try_from(geometry_vtk)?;
+    cell_size_from_segments(&geometry).ok_or(GrisubalError::InvalidShape(
+        "geometry contains no non-degenerate segment",
+    ))
+}
+
+/// Compute the suggested cell size, or `None` if there is no non-degenerate segment.
+fn cell_size_from_segments<T: CoordsFloat>(geometry: &Geometry2<T>) -> Option<T> {
+    geometry
+        .min_segment_length()
+        .map(|l| l / T::from(SEGMENT_TO_CELL_SIZE_RATIO).unwrap())
 }
 
 // ------ TESTS
@@ -6,12 +6,14 @@
 
 // ------ IMPORTS
 
+use crate::grisubal::routines::detect_orientation_issue;
 use crate::grisubal::GrisubalError;
 use honeycomb_core::attributes::AttrSparseVec;
 use honeycomb_core::cmap::CMapResult;
 use honeycomb_core::prelude::{
     AttributeBind, AttributeUpdate, CoordsFloat, DartIdType, OrbitPolicy, Vertex2,
 };
+use std::collections::HashMap;
 use vtkio::{
     model::{CellType, DataSet, VertexNumbers},
     IOBuffer, Vtk,
@@ -46,7 +48,12 @@ impl GridCellId {
 
 /// Geometry representation structure.
 ///
-/// For specification of the accepted VTK file format, see [`crate::grisubal`]'s documentation entry.
+/// A geometry is usually built from a VTK file using the `TryFrom<Vtk>` implementation. For
+/// specification of the accepted VTK file format, see [`crate::grisubal`]'s documentation entry.
+///
+/// It can also be built programmatically by filling its fields, and passed to
+/// [`grisubal_from_geometry`][crate::grisubal::grisubal_from_geometry]. Segments and points of
+/// interest are given as indices into the `vertices` list.
 pub struct Geometry2<T: CoordsFloat> {
     /// Vertices of the geometry.
     pub vertices: Vec<Vertex2<T>>,
@@ -56,6 +63,149 @@ pub struct Geometry2<T: CoordsFloat> {
     pub poi: Vec<usize>,
 }
 
+impl<T: CoordsFloat> Geometry2<T> {
+    /// Build a geometry from explicit vertices, segments and points of interest.
+    ///
+    /// This is the programmatic equivalent of the VTK import, where `Line` cells are interpreted
+    /// as segments, and `Vertex` cells as points of interest.
+    ///
+    /// # Arguments
+    ///
+    /// - `vertices: Vec<Vertex2<T>>` -- Vertices of the geometry.
+    /// - `segments: Vec<(usize, usize)>` -- Oriented segments making up the boundaries of the
+    ///   geometry, as pairs of indices into `vertices`.
+    /// - `poi: Vec<usize>` -- Points of interest, as indices into `vertices`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - a segment or a point of interest refers to an undefined vertex
+    ///   ([`GrisubalError::InvalidShape`]),
+    /// - a boundary isn't consistently oriented ([`GrisubalError::InconsistentOrientation`]),
+    /// - a boundary isn't closed ([`GrisubalError::InvalidShape`]).
+    pub fn from_segments(
+        vertices: Vec<Vertex2<T>>,
+        segments: Vec<(usize, usize)>,
+        poi: Vec<usize>,
+    ) -> Result<Self, GrisubalError> {
+        let n_vertices = vertices.len();
+        if segments
+            .iter()
+            .any(|(v1, v2)| *v1 >= n_vertices || *v2 >= n_vertices)
+        {
+            return Err(GrisubalError::InvalidShape(
+                "segment refers to an undefined vertex",
+            ));
+        }
+        if poi.iter().any(|v| *v >= n_vertices) {
+            return Err(GrisubalError::InvalidShape(
+                "point of interest refers to an undefined vertex",
+            ));
+        }
+
+        let geometry = Self {
+            vertices,
+            segments,
+            poi,
+        };
+        detect_orientation_issue(&geometry)?;
+        if !geometry
+            .boundary_loops()
+            .iter()
+            .all(|l| geometry.is_closed(l))
+        {
+            return Err(GrisubalError::InvalidShape("boundary isn't closed"));
+        }
+
+        Ok(geometry)
+    }
+
+    /// Return the length of the shortest non-degenerate segment of the geometry, if any.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if a segment refers to a vertex that doesn't exist, or if a segment
+    /// length cannot be compared (e.g. a coordinate is `NaN`).
+    #[must_use = "unused return value"]
+    pub fn min_segment_length(&self) -> Option<T> {
+        self.segments
+            .iter()
+            .map(|(v1, v2)| (self.vertices[*v2] - self.vertices[*v1]).norm())
+            .filter(|l| *l > T::epsilon())
+            .min_by(|l1, l2| l1.partial_cmp(l2).expect("E: unreachable"))
+    }
+
+    /// Return the boundary loops of the geometry, as lists of segment indices.
+    ///
+    /// A loop is a connected set of segments. Loops are indexed by the order in which they
+    /// appear in the segment list, i.e. by the index of their first segment.
+    #[must_use = "unused return value"]
+    pub fn boundary_loops(&self) -> Vec<Vec<usize>> {
+        let mut vertex_segments: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (i, (v1, v2)) in self.segments.iter().enumerate() {
+            vertex_segments.entry(*v1).or_default().push(i);
+            vertex_segments.entry(*v2).or_default().push(i);
+        }
+        let mut visited = vec![false; self.segments.len()];
+        let mut loops = Vec::new();
+        for first in 0..self.segments.len() {
+            if visited[first] {
+                continue;
+            }
+            visited[first] = true;
+            let mut current = vec![first];
+            let mut pending = vec![first];
+            while let Some(i) = pending.pop() {
+                let (v1, v2) = self.segments[i];
+                for j in vertex_segments[&v1].iter().chain(&vertex_segments[&v2]) {
+                    if !visited[*j] {
+                        visited[*j] = true;
+                        current.push(*j);
+                        pending.push(*j);
+                    }
+                }
+            }
+            current.sort_unstable();
+            loops.push(current);
+        }
+        loops
+    }
+
+    /// Return whether the given segments form a closed loop, i.e. if each of their vertices
+    /// starts exactly one segment, and ends exactly one segment.
+    #[must_use = "unused return value"]
+    pub fn is_closed(&self, segments: &[usize]) -> bool {
+        let mut degrees: HashMap<usize, (usize, usize)> = HashMap::new();
+        for i in segments {
+            let (v1, v2) = self.segments[*i];
+            degrees.entry(v1).or_default().0 += 1;
+            degrees.entry(v2).or_default().1 += 1;
+        }
+        degrees.values().all(|d| *d == (1, 1))
+    }
+
+    /// Return the signed area enclosed by the given segments.
+    ///
+    /// The area is positive if the loop is oriented counterclockwise. It is only meaningful for
+    /// closed loops.
+    ///
+    /// # Panics
+    ///
+    /// This method may panic if a segment refers to a vertex that doesn't exist, or if the value
+    /// `2.0` cannot be converted to `T`.
+    #[must_use = "unused return value"]
+    pub fn signed_area(&self, segments: &[usize]) -> T {
+        let two = T::from(2.0).unwrap();
+        segments.iter().fold(T::zero(), |acc, i| {
+            let (v1, v2) = (
+                self.vertices[self.segments[*i].0],
+                self.vertices[self.segments[*i].1],
+            );
+            acc + (v1.x() * v2.y() - v2.x() * v1.y()) / two
+        })
+    }
+}
+
 macro_rules! build_vertices {
     ($v: ident) => {{
         if $v.len() % 3 != 0 {
@@ -240,7 +390,18 @@ pub struct MapEdge<T: CoordsFloat> {
 ///
 /// ![`DART_SIDES`](https://lihpc-computational-geometry.github.io/honeycomb/images/grisubal/left_right_darts.svg)
 ///
+/// In terms of faces, since darts of a face are oriented counterclockwise:
+///
+/// - a `Left` dart has the same orientation as the boundary; its face is located on the left side
+///   of the boundary,
+/// - a `Right` dart has the opposite orientation; its face is located on the right side of the
+///   boundary.
+///
+/// For example, the interior of a counterclockwise boundary is made up of faces of `Left` darts.
+///
 /// The attribute is set during the capture of the geometry so that it can be used at the (optional) clipping step.
+/// It is removed from the map before the kernel returns, unless
+/// [`GrisubalParams::keep_boundary`][crate::grisubal::GrisubalParams::keep_boundary] is set.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Boundary {
     /// Dart model the left side of the oriented boundary.
@@ -2,16 +2,24 @@
 
 // ------ IMPORTS
 
-use crate::grisubal::model::Boundary;
+use crate::grisubal::model::{Boundary, Geometry2};
 use crate::grisubal::GrisubalError;
 use honeycomb_core::prelude::{
     CMap2, CoordsFloat, DartIdType, FaceIdType, Orbit2, OrbitPolicy, Vertex2, NULL_DART_ID,
 };
 use std::collections::{HashSet, VecDeque};
 
+#[cfg(feature = "par-internals")]
+use honeycomb_core::stm::atomically;
+#[cfg(feature = "par-internals")]
+use rayon::prelude::*;
+#[cfg(feature = "par-internals")]
+use std::sync::atomic::{AtomicBool, Ordering};
+
 // ------ CONTENT
 
 /// Clip content on the left side of the boundary.
+#[cfg_attr(feature = "par-internals", allow(dead_code))]
 pub fn clip_left<T: CoordsFloat>(cmap: &mut CMap2<T>) -> Result<(), GrisubalError> {
     // color faces using a bfs starting on multiple nodes
     let marked = mark_faces(cmap, Boundary::Left, Boundary::Right)?;
@@ -23,6 +31,7 @@ pub fn clip_left<T: CoordsFloat>(cmap: &mut CMap2<T>) -> Result<(), GrisubalErro
 }
 
 /// Clip content on the right side of the boundary.
+#[cfg_attr(feature = "par-internals", allow(dead_code))]
 pub fn clip_right<T: CoordsFloat>(cmap: &mut CMap2<T>) -> Result<(), GrisubalError> {
     // color faces using a bfs starting on multiple nodes
     let marked = mark_faces(cmap, Boundary::Right, Boundary::Left)?;
@@ -33,8 +42,166 @@ pub fn clip_right<T: CoordsFloat>(cmap: &mut CMap2<T>) -> Result<(), GrisubalErr
     Ok(())
 }
 
+#[cfg(feature = "par-internals")]
+/// Clip content on the left side of the boundary, in parallel.
+///
+/// This yields the same map as [`clip_left`].
+pub fn clip_left_par<T: CoordsFloat>(cmap: &mut CMap2<T>) -> Result<(), GrisubalError> {
+    let marked = mark_faces_par(cmap, Boundary::Left, Boundary::Right)?;
+
+    delete_darts_par(cmap, &marked, Boundary::Right);
+
+    Ok(())
+}
+
+#[cfg(feature = "par-internals")]
+/// Clip content on the right side of the boundary, in parallel.
+///
+/// This yields the same map as [`clip_right`].
+pub fn clip_right_par<T: CoordsFloat>(cmap: &mut CMap2<T>) -> Result<(), GrisubalError> {
+    let marked = mark_faces_par(cmap, Boundary::Right, Boundary::Left)?;
+
+    delete_darts_par(cmap, &marked, Boundary::Left);
+
+    Ok(())
+}
+
+/// Keep only the content enclosed by one of the geometry's boundary loops.
+///
+/// Loops are indexed as in [`Geometry2::boundary_loops`]. Other boundaries located inside the
+/// selected loop are kept as is.
+///
+/// # Errors
+///
+/// This function returns an error if the index doesn't correspond to a loop, if the selected
+/// loop isn't closed, or if its darts cannot be found in the map.
+pub fn clip_region<T: CoordsFloat>(
+    cmap: &mut CMap2<T>,
+    geometry: &Geometry2<T>,
+    loop_idx: usize,
+) -> Result<(), GrisubalError> {
+    let loops = geometry.boundary_loops();
+    let Some(segments) = loops.get(loop_idx) else {
+        return Err(GrisubalError::InvalidShape(
+            "selected boundary loop doesn't exist",
+        ));
+    };
+    if !geometry.is_closed(segments) {
+        return Err(GrisubalError::InvalidShape(
+            "selected boundary loop isn't closed",
+        ));
+    }
+
+    // darts of the loop on the side of its interior
+    let loop_darts = loop_darts(cmap, geometry, segments[0])?;
+    let inner_darts: Vec<DartIdType> = if geometry.signed_area(segments) > T::zero() {
+        loop_darts
+    } else {
+        loop_darts.iter().map(|d| cmap.beta::<2>(*d)).collect()
+    };
+
+    // color faces, only stopping at the selected loop
+    let inner_set: HashSet<DartIdType> = inner_darts.iter().copied().collect();
+    let mut marked: HashSet<FaceIdType> = HashSet::from([0]);
+    let mut queue: VecDeque<FaceIdType> = inner_darts.iter().map(|d| cmap.face_id(*d)).collect();
+    while let Some(face_id) = queue.pop_front() {
+        if marked.insert(face_id) {
+            let darts = Orbit2::new(cmap, OrbitPolicy::Face, face_id as DartIdType);
+            queue.extend(
+                darts
+                    .filter(|d| !inner_set.contains(d))
+                    .map(|d| cmap.face_id(cmap.beta::<2>(d))),
+            );
+        }
+    }
+    let outer: HashSet<FaceIdType> = cmap.iter_faces().filter(|f| !marked.contains(f)).collect();
+
+    let kept_boundary_components: Vec<(DartIdType, Vertex2<T>)> = inner_darts
+        .into_iter()
+        .map(|dart_id| {
+            (
+                dart_id,
+                cmap.force_read_vertex(cmap.vertex_id(dart_id))
+                    .expect("E: found a topological vertex with no associated coordinates"),
+            )
+        })
+        .collect();
+    remove_faces(cmap, outer, kept_boundary_components);
+
+    Ok(())
+}
+
 // --- internals
 
+/// Return `Left` darts modeling the boundary loop containing segment `first_segment`.
+///
+/// A first dart is searched along the segment, then the loop is walked by turning around
+/// vertices on its left side.
+#[allow(clippy::cast_possible_truncation)]
+fn loop_darts<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    geometry: &Geometry2<T>,
+    first_segment: usize,
+) -> Result<Vec<DartIdType>, GrisubalError> {
+    let (p, q) = (
+        geometry.vertices[geometry.segments[first_segment].0],
+        geometry.vertices[geometry.segments[first_segment].1],
+    );
+    let is_left = |d: DartIdType| cmap.force_read_attribute::<Boundary>(d) == Some(Boundary::Left);
+    let seed = (1..cmap.n_darts() as DartIdType)
+        .filter(|d| is_left(*d) && !cmap.is_free(*d))
+        .find(|d| {
+            let (Some(a), Some(b)) = (
+                cmap.force_read_vertex(cmap.vertex_id(*d)),
+                cmap.force_read_vertex(cmap.vertex_id(cmap.beta::<1>(*d))),
+            ) else {
+                return false;
+            };
+            is_on_segment(&p, &q, &Vertex2::average(&a, &b))
+        })
+        .ok_or(GrisubalError::InvalidShape(
+            "selected boundary loop wasn't captured",
+        ))?;
+
+    let mut darts = vec![seed];
+    let mut d = seed;
+    loop {
+        // turn around the end vertex of `d`, staying on the left side of the boundary
+        let mut next = cmap.beta::<1>(d);
+        let mut n_turns = 0;
+        while !is_left(next) {
+            next = cmap.beta::<1>(cmap.beta::<2>(next));
+            n_turns += 1;
+            if next == NULL_DART_ID || n_turns > cmap.n_darts() {
+                return Err(GrisubalError::InvalidShape(
+                    "selected boundary loop isn't closed",
+                ));
+            }
+        }
+        if next == seed {
+            break;
+        }
+        if darts.len() > cmap.n_darts() {
+            return Err(GrisubalError::InvalidShape(
+                "selected boundary loop isn't closed",
+            ));
+        }
+        darts.push(next);
+        d = next;
+    }
+    Ok(darts)
+}
+
+/// Return whether `m` is located on segment `[p, q]`, up to a small relative tolerance.
+fn is_on_segment<T: CoordsFloat>(p: &Vertex2<T>, q: &Vertex2<T>, m: &Vertex2<T>) -> bool {
+    let tol = T::from(1e-6).unwrap();
+    let (pq, pm) = (*q - *p, *m - *p);
+    let sq_len = pq.dot(&pq);
+    let t = pm.dot(&pq) / sq_len;
+    (pq.x() * pm.y() - pq.y() * pm.x()).abs() <= tol * sq_len && t >= T::zero() && t <= T::one()
+}
+
+#[cfg_attr(feature = "par-internals", allow(dead_code))]
 #[allow(clippy::cast_possible_truncation)]
 fn mark_faces<T: CoordsFloat>(
     cmap: &CMap2<T>,
@@ -80,6 +247,7 @@ fn mark_faces<T: CoordsFloat>(
     Ok(marked)
 }
 
+#[cfg_attr(feature = "par-internals", allow(dead_code))]
 #[allow(clippy::cast_possible_truncation)]
 fn delete_darts<T: CoordsFloat>(
     cmap: &mut CMap2<T>,
@@ -99,18 +267,137 @@ fn delete_darts<T: CoordsFloat>(
         })
         .collect();
 
+    remove_faces(cmap, marked, kept_boundary_components);
+}
+
+/// Remove `marked` faces from the map, then detach the kept side of the boundary.
+#[allow(clippy::cast_possible_truncation)]
+fn remove_faces<T: CoordsFloat>(
+    cmap: &mut CMap2<T>,
+    marked: HashSet<FaceIdType>,
+    kept_boundary_components: Vec<(DartIdType, Vertex2<T>)>,
+) {
     for face_id in marked {
         let darts: Vec<DartIdType> =
             Orbit2::new(cmap, OrbitPolicy::Face, face_id as DartIdType).collect();
         for &dart in &darts {
             let _ = cmap.force_remove_vertex(cmap.vertex_id(dart));
             cmap.set_betas(dart, [NULL_DART_ID; 3]);
+            // don't leave stale values behind in case the attribute is kept after the kernel
+            let _ = cmap.force_remove_attribute::<Boundary>(dart);
             cmap.remove_free_dart(dart);
         }
     }
 
+    // the boundary is fully detached before writing vertices back, so that their IDs are final
+    for (dart, _) in &kept_boundary_components {
+        cmap.set_beta::<2>(*dart, NULL_DART_ID); // set beta2(dart) to 0
+    }
+    for (dart, vertex) in kept_boundary_components {
+        cmap.force_write_vertex(cmap.vertex_id(dart), vertex);
+    }
+}
+
+#[cfg(feature = "par-internals")]
+#[allow(clippy::cast_possible_truncation)]
+fn mark_faces_par<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    mark: Boundary,
+    other: Boundary,
+) -> Result<Vec<FaceIdType>, GrisubalError> {
+    // faces are claimed by the first walk reaching them, so that each face is processed once
+    let claimed: Vec<AtomicBool> = (0..cmap.n_darts())
+        .map(|face_id| AtomicBool::new(face_id == 0))
+        .collect();
+    let seeds: Vec<FaceIdType> = (1..cmap.n_darts() as DartIdType)
+        .into_par_iter()
+        .filter_map(|dart_id| {
+            if cmap.force_read_attribute::<Boundary>(dart_id) == Some(mark)
+                && !cmap.is_free(dart_id)
+            {
+                return Some(cmap.face_id(dart_id));
+            }
+            None
+        })
+        .collect();
+
+    // seeds of distinct boundary components are walked concurrently
+    seeds.par_iter().try_for_each(|seed| {
+        let mut stack = vec![*seed];
+        while let Some(face_id) = stack.pop() {
+            if claimed[face_id as usize].swap(true, Ordering::Relaxed) {
+                continue;
+            }
+            let darts: Vec<DartIdType> =
+                Orbit2::new(cmap, OrbitPolicy::Face, face_id as DartIdType).collect();
+            if darts
+                .iter()
+                .any(|did| cmap.force_read_attribute::<Boundary>(*did) == Some(other))
+            {
+                return Err(GrisubalError::InconsistentOrientation(
+                    "between-boundary inconsistency",
+                ));
+            }
+            stack.extend(darts.iter().filter_map(|dart_id| {
+                if matches!(
+                    cmap.force_read_attribute::<Boundary>(cmap.beta::<2>(*dart_id)),
+                    Some(Boundary::None) | None
+                ) {
+                    return Some(cmap.face_id(cmap.beta::<2>(*dart_id)));
+                }
+                None
+            }));
+        }
+        Ok(())
+    })?;
+
+    Ok(claimed
+        .par_iter()
+        .enumerate()
+        .skip(1)
+        .filter_map(|(face_id, c)| c.load(Ordering::Relaxed).then_some(face_id as FaceIdType))
+        .collect())
+}
+
+#[cfg(feature = "par-internals")]
+#[allow(clippy::cast_possible_truncation)]
+fn delete_darts_par<T: CoordsFloat>(
+    cmap: &mut CMap2<T>,
+    marked: &[FaceIdType],
+    kept_boundary: Boundary,
+) {
+    let kept_boundary_components: Vec<(DartIdType, Vertex2<T>)> = (1..cmap.n_darts() as DartIdType)
+        .into_par_iter()
+        .filter_map(|dart_id| {
+            if cmap.force_read_attribute::<Boundary>(dart_id) == Some(kept_boundary) {
+                return Some((
+                    dart_id,
+                    cmap.force_read_vertex(cmap.vertex_id(dart_id))
+                        .expect("E: found a topological vertex with no associated coordinates"),
+                ));
+            }
+            None
+        })
+        .collect();
+
+    let darts: Vec<DartIdType> = marked
+        .par_iter()
+        .flat_map_iter(|face_id| Orbit2::new(cmap, OrbitPolicy::Face, *face_id as DartIdType))
+        .collect();
+    // vertex IDs are computed before any link is modified
+    darts.par_iter().for_each(|dart| {
+        let _ = cmap.force_remove_vertex(cmap.vertex_id(*dart));
+    });
+    darts.par_iter().for_each(|dart| {
+        cmap.set_betas(*dart, [NULL_DART_ID; 3]);
+        let _ = cmap.force_remove_attribute::<Boundary>(*dart);
+        atomically(|trans| cmap.remove_free_dart_transac(trans, *dart));
+    });
+
+    for (dart, _) in &kept_boundary_components {
+        cmap.set_beta::<2>(*dart, NULL_DART_ID); // set beta2(dart) to 0
+    }
     for (dart, vertex) in kept_boundary_components {
-        cmap.set_beta::<2>(dart, NULL_DART_ID); // set beta2(dart) to 0
         cmap.force_write_vertex(cmap.vertex_id(dart), vertex);
     }
 }
@@ -70,13 +70,17 @@ pub(crate) fn insert_edges_in_map<T: CoordsFloat>(cmap: &mut CMap2<T>, edges: &[
             // create the topology components
             let edge_id = cmap.edge_id(d_new);
             let new_darts = &dslice[2..];
+            let n_inter = intermediates.len();
             atomically(|trans| {
                 if let Err(SplitEdgeError::FailedTransaction(e)) = splitn_edge_transac(
                     cmap,
                     trans,
                     edge_id,
                     new_darts,
-                    &vec![T::from(0.5).unwrap(); intermediates.len()],
+                    // placeholder placements, vertices are replaced below
+                    &(1..=n_inter)
+                        .map(|i| T::from(i).unwrap() / T::from(n_inter + 1).unwrap())
+                        .collect::<Vec<_>>(),
                 ) {
                     Err(e)
                 } else {
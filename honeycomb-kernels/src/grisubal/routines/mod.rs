@@ -35,7 +35,10 @@ pub(crate) use compute_new_edges::*;
 pub(crate) use insert_new_edges::*;
 
 // optional clipping routines
-pub(crate) use clip::{clip_left, clip_right};
+#[cfg_attr(feature = "par-internals", allow(unused_imports))]
+pub(crate) use clip::{clip_left, clip_region, clip_right};
+#[cfg(feature = "par-internals")]
+pub(crate) use clip::{clip_left_par, clip_right_par};
 
 // ------ IMPORTS
 
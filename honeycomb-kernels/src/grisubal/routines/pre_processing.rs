@@ -39,10 +39,16 @@ pub fn detect_orientation_issue<T: CoordsFloat>(
     Ok(())
 }
 
+/// Maximum number of times the origin of the overlapping grid is shifted.
+///
+/// Shifts are halved at each iteration; the last one is 2^-30 times the cell size.
+const MAX_ORIGIN_SHIFTS: u32 = 29;
+
 #[allow(clippy::cast_precision_loss)]
 pub fn compute_overlapping_grid<T: CoordsFloat>(
     geometry: &Geometry2<T>,
     [len_cell_x, len_cell_y]: [T; 2],
+    snap_tolerance: T,
 ) -> Result<([usize; 2], Vertex2<T>), GrisubalError> {
     // compute the minimum bounding box
     let (mut min_x, mut max_x, mut min_y, mut max_y): (T, T, T, T) = {
@@ -80,18 +86,31 @@ pub fn compute_overlapping_grid<T: CoordsFloat>(
     let mut og_y = min_y - len_cell_y * T::from(1.5).unwrap();
     // we check for some extremely annoying cases here
     // if some are detected, the origin is incrementally shifted
-    let (mut on_corner, mut reflect) =
-        detect_overlaps(geometry, [len_cell_x, len_cell_y], Vertex2(og_x, og_y));
+    let (mut on_corner, mut reflect) = detect_overlaps(
+        geometry,
+        [len_cell_x, len_cell_y],
+        Vertex2(og_x, og_y),
+        snap_tolerance,
+    );
     let mut i = 1;
 
     while on_corner | reflect {
+        if i > MAX_ORIGIN_SHIFTS {
+            return Err(GrisubalError::InvalidShape(
+                "cannot place the grid away from vertices - is the snap tolerance too large?",
+            ));
+        }
         eprintln!(
             "W: land on corner: {on_corner} - reflect on an axis: {reflect}, shifting origin"
         );
         og_x += len_cell_x * T::from(1. / (2_i32.pow(i + 1) as f32)).unwrap();
         og_y += len_cell_y * T::from(1. / (2_i32.pow(i + 1) as f32)).unwrap();
-        (on_corner, reflect) =
-            detect_overlaps(geometry, [len_cell_x, len_cell_y], Vertex2(og_x, og_y));
+        (on_corner, reflect) = detect_overlaps(
+            geometry,
+            [len_cell_x, len_cell_y],
+            Vertex2(og_x, og_y),
+            snap_tolerance,
+        );
         i += 1;
     }
 
@@ -103,19 +122,23 @@ pub fn compute_overlapping_grid<T: CoordsFloat>(
 
 /// Remove from their geometry points of interest that intersect with a grid of specified dimension.
 ///
+/// Points of interest located within `merge_distance` of a grid line are considered to intersect
+/// with it, since they will be captured by the intersection vertex inserted on the line.
+///
 /// This function works under the assumption that the grid is Cartesian & has its origin on `(0.0, 0.0)`.
 pub fn remove_redundant_poi<T: CoordsFloat>(
     geometry: &mut Geometry2<T>,
     [cx, cy]: [T; 2],
     origin: Vertex2<T>,
+    merge_distance: T,
 ) {
     // PoI that land on the grid create a number of issues; removing them is ok since we're intersecting the grid
     // at their coordinates, so the shape will be captured via intersection anyway
     geometry.poi.retain(|idx| {
         let v = geometry.vertices[*idx];
         // origin is assumed to be (0.0, 0.0)
-        let on_x_axis = ((v.x() - origin.x()) % cx).is_zero();
-        let on_y_axis = ((v.y() - origin.y()) % cy).is_zero();
+        let on_x_axis = on_grid_line(v.x(), origin.x(), cx, merge_distance);
+        let on_y_axis = on_grid_line(v.y(), origin.y(), cy, merge_distance);
         !(on_x_axis | on_y_axis)
     });
 }
@@ -125,13 +148,14 @@ pub fn detect_overlaps<T: CoordsFloat>(
     geometry: &Geometry2<T>,
     [cx, cy]: [T; 2],
     origin: Vertex2<T>,
+    tolerance: T,
 ) -> (bool, bool) {
     let on_corner = geometry
         .vertices
         .iter()
         .map(|v| {
-            let on_x_axis = ((v.x() - origin.x()) % cx).is_zero();
-            let on_y_axis = ((v.y() - origin.y()) % cy).is_zero();
+            let on_x_axis = on_grid_line(v.x(), origin.x(), cx, tolerance);
+            let on_y_axis = on_grid_line(v.y(), origin.y(), cy, tolerance);
             on_x_axis && on_y_axis
         })
         .any(|a| a);
@@ -141,8 +165,8 @@ pub fn detect_overlaps<T: CoordsFloat>(
         .iter()
         .enumerate()
         .filter_map(|(id, v)| {
-            let on_x_axis = ((v.x() - origin.x()) % cx).is_zero();
-            let on_y_axis = ((v.y() - origin.y()) % cy).is_zero();
+            let on_x_axis = on_grid_line(v.x(), origin.x(), cx, tolerance);
+            let on_y_axis = on_grid_line(v.y(), origin.y(), cy, tolerance);
             if on_x_axis | on_y_axis {
                 return Some(id);
             }
@@ -200,3 +224,12 @@ pub fn detect_overlaps<T: CoordsFloat>(
 
     (on_corner, bad_reflection)
 }
+
+/// Return `true` if `coord` lies within `tolerance` of a line of a grid starting at `origin`, with
+/// cells of length `len_cell`.
+///
+/// With a null tolerance, this only detects coordinates located exactly on a line.
+fn on_grid_line<T: CoordsFloat>(coord: T, origin: T, len_cell: T, tolerance: T) -> bool {
+    let offset = ((coord - origin) % len_cell).abs();
+    offset.min(len_cell - offset) <= tolerance.abs()
+}
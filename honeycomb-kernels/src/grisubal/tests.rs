@@ -1,11 +1,21 @@
 // ------ IMPORTS
 
 use crate::grisubal::model::{Boundary, Geometry2, GeometryVertex};
+use crate::grisubal::routines::clip_region;
+#[cfg(feature = "par-internals")]
+use crate::grisubal::routines::{clip_left, clip_left_par, clip_right, clip_right_par};
 use crate::grisubal::routines::{
     compute_intersection_ids, generate_edge_data, generate_intersection_data,
     group_intersections_per_edge, insert_edges_in_map, insert_intersections,
 };
-use honeycomb_core::prelude::{CMapBuilder, GridDescriptor, Orbit2, OrbitPolicy, Vertex2};
+use crate::grisubal::{
+    grisubal_from_geometry, grisubal_kernel, grisubal_with_report, suggest_cell_size, Clip,
+    GrisubalError, GrisubalParams,
+};
+use crate::quality::signed_face_area;
+use honeycomb_core::prelude::{
+    CMap2, CMapBuilder, DartIdType, GridDescriptor, Orbit2, OrbitPolicy, Vertex2,
+};
 use vtkio::Vtk;
 // ------ CONTENT
 
@@ -112,6 +122,88 @@ fn build_valid_geometry() {
     assert!(poi.contains(&8));
 }
 
+#[test]
+fn build_geometry_from_map_boundary() {
+    let cmap: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    let mut buffer = Vec::new();
+    cmap.write_boundary_vtk(&mut buffer);
+    // re-import
+    let vtk = Vtk::parse_legacy_be(buffer.as_slice()).unwrap();
+    let geometry: Geometry2<f64> = Geometry2::try_from(vtk).unwrap();
+    // check results; we're expecting:
+    // - 8 vertices on the boundary of the grid
+    // - 8 segments making up a single closed loop
+    // - 4 points of interest at the corners of the grid
+    assert_eq!(geometry.vertices.len(), 8);
+    assert_eq!(geometry.segments.len(), 8);
+    let loops = geometry.boundary_loops();
+    assert_eq!(loops.len(), 1);
+    assert!(geometry.is_closed(&loops[0]));
+    assert!((geometry.signed_area(&loops[0]) - 4.0).abs() < 1e-10);
+    assert_eq!(geometry.poi.len(), 4);
+    for corner in [(0., 0.), (2., 0.), (2., 2.), (0., 2.)] {
+        assert!(geometry
+            .poi
+            .iter()
+            .any(|&id| geometry.vertices[id] == Vertex2::from(corner)));
+    }
+}
+
+#[test]
+fn suggested_cell_size() {
+    // square with a thin notch; the shortest feature is the 0.1-wide opening
+    let geometry: Geometry2<f64> = Geometry2 {
+        vertices: vec![
+            Vertex2(0.0, 0.0),
+            Vertex2(1.0, 0.0),
+            Vertex2(1.0, 1.0),
+            Vertex2(0.55, 1.0),
+            Vertex2(0.55, 0.5),
+            Vertex2(0.45, 0.5),
+            Vertex2(0.45, 1.0),
+            Vertex2(0.0, 1.0),
+        ],
+        segments: vec![
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+            (7, 0),
+        ],
+        poi: vec![],
+    };
+    let size = suggest_cell_size(&geometry);
+    assert!((size - 0.1 / 4.0).abs() < 1e-10);
+
+    // scaling the geometry scales the suggestion
+    let scaled = Geometry2 {
+        vertices: geometry
+            .vertices
+            .iter()
+            .map(|v| Vertex2(v.x() * 10.0, v.y() * 10.0))
+            .collect(),
+        segments: geometry.segments.clone(),
+        poi: vec![],
+    };
+    let scaled_size = suggest_cell_size(&scaled);
+    assert!((scaled_size - 10.0 * size).abs() < 1e-10);
+}
+
+#[test]
+#[should_panic(expected = "geometry contains no non-degenerate segment")]
+fn suggest_cell_size_degenerate() {
+    // degenerate segments are ignored
+    let degenerate: Geometry2<f64> = Geometry2 {
+        vertices: vec![Vertex2(0.0, 0.0), Vertex2(0.0, 0.0)],
+        segments: vec![(0, 1)],
+        poi: vec![],
+    };
+    let _ = suggest_cell_size(&degenerate);
+}
+
 #[allow(clippy::too_many_lines)]
 #[test]
 fn regular_intersections() {
@@ -867,3 +959,539 @@ pub fn successive_diag_intersections() {
         .collect();
     assert_eq!(face34_vertices.len(), 5);
 }
+
+// --- clip
+
+/// Capture `geometry` using a grid of unit cells, without clipping.
+fn captured_map(geometry: &Geometry2<f64>, [nx, ny]: [usize; 2]) -> CMap2<f64> {
+    let mut cmap = CMapBuilder::from(
+        GridDescriptor::default()
+            .len_per_cell([1.0; 3])
+            .n_cells([nx, ny, 0]),
+    )
+    .add_attribute::<Boundary>()
+    .build()
+    .unwrap();
+
+    let (segments, intersection_metadata) =
+        generate_intersection_data(&cmap, geometry, [nx, ny], [1.0, 1.0], Vertex2::default());
+    let n_intersec = intersection_metadata.len();
+    let (edge_intersec, dart_slices) =
+        group_intersections_per_edge(&mut cmap, intersection_metadata);
+    let intersection_darts = compute_intersection_ids(n_intersec, &edge_intersec, &dart_slices);
+    insert_intersections(&cmap, &edge_intersec, &dart_slices);
+    let edges = generate_edge_data(&cmap, geometry, &segments, &intersection_darts);
+    insert_edges_in_map(&mut cmap, &edges);
+
+    cmap
+}
+
+#[cfg(feature = "par-internals")]
+fn two_squares_map() -> CMap2<f64> {
+    // two disjoint squares, each corner in a distinct cell
+    let geometry = Geometry2 {
+        vertices: vec![
+            Vertex2(0.5, 0.5),
+            Vertex2(2.5, 0.5),
+            Vertex2(2.5, 2.5),
+            Vertex2(0.5, 2.5),
+            Vertex2(3.5, 0.5),
+            Vertex2(5.5, 0.5),
+            Vertex2(5.5, 2.5),
+            Vertex2(3.5, 2.5),
+        ],
+        segments: vec![
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+            (7, 4),
+        ],
+        poi: vec![0, 1, 2, 3, 4, 5, 6, 7],
+    };
+
+    captured_map(&geometry, [6, 3])
+}
+
+/// Return a square with a square hole, along with the map capturing it.
+fn nested_squares_map() -> (Geometry2<f64>, CMap2<f64>) {
+    // the outer square is oriented counterclockwise, the inner one clockwise
+    let geometry = Geometry2 {
+        vertices: vec![
+            Vertex2(0.5, 0.5),
+            Vertex2(5.5, 0.5),
+            Vertex2(5.5, 5.5),
+            Vertex2(0.5, 5.5),
+            Vertex2(1.5, 1.5),
+            Vertex2(1.5, 4.5),
+            Vertex2(4.5, 4.5),
+            Vertex2(4.5, 1.5),
+        ],
+        segments: vec![
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+            (7, 4),
+        ],
+        poi: vec![0, 1, 2, 3, 4, 5, 6, 7],
+    };
+    let cmap = captured_map(&geometry, [6, 6]);
+    (geometry, cmap)
+}
+
+#[test]
+fn boundary_loops() {
+    let (mut geometry, _) = nested_squares_map();
+    let loops = geometry.boundary_loops();
+    assert_eq!(loops, vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7]]);
+    assert!(loops.iter().all(|l| geometry.is_closed(l)));
+    assert!((geometry.signed_area(&loops[0]) - 25.0).abs() < 1e-10);
+    assert!((geometry.signed_area(&loops[1]) + 9.0).abs() < 1e-10);
+
+    geometry.segments.remove(5);
+    let loops = geometry.boundary_loops();
+    assert_eq!(loops[1], vec![4, 5, 6]);
+    assert!(!geometry.is_closed(&loops[1]));
+}
+
+#[test]
+fn clip_by_region() {
+    let total_area = |cmap: &CMap2<f64>| {
+        cmap.iter_faces()
+            .map(|fid| signed_face_area(cmap, fid))
+            .sum::<f64>()
+    };
+
+    // outer loop: the hole is kept
+    let (geometry, mut cmap) = nested_squares_map();
+    clip_region(&mut cmap, &geometry, 0).unwrap();
+    assert!((total_area(&cmap) - 25.0).abs() < 1e-10);
+    assert!((cmap.boundary_length() - 20.0).abs() < 1e-10);
+
+    // inner loop: only its interior is kept, whatever its orientation
+    let (geometry, mut cmap) = nested_squares_map();
+    clip_region(&mut cmap, &geometry, 1).unwrap();
+    assert!((total_area(&cmap) - 9.0).abs() < 1e-10);
+    assert!((cmap.boundary_length() - 12.0).abs() < 1e-10);
+
+    let (geometry, mut cmap) = nested_squares_map();
+    assert!(matches!(
+        clip_region(&mut cmap, &geometry, 2),
+        Err(GrisubalError::InvalidShape(_))
+    ));
+}
+
+#[test]
+fn clip_region_open_loop() {
+    // three sides of a square
+    let geometry = Geometry2 {
+        vertices: vec![
+            Vertex2(0.5, 0.5),
+            Vertex2(2.5, 0.5),
+            Vertex2(2.5, 2.5),
+            Vertex2(0.5, 2.5),
+        ],
+        segments: vec![(0, 1), (1, 2), (2, 3)],
+        poi: vec![0, 1, 2, 3],
+    };
+    // the loop is checked before the map is explored, so the geometry doesn't need capturing
+    let mut cmap = CMapBuilder::from(
+        GridDescriptor::default()
+            .len_per_cell([1.0; 3])
+            .n_cells([3, 3, 0]),
+    )
+    .add_attribute::<Boundary>()
+    .build()
+    .unwrap();
+    let n_faces = cmap.iter_faces().count();
+    assert!(matches!(
+        clip_region(&mut cmap, &geometry, 0),
+        Err(GrisubalError::InvalidShape(
+            "selected boundary loop isn't closed"
+        ))
+    ));
+    // the map is left untouched
+    assert_eq!(cmap.iter_faces().count(), n_faces);
+}
+
+#[cfg(feature = "par-internals")]
+/// Return faces of the map as vertex lists, in a layout independent from dart numbering.
+fn face_geometries(cmap: &CMap2<f64>) -> Vec<Vec<(f64, f64)>> {
+    let mut faces: Vec<Vec<(f64, f64)>> = cmap
+        .iter_faces_with_geometry()
+        .map(|(_, vertices)| {
+            let mut face: Vec<(f64, f64)> = vertices.iter().map(|v| (v.x(), v.y())).collect();
+            // start from the lowest vertex so that faces can be compared
+            let start = (0..face.len())
+                .min_by(|a, b| face[*a].partial_cmp(&face[*b]).unwrap())
+                .unwrap_or(0);
+            face.rotate_left(start);
+            face
+        })
+        .collect();
+    faces.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    faces
+}
+
+#[cfg(feature = "par-internals")]
+#[test]
+fn clip_par_matches_seq() {
+    type ClipFn = fn(&mut CMap2<f64>) -> Result<(), GrisubalError>;
+    let variants: [(ClipFn, ClipFn); 2] =
+        [(clip_left, clip_left_par), (clip_right, clip_right_par)];
+    for (seq_clip, par_clip) in variants {
+        // dart numbering isn't deterministic, so maps are compared using their geometry
+        let mut seq = two_squares_map();
+        let mut par = two_squares_map();
+        let n_faces = seq.iter_faces().count();
+        seq_clip(&mut seq).unwrap();
+        par_clip(&mut par).unwrap();
+
+        assert!(par.iter_faces().count() < n_faces);
+        assert_eq!(
+            seq.n_darts() - seq.n_unused_darts(),
+            par.n_darts() - par.n_unused_darts()
+        );
+        assert_eq!(seq.iter_vertices().count(), par.iter_vertices().count());
+        assert_eq!(face_geometries(&seq), face_geometries(&par));
+        assert_eq!(
+            seq.iter_edges().filter(|e| seq.is_i_free::<2>(*e)).count(),
+            par.iter_edges().filter(|e| par.is_i_free::<2>(*e)).count()
+        );
+    }
+}
+
+const VTK_SQUARE: &[u8] = b"
+# vtk DataFile Version 2.0
+square
+ASCII
+
+DATASET UNSTRUCTURED_GRID
+POINTS 4 double
+0.3 0.3 0  2.7 0.3 0  2.7 2.7 0  0.3 2.7 0
+
+CELLS 8 20
+1 0
+1 1
+1 2
+1 3
+2 0 1
+2 1 2
+2 2 3
+2 3 0
+
+CELL_TYPES 8
+1
+1
+1
+1
+3
+3
+3
+3
+";
+
+#[test]
+fn grisubal_clip_sides() {
+    use crate::grisubal::{grisubal, Clip};
+    use honeycomb_core::prelude::CMap2;
+
+    let path = std::env::temp_dir().join("honeycomb_grisubal_clip_square.vtk");
+    std::fs::write(&path, VTK_SQUARE).unwrap();
+    let vertices = |map: &CMap2<f64>| -> Vec<Vertex2<f64>> {
+        map.iter_vertices()
+            .map(|vid| map.force_read_vertex(vid).unwrap())
+            .collect()
+    };
+    let area = |map: &CMap2<f64>| -> f64 {
+        map.iter_faces_with_geometry()
+            .map(|(_, vs)| {
+                (0..vs.len())
+                    .map(|i| {
+                        let (a, b) = (vs[i], vs[(i + 1) % vs.len()]);
+                        a.x() * b.y() - b.x() * a.y()
+                    })
+                    .sum::<f64>()
+                    / 2.0
+            })
+            .sum()
+    };
+    let in_square = |v: &Vertex2<f64>, tol: f64| {
+        (0.3 - tol..=2.7 + tol).contains(&v.x()) && (0.3 - tol..=2.7 + tol).contains(&v.y())
+    };
+
+    // the square is counterclockwise: its interior is on the left side of the boundary
+    let map = grisubal(&path, [1.0, 1.0], Clip::Left).unwrap();
+    let vs = vertices(&map);
+    assert!(vs.iter().all(|v| !in_square(v, -1e-6)));
+    let (min_x, max_x, min_y, max_y) = vs.iter().fold(
+        (f64::MAX, f64::MIN, f64::MAX, f64::MIN),
+        |(x0, x1, y0, y1), v| (x0.min(v.x()), x1.max(v.x()), y0.min(v.y()), y1.max(v.y())),
+    );
+    let grid_area = (max_x - min_x) * (max_y - min_y);
+    assert!((area(&map) - (grid_area - 2.4 * 2.4)).abs() < 1e-6);
+
+    let map = grisubal(&path, [1.0, 1.0], Clip::Right).unwrap();
+    let vs = vertices(&map);
+    assert!(vs.iter().all(|v| in_square(v, 1e-6)));
+    // corners, intersections of the boundary with the grid, and inner grid vertices
+    assert_eq!(vs.len(), 4 + 8 + 4);
+    assert_eq!(map.iter_faces().count(), 9);
+    assert!((area(&map) - 2.4 * 2.4).abs() < 1e-6);
+}
+
+// --- in-memory input
+
+#[test]
+fn grisubal_in_memory() {
+    // counterclockwise square
+    let square = || Geometry2 {
+        vertices: vec![
+            Vertex2(0.5, 0.5),
+            Vertex2(2.5, 0.5),
+            Vertex2(2.5, 2.5),
+            Vertex2(0.5, 2.5),
+        ],
+        segments: vec![(0, 1), (1, 2), (2, 3), (3, 0)],
+        poi: vec![0, 1, 2, 3],
+    };
+    let total_area = |cmap: &CMap2<f64>| {
+        cmap.iter_faces()
+            .map(|f| signed_face_area(cmap, f))
+            .sum::<f64>()
+    };
+
+    // the overlapping grid has 5x5 cells
+    let cmap = grisubal_from_geometry(square(), [1., 1.], Clip::None).unwrap();
+    assert!((total_area(&cmap) - 25.0).abs() < 1e-10);
+    // the interior of the square is on the left side of its boundary
+    let cmap = grisubal_from_geometry(square(), [1., 1.], Clip::Right).unwrap();
+    assert!((total_area(&cmap) - 4.0).abs() < 1e-10);
+    let cmap = grisubal_from_geometry(square(), [1., 1.], Clip::Left).unwrap();
+    assert!((total_area(&cmap) - 21.0).abs() < 1e-10);
+
+    // inconsistently oriented geometries are rejected
+    let mut reversed = square();
+    reversed.segments[1] = (2, 1);
+    assert!(matches!(
+        grisubal_from_geometry(reversed, [1., 1.], Clip::None),
+        Err(GrisubalError::InconsistentOrientation(_))
+    ));
+}
+
+#[test]
+#[allow(clippy::cast_possible_truncation)]
+fn keep_boundary() {
+    let square = || Geometry2 {
+        vertices: vec![
+            Vertex2(0.5, 0.5),
+            Vertex2(2.5, 0.5),
+            Vertex2(2.5, 2.5),
+            Vertex2(0.5, 2.5),
+        ],
+        segments: vec![(0, 1), (1, 2), (2, 3), (3, 0)],
+        poi: vec![0, 1, 2, 3],
+    };
+    let count = |cmap: &CMap2<f64>, side: Boundary| {
+        (1..cmap.n_darts() as DartIdType)
+            .filter(|d| cmap.force_read_attribute::<Boundary>(*d) == Some(side))
+            .count()
+    };
+
+    let (cmap, _) = grisubal_kernel(
+        square(),
+        [1., 1.],
+        Clip::None,
+        &GrisubalParams {
+            keep_boundary: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    // each side of the square is cut in three by the grid
+    assert_eq!(count(&cmap, Boundary::Left), 12);
+    assert_eq!(count(&cmap, Boundary::Right), 12);
+    // faces of left darts make up the interior of the (counterclockwise) square, except for the
+    // central cell, which isn't adjacent to the boundary
+    let mut interior_faces: Vec<_> = (1..cmap.n_darts() as DartIdType)
+        .filter(|d| cmap.force_read_attribute::<Boundary>(*d) == Some(Boundary::Left))
+        .map(|d| cmap.face_id(d))
+        .collect();
+    interior_faces.sort_unstable();
+    interior_faces.dedup();
+    let area: f64 = interior_faces
+        .iter()
+        .map(|f| signed_face_area(&cmap, *f))
+        .sum();
+    assert!((area - 3.0).abs() < 1e-10);
+
+    // clipped darts are removed along with their faces
+    let (cmap, _) = grisubal_kernel(
+        square(),
+        [1., 1.],
+        Clip::Right,
+        &GrisubalParams {
+            keep_boundary: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(count(&cmap, Boundary::Left), 12);
+    assert_eq!(count(&cmap, Boundary::Right), 0);
+}
+
+#[test]
+fn geometry_from_segments() {
+    let vertices = vec![
+        Vertex2(0.5, 0.5),
+        Vertex2(2.5, 0.5),
+        Vertex2(2.5, 2.5),
+        Vertex2(0.5, 2.5),
+    ];
+    let geometry = Geometry2::from_segments(
+        vertices.clone(),
+        vec![(0, 1), (1, 2), (2, 3), (3, 0)],
+        vec![0, 2],
+    )
+    .unwrap();
+    assert_eq!(geometry.boundary_loops(), vec![vec![0, 1, 2, 3]]);
+    assert_eq!(geometry.poi, vec![0, 2]);
+
+    // undefined vertices
+    assert!(matches!(
+        Geometry2::from_segments(vertices.clone(), vec![(0, 1), (1, 4)], vec![]),
+        Err(GrisubalError::InvalidShape(_))
+    ));
+    assert!(matches!(
+        Geometry2::from_segments(
+            vertices.clone(),
+            vec![(0, 1), (1, 2), (2, 3), (3, 0)],
+            vec![7]
+        ),
+        Err(GrisubalError::InvalidShape(_))
+    ));
+    // inconsistent orientation
+    assert!(matches!(
+        Geometry2::from_segments(
+            vertices.clone(),
+            vec![(0, 1), (2, 1), (2, 3), (3, 0)],
+            vec![]
+        ),
+        Err(GrisubalError::InconsistentOrientation(_))
+    ));
+    // open boundary
+    assert!(matches!(
+        Geometry2::from_segments(vertices, vec![(0, 1), (1, 2), (2, 3)], vec![]),
+        Err(GrisubalError::InvalidShape(_))
+    ));
+}
+
+// --- reporting
+
+#[test]
+fn grisubal_report() {
+    // square with an additional PoI landing on the grid, in the middle of the bottom segment
+    let vtk = b"# vtk DataFile Version 2.0
+square
+ASCII
+
+DATASET UNSTRUCTURED_GRID
+POINTS 5 float
+0.5 0.5 0  1 0.5 0  2.5 0.5 0
+2.5 2.5 0  0.5 2.5 0
+
+CELLS 10 25
+1 0
+1 1
+1 2
+1 3
+1 4
+2 0 1
+2 1 2
+2 2 3
+2 3 4
+2 4 0
+
+CELL_TYPES 10
+1
+1
+1
+1
+1
+3
+3
+3
+3
+3
+";
+    let path = std::env::temp_dir().join("honeycomb_grisubal_report.vtk");
+    std::fs::write(&path, vtk).unwrap();
+    let (_, report) = grisubal_with_report::<f64>(&path, [1., 1.], Clip::None).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    // the grid has a ~one-and-a-half cell buffer around the geometry
+    assert_eq!(report.grid_dims, [5, 5]);
+    assert_eq!(report.origin, Vertex2(-1., -1.));
+    assert_eq!(report.removed_poi, vec![1]);
+    // each side of the square crosses two grid lines, on distinct edges
+    assert_eq!(report.intersections_per_edge.len(), 8);
+    assert!(report.intersections_per_edge.values().all(|n| *n == 1));
+}
+
+#[test]
+fn grisubal_params() {
+    // square with two additional PoIs close to the grid: one near a corner, one near a line
+    let geometry = || Geometry2 {
+        vertices: vec![
+            Vertex2(0.5, 0.5),
+            Vertex2(2.5, 0.5),
+            Vertex2(2.5, 2.5),
+            Vertex2(0.5, 2.5),
+            Vertex2(1.0004, 1.0004),
+            Vertex2(1.5, 2.0004),
+        ],
+        segments: vec![(0, 1), (1, 2), (2, 3), (3, 0)],
+        poi: vec![0, 1, 2, 3, 4, 5],
+    };
+    let run = |params: GrisubalParams<f64>| {
+        grisubal_kernel(geometry(), [1., 1.], Clip::None, &params).map(|(_, r)| r)
+    };
+
+    // default parameters only detect exact overlaps
+    let report = run(GrisubalParams::default()).unwrap();
+    assert_eq!(report.origin, Vertex2(-1., -1.));
+    assert!(report.removed_poi.is_empty());
+
+    // the grid is shifted away from the vertex near a corner
+    let report = run(GrisubalParams {
+        snap_tolerance: 1e-3,
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(report.origin, Vertex2(-0.75, -0.75));
+    assert!(report.removed_poi.is_empty());
+
+    // PoIs close to a grid line are removed
+    let report = run(GrisubalParams {
+        poi_merge_distance: 1e-3,
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(report.removed_poi, vec![4, 5]);
+
+    // every vertex is within half a cell of a grid line
+    assert!(matches!(
+        run(GrisubalParams {
+            snap_tolerance: 0.5,
+            ..Default::default()
+        }),
+        Err(GrisubalError::InvalidShape(_))
+    ));
+}
@@ -15,6 +15,13 @@
 
 // ------ MODULE DECLARATIONS
 
+pub mod dual;
 pub mod grisubal;
+pub mod locate;
+pub mod quality;
+pub mod remeshing;
+pub mod sanitize;
 pub mod splits;
 pub mod triangulation;
+pub mod utils;
+pub mod voronoi;
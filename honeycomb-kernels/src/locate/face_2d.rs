@@ -0,0 +1,95 @@
+//! `locate_containing_face_2d` and `barycentric_coords_2d` functions
+
+// ------ IMPORTS
+
+use crate::triangulation::crossp_from_verts;
+use honeycomb_core::cmap::{CMap2, DartIdType, FaceIdType, VertexIdType, NULL_DART_ID};
+use honeycomb_core::geometry::{CoordsFloat, Vertex2};
+
+// ------ CONTENT
+
+#[must_use = "unused return value"]
+/// Compute the barycentric coordinates of a point in a triangle.
+///
+/// # Arguments
+///
+/// - `p: &Vertex2<T>` -- Point of which coordinates are computed.
+/// - `a: &Vertex2<T>`, `b: &Vertex2<T>`, `c: &Vertex2<T>` -- Vertices of the triangle.
+///
+/// # Return
+///
+/// Return the coordinates `[la, lb, lc]` of `p`, such that `p = la * a + lb * b + lc * c` and
+/// `la + lb + lc = 1`, or `None` if the triangle is degenerate. The point is inside of the
+/// triangle if all three coordinates are positive.
+pub fn barycentric_coords_2d<T: CoordsFloat>(
+    p: &Vertex2<T>,
+    a: &Vertex2<T>,
+    b: &Vertex2<T>,
+    c: &Vertex2<T>,
+) -> Option<[T; 3]> {
+    let area = crossp_from_verts(a, b, c);
+    if area.abs() < T::epsilon() {
+        return None;
+    }
+    Some([
+        crossp_from_verts(p, b, c) / area,
+        crossp_from_verts(a, p, c) / area,
+        crossp_from_verts(a, b, p) / area,
+    ])
+}
+
+#[allow(clippy::missing_panics_doc, clippy::many_single_char_names)]
+#[must_use = "unused return value"]
+/// Find the face containing a point.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// Faces are split into triangles using a fan centered on their first vertex, so they are
+/// expected to be convex. Points located on an edge or vertex may be attributed to any of the
+/// adjacent faces.
+///
+/// Faces are searched exhaustively, resulting in a `O(n_faces)` complexity.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the map.
+/// - `p: &Vertex2<T>` -- Point to locate.
+///
+/// # Return
+///
+/// Return `Some((face_id, weights))` if the point is located inside of the face `face_id`.
+/// `weights` contains the vertices of the triangle of the fan containing `p`, along with the
+/// barycentric coordinates of `p` in this triangle. Return `None` if the point is located outside
+/// of all faces with fully defined vertices.
+pub fn locate_containing_face_2d<T: CoordsFloat>(
+    map: &CMap2<T>,
+    p: &Vertex2<T>,
+) -> Option<(FaceIdType, [(VertexIdType, T); 3])> {
+    // tolerance so that points on edges are not missed due to rounding errors
+    let tol = T::from(1e-10).unwrap();
+    map.iter_faces().find_map(|fid| {
+        // walk through the face in order; orbits do not guarantee it
+        let mut corners = Vec::new();
+        let mut d = fid as DartIdType;
+        loop {
+            let vid = map.vertex_id(d);
+            corners.push((vid, map.force_read_vertex(vid)?));
+            d = map.beta::<1>(d);
+            if d == fid as DartIdType || d == NULL_DART_ID {
+                break;
+            }
+        }
+        let (va, a) = corners[0];
+        corners.windows(2).skip(1).find_map(|w| {
+            let [(vb, b), (vc, c)] = [w[0], w[1]];
+            let [la, lb, lc] = barycentric_coords_2d(p, &a, &b, &c)?;
+            if la >= -tol && lb >= -tol && lc >= -tol {
+                Some((fid, [(va, la), (vb, lb), (vc, lc)]))
+            } else {
+                None
+            }
+        })
+    })
+}
@@ -0,0 +1,49 @@
+//! Point location functions
+//!
+//! This module contains implementations of point location methods, i.e. routines searching for
+//! the cell of a mesh containing a given point. We currently define:
+//! - 2D face location -- an exhaustive search through the faces of a `CMap2`, along with a
+//!   barycentric coordinates helper
+//! - 3D tetrahedral location -- a visibility walk through the tetrahedra of a `CMap3`
+
+// ------ MODULE DECLARATIONS
+
+mod face_2d;
+mod tet_3d;
+
+// ------ PUBLIC RE-EXPORTS
+
+pub use face_2d::{barycentric_coords_2d, locate_containing_face_2d};
+use honeycomb_core::stm::StmError;
+pub use tet_3d::{force_locate_tet_3d, locate_tet_3d};
+
+// ------ CONTENT
+
+/// Error-modeling enum for point location routines.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum LocateError {
+    /// STM transaction failed.
+    #[error("transaction failed")]
+    FailedTransaction(/*#[from]*/ StmError),
+    /// A volume crossed by the walk isn't a tetrahedron.
+    #[error("crossed a volume that isn't a tetrahedron")]
+    NonTetrahedralVolume,
+    /// A vertex of a volume crossed by the walk is undefined.
+    #[error("crossed a volume with undefined vertices")]
+    UndefinedVertex,
+    /// The walk did not reach the point within the maximum number of steps. The `usize` value is
+    /// the number of steps taken.
+    #[error("walk did not terminate after {0} steps")]
+    WalkTooLong(usize),
+}
+
+impl From<StmError> for LocateError {
+    fn from(value: StmError) -> Self {
+        Self::FailedTransaction(value)
+    }
+}
+
+// ------ TESTS
+
+#[cfg(test)]
+mod tests;
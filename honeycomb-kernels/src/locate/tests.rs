@@ -0,0 +1,183 @@
+// ------ IMPORTS
+
+use std::fmt::Write;
+
+use honeycomb_core::cmap::{
+    CMap2, CMap3, CMapBuilder, DartIdType, Orbit2, OrbitPolicy, VolumeIdType,
+};
+use honeycomb_core::geometry::{Vertex2, Vertex3};
+use honeycomb_core::stm::atomically;
+
+use crate::locate::{
+    barycentric_coords_2d, force_locate_tet_3d, locate_containing_face_2d, locate_tet_3d,
+    LocateError,
+};
+use crate::quality::count_inverted_tets;
+
+// ------ CONTENT
+
+/// Build a `n * n * n` grid of unit cubes, each split into six positively oriented tetrahedra.
+fn tet_grid(n: usize) -> CMap3<f64> {
+    let vid = |x: usize, y: usize, z: usize| 1 + x + (n + 1) * (y + (n + 1) * z);
+    let mut contents = format!(
+        "MeshVersionFormatted 2\nDimension 3\nVertices\n{}\n",
+        (n + 1).pow(3)
+    );
+    for z in 0..=n {
+        for y in 0..=n {
+            for x in 0..=n {
+                writeln!(contents, "{x} {y} {z} 0").unwrap();
+            }
+        }
+    }
+    // each tetrahedron goes from the lowest to the highest corner of the cube, along a path of
+    // two intermediate corners; paths following an odd permutation of the axes are swapped to
+    // keep tetrahedra positively oriented
+    let paths: [[[usize; 3]; 2]; 6] = [
+        [[1, 0, 0], [1, 1, 0]],
+        [[0, 1, 0], [0, 1, 1]],
+        [[0, 0, 1], [1, 0, 1]],
+        [[1, 0, 1], [1, 0, 0]],
+        [[1, 1, 0], [0, 1, 0]],
+        [[0, 1, 1], [0, 0, 1]],
+    ];
+    writeln!(contents, "Tetrahedra\n{}", 6 * n.pow(3)).unwrap();
+    for z in 0..n {
+        for y in 0..n {
+            for x in 0..n {
+                for [[ax, ay, az], [bx, by, bz]] in paths {
+                    writeln!(
+                        contents,
+                        "{} {} {} {} 0",
+                        vid(x, y, z),
+                        vid(x + ax, y + ay, z + az),
+                        vid(x + bx, y + by, z + bz),
+                        vid(x + 1, y + 1, z + 1),
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+    contents.push_str("End\n");
+
+    let path = std::env::temp_dir().join(format!("honeycomb_locate_tet_grid_{n}.mesh"));
+    std::fs::write(&path, contents).unwrap();
+    let map = CMap3::from_medit_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    map
+}
+
+/// Return `true` if `p` lies strictly inside the (positively oriented) tetrahedron `vid`.
+fn tet_contains(map: &CMap3<f64>, vid: VolumeIdType, p: Vertex3<f64>) -> bool {
+    let d = vid as DartIdType;
+    let corners: Vec<Vertex3<f64>> = [
+        d,
+        map.beta::<1>(d),
+        map.beta::<0>(d),
+        map.beta::<0>(map.beta::<2>(d)),
+    ]
+    .into_iter()
+    .map(|dart| map.force_read_vertex(map.vertex_id(dart)).unwrap())
+    .collect();
+    let signed_volume = |[a, b, c, d]: [Vertex3<f64>; 4]| (b - a).cross(&(c - a)).dot(&(d - a));
+    assert!(signed_volume([corners[0], corners[1], corners[2], corners[3]]) > 0.0);
+    // replacing each corner by `p` yields a positive volume iff `p` is on the inner side of the
+    // opposite face
+    (0..4).all(|i| {
+        let mut sub = [corners[0], corners[1], corners[2], corners[3]];
+        sub[i] = p;
+        signed_volume(sub) > 0.0
+    })
+}
+
+#[test]
+fn locate_inside() {
+    let map = tet_grid(3);
+    assert_eq!(map.iter_volumes().count(), 162);
+    assert_eq!(count_inverted_tets(&map), 0);
+
+    let starts: Vec<_> = map.iter_volumes().step_by(40).collect();
+    for p in [
+        Vertex3(0.3, 0.4, 0.6),
+        Vertex3(2.7, 1.2, 0.1),
+        Vertex3(1.5, 2.6, 2.9),
+    ] {
+        let found = force_locate_tet_3d(&map, starts[0], p).unwrap().unwrap();
+        assert!(tet_contains(&map, found, p));
+        // the point is strictly inside the tet, so the result doesn't depend on the start
+        for start in &starts {
+            assert_eq!(force_locate_tet_3d(&map, *start, p), Ok(Some(found)));
+        }
+        // transactional variant
+        let res = atomically(|trans| match locate_tet_3d(trans, &map, found, p) {
+            Err(LocateError::FailedTransaction(stme)) => Err(stme),
+            other => Ok(other),
+        });
+        assert_eq!(res, Ok(Some(found)));
+    }
+}
+
+#[test]
+fn locate_outside() {
+    let map = tet_grid(2);
+    let start = map.iter_volumes().next().unwrap();
+    for p in [
+        Vertex3(-0.5, 1.0, 1.0),
+        Vertex3(1.0, 1.0, 10.0),
+        Vertex3(2.5, 2.5, 2.5),
+    ] {
+        assert_eq!(force_locate_tet_3d(&map, start, p), Ok(None));
+    }
+}
+
+#[test]
+fn locate_undefined_vertex() {
+    let map = tet_grid(1);
+    let start = map.iter_volumes().next().unwrap();
+    map.force_remove_vertex(map.vertex_id(start as DartIdType));
+    assert_eq!(
+        force_locate_tet_3d(&map, start, Vertex3(0.5, 0.5, 0.5)),
+        Err(LocateError::UndefinedVertex)
+    );
+}
+
+// --- 2D face location
+
+#[test]
+fn barycentric_coords() {
+    let (a, b, c) = (Vertex2(0.0_f64, 0.0), Vertex2(2.0, 0.0), Vertex2(0.0, 2.0));
+    let [la, lb, lc] = barycentric_coords_2d(&Vertex2(0.5, 0.5), &a, &b, &c).unwrap();
+    assert!((la - 0.5).abs() < 1e-10);
+    assert!((lb - 0.25).abs() < 1e-10);
+    assert!((lc - 0.25).abs() < 1e-10);
+    // outside points have negative coordinates
+    let coords = barycentric_coords_2d(&Vertex2(2.0, 2.0), &a, &b, &c).unwrap();
+    assert!(coords.iter().any(|l| *l < 0.0));
+    // degenerate triangle
+    assert!(barycentric_coords_2d(&Vertex2(0.5, 0.5), &a, &b, &Vertex2(1.0, 0.0)).is_none());
+}
+
+#[test]
+fn locate_face_2d() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    for p in [Vertex2(0.5, 0.5), Vertex2(1.75, 0.25), Vertex2(0.3, 1.9)] {
+        let (fid, weights) = locate_containing_face_2d(&map, &p).unwrap();
+        // the face is the grid cell containing the point
+        let corners: Vec<_> = Orbit2::new(&map, OrbitPolicy::Face, fid as DartIdType)
+            .map(|d| map.force_read_vertex(map.vertex_id(d)).unwrap())
+            .collect();
+        assert!(corners
+            .iter()
+            .all(|v| (v.x() - p.x()).abs() <= 1.0 && (v.y() - p.y()).abs() <= 1.0));
+        // weights interpolate the point
+        let (x, y) = weights.iter().fold((0.0, 0.0), |(x, y), (vid, l)| {
+            let v = map.force_read_vertex(*vid).unwrap();
+            (x + l * v.x(), y + l * v.y())
+        });
+        assert!((x - p.x()).abs() < 1e-10);
+        assert!((y - p.y()).abs() < 1e-10);
+    }
+    assert!(locate_containing_face_2d(&map, &Vertex2(2.5, 1.0)).is_none());
+    assert!(locate_containing_face_2d(&map, &Vertex2(-0.5, -0.5)).is_none());
+}
@@ -0,0 +1,133 @@
+//! standard and convenience variants of the `locate_tet_3d` function
+
+// ------ IMPORTS
+
+use crate::locate::LocateError;
+use honeycomb_core::cmap::{CMap3, DartIdType, VolumeIdType, NULL_DART_ID};
+use honeycomb_core::geometry::{CoordsFloat, Vertex3};
+use honeycomb_core::stm::{atomically, Transaction};
+
+// ------ CONTENT
+
+#[allow(clippy::missing_errors_doc)]
+/// Find the tetrahedron containing a point.
+///
+/// <div class="warning">
+/// This implementation is 3D specific.
+/// </div>
+///
+/// This function walks through the mesh, starting from the volume `start`, towards the point
+/// `p`: at each step, the walk crosses a face of the current tetrahedron whose plane separates
+/// the tetrahedron from `p`, until reaching a tetrahedron with no such face. Tetrahedra are
+/// expected to be positively oriented (see
+/// [`volume_measure`][crate::quality::volume_measure]). Points located on a face, edge or vertex
+/// may be attributed to any of the adjacent tetrahedra.
+///
+/// # Arguments
+///
+/// - `trans: &mut Transaction` -- Transaction associated to the operation.
+/// - `map: &CMap3<T>` -- Reference to the map. All volumes crossed by the walk are expected to
+///   be tetrahedra.
+/// - `start: VolumeIdType` -- Volume from which the walk starts.
+/// - `p: Vertex3<T>` -- Point to locate.
+///
+/// # Return / Errors
+///
+/// This function will return:
+/// - `Ok(Some(volume_id))` if the point is located inside of the tetrahedron `volume_id`,
+/// - `Ok(None)` if the point is located outside of the mesh, i.e. the walk has to cross a
+///   boundary face,
+/// - `Err(LocateError)` if the walk fails. Causes of failure are described in [`LocateError`]'s
+///   documentation.
+///
+/// The number of steps of the walk is bounded by `n_darts / 12`, i.e. the number of tetrahedra
+/// the map can hold. Exceeding it, which may happen on inverted or degenerate tetrahedra,
+/// results in a [`LocateError::WalkTooLong`] error.
+///
+/// On non-convex meshes, the walk may reach the boundary before the point; in this case, the
+/// point is reported as outside even if another part of the mesh contains it.
+pub fn locate_tet_3d<T: CoordsFloat>(
+    trans: &mut Transaction,
+    map: &CMap3<T>,
+    start: VolumeIdType,
+    p: Vertex3<T>,
+) -> Result<Option<VolumeIdType>, LocateError> {
+    let max_steps = map.n_darts() / 12;
+    let mut d = start as DartIdType;
+    // the face used to enter the current tetrahedron doesn't need to be checked
+    let mut entered = false;
+
+    for _ in 0..=max_steps {
+        let (b0d, b1d) = (
+            map.beta_transac::<0>(trans, d)?,
+            map.beta_transac::<1>(trans, d)?,
+        );
+        let faces = [
+            d,
+            map.beta_transac::<2>(trans, d)?,
+            map.beta_transac::<2>(trans, b1d)?,
+            map.beta_transac::<2>(trans, b0d)?,
+        ];
+
+        let mut next = None;
+        for &f in faces.iter().skip(usize::from(entered)) {
+            if is_separating_face(trans, map, f, &p)? {
+                next = Some(map.beta_transac::<3>(trans, f)?);
+                break;
+            }
+        }
+        match next {
+            None => return Ok(Some(map.volume_id_transac(trans, d)?)),
+            Some(NULL_DART_ID) => return Ok(None),
+            Some(b3f) => {
+                d = b3f;
+                entered = true;
+            }
+        }
+    }
+
+    Err(LocateError::WalkTooLong(max_steps + 1))
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Find the tetrahedron containing a point.
+///
+/// This variant is equivalent to [`locate_tet_3d`], but internally uses a transaction that will
+/// be retried until validated.
+pub fn force_locate_tet_3d<T: CoordsFloat>(
+    map: &CMap3<T>,
+    start: VolumeIdType,
+    p: Vertex3<T>,
+) -> Result<Option<VolumeIdType>, LocateError> {
+    atomically(|trans| match locate_tet_3d(trans, map, start, p) {
+        Ok(res) => Ok(Ok(res)),
+        Err(LocateError::FailedTransaction(stme)) => Err(stme),
+        Err(e) => Ok(Err(e)),
+    })
+}
+
+/// Return `true` if `p` is strictly on the outer side of the triangular face of `dart_id`.
+fn is_separating_face<T: CoordsFloat>(
+    trans: &mut Transaction,
+    map: &CMap3<T>,
+    dart_id: DartIdType,
+    p: &Vertex3<T>,
+) -> Result<bool, LocateError> {
+    let b1d = map.beta_transac::<1>(trans, dart_id)?;
+    let b0d = map.beta_transac::<0>(trans, dart_id)?;
+    if [dart_id, b1d, b0d].contains(&NULL_DART_ID) || map.beta_transac::<1>(trans, b1d)? != b0d {
+        return Err(LocateError::NonTetrahedralVolume);
+    }
+
+    let mut corners = [Vertex3::default(); 3];
+    for (corner, dart) in corners.iter_mut().zip([dart_id, b1d, b0d]) {
+        let vertex_id = map.vertex_id_transac(trans, dart)?;
+        *corner = map
+            .read_vertex(trans, vertex_id)?
+            .ok_or(LocateError::UndefinedVertex)?;
+    }
+    let [a, b, c] = corners;
+    // faces of a positively oriented tetrahedron have normals pointing inward
+    let normal = (b - a).cross(&(c - a));
+    Ok(normal.dot(&(*p - a)) < T::zero())
+}
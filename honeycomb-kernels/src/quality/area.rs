@@ -0,0 +1,83 @@
+//! face area computation functions
+
+// ------ IMPORTS
+
+use honeycomb_core::cmap::{CMap2, DartIdType, FaceIdType, NULL_DART_ID};
+use honeycomb_core::geometry::CoordsFloat;
+use honeycomb_core::stm::atomically;
+
+// ------ CONTENT
+
+/// Compute the area of a face.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// This is the absolute value of [`signed_face_area`]; refer to its documentation for more
+/// information.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the map containing the face.
+/// - `fid: FaceIdType` -- Face of interest.
+#[must_use = "unused return value"]
+pub fn face_area<T: CoordsFloat>(map: &CMap2<T>, fid: FaceIdType) -> T {
+    signed_face_area(map, fid).abs()
+}
+
+/// Compute the signed area of a face.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// The area is computed using the shoelace formula over the vertices of the face, taken in the
+/// order given by β<sub>1</sub>. The result is positive for counter-clockwise faces, and negative
+/// for clockwise (i.e. inverted) faces. Vertices are read using an internal transaction.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the map containing the face.
+/// - `fid: FaceIdType` -- Face of interest.
+///
+/// # Return
+///
+/// Return the signed area of the face. Degenerate faces, i.e. open faces, faces with less than
+/// three vertices, or faces with undefined vertices, have an area of zero.
+///
+/// # Panics
+///
+/// This function may panic if the value `0.5` cannot be converted to `T`.
+#[must_use = "unused return value"]
+pub fn signed_face_area<T: CoordsFloat>(map: &CMap2<T>, fid: FaceIdType) -> T {
+    atomically(|trans| {
+        let mut vertices = Vec::new();
+        let mut d = fid as DartIdType;
+        loop {
+            let vid = map.vertex_id_transac(trans, d)?;
+            let Some(v) = map.read_vertex(trans, vid)? else {
+                return Ok(T::zero());
+            };
+            vertices.push(v);
+            d = map.beta_transac::<1>(trans, d)?;
+            if d == NULL_DART_ID {
+                return Ok(T::zero());
+            }
+            if d == fid as DartIdType {
+                break;
+            }
+        }
+        if vertices.len() < 3 {
+            return Ok(T::zero());
+        }
+
+        let twice_area = vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .fold(T::zero(), |acc, (v1, v2)| {
+                acc + v1.x() * v2.y() - v2.x() * v1.y()
+            });
+        Ok(twice_area * T::from(0.5).unwrap())
+    })
+}
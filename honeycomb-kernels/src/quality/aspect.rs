@@ -0,0 +1,63 @@
+//! face aspect ratio computation functions
+
+// ------ IMPORTS
+
+use crate::quality::face_vertices;
+use honeycomb_core::cmap::{CMap2, FaceIdType};
+use honeycomb_core::geometry::CoordsFloat;
+
+// ------ CONTENT
+
+/// Compute the aspect ratio of a face.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// For triangles, this is the radius ratio `2 * r / R`, where `r` is the radius of the inscribed
+/// circle and `R` the radius of the circumscribed circle. For other faces, this is the ratio of
+/// the shortest edge length to the longest one.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the map containing the face.
+/// - `fid: FaceIdType` -- Face of interest.
+///
+/// # Return
+///
+/// Return a value in `[0; 1]`; `1` corresponds to an equilateral triangle (or to a face with
+/// edges of equal length), `0` to a degenerate face. Open faces, faces with less than three
+/// vertices, or faces with undefined vertices, have an aspect ratio of `0`.
+///
+/// # Panics
+///
+/// This function may panic if the value `4` cannot be converted to `T`.
+#[must_use = "unused return value"]
+pub fn face_aspect_ratio<T: CoordsFloat>(map: &CMap2<T>, fid: FaceIdType) -> T {
+    let Some(vertices) = face_vertices(map, fid) else {
+        return T::zero();
+    };
+    let n = vertices.len();
+
+    let lengths: Vec<T> = (0..n)
+        .map(|i| (vertices[(i + 1) % n] - vertices[i]).norm())
+        .collect();
+    let (min, max) = lengths
+        .iter()
+        .fold((T::infinity(), T::zero()), |(min, max), l| {
+            (min.min(*l), max.max(*l))
+        });
+    if min <= T::zero() {
+        return T::zero();
+    }
+    if n != 3 {
+        return min / max;
+    }
+
+    // 2 * r / R, with r = 2A / P & R = abc / 4A
+    let (ab, ac) = (vertices[1] - vertices[0], vertices[2] - vertices[0]);
+    let twice_area = (ab.x() * ac.y() - ab.y() * ac.x()).abs();
+    let perimeter = lengths[0] + lengths[1] + lengths[2];
+    let product = lengths[0] * lengths[1] * lengths[2];
+    (T::from(4.0).unwrap() * twice_area * twice_area / (perimeter * product)).min(T::one())
+}
@@ -0,0 +1,55 @@
+//! Mesh quality metrics
+//!
+//! This module contains implementations of geometric measures used to evaluate the quality of
+//! a mesh. We currently define:
+//! - face area -- signed & unsigned area of polygonal faces
+//! - face aspect ratio -- radius ratio of triangles, edge length ratio of other faces
+//! - face skewness -- equiangle skewness of polygonal faces, and its distribution over a map
+//! - tetrahedron volume -- signed volume of tetrahedra, used to detect inverted cells
+
+// ------ MODULE DECLARATIONS
+
+mod area;
+mod aspect;
+mod skewness;
+mod volume;
+
+// ------ PUBLIC RE-EXPORTS
+
+pub use area::{face_area, signed_face_area};
+pub use aspect::face_aspect_ratio;
+#[cfg(feature = "par-internals")]
+pub use skewness::par_skewness_histogram_2d;
+pub use skewness::{face_skewness, skewness_histogram_2d, SkewnessStats};
+pub use volume::{count_inverted_tets, volume_measure};
+
+// ------ IMPORTS
+
+use honeycomb_core::cmap::{CMap2, DartIdType, FaceIdType, NULL_DART_ID};
+use honeycomb_core::geometry::{CoordsFloat, Vertex2};
+
+// ------ CONTENT
+
+/// Return the vertices of a face, in the order given by β<sub>1</sub>.
+///
+/// Return `None` if the face is open, has less than three vertices, or has undefined vertices.
+fn face_vertices<T: CoordsFloat>(map: &CMap2<T>, fid: FaceIdType) -> Option<Vec<Vertex2<T>>> {
+    let mut vertices = Vec::new();
+    let mut d = fid as DartIdType;
+    loop {
+        vertices.push(map.force_read_vertex(map.vertex_id(d))?);
+        d = map.beta::<1>(d);
+        if d == NULL_DART_ID {
+            return None;
+        }
+        if d == fid as DartIdType {
+            break;
+        }
+    }
+    (vertices.len() >= 3).then_some(vertices)
+}
+
+// ------ TESTS
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,167 @@
+//! face skewness computation functions
+
+// ------ IMPORTS
+
+use crate::quality::face_vertices;
+use honeycomb_core::cmap::{CMap2, FaceIdType};
+use honeycomb_core::geometry::CoordsFloat;
+
+// ------ CONTENT
+
+/// Summary statistics of face skewness over a map.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkewnessStats<T: CoordsFloat> {
+    /// Smallest skewness value.
+    pub min: T,
+    /// Largest skewness value.
+    pub max: T,
+    /// Average skewness value.
+    pub mean: T,
+}
+
+/// Compute the equiangle skewness of a face.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// The skewness of a face with `n` vertices is computed from its largest & smallest angles,
+/// `a_max` and `a_min`, and the angle of the equiangular polygon with the same number of
+/// vertices, `a_eq = (n - 2) * pi / n`:
+///
+/// ```text
+/// max((a_max - a_eq) / (pi - a_eq), (a_eq - a_min) / a_eq)
+/// ```
+///
+/// The face is expected to be counter-clockwise, i.e. to have a positive
+/// [`signed_face_area`][super::signed_face_area].
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the map containing the face.
+/// - `fid: FaceIdType` -- Face of interest.
+///
+/// # Return
+///
+/// Return a value in `[0; 1]`; `0` corresponds to an equiangular face, `1` to a degenerate one.
+/// Open faces, faces with less than three vertices, or faces with undefined vertices, have a
+/// skewness of `1`.
+///
+/// # Panics
+///
+/// This function may panic if `pi` or the face's vertex count cannot be converted to `T`.
+#[must_use = "unused return value"]
+pub fn face_skewness<T: CoordsFloat>(map: &CMap2<T>, fid: FaceIdType) -> T {
+    let Some(vertices) = face_vertices(map, fid) else {
+        return T::one();
+    };
+    let n = vertices.len();
+
+    let pi = T::from(std::f64::consts::PI).unwrap();
+    let two_pi = pi + pi;
+    let (mut a_min, mut a_max) = (two_pi, T::zero());
+    for i in 0..n {
+        let v = vertices[i];
+        let (prev, next) = (vertices[(i + n - 1) % n] - v, vertices[(i + 1) % n] - v);
+        // interior angle, measured from `next` to `prev`
+        let mut angle = (next.x() * prev.y() - next.y() * prev.x()).atan2(next.dot(&prev));
+        if angle < T::zero() {
+            angle += two_pi;
+        }
+        a_min = a_min.min(angle);
+        a_max = a_max.max(angle);
+    }
+    let n = T::from(n).unwrap();
+    let a_eq = (n - T::from(2).unwrap()) * pi / n;
+    ((a_max - a_eq) / (pi - a_eq))
+        .max((a_eq - a_min) / a_eq)
+        .min(T::one())
+        .max(T::zero())
+}
+
+/// Compute a histogram of face skewness over a map.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// The `[0; 1]` range is divided into `n_buckets` buckets of equal width; each face of the map
+/// is counted in the bucket containing its [`face_skewness`]. A skewness of exactly `1` is
+/// counted in the last bucket.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the map.
+/// - `n_buckets: usize` -- Number of buckets of the histogram.
+///
+/// # Return
+///
+/// Return the count of faces per bucket, along with the [`SkewnessStats`] of the map, or `None`
+/// if the map has no faces.
+///
+/// # Panics
+///
+/// This function will panic if `n_buckets` is zero.
+#[must_use = "unused return value"]
+pub fn skewness_histogram_2d<T: CoordsFloat>(
+    map: &CMap2<T>,
+    n_buckets: usize,
+) -> (Vec<usize>, Option<SkewnessStats<T>>) {
+    let values: Vec<T> = map
+        .iter_faces()
+        .map(|fid| face_skewness(map, fid))
+        .collect();
+    histogram(&values, n_buckets)
+}
+
+#[cfg(feature = "par-internals")]
+/// Compute a histogram of face skewness over a map, in parallel.
+///
+/// This function yields the same result as [`skewness_histogram_2d`], but is intended for large
+/// maps.
+///
+/// # Panics
+///
+/// This function will panic if `n_buckets` is zero.
+#[must_use = "unused return value"]
+pub fn par_skewness_histogram_2d<T: CoordsFloat>(
+    map: &CMap2<T>,
+    n_buckets: usize,
+) -> (Vec<usize>, Option<SkewnessStats<T>>) {
+    use rayon::prelude::*;
+
+    let fids: Vec<FaceIdType> = map.iter_faces().collect();
+    let values: Vec<T> = fids
+        .into_par_iter()
+        .map(|fid| face_skewness(map, fid))
+        .collect();
+    histogram(&values, n_buckets)
+}
+
+// --- common inner routines
+
+/// Bucket `values` & compute their statistics.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn histogram<T: CoordsFloat>(
+    values: &[T],
+    n_buckets: usize,
+) -> (Vec<usize>, Option<SkewnessStats<T>>) {
+    assert_ne!(n_buckets, 0, "E: histogram must have at least one bucket");
+    let mut counts = vec![0; n_buckets];
+    let width = T::from(n_buckets).unwrap();
+    for val in values {
+        let idx = (*val * width).floor().to_usize().unwrap_or(0);
+        counts[idx.min(n_buckets - 1)] += 1;
+    }
+    let stats = if values.is_empty() {
+        None
+    } else {
+        let sum = values.iter().fold(T::zero(), |acc, v| acc + *v);
+        Some(SkewnessStats {
+            min: values.iter().copied().fold(T::infinity(), T::min),
+            max: values.iter().copied().fold(T::neg_infinity(), T::max),
+            mean: sum / T::from(values.len()).unwrap(),
+        })
+    };
+    (counts, stats)
+}
@@ -0,0 +1,198 @@
+use std::fmt::Write;
+
+use super::*;
+use honeycomb_core::cmap::{CMap2, CMap3, CMapBuilder, GridDescriptor};
+
+#[test]
+fn area_grid() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    map.iter_faces().for_each(|fid| {
+        assert!((signed_face_area(&map, fid) - 1.0).abs() < f64::EPSILON);
+    });
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    map.iter_faces().for_each(|fid| {
+        assert!((signed_face_area(&map, fid) - 0.5).abs() < f64::EPSILON);
+    });
+}
+
+#[test]
+fn area_clockwise() {
+    // (0, 0) -> (0, 2) -> (3, 2) -> (3, 0)
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(4).build().unwrap();
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 4);
+    map.force_link::<1>(4, 1);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (0.0, 2.0));
+    map.force_write_vertex(3, (3.0, 2.0));
+    map.force_write_vertex(4, (3.0, 0.0));
+
+    assert!((signed_face_area(&map, 1) + 6.0).abs() < f64::EPSILON);
+    assert!((face_area(&map, 1) - 6.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn area_degenerate() {
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(3).build().unwrap();
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (1.0, 0.0));
+    map.force_write_vertex(3, (0.0, 1.0));
+    // open face
+    assert!(signed_face_area(&map, 1).abs() < f64::EPSILON);
+    // closed face with an undefined vertex
+    map.force_link::<1>(3, 1);
+    assert!((signed_face_area(&map, 1) - 0.5).abs() < f64::EPSILON);
+    map.force_remove_vertex(3);
+    assert!(signed_face_area(&map, 1).abs() < f64::EPSILON);
+    // collinear vertices
+    map.force_write_vertex(3, (2.0, 0.0));
+    assert!(face_area(&map, 1).abs() < f64::EPSILON);
+}
+
+#[test]
+fn skewness_regular() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    assert!(map
+        .iter_faces()
+        .all(|fid| face_skewness(&map, fid).abs() < 1e-10));
+    // right isoceles triangles: (pi/2 - pi/3) / (pi - pi/3)
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    assert!(map
+        .iter_faces()
+        .all(|fid| (face_skewness(&map, fid) - 0.25).abs() < 1e-10));
+}
+
+#[test]
+fn skewness_histogram() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(3).build().unwrap();
+    // flatten the first face, turning it into a degenerate quad
+    map.force_write_vertex(map.vertex_id(3), (1.0, 0.0));
+    let (counts, stats) = skewness_histogram_2d(&map, 4);
+    assert_eq!(counts.iter().sum::<usize>(), 9);
+    // faces not adjacent to the moved vertex are still squares
+    assert_eq!(counts[0], 5);
+    assert!(counts[3] >= 1);
+    let stats = stats.unwrap();
+    assert!(stats.min.abs() < 1e-10);
+    assert!((stats.max - 1.0).abs() < 1e-10);
+    assert!(stats.mean > 0.0 && stats.mean < stats.max);
+    #[cfg(feature = "par-internals")]
+    assert_eq!(par_skewness_histogram_2d(&map, 4), (counts, Some(stats)));
+
+    // open faces are degenerate
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(4).build().unwrap();
+    let (counts, stats) = skewness_histogram_2d(&map, 2);
+    assert_eq!(counts, vec![0, 4]);
+    assert_eq!(stats.map(|s| s.min), Some(1.0));
+}
+
+#[test]
+fn aspect_ratio() {
+    // right isoceles triangles: 2 * (sqrt(2) - 1)
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    assert!(map
+        .iter_faces()
+        .all(|fid| (face_aspect_ratio(&map, fid) - 2.0 * (2.0_f64.sqrt() - 1.0)).abs() < 1e-10));
+    // equilateral triangle
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(3).build().unwrap();
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 1);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (2.0, 0.0));
+    map.force_write_vertex(3, (1.0, 3.0_f64.sqrt()));
+    assert!((face_aspect_ratio(&map, 1) - 1.0).abs() < 1e-10);
+    // flat triangle
+    map.force_write_vertex(3, (1.0, 0.0));
+    assert!(face_aspect_ratio(&map, 1).abs() < 1e-10);
+
+    // quads use the edge length ratio
+    let map: CMap2<f64> = CMapBuilder::from(
+        GridDescriptor::default()
+            .len_per_cell([2.0, 1.0, 1.0])
+            .n_cells([2, 2, 0]),
+    )
+    .build()
+    .unwrap();
+    assert!(map
+        .iter_faces()
+        .all(|fid| (face_aspect_ratio(&map, fid) - 0.5).abs() < 1e-10));
+}
+
+/// Build a 3-map from a list of vertices & tetrahedra, using a temporary MEDIT file.
+fn tet_mesh(name: &str, vertices: &[[f64; 3]], tets: &[[usize; 4]]) -> CMap3<f64> {
+    let mut contents = format!(
+        "MeshVersionFormatted 2\nDimension 3\nVertices\n{}\n",
+        vertices.len()
+    );
+    for [x, y, z] in vertices {
+        writeln!(contents, "{x} {y} {z} 0").unwrap();
+    }
+    writeln!(contents, "Tetrahedra\n{}", tets.len()).unwrap();
+    for [a, b, c, d] in tets {
+        // MEDIT indices start at 1
+        writeln!(contents, "{} {} {} {} 0", a + 1, b + 1, c + 1, d + 1).unwrap();
+    }
+    contents.push_str("End\n");
+
+    let path = std::env::temp_dir().join(format!("honeycomb_quality_{name}.mesh"));
+    std::fs::write(&path, contents).unwrap();
+    let map = CMap3::from_medit_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    map
+}
+
+#[test]
+fn volume_tet() {
+    // corner tetrahedron of a 2 * 3 * 4 box: 2 * 3 * 4 / 6
+    let vertices = [
+        [0.0, 0.0, 0.0],
+        [2.0, 0.0, 0.0],
+        [0.0, 3.0, 0.0],
+        [0.0, 0.0, 4.0],
+    ];
+    let map = tet_mesh("tet", &vertices, &[[0, 1, 2, 3]]);
+    let vid = map.iter_volumes().next().unwrap();
+    assert!((volume_measure(&map, vid) - 4.0).abs() < 1e-10);
+    assert_eq!(count_inverted_tets(&map), 0);
+
+    // swapping two vertices inverts the tetrahedron
+    let map = tet_mesh("tet_inverted", &vertices, &[[0, 2, 1, 3]]);
+    let vid = map.iter_volumes().next().unwrap();
+    assert!((volume_measure(&map, vid) + 4.0).abs() < 1e-10);
+    assert_eq!(count_inverted_tets(&map), 1);
+
+    // undefined vertices yield a zero volume
+    map.force_remove_vertex(map.vertex_id(vid));
+    assert!(volume_measure(&map, vid).abs() < 1e-10);
+}
+
+#[test]
+fn volume_unit_cube() {
+    // unit cube split into six tetrahedra sharing its main diagonal
+    let vertices: Vec<[f64; 3]> = (0..8)
+        .map(|i| [f64::from(i & 1), f64::from((i >> 1) & 1), f64::from(i >> 2)])
+        .collect();
+    let tets = [
+        [0, 1, 3, 7],
+        [0, 2, 6, 7],
+        [0, 4, 5, 7],
+        [0, 5, 1, 7],
+        [0, 3, 2, 7],
+        [0, 6, 4, 7],
+    ];
+    let map = tet_mesh("unit_cube", &vertices, &tets);
+    assert_eq!(map.iter_volumes().count(), 6);
+    assert!(map
+        .iter_volumes()
+        .all(|vid| (volume_measure(&map, vid) - 1.0 / 6.0).abs() < 1e-10));
+    let total: f64 = map
+        .iter_volumes()
+        .map(|vid| volume_measure(&map, vid))
+        .sum();
+    assert!((total - 1.0).abs() < 1e-10);
+    assert_eq!(count_inverted_tets(&map), 0);
+}
@@ -0,0 +1,87 @@
+//! tetrahedron volume computation functions
+
+// ------ IMPORTS
+
+use honeycomb_core::cmap::{CMap3, DartIdType, VolumeIdType, NULL_DART_ID};
+use honeycomb_core::geometry::CoordsFloat;
+use honeycomb_core::stm::atomically;
+
+// ------ CONTENT
+
+/// Compute the signed volume of a tetrahedron.
+///
+/// The four corners of the tetrahedron are fetched by walking from the dart `d` sharing the
+/// volume's ID: `A`, `B` & `C` are the vertices of the face of `d`, in the order given by
+/// β<sub>1</sub>, and `D` is the remaining vertex of the face of β<sub>2</sub>(`d`). The volume
+/// is computed as one sixth of the scalar triple product:
+///
+/// ```text
+/// V = ((B - A) x (C - A)) . (D - A) / 6
+/// ```
+///
+/// The result is positive if `D` lies on the side pointed to by the normal of face `ABC`
+/// (right-hand rule), and negative otherwise. Since all faces of a volume share the same
+/// orientation, a negative value indicates an inverted tetrahedron. Vertices are read using an
+/// internal transaction.
+///
+/// # Arguments
+///
+/// - `map: &CMap3<T>` -- Reference to the map containing the volume.
+/// - `vid: VolumeIdType` -- Volume of interest. It is expected to be a tetrahedron.
+///
+/// # Return
+///
+/// Return the signed volume of the tetrahedron. Degenerate volumes, i.e. volumes with free
+/// darts on the walk or undefined vertices, have a volume of zero.
+///
+/// # Panics
+///
+/// This function may panic if the value `6.0` cannot be converted to `T`.
+#[must_use = "unused return value"]
+pub fn volume_measure<T: CoordsFloat>(map: &CMap3<T>, vid: VolumeIdType) -> T {
+    atomically(|trans| {
+        let d = vid as DartIdType;
+        let (b1d, b0d, b2d) = (
+            map.beta_transac::<1>(trans, d)?,
+            map.beta_transac::<0>(trans, d)?,
+            map.beta_transac::<2>(trans, d)?,
+        );
+        if b1d == NULL_DART_ID || b0d == NULL_DART_ID || b2d == NULL_DART_ID {
+            return Ok(T::zero());
+        }
+        let b0b2d = map.beta_transac::<0>(trans, b2d)?;
+        if b0b2d == NULL_DART_ID {
+            return Ok(T::zero());
+        }
+
+        let mut corners = Vec::with_capacity(4);
+        for dart in [d, b1d, b0d, b0b2d] {
+            let vertex_id = map.vertex_id_transac(trans, dart)?;
+            let Some(v) = map.read_vertex(trans, vertex_id)? else {
+                return Ok(T::zero());
+            };
+            corners.push(v);
+        }
+        let (ab, ac, ad) = (
+            corners[1] - corners[0],
+            corners[2] - corners[0],
+            corners[3] - corners[0],
+        );
+        Ok(ab.cross(&ac).dot(&ad) / T::from(6.0).unwrap())
+    })
+}
+
+/// Count inverted tetrahedra of the map.
+///
+/// This function iterates over all volumes of the map, and counts those with a negative
+/// [`volume_measure`].
+///
+/// # Arguments
+///
+/// - `map: &CMap3<T>` -- Reference to the map. All of its volumes are expected to be tetrahedra.
+#[must_use = "unused return value"]
+pub fn count_inverted_tets<T: CoordsFloat>(map: &CMap3<T>) -> usize {
+    map.iter_volumes()
+        .filter(|vid| volume_measure(map, *vid) < T::zero())
+        .count()
+}
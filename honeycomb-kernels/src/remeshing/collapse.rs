@@ -0,0 +1,378 @@
+//! standard and convenience variants of the `collapse_edge` function
+
+// ------ IMPORTS
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use crate::remeshing::{is_constraint_edge, CollapseEdgeError, TargetSize};
+use honeycomb_core::attributes::AttributeUpdate;
+use honeycomb_core::cmap::{CMap2, DartIdType, EdgeIdType, VertexIdType, NULL_DART_ID};
+use honeycomb_core::geometry::{CoordsFloat, Vertex2};
+use honeycomb_core::stm::{atomically, StmClosureResult, Transaction};
+
+// ------ CONTENT
+
+/// Position of the vertex resulting from an edge collapse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollapsePlacement {
+    /// The new vertex is placed at the middle of the collapsed edge.
+    Midpoint,
+    /// The new vertex is placed at the source of the collapsed edge, i.e. the vertex of the dart
+    /// sharing the edge's identifier.
+    KeepSource,
+    /// The new vertex is placed at the target of the collapsed edge.
+    KeepTarget,
+}
+
+#[allow(
+    clippy::many_single_char_names,
+    clippy::missing_errors_doc,
+    clippy::missing_panics_doc,
+    clippy::too_many_lines
+)]
+/// Collapse an edge of a triangle mesh.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// This method merges both vertices of an edge into a single one and removes the triangle(s)
+/// adjacent to the edge. The remaining edges of each removed triangle are sewn together. Removed
+/// darts are marked as unused.
+///
+/// # Arguments
+///
+/// - `cmap: &CMap2<T>` -- Reference to the modified map.
+/// - `trans: &mut Transaction` -- Transaction associated to the operation.
+/// - `edge_id: EdgeIdType` -- Edge to collapse.
+/// - `placement: CollapsePlacement` -- Position of the resulting vertex.
+///
+/// # Return / Errors
+///
+/// This method will return:
+/// - `Ok(VertexIdType)` if the operation is successful; the returned value is the ID of the
+///   vertex resulting from the collapse
+/// - `Err(CollapseEdgeError)` if the operation fails & the edge is left unchanged. Causes of
+///   failure are described in [`CollapseEdgeError`]'s documentation.
+///
+/// The checks are all done before any modification of the map, meaning that an error other than
+/// `FailedTransaction` can safely be ignored.
+///
+/// Constraint edges (see [`ConstraintEdge`][crate::remeshing::ConstraintEdge]) are preserved:
+/// the collapse fails if it would remove one of them, or move one of their vertices.
+///
+/// If the map has a [`TargetSize`] storage, sizes are carried over to the new vertex according
+/// to `placement`, i.e. averaged for [`CollapsePlacement::Midpoint`], copied from the kept vertex
+/// otherwise.
+///
+/// Note that the geometry of the resulting triangles is not checked; moving the new vertex may
+/// invert some of them.
+///
+/// # Example
+///
+/// Collapsing the edge `PQ` of the following mesh removes triangles `(P, Q, R)` and `(Q, P, S)`:
+///
+/// ```text
+///  + ------ R ------ +         + ------ R ------ +
+///  |      /   \      |         |        |        |
+///  |    /   A   \    |         |        |        |
+///  + - P ------- Q - +   =>    + ----- P/Q ----- +
+///  |    \   B   /    |         |        |        |
+///  |      \   /      |         |        |        |
+///  + ------ S ------ +         + ------ S ------ +
+/// ```
+pub fn collapse_edge<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    trans: &mut Transaction,
+    edge_id: EdgeIdType,
+    placement: CollapsePlacement,
+) -> Result<VertexIdType, CollapseEdgeError> {
+    // darts making up the edge
+    let d = edge_id as DartIdType;
+    let e = cmap.beta_transac::<2>(trans, d)?;
+
+    // darts of both triangles; a* from `d`'s face, b* from `e`'s face
+    let [a1, a2] = triangle_darts(cmap, trans, d)?;
+    let [b1, b2] = if e == NULL_DART_ID {
+        [NULL_DART_ID; 2]
+    } else {
+        triangle_darts(cmap, trans, e)?
+    };
+
+    // vertices
+    let (vid_p, vid_q) = (
+        cmap.vertex_id_transac(trans, d)?,
+        cmap.vertex_id_transac(trans, a1)?,
+    );
+    let (Some(p), Some(q)) = (
+        cmap.read_vertex(trans, vid_p)?,
+        cmap.read_vertex(trans, vid_q)?,
+    ) else {
+        return Err(CollapseEdgeError::UndefinedEdge);
+    };
+    let vid_r = cmap.vertex_id_transac(trans, a2)?;
+    let vid_s = if e == NULL_DART_ID {
+        None
+    } else {
+        Some(cmap.vertex_id_transac(trans, b2)?)
+    };
+
+    // topology checks
+    let (p_darts, q_darts) = (
+        outgoing_darts(cmap, trans, d)?,
+        outgoing_darts(cmap, trans, a1)?,
+    );
+    let common: BTreeSet<VertexIdType> = neighbors(cmap, trans, &p_darts)?
+        .intersection(&neighbors(cmap, trans, &q_darts)?)
+        .copied()
+        .collect();
+    let expected: BTreeSet<VertexIdType> = std::iter::once(vid_r).chain(vid_s).collect();
+    if common != expected {
+        return Err(CollapseEdgeError::NonManifoldResult(
+            "endpoints share neighbors outside of adjacent triangles",
+        ));
+    }
+    if e != NULL_DART_ID
+        && is_boundary(cmap, trans, &p_darts)?
+        && is_boundary(cmap, trans, &q_darts)?
+    {
+        return Err(CollapseEdgeError::NonManifoldResult(
+            "interior edge joins two boundary vertices",
+        ));
+    }
+    let moved_darts: &[&[DartIdType]] = match placement {
+        CollapsePlacement::Midpoint => &[&p_darts, &q_darts],
+        CollapsePlacement::KeepSource => &[&q_darts],
+        CollapsePlacement::KeepTarget => &[&p_darts],
+    };
+    if has_constraint_edge(cmap, trans, &[d, a1, a2, b1, b2])?
+        || has_constraint_edge(cmap, trans, &moved_darts.concat())?
+    {
+        return Err(CollapseEdgeError::ConstrainedEdge);
+    }
+    let mut opposite_darts = vec![outgoing_darts(cmap, trans, a2)?];
+    if e != NULL_DART_ID {
+        opposite_darts.push(outgoing_darts(cmap, trans, b2)?);
+    }
+    for darts in &opposite_darts {
+        let valence = neighbors(cmap, trans, darts)?.len();
+        let min_valence = if is_boundary(cmap, trans, darts)? {
+            3
+        } else {
+            4
+        };
+        if valence < min_valence {
+            return Err(CollapseEdgeError::LowValence);
+        }
+    }
+
+    // fetch darts that will remain in each vertex
+    let removed = [d, a1, a2, e, b1, b2];
+    let find_remaining = |darts: &[DartIdType]| {
+        darts
+            .iter()
+            .find(|dart| !removed.contains(dart))
+            .copied()
+            .expect("E: unreachable")
+    };
+    let pq_dart = find_remaining(&[p_darts, q_darts].concat());
+    let opposite_vertices: Vec<(DartIdType, Vertex2<T>)> = opposite_darts
+        .iter()
+        .map(|darts| {
+            let vid = cmap.vertex_id_transac(trans, darts[0])?;
+            let v = cmap
+                .read_vertex(trans, vid)?
+                .ok_or(CollapseEdgeError::UndefinedEdge)?;
+            Ok((find_remaining(darts), v))
+        })
+        .collect::<Result<_, CollapseEdgeError>>()?;
+
+    // remove old vertex values
+    let has_sizes = cmap.contains_attribute_storage::<TargetSize<T>>();
+    let mut sizes = Vec::with_capacity(4);
+    for vid in [vid_p, vid_q, vid_r].into_iter().chain(vid_s) {
+        cmap.remove_vertex(trans, vid)?;
+        if has_sizes {
+            sizes.push(cmap.remove_attribute::<TargetSize<T>>(trans, vid)?);
+        }
+    }
+
+    // detach & remove triangles, sew outer edges
+    for [d0, d1, d2] in [[d, a1, a2], [e, b1, b2]] {
+        if d0 == NULL_DART_ID {
+            continue;
+        }
+        if cmap.beta_transac::<2>(trans, d0)? != NULL_DART_ID {
+            cmap.unlink::<2>(trans, d0)?;
+        }
+        let (t1, t2) = (
+            cmap.beta_transac::<2>(trans, d1)?,
+            cmap.beta_transac::<2>(trans, d2)?,
+        );
+        if t1 != NULL_DART_ID {
+            cmap.unlink::<2>(trans, d1)?;
+        }
+        if t2 != NULL_DART_ID {
+            cmap.unlink::<2>(trans, d2)?;
+        }
+        if t1 != NULL_DART_ID && t2 != NULL_DART_ID {
+            cmap.link::<2>(trans, t1, t2)?;
+        }
+        for dart in [d0, d1, d2] {
+            cmap.unlink::<1>(trans, dart)?;
+        }
+        for dart in [d0, d1, d2] {
+            cmap.remove_free_dart_transac(trans, dart)?;
+        }
+    }
+
+    // write vertex values at new IDs
+    let new_vid = cmap.vertex_id_transac(trans, pq_dart)?;
+    let new_vertex = match placement {
+        CollapsePlacement::Midpoint => Vertex2::average(&p, &q),
+        CollapsePlacement::KeepSource => p,
+        CollapsePlacement::KeepTarget => q,
+    };
+    cmap.write_vertex(trans, new_vid, new_vertex)?;
+    for (dart, v) in &opposite_vertices {
+        let vid = cmap.vertex_id_transac(trans, *dart)?;
+        cmap.write_vertex(trans, vid, *v)?;
+    }
+
+    // carry target sizes over, if any
+    if has_sizes {
+        let new_size = match (placement, sizes[0], sizes[1]) {
+            (CollapsePlacement::Midpoint, Some(s1), Some(s2)) => Some(TargetSize::merge(s1, s2)),
+            (CollapsePlacement::KeepSource, s, _) | (CollapsePlacement::KeepTarget, _, s) => s,
+            (CollapsePlacement::Midpoint, s1, s2) => s1.or(s2),
+        };
+        if let Some(size) = new_size {
+            cmap.write_attribute(trans, new_vid, size)?;
+        }
+        for ((dart, _), size) in opposite_vertices.iter().zip(&sizes[2..]) {
+            if let Some(size) = size {
+                let vid = cmap.vertex_id_transac(trans, *dart)?;
+                cmap.write_attribute(trans, vid, *size)?;
+            }
+        }
+    }
+
+    Ok(new_vid)
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Collapse an edge of a triangle mesh.
+///
+/// This variant is equivalent to [`collapse_edge`], but internally uses a transaction that will
+/// be retried until validated.
+pub fn force_collapse_edge<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    edge_id: EdgeIdType,
+    placement: CollapsePlacement,
+) -> Result<VertexIdType, CollapseEdgeError> {
+    atomically(
+        |trans| match collapse_edge(cmap, trans, edge_id, placement) {
+            Ok(vid) => Ok(Ok(vid)),
+            Err(CollapseEdgeError::FailedTransaction(stme)) => Err(stme),
+            Err(e) => Ok(Err(e)),
+        },
+    )
+}
+
+// --- common inner routines
+
+/// Return the two other darts of the triangle containing `d`.
+fn triangle_darts<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    trans: &mut Transaction,
+    d: DartIdType,
+) -> Result<[DartIdType; 2], CollapseEdgeError> {
+    let d1 = cmap.beta_transac::<1>(trans, d)?;
+    let d2 = cmap.beta_transac::<1>(trans, d1)?;
+    if d1 == NULL_DART_ID || d2 == NULL_DART_ID || cmap.beta_transac::<1>(trans, d2)? != d {
+        return Err(CollapseEdgeError::NonTriangularFaces);
+    }
+    Ok([d1, d2])
+}
+
+/// Return all darts starting from the same vertex as `d`.
+fn outgoing_darts<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    trans: &mut Transaction,
+    d: DartIdType,
+) -> StmClosureResult<Vec<DartIdType>> {
+    let mut marked = HashSet::from([NULL_DART_ID, d]);
+    let mut pending = VecDeque::from([d]);
+    let mut darts = Vec::new();
+    while let Some(dart) = pending.pop_front() {
+        darts.push(dart);
+        let b2 = cmap.beta_transac::<2>(trans, dart)?;
+        let b0 = cmap.beta_transac::<0>(trans, dart)?;
+        for image in [
+            cmap.beta_transac::<1>(trans, b2)?,
+            cmap.beta_transac::<2>(trans, b0)?,
+        ] {
+            if marked.insert(image) {
+                pending.push_back(image);
+            }
+        }
+    }
+    Ok(darts)
+}
+
+/// Return the IDs of vertices sharing an edge with the vertex made of `darts`.
+fn neighbors<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    trans: &mut Transaction,
+    darts: &[DartIdType],
+) -> StmClosureResult<BTreeSet<VertexIdType>> {
+    let mut res = BTreeSet::new();
+    for dart in darts {
+        // darts coming in have their source at a neighbor too
+        for other in [
+            cmap.beta_transac::<1>(trans, *dart)?,
+            cmap.beta_transac::<0>(trans, *dart)?,
+        ] {
+            if other != NULL_DART_ID {
+                res.insert(cmap.vertex_id_transac(trans, other)?);
+            }
+        }
+    }
+    Ok(res)
+}
+
+/// Return `true` if one of the `darts`, or of the darts preceding them, belongs to a constraint
+/// edge.
+fn has_constraint_edge<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    trans: &mut Transaction,
+    darts: &[DartIdType],
+) -> StmClosureResult<bool> {
+    for dart in darts {
+        // darts coming in are needed to cover all edges of boundary vertices
+        for other in [*dart, cmap.beta_transac::<0>(trans, *dart)?] {
+            if other == NULL_DART_ID {
+                continue;
+            }
+            let eid = cmap.edge_id_transac(trans, other)?;
+            if is_constraint_edge(cmap, trans, eid)? {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Return `true` if one of the `darts` is on the boundary.
+fn is_boundary<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    trans: &mut Transaction,
+    darts: &[DartIdType],
+) -> StmClosureResult<bool> {
+    for dart in darts {
+        if cmap.beta_transac::<2>(trans, *dart)? == NULL_DART_ID {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
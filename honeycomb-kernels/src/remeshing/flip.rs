@@ -0,0 +1,172 @@
+//! standard and convenience variants of the `flip_edge` function
+
+// ------ IMPORTS
+
+use crate::remeshing::{is_constraint_edge, FlipEdgeError, TargetSize};
+use crate::triangulation::crossp_from_verts;
+use honeycomb_core::cmap::{CMap2, DartIdType, EdgeIdType, NULL_DART_ID};
+use honeycomb_core::geometry::CoordsFloat;
+use honeycomb_core::stm::{atomically, Transaction};
+
+// ------ CONTENT
+
+#[allow(clippy::missing_errors_doc, clippy::many_single_char_names)]
+/// Flip an edge shared by two triangles.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// This method replaces the edge shared by two triangles by the other diagonal of the
+/// quadrilateral they form. The darts making up the edge are reused to model the new diagonal;
+/// the four remaining darts keep their original vertices.
+///
+/// Constraint edges (see [`ConstraintEdge`][crate::remeshing::ConstraintEdge]) cannot be
+/// flipped.
+///
+/// # Arguments
+///
+/// - `cmap: &CMap2<T>` -- Reference to the modified map.
+/// - `trans: &mut Transaction` -- Transaction associated to the operation.
+/// - `edge_id: EdgeIdType` -- Edge to flip.
+///
+/// # Return / Errors
+///
+/// This method will return:
+/// - `Ok(())` if the operation is successful & the edge was flipped
+/// - `Err(FlipEdgeError)` if the operation fails & the edge is left unchanged. Causes of failure
+///   are described in [`FlipEdgeError`]'s documentation.
+///
+/// The checks are all done before any modification of the map, meaning that an error other than
+/// `FailedTransaction` can safely be ignored.
+///
+/// # Example
+///
+/// Given two triangles `(P, Q, R)` and `(Q, P, S)` sharing the edge `PQ`, flipping the edge
+/// results in two triangles `(R, P, S)` and `(S, Q, R)` sharing the edge `RS`:
+///
+/// ```text
+///  R +-------+ Q          R +-------+ Q
+///    |     / |              | \     |
+///    |   /   |      =>      |   \   |
+///    | /     |              |     \ |
+///  P +-------+ S          P +-------+ S
+/// ```
+pub fn flip_edge<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    trans: &mut Transaction,
+    edge_id: EdgeIdType,
+) -> Result<(), FlipEdgeError> {
+    // darts making up the edge
+    let d = edge_id as DartIdType;
+    let e = cmap.beta_transac::<2>(trans, d)?;
+    if e == NULL_DART_ID {
+        return Err(FlipEdgeError::BoundaryEdge);
+    }
+    if is_constraint_edge(cmap, trans, edge_id)? {
+        return Err(FlipEdgeError::ConstrainedEdge);
+    }
+
+    // darts of both triangles; a* from `d`'s face, b* from `e`'s face
+    let (a1, b1) = (
+        cmap.beta_transac::<1>(trans, d)?,
+        cmap.beta_transac::<1>(trans, e)?,
+    );
+    let (a2, b2) = (
+        cmap.beta_transac::<1>(trans, a1)?,
+        cmap.beta_transac::<1>(trans, b1)?,
+    );
+    if [a1, a2, b1, b2].contains(&NULL_DART_ID)
+        || cmap.beta_transac::<1>(trans, a2)? != d
+        || cmap.beta_transac::<1>(trans, b2)? != e
+    {
+        return Err(FlipEdgeError::NonTriangularFaces);
+    }
+
+    // vertices of the quad; the darts used here keep their source vertex after the flip
+    let vids = [
+        cmap.vertex_id_transac(trans, b1)?, // P
+        cmap.vertex_id_transac(trans, a1)?, // Q
+        cmap.vertex_id_transac(trans, a2)?, // R
+        cmap.vertex_id_transac(trans, b2)?, // S
+    ];
+    if vids[2] == vids[3] {
+        return Err(FlipEdgeError::InvalidGeometry(
+            "opposite vertices are identical",
+        ));
+    }
+    let (Some(p), Some(q), Some(r), Some(s)) = (
+        cmap.read_vertex(trans, vids[0])?,
+        cmap.read_vertex(trans, vids[1])?,
+        cmap.read_vertex(trans, vids[2])?,
+        cmap.read_vertex(trans, vids[3])?,
+    ) else {
+        return Err(FlipEdgeError::UndefinedFaces);
+    };
+
+    // new triangles must keep the orientation of the old ones
+    let orientation = crossp_from_verts(&p, &q, &r);
+    if [crossp_from_verts(&r, &p, &s), crossp_from_verts(&s, &q, &r)]
+        .iter()
+        .any(|&crossp| crossp * orientation <= T::zero())
+    {
+        return Err(FlipEdgeError::InvalidGeometry(
+            "new triangle would be degenerate or inverted",
+        ));
+    }
+
+    // rewire the two triangles: (a2, b1, d) & (b2, a1, e)
+    for dart in [d, a1, a2, e, b1, b2] {
+        cmap.unlink::<1>(trans, dart)?;
+    }
+    cmap.link::<1>(trans, a2, b1)?;
+    cmap.link::<1>(trans, b1, d)?;
+    cmap.link::<1>(trans, d, a2)?;
+    cmap.link::<1>(trans, b2, a1)?;
+    cmap.link::<1>(trans, a1, e)?;
+    cmap.link::<1>(trans, e, b2)?;
+
+    // vertex IDs may have changed; move the values accordingly
+    let new_vids = [
+        cmap.vertex_id_transac(trans, b1)?,
+        cmap.vertex_id_transac(trans, a1)?,
+        cmap.vertex_id_transac(trans, a2)?,
+        cmap.vertex_id_transac(trans, b2)?,
+    ];
+    if vids != new_vids {
+        let has_sizes = cmap.contains_attribute_storage::<TargetSize<T>>();
+        let mut sizes = [None; 4];
+        for (vid, size) in vids.into_iter().zip(&mut sizes) {
+            cmap.remove_vertex(trans, vid)?;
+            if has_sizes {
+                *size = cmap.remove_attribute::<TargetSize<T>>(trans, vid)?;
+            }
+        }
+        for (vid, v) in new_vids.into_iter().zip([p, q, r, s]) {
+            cmap.write_vertex(trans, vid, v)?;
+        }
+        for (vid, size) in new_vids.into_iter().zip(sizes) {
+            if let Some(size) = size {
+                cmap.write_attribute(trans, vid, size)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Flip an edge shared by two triangles.
+///
+/// This variant is equivalent to [`flip_edge`], but internally uses a transaction that will be
+/// retried until validated.
+pub fn force_flip_edge<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    edge_id: EdgeIdType,
+) -> Result<(), FlipEdgeError> {
+    atomically(|trans| match flip_edge(cmap, trans, edge_id) {
+        Ok(()) => Ok(Ok(())),
+        Err(FlipEdgeError::FailedTransaction(stme)) => Err(stme),
+        Err(e) => Ok(Err(e)),
+    })
+}
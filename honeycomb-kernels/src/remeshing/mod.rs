@@ -0,0 +1,189 @@
+//! Local remeshing operators
+//!
+//! This module contains implementations of local topological operators used to improve the
+//! quality of existing meshes. We currently define:
+//! - edge collapsing -- merge both vertices of an edge, removing adjacent triangles
+//! - edge flipping -- swap the diagonal of the quadrilateral formed by two adjacent triangles
+//! - non-manifold vertex splitting -- give each fan of a "bowtie" vertex its own vertex
+//! - Delaunay refinement -- insert circumcenters of poor-quality triangles
+//! - isotropic remeshing -- cut, collapse, flip & relax edges until they match a target length
+//! - field transfer -- interpolate a vertex attribute from a mesh onto another
+//! - vertex smoothing -- relax interior vertices toward a (weighted) barycenter of their neighbors
+//!
+//! Edges tagged using the [`ConstraintEdge`] attribute are preserved by these operators: they
+//! cannot be flipped or collapsed, their vertices are not moved by smoothing, and splitting them
+//! results in tagged edges.
+//!
+//! The [`TargetSize`] vertex attribute can be used to define a non-uniform size field: edge
+//! length criteria are then evaluated using the average size of each edge's endpoints.
+
+// ------ MODULE DECLARATIONS
+
+mod collapse;
+mod flip;
+mod refine;
+mod remesh;
+mod repair;
+mod smooth;
+mod transfer;
+
+// ------ PUBLIC RE-EXPORTS
+
+pub use collapse::{collapse_edge, force_collapse_edge, CollapsePlacement};
+pub use flip::{flip_edge, force_flip_edge};
+pub use refine::delaunay_refine;
+pub use remesh::{remesh_2d, RemeshConfig};
+pub use repair::split_nonmanifold_vertices;
+pub use smooth::{anisotropic_smooth, laplacian_smooth, laplacian_smooth_uniform, taubin_smooth};
+pub use transfer::transfer_field;
+
+pub use crate::utils::ConstraintEdge;
+pub(crate) use crate::utils::{force_is_constraint_edge, is_constraint_edge};
+
+// ------ CONTENT
+
+use honeycomb_core::attributes::{AttrSparseVec, AttributeBind, AttributeUpdate};
+use honeycomb_core::cmap::{CMap2, DartIdType, EdgeIdType, OrbitPolicy, VertexIdType};
+use honeycomb_core::geometry::CoordsFloat;
+use honeycomb_core::stm::{atomically, StmClosureResult, StmError, Transaction};
+
+/// Vertex attribute used to define a target edge length field.
+///
+/// Edges are considered too long or too short by comparing their length to the average target
+/// size of their endpoints, see [`edge_target_size`]. Vertices without a value fall back to the
+/// global target length, so a uniform field is equivalent to not using the attribute at all.
+///
+/// Merging two vertices averages their target sizes; splitting a vertex copies its value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetSize<T: CoordsFloat>(pub T);
+
+impl<T: CoordsFloat> AttributeUpdate for TargetSize<T> {
+    fn merge(attr1: Self, attr2: Self) -> Self {
+        TargetSize((attr1.0 + attr2.0) / T::from(2.0).unwrap())
+    }
+
+    fn split(attr: Self) -> (Self, Self) {
+        (attr, attr)
+    }
+}
+
+impl<T: CoordsFloat> AttributeBind for TargetSize<T> {
+    type StorageType = AttrSparseVec<Self>;
+    type IdentifierType = VertexIdType;
+    const BIND_POLICY: OrbitPolicy = OrbitPolicy::Vertex;
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Return the target length of an edge.
+///
+/// The target length is the average of the [`TargetSize`] values of both endpoints of the edge.
+/// If only one of them has a value, it is used as is; if neither does, or if the map has no
+/// storage for the attribute, `default` is returned.
+///
+/// # Arguments
+///
+/// - `cmap: &CMap2<T>` -- Reference to the map.
+/// - `trans: &mut Transaction` -- Transaction associated to the operation.
+/// - `edge_id: EdgeIdType` -- Edge of interest.
+/// - `default: T` -- Global target length.
+pub fn edge_target_size<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    trans: &mut Transaction,
+    edge_id: EdgeIdType,
+    default: T,
+) -> StmClosureResult<T> {
+    if !cmap.contains_attribute_storage::<TargetSize<T>>() {
+        return Ok(default);
+    }
+    let d = edge_id as DartIdType;
+    let b1d = cmap.beta_transac::<1>(trans, d)?;
+    let (vid1, vid2) = (
+        cmap.vertex_id_transac(trans, d)?,
+        cmap.vertex_id_transac(trans, b1d)?,
+    );
+    let (s1, s2) = (
+        cmap.read_attribute::<TargetSize<T>>(trans, vid1)?,
+        cmap.read_attribute::<TargetSize<T>>(trans, vid2)?,
+    );
+    Ok(match (s1, s2) {
+        (Some(s1), Some(s2)) => TargetSize::merge(s1, s2).0,
+        (Some(s), None) | (None, Some(s)) => s.0,
+        (None, None) => default,
+    })
+}
+
+/// Return the target length of an edge.
+///
+/// This variant is equivalent to [`edge_target_size`], but internally uses a transaction that
+/// will be retried until validated.
+pub fn force_edge_target_size<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    edge_id: EdgeIdType,
+    default: T,
+) -> T {
+    atomically(|trans| edge_target_size(cmap, trans, edge_id, default))
+}
+
+/// Error-modeling enum for edge-flipping routines.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum FlipEdgeError {
+    /// STM transaction failed.
+    #[error("transaction failed")]
+    FailedTransaction(/*#[from]*/ StmError),
+    /// The edge is on the boundary of the mesh, i.e. it is adjacent to a single face.
+    #[error("cannot flip a boundary edge")]
+    BoundaryEdge,
+    /// One or both faces adjacent to the edge are not triangles.
+    #[error("cannot flip an edge between non-triangular faces")]
+    NonTriangularFaces,
+    /// One or more vertices of the adjacent faces are undefined.
+    #[error("adjacent faces aren't defined correctly")]
+    UndefinedFaces,
+    /// The flip would result in a degenerate or inverted triangle.
+    #[error("flip would produce an invalid triangle - {0}")]
+    InvalidGeometry(&'static str),
+    /// The edge is tagged as a constraint edge.
+    #[error("cannot flip a constraint edge")]
+    ConstrainedEdge,
+}
+
+impl From<StmError> for FlipEdgeError {
+    fn from(value: StmError) -> Self {
+        Self::FailedTransaction(value)
+    }
+}
+
+/// Error-modeling enum for edge-collapsing routines.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum CollapseEdgeError {
+    /// STM transaction failed.
+    #[error("transaction failed")]
+    FailedTransaction(/*#[from]*/ StmError),
+    /// One or both faces adjacent to the edge are not triangles.
+    #[error("cannot collapse an edge adjacent to non-triangular faces")]
+    NonTriangularFaces,
+    /// One or more vertices of the edge or of the adjacent faces are undefined.
+    #[error("edge isn't defined correctly")]
+    UndefinedEdge,
+    /// A vertex opposite to the edge would be left with too few neighbors, resulting in
+    /// degenerate cells.
+    #[error("collapse would leave a vertex with an insufficient valence")]
+    LowValence,
+    /// The collapse would result in a non-manifold mesh.
+    #[error("collapse would produce a non-manifold mesh - {0}")]
+    NonManifoldResult(&'static str),
+    /// The collapse would remove a constraint edge, or move one of its vertices.
+    #[error("collapse would alter a constraint edge")]
+    ConstrainedEdge,
+}
+
+impl From<StmError> for CollapseEdgeError {
+    fn from(value: StmError) -> Self {
+        Self::FailedTransaction(value)
+    }
+}
+
+// ------ TESTS
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,378 @@
+//! Delaunay refinement functions
+
+// ------ IMPORTS
+
+use std::collections::HashSet;
+
+use crate::remeshing::{force_flip_edge, force_is_constraint_edge};
+use crate::splits::split_edge;
+use crate::triangulation::crossp_from_verts;
+use honeycomb_core::cmap::{
+    CMap2, DartIdType, EdgeIdType, Orbit2, OrbitPolicy, VertexIdType, NULL_DART_ID,
+};
+use honeycomb_core::geometry::{CoordsFloat, Vector2, Vertex2};
+
+// ------ CONTENT
+
+/// Refine a triangle mesh by inserting circumcenters of poor-quality triangles.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// The mesh is first made Delaunay using edge flips. Then, as long as a triangle has an angle
+/// smaller than `min_angle`, or an area larger than `max_area`, its circumcenter is inserted
+/// into the mesh & the Delaunay property is restored using edge flips. A circumcenter lying on
+/// an edge is inserted by splitting that edge.
+///
+/// Boundary edges, as well as constraint edges (see
+/// [`ConstraintEdge`][crate::remeshing::ConstraintEdge]), are treated as segments that must be
+/// preserved, following Ruppert's algorithm: a segment is encroached if a vertex lies strictly
+/// inside its diametral circle.
+/// Encroached segments are split at their midpoint before any circumcenter is inserted. If the
+/// circumcenter of a triangle encroaches one or more segments, these segments are split instead
+/// of inserting the circumcenter. Only the vertex opposite to a segment is checked for
+/// encroachment, which is sufficient in a Delaunay mesh.
+///
+/// Triangles whose circumcenter lies outside of the mesh without encroaching a segment, or on
+/// one of its vertices, are left as is. Faces that are not triangles are ignored.
+///
+/// Triangles are located by exhaustive search, resulting in a `O(n_faces)` cost per insertion.
+///
+/// # Arguments
+///
+/// - `map: &mut CMap2<T>` -- Reference to the modified map.
+/// - `min_angle: T` -- Smallest angle allowed in triangles, in degrees. Refinement is only
+///   guaranteed to terminate for values lower than ~20.7 degrees.
+/// - `max_area: T` -- Largest area allowed for triangles.
+///
+/// # Return
+///
+/// Return the number of inserted vertices.
+pub fn delaunay_refine<T: CoordsFloat>(map: &mut CMap2<T>, min_angle: T, max_area: T) -> usize {
+    let min_angle = min_angle.to_radians();
+
+    let edges: Vec<DartIdType> = map.iter_edges().map(|eid| eid as DartIdType).collect();
+    legalize_edges(map, edges);
+    let mut n_inserted = split_encroached_segments(map);
+
+    let mut skipped: HashSet<[DartIdType; 3]> = HashSet::new();
+    while let Some((darts, [a, b, c])) = find_bad_triangle(map, min_angle, max_area, &skipped) {
+        let Some(center) = Vertex2::circumcenter(&a, &b, &c) else {
+            skip(&mut skipped, darts);
+            continue;
+        };
+        // split segments encroached by the circumcenter instead of inserting it
+        let encroached: Vec<DartIdType> = segments(map)
+            .into_iter()
+            .filter(|d| is_encroached(map, *d, &center))
+            .collect();
+        if !encroached.is_empty() {
+            for d in encroached {
+                if split_segment(map, d).is_some() {
+                    n_inserted += 1;
+                }
+            }
+            n_inserted += split_encroached_segments(map);
+            continue;
+        }
+        let Some(new_vid) = insert_vertex(map, center) else {
+            skip(&mut skipped, darts);
+            continue;
+        };
+        legalize_around(map, new_vid);
+        n_inserted += 1;
+    }
+    n_inserted
+}
+
+// --- common inner routines
+
+/// Relative tolerance used by geometric predicates.
+const TOLERANCE: f64 = 1e-10;
+
+/// Location of a point in the mesh.
+enum Location {
+    /// Strictly inside the triangle containing the dart.
+    Face(DartIdType),
+    /// On the edge containing the dart.
+    Edge(DartIdType),
+}
+
+/// Return the three darts of the triangular face containing `d`, or `None` if the face isn't a
+/// triangle.
+fn triangle_darts<T: CoordsFloat>(map: &CMap2<T>, d: DartIdType) -> Option<[DartIdType; 3]> {
+    let d1 = map.beta::<1>(d);
+    let d2 = map.beta::<1>(d1);
+    if d1 == NULL_DART_ID || d2 == NULL_DART_ID || map.beta::<1>(d2) != d {
+        return None;
+    }
+    Some([d, d1, d2])
+}
+
+/// Return the vertices of the triangle made of `darts`, or `None` if one is undefined.
+fn triangle_vertices<T: CoordsFloat>(
+    map: &CMap2<T>,
+    darts: [DartIdType; 3],
+) -> Option<[Vertex2<T>; 3]> {
+    Some([
+        map.force_read_vertex(map.vertex_id(darts[0]))?,
+        map.force_read_vertex(map.vertex_id(darts[1]))?,
+        map.force_read_vertex(map.vertex_id(darts[2]))?,
+    ])
+}
+
+/// Return the smallest angle (in radians) & the area of the triangle `(a, b, c)`.
+fn triangle_quality<T: CoordsFloat>(a: &Vertex2<T>, b: &Vertex2<T>, c: &Vertex2<T>) -> (T, T) {
+    let angle =
+        |u: Vector2<T>, v: Vector2<T>| (u.x() * v.y() - u.y() * v.x()).abs().atan2(u.dot(&v));
+    let min_angle = angle(*b - *a, *c - *a)
+        .min(angle(*c - *b, *a - *b))
+        .min(angle(*a - *c, *b - *c));
+    let area = crossp_from_verts(a, b, c).abs() / (T::one() + T::one());
+    (min_angle, area)
+}
+
+/// Return the first triangle violating one of the quality bounds, along with its vertices.
+#[allow(clippy::type_complexity)]
+fn find_bad_triangle<T: CoordsFloat>(
+    map: &CMap2<T>,
+    min_angle: T,
+    max_area: T,
+    skipped: &HashSet<[DartIdType; 3]>,
+) -> Option<([DartIdType; 3], [Vertex2<T>; 3])> {
+    map.iter_faces().find_map(|fid| {
+        let darts = triangle_darts(map, fid as DartIdType)?;
+        let mut key = darts;
+        key.sort_unstable();
+        if skipped.contains(&key) {
+            return None;
+        }
+        let verts = triangle_vertices(map, darts)?;
+        let (angle, area) = triangle_quality(&verts[0], &verts[1], &verts[2]);
+        if angle < min_angle || area > max_area {
+            Some((darts, verts))
+        } else {
+            None
+        }
+    })
+}
+
+#[allow(clippy::many_single_char_names)]
+/// Locate `p` in the mesh, or return `None` if it lies outside of it or on one of its vertices.
+fn locate<T: CoordsFloat>(map: &CMap2<T>, p: &Vertex2<T>) -> Option<Location> {
+    let tol = T::from(TOLERANCE).unwrap();
+    for fid in map.iter_faces() {
+        let Some(darts) = triangle_darts(map, fid as DartIdType) else {
+            continue;
+        };
+        let Some([a, b, c]) = triangle_vertices(map, darts) else {
+            continue;
+        };
+        let area = crossp_from_verts(&a, &b, &c);
+        if area.abs() < T::epsilon() {
+            continue;
+        }
+        let coords = [
+            crossp_from_verts(p, &b, &c) / area,
+            crossp_from_verts(&a, p, &c) / area,
+            crossp_from_verts(&a, &b, p) / area,
+        ];
+        if coords.iter().any(|l| *l < -tol) {
+            continue;
+        }
+        // a null coordinate means that `p` is on the edge opposite to the associated vertex
+        let on_edges: Vec<usize> = (0..3).filter(|i| coords[*i] <= tol).collect();
+        return match on_edges.as_slice() {
+            [] => Some(Location::Face(darts[0])),
+            [i] => Some(Location::Edge(darts[(i + 1) % 3])),
+            _ => None,
+        };
+    }
+    None
+}
+
+/// Mark the triangle made of `darts` as left as is.
+fn skip(skipped: &mut HashSet<[DartIdType; 3]>, mut darts: [DartIdType; 3]) {
+    darts.sort_unstable();
+    skipped.insert(darts);
+}
+
+/// Return a dart of each segment of the mesh, i.e. of each boundary or constraint edge.
+fn segments<T: CoordsFloat>(map: &CMap2<T>) -> Vec<DartIdType> {
+    map.iter_edges()
+        .filter(|eid| {
+            map.beta::<2>(*eid as DartIdType) == NULL_DART_ID || force_is_constraint_edge(map, *eid)
+        })
+        .map(|eid| eid as DartIdType)
+        .collect()
+}
+
+/// Return `true` if `p` lies strictly inside the diametral circle of the edge containing `d`.
+fn is_encroached<T: CoordsFloat>(map: &CMap2<T>, d: DartIdType, p: &Vertex2<T>) -> bool {
+    let (Some(a), Some(b)) = (
+        map.force_read_vertex(map.vertex_id(d)),
+        map.force_read_vertex(map.vertex_id(map.beta::<1>(d))),
+    ) else {
+        return false;
+    };
+    // the angle `apb` is obtuse iff `p` is inside the circle
+    let (pa, pb) = (a - *p, b - *p);
+    pa.dot(&pb) < -T::from(TOLERANCE).unwrap() * (b - a).dot(&(b - a))
+}
+
+/// Split segments encroached by the opposite vertex of their adjacent triangles, until there
+/// are none left. Return the number of inserted vertices.
+fn split_encroached_segments<T: CoordsFloat>(map: &mut CMap2<T>) -> usize {
+    let mut n_inserted = 0;
+    loop {
+        let encroached = segments(map).into_iter().find(|d| {
+            [*d, map.beta::<2>(*d)]
+                .into_iter()
+                .filter(|side| *side != NULL_DART_ID)
+                .any(|side| {
+                    triangle_darts(map, side).is_some_and(|[_, _, d2]| {
+                        map.force_read_vertex(map.vertex_id(d2))
+                            .is_some_and(|apex| is_encroached(map, *d, &apex))
+                    })
+                })
+        });
+        match encroached.and_then(|d| split_segment(map, d)) {
+            Some(_) => n_inserted += 1,
+            None => return n_inserted,
+        }
+    }
+}
+
+/// Split the segment containing `d` at its midpoint, returning the new vertex's ID.
+fn split_segment<T: CoordsFloat>(map: &mut CMap2<T>, d: DartIdType) -> Option<VertexIdType> {
+    let a = map.force_read_vertex(map.vertex_id(d))?;
+    let b = map.force_read_vertex(map.vertex_id(map.beta::<1>(d)))?;
+    let new_vid = insert_on_edge(map, d, Vertex2::average(&a, &b))?;
+    legalize_around(map, new_vid);
+    Some(new_vid)
+}
+
+/// Insert a new vertex at `p`, returning its ID, or `None` if `p` couldn't be located.
+fn insert_vertex<T: CoordsFloat>(map: &mut CMap2<T>, p: Vertex2<T>) -> Option<VertexIdType> {
+    match locate(map, &p)? {
+        Location::Face(d) => Some(insert_in_triangle(map, d, p)),
+        Location::Edge(d) => insert_on_edge(map, d, p),
+    }
+}
+
+/// Split the triangle containing `d` into three triangles sharing a new vertex at `p`.
+fn insert_in_triangle<T: CoordsFloat>(
+    map: &mut CMap2<T>,
+    d: DartIdType,
+    p: Vertex2<T>,
+) -> VertexIdType {
+    let darts = [d, map.beta::<1>(d), map.beta::<0>(d)];
+    let start = map.add_free_darts(6);
+    // `to_p[i]` goes from the end of `darts[i]` to `p`, `from_p[i]` from `p` to its start
+    let to_p = [start, start + 1, start + 2];
+    let from_p = [start + 3, start + 4, start + 5];
+    for dart in darts {
+        map.force_unlink::<1>(dart);
+    }
+    for i in 0..3 {
+        map.force_link::<1>(darts[i], to_p[i]);
+        map.force_link::<1>(to_p[i], from_p[i]);
+        map.force_link::<1>(from_p[i], darts[i]);
+        map.force_link::<2>(to_p[i], from_p[(i + 1) % 3]);
+    }
+    // new darts have the largest IDs, so existing vertex IDs are unchanged
+    let vid = map.vertex_id(from_p[0]);
+    map.force_write_vertex(vid, p);
+    vid
+}
+
+#[allow(clippy::many_single_char_names)]
+/// Split the edge containing `d` at `p`, and split adjacent faces into triangles.
+pub(crate) fn insert_on_edge<T: CoordsFloat>(
+    map: &mut CMap2<T>,
+    d: DartIdType,
+    p: Vertex2<T>,
+) -> Option<VertexIdType> {
+    let e = map.beta::<2>(d);
+    let a = map.force_read_vertex(map.vertex_id(d))?;
+    let b = map.force_read_vertex(map.vertex_id(map.beta::<1>(d)))?;
+    let t = (p - a).norm() / (b - a).norm();
+    split_edge(map, d as EdgeIdType, Some(t)).ok()?;
+    split_quad(map, d);
+    if e != NULL_DART_ID {
+        split_quad(map, e);
+    }
+    Some(map.vertex_id(map.beta::<1>(d)))
+}
+
+/// Split the quadrangle starting with `d` into two triangles, by linking the end of `d` to the
+/// opposite vertex.
+fn split_quad<T: CoordsFloat>(map: &mut CMap2<T>, d: DartIdType) {
+    let d1 = map.beta::<1>(d);
+    let d2 = map.beta::<1>(d1);
+    let d3 = map.beta::<1>(d2);
+    let start = map.add_free_darts(2);
+    let (new1, new2) = (start, start + 1);
+    map.force_unlink::<1>(d);
+    map.force_unlink::<1>(d2);
+    map.force_link::<1>(d, new1);
+    map.force_link::<1>(new1, d3);
+    map.force_link::<1>(d2, new2);
+    map.force_link::<1>(new2, d1);
+    map.force_link::<2>(new1, new2);
+}
+
+#[allow(clippy::many_single_char_names)]
+/// Return `true` if the edge containing `d` doesn't satisfy the Delaunay property, i.e. the
+/// circumcircle of the face of `d` strictly contains the opposite vertex of the adjacent face.
+fn is_illegal<T: CoordsFloat>(map: &CMap2<T>, d: DartIdType) -> bool {
+    let e = map.beta::<2>(d);
+    if e == NULL_DART_ID {
+        return false;
+    }
+    let (Some(darts), Some(other)) = (triangle_darts(map, d), triangle_darts(map, e)) else {
+        return false;
+    };
+    let (Some([a, b, c]), Some(opposite)) = (
+        triangle_vertices(map, darts),
+        map.force_read_vertex(map.vertex_id(other[2])),
+    ) else {
+        return false;
+    };
+    let Some(center) = Vertex2::circumcenter(&a, &b, &c) else {
+        return false;
+    };
+    let (r2, d2) = (
+        (a - center).dot(&(a - center)),
+        (opposite - center).dot(&(opposite - center)),
+    );
+    d2 < r2 * (T::one() - T::from(TOLERANCE).unwrap())
+}
+
+/// Restore the Delaunay property after the insertion of a vertex.
+fn legalize_around<T: CoordsFloat>(map: &CMap2<T>, vid: VertexIdType) {
+    let edges: Vec<DartIdType> = Orbit2::new(map, OrbitPolicy::Vertex, vid as DartIdType)
+        .map(|d| map.beta::<1>(d))
+        .collect();
+    legalize_edges(map, edges);
+}
+
+/// Flip edges until all edges reachable from `pending` satisfy the Delaunay property.
+fn legalize_edges<T: CoordsFloat>(map: &CMap2<T>, mut pending: Vec<DartIdType>) {
+    while let Some(d) = pending.pop() {
+        if !is_illegal(map, d) {
+            continue;
+        }
+        let e = map.beta::<2>(d);
+        let outer = [
+            map.beta::<1>(d),
+            map.beta::<0>(d),
+            map.beta::<1>(e),
+            map.beta::<0>(e),
+        ];
+        if force_flip_edge(map, d as EdgeIdType).is_ok() {
+            pending.extend(outer);
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! isotropic remeshing loop
+
+// ------ IMPORTS
+
+use std::collections::HashSet;
+
+use crate::remeshing::refine::insert_on_edge;
+use crate::remeshing::{
+    force_collapse_edge, force_edge_target_size, force_flip_edge, laplacian_smooth_uniform,
+    CollapsePlacement, TargetSize,
+};
+use crate::triangulation::crossp_from_verts;
+use honeycomb_core::cmap::{
+    CMap2, DartIdType, EdgeIdType, Orbit2, OrbitPolicy, VertexIdType, NULL_DART_ID,
+};
+use honeycomb_core::geometry::{CoordsFloat, Vertex2};
+
+// ------ CONTENT
+
+/// Parameters of the [`remesh_2d`] routine.
+///
+/// # Example
+///
+/// ```
+/// # use honeycomb_kernels::remeshing::RemeshConfig;
+/// let config = RemeshConfig {
+///     n_rounds: 10,
+///     n_relax_rounds: 5,
+///     target_length: 0.1,
+///     target_tolerance: 0.2,
+///     enable_early_return: true,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemeshConfig<T: CoordsFloat> {
+    /// Maximum number of cut/collapse/flip/relax rounds.
+    pub n_rounds: usize,
+    /// Number of Laplacian smoothing rounds executed at the end of each round.
+    pub n_relax_rounds: usize,
+    /// Target edge length, used for edges whose vertices have no [`TargetSize`] value.
+    pub target_length: T,
+    /// Relative tolerance on edge lengths. An edge of target length `l` is considered too long
+    /// above `l * (1 + target_tolerance)`, and too short below `l * (1 - target_tolerance)`.
+    pub target_tolerance: T,
+    /// If `true`, stop as soon as all edges are within tolerance of their target length.
+    pub enable_early_return: bool,
+}
+
+/// Remesh a triangle mesh so that its edges match a target length.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// Each round executes the following steps:
+///
+/// 1. if `enable_early_return` is set, stop if all edges are within tolerance;
+/// 2. cut edges that are too long at their midpoint, splitting adjacent triangles;
+/// 3. collapse edges that are too short, unless this would create an edge that is too long or
+///    a degenerate triangle; boundary vertices are kept in place, so edges joining two of them
+///    are not collapsed;
+/// 4. flip edges to bring vertex valences closer to 6 (4 on the boundary);
+/// 5. relax vertices using `n_relax_rounds` rounds of [`laplacian_smooth_uniform`].
+///
+/// The target length of an edge is computed using [`force_edge_target_size`]; if the map has a
+/// [`TargetSize`] storage, vertices inserted by cuts are given the target length of the cut
+/// edge.
+///
+/// Operations that fail, e.g. because of a constraint edge or of a non-triangular face, are
+/// skipped. Boundary vertices & vertices of constraint edges are not moved by relaxation.
+///
+/// # Arguments
+///
+/// - `map: &mut CMap2<T>` -- Reference to the modified map.
+/// - `config: RemeshConfig<T>` -- Parameters of the algorithm.
+///
+/// # Return
+///
+/// Return the number of rounds executed. A value lower than `config.n_rounds` indicates that
+/// the algorithm returned early.
+pub fn remesh_2d<T: CoordsFloat>(map: &mut CMap2<T>, config: RemeshConfig<T>) -> usize {
+    let (low, high) = (
+        T::one() - config.target_tolerance,
+        T::one() + config.target_tolerance,
+    );
+    for round in 0..config.n_rounds {
+        if config.enable_early_return && edges_within_tolerance(map, &config, low, high) {
+            return round;
+        }
+        cut_long_edges(map, &config, high);
+        collapse_short_edges(map, &config, low, high);
+        flip_edges(map);
+        laplacian_smooth_uniform(map, config.n_relax_rounds);
+    }
+    config.n_rounds
+}
+
+// --- inner routines
+
+/// Return the length of the edge & its target length, or `None` if the edge is undefined.
+fn edge_lengths<T: CoordsFloat>(
+    map: &CMap2<T>,
+    config: &RemeshConfig<T>,
+    edge_id: EdgeIdType,
+) -> Option<(T, T)> {
+    let d = edge_id as DartIdType;
+    let a = map.force_read_vertex(map.vertex_id(d))?;
+    let b = map.force_read_vertex(map.vertex_id(map.beta::<1>(d)))?;
+    Some((
+        (b - a).norm(),
+        force_edge_target_size(map, edge_id, config.target_length),
+    ))
+}
+
+/// Return `true` if all edges have a length within tolerance of their target length.
+fn edges_within_tolerance<T: CoordsFloat>(
+    map: &CMap2<T>,
+    config: &RemeshConfig<T>,
+    low: T,
+    high: T,
+) -> bool {
+    map.iter_edges().all(|eid| {
+        edge_lengths(map, config, eid)
+            .is_none_or(|(length, target)| length >= target * low && length <= target * high)
+    })
+}
+
+/// Cut edges longer than their target length.
+fn cut_long_edges<T: CoordsFloat>(map: &mut CMap2<T>, config: &RemeshConfig<T>, high: T) {
+    let has_sizes = map.contains_attribute_storage::<TargetSize<T>>();
+    let edges: Vec<EdgeIdType> = map.iter_edges().collect();
+    for eid in edges {
+        let Some((length, target)) = edge_lengths(map, config, eid) else {
+            continue;
+        };
+        if length <= target * high {
+            continue;
+        }
+        let d = eid as DartIdType;
+        let a = map.force_read_vertex(map.vertex_id(d)).unwrap();
+        let b = map
+            .force_read_vertex(map.vertex_id(map.beta::<1>(d)))
+            .unwrap();
+        if let Some(new_vid) = insert_on_edge(map, d, Vertex2::average(&a, &b)) {
+            if has_sizes {
+                map.force_write_attribute(new_vid, TargetSize(target));
+            }
+        }
+    }
+}
+
+/// Collapse edges shorter than their target length.
+fn collapse_short_edges<T: CoordsFloat>(map: &CMap2<T>, config: &RemeshConfig<T>, low: T, high: T) {
+    let edges: Vec<EdgeIdType> = map.iter_edges().collect();
+    for eid in edges {
+        // the edge may have been removed or renumbered by a previous collapse
+        let d = eid as DartIdType;
+        if map.is_free(d) || map.edge_id(d) != eid {
+            continue;
+        }
+        let Some((length, target)) = edge_lengths(map, config, eid) else {
+            continue;
+        };
+        if length >= target * low {
+            continue;
+        }
+        let (vid_p, vid_q) = (map.vertex_id(d), map.vertex_id(map.beta::<1>(d)));
+        let (Some(p), Some(q)) = (map.force_read_vertex(vid_p), map.force_read_vertex(vid_q))
+        else {
+            continue;
+        };
+        // boundary vertices are never moved
+        let (placement, new_vertex) = match (is_boundary(map, vid_p), is_boundary(map, vid_q)) {
+            (false, false) => (CollapsePlacement::Midpoint, Vertex2::average(&p, &q)),
+            (true, false) => (CollapsePlacement::KeepSource, p),
+            (false, true) => (CollapsePlacement::KeepTarget, q),
+            (true, true) => continue,
+        };
+        if is_valid_collapse(map, [vid_p, vid_q], new_vertex, target * high) {
+            let _ = force_collapse_edge(map, eid, placement);
+        }
+    }
+}
+
+/// Return `true` if merging both vertices at `new_vertex` neither creates an edge longer than
+/// `max_length`, nor degenerates or inverts a remaining triangle.
+fn is_valid_collapse<T: CoordsFloat>(
+    map: &CMap2<T>,
+    vids: [VertexIdType; 2],
+    new_vertex: Vertex2<T>,
+    max_length: T,
+) -> bool {
+    let position = |vid: VertexIdType| {
+        if vids.contains(&vid) {
+            Some(new_vertex)
+        } else {
+            map.force_read_vertex(vid)
+        }
+    };
+    let creates_long_edge = neighbors(map, vids[0])
+        .union(&neighbors(map, vids[1]))
+        .filter(|nid| !vids.contains(nid))
+        .any(|nid| position(*nid).is_none_or(|n| (n - new_vertex).norm() > max_length));
+    if creates_long_edge {
+        return false;
+    }
+    vids.into_iter()
+        .flat_map(|vid| Orbit2::new(map, OrbitPolicy::Vertex, vid as DartIdType))
+        .all(|d| {
+            let tri = [d, map.beta::<1>(d), map.beta::<0>(d)].map(|dart| map.vertex_id(dart));
+            // triangles adjacent to the collapsed edge are removed
+            if vids.iter().all(|vid| tri.contains(vid)) {
+                return true;
+            }
+            let (Some(a), Some(b), Some(c)) = (
+                map.force_read_vertex(tri[0]),
+                map.force_read_vertex(tri[1]),
+                map.force_read_vertex(tri[2]),
+            ) else {
+                return false;
+            };
+            let (Some(new_a), Some(new_b), Some(new_c)) =
+                (position(tri[0]), position(tri[1]), position(tri[2]))
+            else {
+                return false;
+            };
+            crossp_from_verts(&a, &b, &c) * crossp_from_verts(&new_a, &new_b, &new_c) > T::zero()
+        })
+}
+
+/// Flip edges that reduce the deviation of vertex valences from their ideal value.
+fn flip_edges<T: CoordsFloat>(map: &CMap2<T>) {
+    let edges: Vec<EdgeIdType> = map.iter_edges().collect();
+    for eid in edges {
+        let d = eid as DartIdType;
+        let e = map.beta::<2>(d);
+        if map.is_free(d) || map.is_i_free::<2>(d) {
+            continue;
+        }
+        let vids = [
+            map.vertex_id(d),
+            map.vertex_id(e),
+            map.vertex_id(map.beta::<0>(d)),
+            map.vertex_id(map.beta::<0>(e)),
+        ];
+        let deviations: Vec<(i64, i64)> = vids.iter().map(|vid| valence(map, *vid)).collect();
+        let deviation = |offsets: [i64; 4]| -> i64 {
+            deviations
+                .iter()
+                .zip(offsets)
+                .map(|((val, target), offset)| (val + offset - target).pow(2))
+                .sum()
+        };
+        if deviation([-1, -1, 1, 1]) < deviation([0; 4]) {
+            let _ = force_flip_edge(map, eid);
+        }
+    }
+}
+
+/// Return the IDs of vertices sharing an edge with the specified vertex.
+fn neighbors<T: CoordsFloat>(map: &CMap2<T>, vid: VertexIdType) -> HashSet<VertexIdType> {
+    Orbit2::new(map, OrbitPolicy::Vertex, vid as DartIdType)
+        .flat_map(|d| [map.beta::<1>(d), map.beta::<0>(d)])
+        .filter(|d| *d != NULL_DART_ID)
+        .map(|d| map.vertex_id(d))
+        .collect()
+}
+
+/// Return `true` if the vertex is on the boundary of the mesh.
+fn is_boundary<T: CoordsFloat>(map: &CMap2<T>, vid: VertexIdType) -> bool {
+    Orbit2::new(map, OrbitPolicy::Vertex, vid as DartIdType).any(|d| map.is_i_free::<2>(d))
+}
+
+#[allow(clippy::cast_possible_wrap)]
+/// Return the valence of a vertex, along with its ideal value.
+fn valence<T: CoordsFloat>(map: &CMap2<T>, vid: VertexIdType) -> (i64, i64) {
+    (
+        neighbors(map, vid).len() as i64,
+        if is_boundary(map, vid) { 4 } else { 6 },
+    )
+}
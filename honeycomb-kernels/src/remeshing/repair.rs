@@ -0,0 +1,128 @@
+//! mesh repair functions
+
+// ------ IMPORTS
+
+use std::collections::HashSet;
+
+use honeycomb_core::cmap::{CMap2, DartIdType, Orbit2, OrbitPolicy, VertexIdType, NULL_DART_ID};
+use honeycomb_core::geometry::{CoordsFloat, Vector2};
+use honeycomb_core::stm::atomically;
+
+// ------ CONTENT
+
+/// Fraction of the distance to its neighbors by which a pinched vertex is moved.
+const NUDGE_FACTOR: f64 = 1e-2;
+
+#[allow(clippy::must_use_candidate)]
+/// Split non-manifold vertices of the map.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// This function detects "bowtie" vertices using the vertex criterion of
+/// [`CMap2::is_manifold`], and gives each fan of darts its own vertex:
+///
+/// - fans merged into a single vertex (see [`CMap2::vertex_fans`]) are separated by unlinking
+///   β<sub>2</sub> links that are not reciprocated, leaving reciprocated links untouched. Each
+///   new vertex is given the value of the original vertex, unless it already has one.
+/// - vertices located at the same position (see [`CMap2::coincident_vertices`]) are pulled
+///   apart: the vertex with the lowest ID is kept in place, while others are moved towards the
+///   average position of their neighbors, by 1% of the distance.
+///
+/// Unless a pinched vertex has no neighbor to be moved towards, the resulting map is manifold.
+///
+/// # Arguments
+///
+/// - `map: &mut CMap2<T>` -- Reference to the modified map.
+///
+/// # Return
+///
+/// Return the number of non-manifold vertices that were fixed, counting each bowtie once. Note
+/// that a single inconsistent link usually merges two pairs of fans, one at each end of the
+/// dart.
+pub fn split_nonmanifold_vertices<T: CoordsFloat>(map: &mut CMap2<T>) -> usize {
+    // fans merged by one-way links
+    let merged: Vec<(VertexIdType, Vec<Vec<DartIdType>>)> = map
+        .iter_vertices()
+        .map(|vid| (vid, map.vertex_fans(vid)))
+        .filter(|(_, fans)| fans.len() > 1)
+        .collect();
+    for (vid, fans) in &merged {
+        let value = map.force_read_vertex(*vid);
+
+        // unlink darts merging fans; both outgoing & incoming darts are checked
+        for dart in fans.iter().flatten().flat_map(|d| [*d, map.beta::<0>(*d)]) {
+            let b2 = map.beta::<2>(dart);
+            if dart != NULL_DART_ID && b2 != NULL_DART_ID && map.beta::<2>(b2) != dart {
+                cut_one_way_link(map, dart, b2);
+            }
+        }
+
+        // each fan is now its own vertex
+        for fan in &fans[1..] {
+            let new_vid = map.vertex_id(fan[0]);
+            if let Some(v) = value {
+                if map.force_read_vertex(new_vid).is_none() {
+                    map.force_write_vertex(new_vid, v);
+                }
+            }
+        }
+    }
+    let split: HashSet<VertexIdType> = merged
+        .iter()
+        .flat_map(|(_, fans)| fans.iter().map(|fan| map.vertex_id(fan[0])))
+        .collect();
+    let mut n_fixed = merged
+        .iter()
+        .filter(|(vid, _)| map.vertex_fans(*vid).len() == 1)
+        .count();
+
+    // fans pinched at the same position, including the ones split above
+    for group in map.coincident_vertices() {
+        for vid in &group[1..] {
+            nudge_vertex(map, *vid);
+        }
+        if group.iter().all(|vid| !split.contains(vid)) {
+            n_fixed += 1;
+        }
+    }
+
+    n_fixed
+}
+
+/// Move a vertex towards the average position of its neighbors.
+fn nudge_vertex<T: CoordsFloat>(map: &CMap2<T>, vid: VertexIdType) {
+    let Some(v) = map.force_read_vertex(vid) else {
+        return;
+    };
+    let offsets: Vec<Vector2<T>> = Orbit2::new(map, OrbitPolicy::Vertex, vid as DartIdType)
+        .flat_map(|d| [map.beta::<1>(d), map.beta::<0>(d)])
+        .filter(|d| *d != NULL_DART_ID)
+        .map(|d| map.vertex_id(d))
+        .filter(|nid| *nid != vid)
+        .filter_map(|nid| map.force_read_vertex(nid))
+        .map(|n| n - v)
+        .collect();
+    if offsets.is_empty() {
+        return;
+    }
+    let offset = offsets.iter().fold(Vector2::default(), |acc, o| acc + *o)
+        / T::from(offsets.len()).unwrap();
+    map.force_write_vertex(vid, v + offset * T::from(NUDGE_FACTOR).unwrap());
+}
+
+/// Unlink `dart` from `b2`, restoring the reciprocated link of `b2` if it has one.
+fn cut_one_way_link<T: CoordsFloat>(map: &CMap2<T>, dart: DartIdType, b2: DartIdType) {
+    atomically(|trans| {
+        let b2b2 = map.beta_transac::<2>(trans, b2)?;
+        let restore = b2b2 != NULL_DART_ID && map.beta_transac::<2>(trans, b2b2)? == b2;
+        // unlinking `dart` also resets the image of `b2`
+        map.unlink::<2>(trans, dart)?;
+        if restore {
+            map.unlink::<2>(trans, b2b2)?;
+            map.link::<2>(trans, b2, b2b2)?;
+        }
+        Ok(())
+    });
+}
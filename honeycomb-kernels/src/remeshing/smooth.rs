@@ -0,0 +1,220 @@
+//! vertex relaxation functions
+
+// ------ IMPORTS
+
+use crate::remeshing::force_is_constraint_edge;
+use honeycomb_core::cmap::{CMap2, DartIdType, Orbit2, OrbitPolicy, VertexIdType};
+use honeycomb_core::geometry::{CoordsFloat, Vector2, Vertex2};
+use honeycomb_core::stm::atomically;
+
+// ------ CONTENT
+
+/// Relax vertices toward the metric-weighted barycenter of their neighbors.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// At each round, each interior vertex `i` is moved to the point:
+///
+/// ```text
+/// x_i = (sum_j M_j)^-1 * sum_j (M_j * x_j)
+/// ```
+///
+/// where `j` spans the neighbors of `i`, and `M_j` is the metric tensor associated to vertex
+/// `j`. Neighbors with a larger metric along a given direction have a stronger pull in that
+/// direction. Using the identity as the metric yields the usual (uniform) Laplacian smoothing.
+///
+/// New positions are computed from the positions of the previous round, i.e. the order in which
+/// vertices are processed doesn't affect the result. Boundary vertices, as well as vertices of
+/// constraint edges (see [`ConstraintEdge`][crate::remeshing::ConstraintEdge]), are left
+/// untouched.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the modified map.
+/// - `n_rounds: usize` -- Number of relaxation rounds.
+/// - `metric: impl Fn(VertexIdType) -> [[T; 2]; 2]` -- Function returning the metric tensor
+///   associated to a given vertex. Returned matrices are expected to be symmetric positive
+///   definite.
+///
+/// Vertices with undefined neighbors, or for which the sum of neighbor metrics isn't invertible,
+/// are left untouched.
+pub fn anisotropic_smooth<T: CoordsFloat>(
+    map: &CMap2<T>,
+    n_rounds: usize,
+    metric: impl Fn(VertexIdType) -> [[T; 2]; 2],
+) {
+    let interior: Vec<VertexIdType> = map
+        .iter_vertices()
+        .filter(|vid| !is_fixed_vertex(map, *vid))
+        .collect();
+    let neighbors: Vec<Vec<VertexIdType>> = interior
+        .iter()
+        .map(|vid| vertex_neighbors(map, *vid))
+        .collect();
+
+    for _ in 0..n_rounds {
+        let new_positions: Vec<Option<Vertex2<T>>> = neighbors
+            .iter()
+            .map(|nids| {
+                let mut m_sum = [[T::zero(); 2]; 2];
+                let (mut rhs_x, mut rhs_y) = (T::zero(), T::zero());
+                for nid in nids {
+                    let v = map.force_read_vertex(*nid)?;
+                    let m = metric(*nid);
+                    m_sum[0][0] += m[0][0];
+                    m_sum[0][1] += m[0][1];
+                    m_sum[1][0] += m[1][0];
+                    m_sum[1][1] += m[1][1];
+                    rhs_x += m[0][0] * v.x() + m[0][1] * v.y();
+                    rhs_y += m[1][0] * v.x() + m[1][1] * v.y();
+                }
+                let det = m_sum[0][0] * m_sum[1][1] - m_sum[0][1] * m_sum[1][0];
+                if det <= T::zero() {
+                    return None;
+                }
+                Some(Vertex2(
+                    (m_sum[1][1] * rhs_x - m_sum[0][1] * rhs_y) / det,
+                    (m_sum[0][0] * rhs_y - m_sum[1][0] * rhs_x) / det,
+                ))
+            })
+            .collect();
+        interior
+            .iter()
+            .zip(new_positions)
+            .for_each(|(vid, new_pos)| {
+                if let Some(v) = new_pos {
+                    map.force_write_vertex(*vid, v);
+                }
+            });
+    }
+}
+
+/// Relax vertices using Laplacian smoothing.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// At each round, each interior vertex `i` is moved along the umbrella operator:
+///
+/// ```text
+/// x_i = x_i + lambda * L(x_i)    with    L(x_i) = (1 / n) * sum_j (x_j - x_i)
+/// ```
+///
+/// where `j` spans the `n` neighbors of `i`. New positions are computed from the positions of
+/// the previous round, and written using one transaction per vertex. Boundary vertices, as well
+/// as vertices of constraint edges (see [`ConstraintEdge`][crate::remeshing::ConstraintEdge]),
+/// are left untouched.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the modified map.
+/// - `n_rounds: usize` -- Number of smoothing rounds.
+/// - `lambda: T` -- Scale factor applied to the umbrella operator. Values in `]0; 1]` are
+///   expected.
+///
+/// Vertices with undefined neighbors are left untouched.
+pub fn laplacian_smooth<T: CoordsFloat>(map: &CMap2<T>, n_rounds: usize, lambda: T) {
+    let interior = interior_vertices(map);
+    for _ in 0..n_rounds {
+        laplacian_step(map, &interior, lambda);
+    }
+}
+
+/// Relax vertices by moving them to the barycenter of their neighbors.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// This is equivalent to calling [`laplacian_smooth`] with `lambda` set to `1`.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the modified map.
+/// - `n_rounds: usize` -- Number of smoothing rounds.
+pub fn laplacian_smooth_uniform<T: CoordsFloat>(map: &CMap2<T>, n_rounds: usize) {
+    laplacian_smooth(map, n_rounds, T::one());
+}
+
+/// Relax vertices using Taubin smoothing.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// Each round is made of two Laplacian steps (see [`laplacian_smooth`]), a shrinking one using
+/// `lambda`, and an inflating one using `mu`. This limits the shrinkage induced by the pure
+/// Laplacian operator.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Reference to the modified map.
+/// - `n_rounds: usize` -- Number of smoothing rounds.
+/// - `lambda: T` -- Scale factor of the first step. A positive value is expected.
+/// - `mu: T` -- Scale factor of the second step. A negative value such that `-mu > lambda` is
+///   expected.
+pub fn taubin_smooth<T: CoordsFloat>(map: &CMap2<T>, n_rounds: usize, lambda: T, mu: T) {
+    let interior = interior_vertices(map);
+    for _ in 0..n_rounds {
+        laplacian_step(map, &interior, lambda);
+        laplacian_step(map, &interior, mu);
+    }
+}
+
+// --- common inner routines
+
+/// Return interior vertices of the map, along with their neighbors.
+fn interior_vertices<T: CoordsFloat>(map: &CMap2<T>) -> Vec<(VertexIdType, Vec<VertexIdType>)> {
+    map.iter_vertices()
+        .filter(|vid| !is_fixed_vertex(map, *vid))
+        .map(|vid| (vid, vertex_neighbors(map, vid)))
+        .collect()
+}
+
+/// Move each vertex along its scaled umbrella operator.
+fn laplacian_step<T: CoordsFloat>(
+    map: &CMap2<T>,
+    interior: &[(VertexIdType, Vec<VertexIdType>)],
+    scale: T,
+) {
+    let offsets: Vec<Option<Vector2<T>>> = interior
+        .iter()
+        .map(|(vid, nids)| {
+            let v = map.force_read_vertex(*vid)?;
+            let mut offset = Vector2(T::zero(), T::zero());
+            for nid in nids {
+                offset += map.force_read_vertex(*nid)? - v;
+            }
+            Some(offset * scale / T::from(nids.len()).unwrap())
+        })
+        .collect();
+    for ((vid, _), offset) in interior.iter().zip(offsets) {
+        if let Some(offset) = offset {
+            atomically(|trans| {
+                if let Some(v) = map.read_vertex(trans, *vid)? {
+                    map.write_vertex(trans, *vid, v + offset)?;
+                }
+                Ok(())
+            });
+        }
+    }
+}
+
+/// Return `true` if the vertex is on the boundary of the mesh, or on a constraint edge.
+fn is_fixed_vertex<T: CoordsFloat>(map: &CMap2<T>, vid: VertexIdType) -> bool {
+    Orbit2::new(map, OrbitPolicy::Vertex, vid as DartIdType).any(|d| {
+        map.is_i_free::<1>(d)
+            || map.is_i_free::<2>(d)
+            || force_is_constraint_edge(map, map.edge_id(d))
+    })
+}
+
+/// Return the IDs of vertices sharing an edge with the specified vertex.
+fn vertex_neighbors<T: CoordsFloat>(map: &CMap2<T>, vid: VertexIdType) -> Vec<VertexIdType> {
+    Orbit2::new(map, OrbitPolicy::Vertex, vid as DartIdType)
+        .map(|d| map.vertex_id(map.beta::<1>(d)))
+        .collect()
+}
@@ -0,0 +1,956 @@
+use super::*;
+use honeycomb_core::attributes::{AttrSparseVec, AttributeBind, AttributeUpdate};
+use honeycomb_core::cmap::{
+    CMap2, CMapBuilder, DartIdType, EdgeIdType, GridDescriptor, OrbitPolicy, VertexIdType,
+    NULL_DART_ID,
+};
+use honeycomb_core::geometry::Vertex2;
+use honeycomb_core::stm::atomically;
+
+// --- flip_edge
+
+//  R +-------+ Q
+//    |  A  / |
+//    |   /   |
+//    | /  B  |
+//  P +-------+ S
+fn two_triangles(s: (f64, f64)) -> CMap2<f64> {
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(6).build().unwrap();
+    // A = (P, Q, R)
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 1);
+    // B = (Q, P, S)
+    map.force_link::<1>(4, 5);
+    map.force_link::<1>(5, 6);
+    map.force_link::<1>(6, 4);
+    map.force_link::<2>(1, 4);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (1.0, 1.0));
+    map.force_write_vertex(3, (0.0, 1.0));
+    map.force_write_vertex(6, s);
+    map
+}
+
+#[test]
+fn flip_edge_valid() {
+    let map = two_triangles((1.0, 0.0));
+    assert!(force_flip_edge(&map, 1).is_ok());
+
+    // (R, P, S)
+    assert_eq!(map.beta::<1>(3), 5);
+    assert_eq!(map.beta::<1>(5), 1);
+    assert_eq!(map.beta::<1>(1), 3);
+    // (S, Q, R)
+    assert_eq!(map.beta::<1>(6), 2);
+    assert_eq!(map.beta::<1>(2), 4);
+    assert_eq!(map.beta::<1>(4), 6);
+    // shared edge
+    assert_eq!(map.beta::<2>(1), 4);
+    assert_eq!(map.iter_faces().count(), 2);
+    assert_eq!(map.iter_edges().count(), 5);
+
+    // vertices
+    assert_eq!(map.vertex_id(1), 1);
+    assert_eq!(map.vertex_id(6), 1);
+    assert_eq!(map.vertex_id(3), 3);
+    assert_eq!(map.vertex_id(4), 3);
+    assert_eq!(map.force_read_vertex(1), Some(Vertex2(1.0, 0.0))); // S
+    assert_eq!(map.force_read_vertex(2), Some(Vertex2(1.0, 1.0))); // Q
+    assert_eq!(map.force_read_vertex(3), Some(Vertex2(0.0, 1.0))); // R
+    assert_eq!(map.force_read_vertex(5), Some(Vertex2(0.0, 0.0))); // P
+    assert_eq!(map.force_read_vertex(6), None);
+
+    // flipping back yields the original diagonal
+    assert!(force_flip_edge(&map, 1).is_ok());
+    assert_eq!(map.iter_faces().count(), 2);
+    let v1 = map.force_read_vertex(map.vertex_id(1)).unwrap();
+    let v4 = map.force_read_vertex(map.vertex_id(4)).unwrap();
+    assert!(
+        (v1 == Vertex2(0.0, 0.0) && v4 == Vertex2(1.0, 1.0))
+            || (v1 == Vertex2(1.0, 1.0) && v4 == Vertex2(0.0, 0.0))
+    );
+}
+
+#[test]
+fn flip_edge_transactional() {
+    let map = two_triangles((1.0, 0.0));
+    atomically(|trans| {
+        assert!(flip_edge(&map, trans, 1).is_ok());
+        Ok(())
+    });
+    assert_eq!(map.beta::<1>(1), 3);
+    assert_eq!(map.beta::<1>(4), 6);
+}
+
+#[test]
+fn flip_edge_boundary() {
+    let map = two_triangles((1.0, 0.0));
+    assert_eq!(force_flip_edge(&map, 2), Err(FlipEdgeError::BoundaryEdge));
+}
+
+#[test]
+fn flip_edge_non_triangular() {
+    let mut map = two_triangles((1.0, 0.0));
+    // turn B into a quad
+    map.force_unlink::<1>(6);
+    let d = map.add_free_dart();
+    map.force_link::<1>(6, d);
+    map.force_link::<1>(d, 4);
+    map.force_write_vertex(d, (2.0, 1.0));
+    assert_eq!(
+        force_flip_edge(&map, 1),
+        Err(FlipEdgeError::NonTriangularFaces)
+    );
+    assert_eq!(map.beta::<1>(1), 2);
+}
+
+#[test]
+fn flip_edge_undefined() {
+    let map = two_triangles((1.0, 0.0));
+    map.force_remove_vertex(6);
+    assert_eq!(force_flip_edge(&map, 1), Err(FlipEdgeError::UndefinedFaces));
+}
+
+#[test]
+fn flip_edge_invalid_geometry() {
+    // R, Q & S are aligned
+    let map = two_triangles((2.0, 1.0));
+    assert!(matches!(
+        force_flip_edge(&map, 1),
+        Err(FlipEdgeError::InvalidGeometry(_))
+    ));
+    // non-convex quad
+    let map = two_triangles((3.0, 2.0));
+    assert!(matches!(
+        force_flip_edge(&map, 1),
+        Err(FlipEdgeError::InvalidGeometry(_))
+    ));
+    // map is left unchanged
+    assert_eq!(map.beta::<1>(1), 2);
+    assert_eq!(map.beta::<1>(4), 5);
+    assert_eq!(map.force_read_vertex(6), Some(Vertex2(3.0, 2.0)));
+}
+
+// --- smoothing
+
+/// Return a map of 4x4 unit squares with its inner vertices shifted by `offset`.
+fn perturbed_grid(offset: (f64, f64)) -> CMap2<f64> {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(4).build().unwrap();
+    map.iter_vertices().for_each(|vid| {
+        let v = map.force_read_vertex(vid).unwrap();
+        if (1.0..=3.0).contains(&v.x()) && (1.0..=3.0).contains(&v.y()) {
+            map.force_write_vertex(vid, (v.x() + offset.0, v.y() + offset.1));
+        }
+    });
+    map
+}
+
+#[test]
+fn anisotropic_smooth_identity() {
+    let map = perturbed_grid((0.2, -0.1));
+    anisotropic_smooth(&map, 50, |_| [[1.0, 0.0], [0.0, 1.0]]);
+    // uniform smoothing restores the regular grid
+    map.iter_vertices().for_each(|vid| {
+        let v = map.force_read_vertex(vid).unwrap();
+        assert!((v.x() - v.x().round()).abs() < 1e-6);
+        assert!((v.y() - v.y().round()).abs() < 1e-6);
+    });
+}
+
+#[test]
+fn anisotropic_smooth_stretch() {
+    let map = perturbed_grid((0.0, 0.0));
+    let initial: Vec<_> = map
+        .iter_vertices()
+        .map(|vid| (vid, map.force_read_vertex(vid).unwrap()))
+        .collect();
+    // metric grows along x, isotropic along y
+    anisotropic_smooth(&map, 1, |vid| {
+        let v = map.force_read_vertex(vid).unwrap();
+        [[1.0 + v.x(), 0.0], [0.0, 1.0]]
+    });
+
+    for (vid, old) in &initial {
+        let new = map.force_read_vertex(*vid).unwrap();
+        // vertices are only moved along x
+        assert!((new.y() - old.y()).abs() < f64::EPSILON);
+        if (1.0..=3.0).contains(&old.x()) && (1.0..=3.0).contains(&old.y()) {
+            // interior vertices are pulled toward higher metric values
+            assert!(new.x() > old.x());
+        } else {
+            // boundary vertices do not move
+            assert_eq!(new, *old);
+        }
+    }
+    // vertex at (1, 1): (1 * 0 + 3 * 2 + 2 * 1 + 2 * 1) / (1 + 3 + 2 + 2)
+    let (vid, _) = initial
+        .iter()
+        .find(|(_, v)| *v == Vertex2(1.0, 1.0))
+        .unwrap();
+    assert_eq!(map.force_read_vertex(*vid), Some(Vertex2(1.25, 1.0)));
+}
+
+#[test]
+fn laplacian_smooth_uniform_grid() {
+    let map = perturbed_grid((0.2, -0.1));
+    laplacian_smooth_uniform(&map, 50);
+    // uniform smoothing restores the regular grid
+    map.iter_vertices().for_each(|vid| {
+        let v = map.force_read_vertex(vid).unwrap();
+        assert!((v.x() - v.x().round()).abs() < 1e-6);
+        assert!((v.y() - v.y().round()).abs() < 1e-6);
+    });
+}
+
+#[test]
+fn laplacian_smooth_scaled() {
+    let map = perturbed_grid((0.2, -0.1));
+    let initial: Vec<_> = map
+        .iter_vertices()
+        .map(|vid| (vid, map.force_read_vertex(vid).unwrap()))
+        .collect();
+    laplacian_smooth(&map, 1, 0.5);
+
+    for (vid, old) in &initial {
+        // boundary vertices do not move
+        if !(1.0..=3.2).contains(&old.x()) || !(0.9..=2.9).contains(&old.y()) {
+            assert_eq!(map.force_read_vertex(*vid), Some(*old));
+        }
+    }
+    // vertex at (1.2, 0.9): neighbors average to (1.1, 0.95), half of the way is covered
+    let (vid, _) = initial
+        .iter()
+        .find(|(_, v)| *v == Vertex2(1.2, 0.9))
+        .unwrap();
+    let v = map.force_read_vertex(*vid).unwrap();
+    assert!((v.x() - 1.15).abs() < 1e-10);
+    assert!((v.y() - 0.925).abs() < 1e-10);
+}
+
+#[test]
+fn taubin_smooth_grid() {
+    let error = |map: &CMap2<f64>| {
+        map.iter_vertices()
+            .map(|vid| {
+                let v = map.force_read_vertex(vid).unwrap();
+                (v.x() - v.x().round()).abs() + (v.y() - v.y().round()).abs()
+            })
+            .sum::<f64>()
+    };
+    let map = perturbed_grid((0.2, -0.1));
+    let mut prev = error(&map);
+    for _ in 0..5 {
+        taubin_smooth(&map, 2, 0.5, -0.53);
+        let current = error(&map);
+        // the perturbation is damped at each round
+        assert!(current < prev);
+        prev = current;
+    }
+}
+
+// --- collapse_edge
+
+/// Return the edge going from `a` to `b`, as the ID of the dart going from `a` to `b`.
+#[allow(clippy::cast_possible_truncation)]
+fn find_edge(map: &CMap2<f64>, a: (f64, f64), b: (f64, f64)) -> EdgeIdType {
+    let (a, b) = (Vertex2::from(a), Vertex2::from(b));
+    (1..map.n_darts() as DartIdType)
+        .find(|d| {
+            !map.is_free(*d)
+                && map.force_read_vertex(map.vertex_id(*d)) == Some(a)
+                && map.force_read_vertex(map.vertex_id(map.beta::<1>(*d))) == Some(b)
+        })
+        .unwrap()
+}
+
+/// Check that all faces of the map are triangles & that β2 is an involution.
+#[allow(clippy::cast_possible_truncation)]
+fn check_triangle_mesh(map: &CMap2<f64>) {
+    for d in 1..map.n_darts() as DartIdType {
+        if map.is_free(d) {
+            continue;
+        }
+        assert_eq!(map.beta::<1>(map.beta::<1>(map.beta::<1>(d))), d);
+        let b2 = map.beta::<2>(d);
+        if b2 != NULL_DART_ID {
+            assert_eq!(map.beta::<2>(b2), d);
+        }
+    }
+}
+
+#[test]
+fn collapse_edge_interior() {
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(3).build().unwrap();
+    assert_eq!(map.iter_vertices().count(), 16);
+    assert_eq!(map.iter_edges().count(), 33);
+    assert_eq!(map.iter_faces().count(), 18);
+
+    let edge = find_edge(&map, (1.0, 1.0), (2.0, 1.0));
+    let vid = force_collapse_edge(&map, edge, CollapsePlacement::Midpoint).unwrap();
+
+    assert_eq!(map.iter_vertices().count(), 15);
+    assert_eq!(map.iter_edges().count(), 30);
+    assert_eq!(map.iter_faces().count(), 16);
+    assert_eq!(map.n_unused_darts(), 6);
+    assert_eq!(map.force_read_vertex(vid), Some(Vertex2(1.5, 1.0)));
+    assert_eq!(map.i_cell::<0>(vid).count(), 8);
+    check_triangle_mesh(&map);
+    // all vertices are still defined
+    assert!(map
+        .iter_vertices()
+        .all(|vid| map.force_read_vertex(vid).is_some()));
+}
+
+#[test]
+fn collapse_edge_placement() {
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(3).build().unwrap();
+    let edge = find_edge(&map, (1.0, 1.0), (2.0, 1.0));
+    let vid = force_collapse_edge(&map, edge, CollapsePlacement::KeepSource).unwrap();
+    assert_eq!(map.force_read_vertex(vid), Some(Vertex2(1.0, 1.0)));
+
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(3).build().unwrap();
+    let edge = find_edge(&map, (1.0, 1.0), (2.0, 1.0));
+    let vid = force_collapse_edge(&map, edge, CollapsePlacement::KeepTarget).unwrap();
+    assert_eq!(map.force_read_vertex(vid), Some(Vertex2(2.0, 1.0)));
+}
+
+#[test]
+fn collapse_edge_boundary() {
+    // square split in four triangles around its center
+    let vertices = [
+        Vertex2(0.0, 0.0),
+        Vertex2(2.0, 0.0),
+        Vertex2(2.0, 2.0),
+        Vertex2(0.0, 2.0),
+        Vertex2(1.0, 1.0),
+    ];
+    let triangles = [[0, 1, 4], [1, 2, 4], [2, 3, 4], [3, 0, 4]];
+    let map = CMap2::from_triangle_soup(&vertices, &triangles).unwrap();
+
+    let edge = find_edge(&map, (0.0, 0.0), (2.0, 0.0));
+    let vid = force_collapse_edge(&map, edge, CollapsePlacement::Midpoint).unwrap();
+
+    assert_eq!(map.iter_vertices().count(), 4);
+    assert_eq!(map.iter_edges().count(), 6);
+    assert_eq!(map.iter_faces().count(), 3);
+    assert_eq!(map.n_unused_darts(), 3);
+    assert_eq!(map.force_read_vertex(vid), Some(Vertex2(1.0, 0.0)));
+    check_triangle_mesh(&map);
+}
+
+#[test]
+fn collapse_edge_non_manifold() {
+    // interior edge joining two boundary vertices
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(1).build().unwrap();
+    let edge = map
+        .iter_edges()
+        .find(|e| !map.is_i_free::<2>(*e as DartIdType))
+        .unwrap();
+    assert!(matches!(
+        force_collapse_edge(&map, edge, CollapsePlacement::Midpoint),
+        Err(CollapseEdgeError::NonManifoldResult(_))
+    ));
+
+    // endpoints sharing a neighbor outside of adjacent triangles
+    let vertices = [
+        Vertex2(0.0, 0.0),
+        Vertex2(2.0, 0.0),
+        Vertex2(1.0, 2.0),
+        Vertex2(1.0, 0.5),
+    ];
+    let triangles = [[0, 1, 3], [1, 2, 3], [2, 0, 3]];
+    let map = CMap2::from_triangle_soup(&vertices, &triangles).unwrap();
+    let edge = find_edge(&map, (0.0, 0.0), (2.0, 0.0));
+    assert!(matches!(
+        force_collapse_edge(&map, edge, CollapsePlacement::Midpoint),
+        Err(CollapseEdgeError::NonManifoldResult(_))
+    ));
+    // map is left unchanged
+    assert_eq!(map.iter_faces().count(), 3);
+    assert_eq!(map.n_unused_darts(), 0);
+}
+
+#[test]
+fn collapse_edge_low_valence() {
+    let vertices = [Vertex2(0.0, 0.0), Vertex2(1.0, 0.0), Vertex2(0.0, 1.0)];
+    let map = CMap2::from_triangle_soup(&vertices, &[[0, 1, 2]]).unwrap();
+    assert_eq!(
+        force_collapse_edge(&map, 1, CollapsePlacement::Midpoint),
+        Err(CollapseEdgeError::LowValence)
+    );
+}
+
+#[test]
+fn collapse_edge_non_triangular() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    assert_eq!(
+        force_collapse_edge(&map, 1, CollapsePlacement::Midpoint),
+        Err(CollapseEdgeError::NonTriangularFaces)
+    );
+}
+
+#[test]
+fn collapse_edge_undefined() {
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(3).build().unwrap();
+    let edge = find_edge(&map, (1.0, 1.0), (2.0, 1.0));
+    map.force_remove_vertex(map.vertex_id(edge as DartIdType));
+    assert_eq!(
+        force_collapse_edge(&map, edge, CollapsePlacement::Midpoint),
+        Err(CollapseEdgeError::UndefinedEdge)
+    );
+}
+
+#[test]
+fn collapse_edge_unused_darts() {
+    let mut map: CMap2<f64> = CMapBuilder::unit_triangles(3).build().unwrap();
+    assert_eq!(map.unused_dart_audit().n_unused, 0);
+
+    let edge = find_edge(&map, (1.0, 1.0), (2.0, 1.0));
+    force_collapse_edge(&map, edge, CollapsePlacement::Midpoint).unwrap();
+    let report = map.unused_dart_audit();
+    assert_eq!(report.n_unused, 6);
+    assert_eq!(report.n_unused, map.n_unused_darts());
+    assert!(report.blocks.iter().all(|(first, last)| first <= last));
+
+    // new darts are appended, removed ones are not reused; splitting a boundary edge adds a
+    // single dart
+    let edge = find_edge(&map, (0.0, 0.0), (1.0, 0.0));
+    crate::splits::split_edge(&mut map, edge, None).unwrap();
+    let report = map.unused_dart_audit();
+    assert_eq!(report.n_unused, 6);
+    assert_eq!(report.n_darts, 55 + 1);
+    assert!(!report.is_trailing());
+}
+
+// --- delaunay_refine
+
+/// Return a regular polygon with `n` vertices on the unit circle, triangulated as a fan
+/// centered on its first vertex.
+#[allow(clippy::cast_precision_loss)]
+fn polygon_fan(n: usize) -> CMap2<f64> {
+    let vertices: Vec<Vertex2<f64>> = (0..n)
+        .map(|i| {
+            let theta = 2.0 * std::f64::consts::PI * i as f64 / n as f64;
+            Vertex2(theta.cos(), theta.sin())
+        })
+        .collect();
+    let triangles: Vec<[usize; 3]> = (1..n - 1).map(|i| [0, i, i + 1]).collect();
+    CMap2::from_triangle_soup(&vertices, &triangles).unwrap()
+}
+
+/// Return the smallest angle (in degrees) & the largest area of the mesh's triangles.
+#[allow(clippy::many_single_char_names)]
+fn mesh_quality(map: &CMap2<f64>) -> (f64, f64) {
+    let (mut min_angle, mut max_area) = (f64::MAX, 0.0_f64);
+    for fid in map.iter_faces() {
+        let d0 = fid as DartIdType;
+        let [a, b, c] = [d0, map.beta::<1>(d0), map.beta::<0>(d0)]
+            .map(|d| map.force_read_vertex(map.vertex_id(d)).unwrap());
+        for (p, q, r) in [(a, b, c), (b, c, a), (c, a, b)] {
+            let (u, v) = (q - p, r - p);
+            min_angle = min_angle.min((u.dot(&v) / (u.norm() * v.norm())).acos().to_degrees());
+        }
+        let area = ((b - a).x() * (c - a).y() - (b - a).y() * (c - a).x()).abs() / 2.0;
+        max_area = max_area.max(area);
+    }
+    (min_angle, max_area)
+}
+
+#[test]
+fn delaunay_refine_min_angle() {
+    let mut map = polygon_fan(12);
+    assert!(mesh_quality(&map).0 < 20.0);
+
+    let n_inserted = delaunay_refine(&mut map, 20.0, f64::MAX);
+    assert!(n_inserted > 0);
+    check_triangle_mesh(&map);
+    assert!(mesh_quality(&map).0 >= 20.0);
+    assert_eq!(map.iter_vertices().count(), 12 + n_inserted);
+}
+
+#[test]
+fn delaunay_refine_max_area() {
+    let mut map: CMap2<f64> = CMapBuilder::unit_triangles(1).build().unwrap();
+    let n_inserted = delaunay_refine(&mut map, 20.0, 0.05);
+    assert!(n_inserted > 0);
+    check_triangle_mesh(&map);
+    let (min_angle, max_area) = mesh_quality(&map);
+    assert!(min_angle >= 20.0);
+    assert!(max_area <= 0.05);
+    // the domain is unchanged
+    let total: f64 = map
+        .iter_faces()
+        .map(|fid| crate::quality::face_area(&map, fid))
+        .sum();
+    assert!((total - 1.0).abs() < 1e-10);
+    // nothing left to refine
+    assert_eq!(delaunay_refine(&mut map, 20.0, 0.05), 0);
+}
+
+#[test]
+fn delaunay_refine_encroachment() {
+    // the circumcenter of this triangle is (2.0, 0.0976...), i.e. inside the diametral circle of
+    // the bottom segment, which should be split instead
+    let vertices = [Vertex2(0.0, 0.0), Vertex2(4.0, 0.0), Vertex2(2.0, 2.1)];
+    let mut map: CMap2<f64> = CMap2::from_triangle_soup(&vertices, &[[0, 1, 2]]).unwrap();
+    let center = Vertex2(2.0, (2.1 * 2.1 - 4.0) / 4.2);
+    let has_vertex = |map: &CMap2<f64>, v: Vertex2<f64>| {
+        map.iter_vertices().any(|vid| {
+            let w = map.force_read_vertex(vid).unwrap();
+            (w - v).norm() < 1e-10
+        })
+    };
+
+    let n_inserted = delaunay_refine(&mut map, 20.0, 3.0);
+    assert!(n_inserted > 0);
+    check_triangle_mesh(&map);
+    assert!(has_vertex(&map, Vertex2(2.0, 0.0)));
+    assert!(!has_vertex(&map, center));
+    let (min_angle, max_area) = mesh_quality(&map);
+    assert!(min_angle >= 20.0);
+    assert!(max_area <= 3.0);
+    // the boundary is preserved
+    assert!((map.boundary_length() - (4.0 + 2.0 * 2.0_f64.hypot(2.1))).abs() < 1e-10);
+}
+
+// --- remesh_2d
+
+/// Return the smallest & largest edge lengths of the map.
+fn edge_length_range(map: &CMap2<f64>) -> (f64, f64) {
+    map.iter_edges()
+        .map(|eid| {
+            let d = eid as DartIdType;
+            let a = map.force_read_vertex(map.vertex_id(d)).unwrap();
+            let b = map
+                .force_read_vertex(map.vertex_id(map.beta::<1>(d)))
+                .unwrap();
+            (b - a).norm()
+        })
+        .fold((f64::MAX, 0.0), |(min, max), l| (min.min(l), max.max(l)))
+}
+
+fn remesh_config(target_length: f64) -> RemeshConfig<f64> {
+    RemeshConfig {
+        n_rounds: 10,
+        n_relax_rounds: 3,
+        target_length,
+        target_tolerance: 0.5,
+        enable_early_return: true,
+    }
+}
+
+#[test]
+fn remesh_2d_refine() {
+    let mut map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    let n_rounds = remesh_2d(&mut map, remesh_config(0.4));
+    assert!(n_rounds < 10);
+    check_triangle_mesh(&map);
+    let (min, max) = edge_length_range(&map);
+    assert!(min >= 0.2);
+    assert!(max <= 0.6);
+    // the boundary is preserved
+    assert!((map.boundary_length() - 8.0).abs() < 1e-10);
+}
+
+#[test]
+fn remesh_2d_coarsen() {
+    let mut map: CMap2<f64> = CMapBuilder::unit_triangles(8).build().unwrap();
+    let n_faces = map.iter_faces().count();
+    remesh_2d(&mut map, remesh_config(3.0));
+    check_triangle_mesh(&map);
+    assert!(map.iter_faces().count() < n_faces);
+    assert!((map.boundary_length() - 32.0).abs() < 1e-10);
+    assert!(map
+        .iter_vertices()
+        .all(|vid| map.force_read_vertex(vid).is_some()));
+}
+
+#[test]
+fn remesh_2d_early_return() {
+    // all edges are within [0.5; 1.5]
+    let mut map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    assert_eq!(remesh_2d(&mut map, remesh_config(1.0)), 0);
+    assert_eq!(map.iter_faces().count(), 8);
+
+    let config = RemeshConfig {
+        enable_early_return: false,
+        ..remesh_config(1.0)
+    };
+    assert_eq!(remesh_2d(&mut map, config), 10);
+}
+
+#[test]
+fn remesh_2d_target_size() {
+    // a uniform size field is equivalent to the global target length
+    let mut map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    remesh_2d(&mut map, remesh_config(0.4));
+    let mut sized_map: CMap2<f64> = CMapBuilder::unit_triangles(2)
+        .add_attribute::<TargetSize<f64>>()
+        .build()
+        .unwrap();
+    for vid in sized_map.iter_vertices() {
+        sized_map.force_write_attribute(vid, TargetSize(0.4));
+    }
+    remesh_2d(&mut sized_map, remesh_config(1.0));
+    assert_eq!(
+        sized_map.iter_vertices().count(),
+        map.iter_vertices().count()
+    );
+
+    // a finer field on the left side results in more vertices there
+    let mut map: CMap2<f64> = CMapBuilder::unit_triangles(2)
+        .add_attribute::<TargetSize<f64>>()
+        .build()
+        .unwrap();
+    for vid in map.iter_vertices() {
+        let v = map.force_read_vertex(vid).unwrap();
+        map.force_write_attribute(vid, TargetSize(if v.x() < 1.0 { 0.3 } else { 1.0 }));
+    }
+    remesh_2d(&mut map, remesh_config(1.0));
+    check_triangle_mesh(&map);
+    let (left, right): (Vec<_>, Vec<_>) = map
+        .iter_vertices()
+        .map(|vid| map.force_read_vertex(vid).unwrap())
+        .partition(|v| v.x() < 1.0);
+    assert!(left.len() > 2 * right.len());
+}
+
+// --- constraint edges
+
+/// Return a 4x4 triangle grid with edges along `x = 2` tagged as constraints.
+fn constrained_grid() -> CMap2<f64> {
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(4)
+        .add_attribute::<ConstraintEdge>()
+        .build()
+        .unwrap();
+    let edges: Vec<EdgeIdType> = map
+        .iter_edges()
+        .filter(|eid| is_on_interface(&map, *eid))
+        .collect();
+    assert_eq!(edges.len(), 4);
+    for eid in edges {
+        map.force_write_attribute(eid, ConstraintEdge(true));
+    }
+    map
+}
+
+fn is_on_interface(map: &CMap2<f64>, eid: EdgeIdType) -> bool {
+    let d = eid as DartIdType;
+    [d, map.beta::<1>(d)].into_iter().all(|dart| {
+        map.force_read_vertex(map.vertex_id(dart))
+            .is_some_and(|v| (v.x() - 2.0).abs() < 1e-10)
+    })
+}
+
+#[test]
+fn constraint_edges_flip_collapse() {
+    let map = constrained_grid();
+    let edge = find_edge(&map, (2.0, 1.0), (2.0, 2.0));
+    assert_eq!(
+        force_flip_edge(&map, map.edge_id(edge)),
+        Err(FlipEdgeError::ConstrainedEdge)
+    );
+    assert_eq!(
+        force_collapse_edge(&map, map.edge_id(edge), CollapsePlacement::Midpoint),
+        Err(CollapseEdgeError::ConstrainedEdge)
+    );
+    // the edge isn't tagged, but collapsing it would move a vertex of the interface
+    let edge = find_edge(&map, (2.0, 2.0), (3.0, 2.0));
+    assert_eq!(
+        force_collapse_edge(&map, map.edge_id(edge), CollapsePlacement::Midpoint),
+        Err(CollapseEdgeError::ConstrainedEdge)
+    );
+    assert_eq!(map.n_unused_darts(), 0);
+}
+
+#[test]
+fn constraint_edges_smooth() {
+    let map = constrained_grid();
+    let vid = map.vertex_id(find_edge(&map, (2.0, 2.0), (3.0, 2.0)));
+    let other = map.vertex_id(find_edge(&map, (1.0, 2.0), (2.0, 2.0)));
+    map.force_write_vertex(vid, (2.0, 2.3));
+    laplacian_smooth_uniform(&map, 10);
+    assert_eq!(map.force_read_vertex(vid), Some(Vertex2(2.0, 2.3)));
+    // other interior vertices were moved
+    assert_ne!(map.force_read_vertex(other), Some(Vertex2(1.0, 2.0)));
+}
+
+#[test]
+fn constraint_edges_refine() {
+    let mut map = constrained_grid();
+    let n_inserted = delaunay_refine(&mut map, 20.0, 0.1);
+    assert!(n_inserted > 0);
+    check_triangle_mesh(&map);
+    let (min_angle, max_area) = mesh_quality(&map);
+    assert!(min_angle >= 20.0);
+    assert!(max_area <= 0.1);
+    // tagged edges still cover the interface, and only the interface
+    let tagged: Vec<EdgeIdType> = map
+        .iter_edges()
+        .filter(|eid| {
+            map.force_read_attribute::<ConstraintEdge>(*eid) == Some(ConstraintEdge(true))
+        })
+        .collect();
+    assert!(tagged.len() > 4);
+    assert!(tagged.iter().all(|eid| is_on_interface(&map, *eid)));
+    let length: f64 = tagged
+        .iter()
+        .map(|eid| {
+            let d = *eid as DartIdType;
+            let a = map.force_read_vertex(map.vertex_id(d)).unwrap();
+            let b = map
+                .force_read_vertex(map.vertex_id(map.beta::<1>(d)))
+                .unwrap();
+            (b - a).norm()
+        })
+        .sum();
+    assert!((length - 4.0).abs() < 1e-10);
+}
+
+// --- target size
+
+#[test]
+fn target_size_edge_average() {
+    // no storage: global value
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    let edge = find_edge(&map, (0.0, 0.0), (1.0, 0.0));
+    assert!((force_edge_target_size(&map, edge, 0.5) - 0.5).abs() < 1e-10);
+
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(2)
+        .add_attribute::<TargetSize<f64>>()
+        .build()
+        .unwrap();
+    let edge = find_edge(&map, (0.0, 0.0), (1.0, 0.0));
+    let (v1, v2) = (
+        map.vertex_id(edge as DartIdType),
+        map.vertex_id(map.beta::<1>(edge as DartIdType)),
+    );
+    // no values: global value
+    assert!((force_edge_target_size(&map, edge, 0.5) - 0.5).abs() < 1e-10);
+    // one value
+    map.force_write_attribute(v1, TargetSize(0.2));
+    assert!((force_edge_target_size(&map, edge, 0.5) - 0.2).abs() < 1e-10);
+    // both values
+    map.force_write_attribute(v2, TargetSize(0.4));
+    assert!((force_edge_target_size(&map, edge, 0.5) - 0.3).abs() < 1e-10);
+}
+
+#[test]
+fn target_size_collapse() {
+    let build = || {
+        let map: CMap2<f64> = CMapBuilder::unit_triangles(3)
+            .add_attribute::<TargetSize<f64>>()
+            .build()
+            .unwrap();
+        for vid in map.iter_vertices() {
+            let v = map.force_read_vertex(vid).unwrap();
+            map.force_write_attribute(vid, TargetSize(0.1 + v.x()));
+        }
+        map
+    };
+
+    for (placement, expected) in [
+        (CollapsePlacement::Midpoint, 1.6),
+        (CollapsePlacement::KeepSource, 1.1),
+        (CollapsePlacement::KeepTarget, 2.1),
+    ] {
+        let map = build();
+        let edge = find_edge(&map, (1.0, 1.0), (2.0, 1.0));
+        let vid = force_collapse_edge(&map, edge, placement).unwrap();
+        let size = map.force_read_attribute::<TargetSize<f64>>(vid).unwrap();
+        assert!((size.0 - expected).abs() < 1e-10);
+        // other vertices keep their own size
+        for vid in map.iter_vertices().filter(|v| *v != vid) {
+            let v = map.force_read_vertex(vid).unwrap();
+            assert_eq!(
+                map.force_read_attribute::<TargetSize<f64>>(vid),
+                Some(TargetSize(0.1 + v.x()))
+            );
+        }
+    }
+}
+
+// --- split_nonmanifold_vertices
+
+/// Two separate triangles `O P Q` & `O' R S`, with `O` and `O'` at the same position.
+///
+/// ```text
+///  Q        S
+///  | \    / |
+///  |  \  /  |
+///  |    O   |
+///  |  /  \  |
+///  | /    \ |
+///  P        R
+/// ```
+fn bowtie() -> CMap2<f64> {
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(6).build().unwrap();
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 1);
+    map.force_link::<1>(4, 5);
+    map.force_link::<1>(5, 6);
+    map.force_link::<1>(6, 4);
+    map.force_write_vertex(1, (1.0, 1.0)); // O
+    map.force_write_vertex(2, (0.0, 0.0)); // P
+    map.force_write_vertex(3, (0.0, 2.0)); // Q
+    map.force_write_vertex(4, (1.0, 1.0)); // O'
+    map.force_write_vertex(5, (2.0, 0.0)); // R
+    map.force_write_vertex(6, (2.0, 2.0)); // S
+    map
+}
+
+#[test]
+fn split_bowtie() {
+    let mut map = bowtie();
+    assert_eq!(map.iter_vertices().count(), 6);
+    assert!(!map.is_manifold());
+
+    assert_eq!(split_nonmanifold_vertices(&mut map), 1);
+    assert!(map.is_manifold());
+    // O is kept in place, O' is moved towards R & S
+    assert_eq!(map.iter_vertices().count(), 6);
+    assert_eq!(map.force_read_vertex(1), Some(Vertex2(1.0, 1.0)));
+    let o_prime = map.force_read_vertex(4).unwrap();
+    assert!((o_prime.x() - 1.01).abs() < 1e-10);
+    assert!((o_prime.y() - 1.0).abs() < 1e-10);
+    // other vertices are left unchanged
+    assert_eq!(map.force_read_vertex(2), Some(Vertex2(0.0, 0.0)));
+    assert_eq!(map.force_read_vertex(6), Some(Vertex2(2.0, 2.0)));
+    // nothing left to fix
+    assert_eq!(split_nonmanifold_vertices(&mut map), 0);
+}
+
+#[test]
+fn split_merged_fans() {
+    let mut map = bowtie();
+    // a one-way link merges both fans of O, as well as P & S
+    let _ = map.force_remove_vertex(4);
+    map.set_beta::<2>(1, 6);
+    assert_eq!(map.iter_vertices().count(), 4);
+    assert_eq!(map.vertex_fans(1).len(), 2);
+    assert!(!map.is_manifold());
+
+    assert_eq!(split_nonmanifold_vertices(&mut map), 2);
+    assert!(map.is_i_free::<2>(1));
+    assert_eq!(map.iter_vertices().count(), 6);
+    assert!(map.is_manifold());
+    // O is made of two separate vertices again
+    assert_eq!(map.vertex_id(1), 1);
+    assert_eq!(map.vertex_id(4), 4);
+    assert_eq!(map.force_read_vertex(1), Some(Vertex2(1.0, 1.0)));
+    assert_ne!(map.force_read_vertex(4), Some(Vertex2(1.0, 1.0)));
+    assert_eq!(split_nonmanifold_vertices(&mut map), 0);
+}
+
+#[test]
+fn split_keeps_reciprocated_links() {
+    // boundary dart 1 is wrongly linked to dart 8, which is already linked to dart 2
+    let mut map: CMap2<f64> = CMapBuilder::unit_grid(2).build().unwrap();
+    assert_eq!(map.beta::<2>(2), 8);
+    assert!(map.is_i_free::<2>(1));
+    assert_eq!(map.iter_vertices().count(), 9);
+    map.set_beta::<2>(1, 8);
+    assert!(map.iter_vertices().count() < 9);
+
+    assert!(split_nonmanifold_vertices(&mut map) > 0);
+    assert!(map.is_i_free::<2>(1));
+    assert_eq!(map.beta::<2>(2), 8);
+    assert_eq!(map.beta::<2>(8), 2);
+    assert_eq!(map.iter_vertices().count(), 9);
+    assert!(map.is_manifold());
+}
+
+// --- transfer_field
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Temperature(pub f64);
+
+impl AttributeUpdate for Temperature {
+    fn merge(attr1: Self, attr2: Self) -> Self {
+        Temperature(attr1.0.max(attr2.0))
+    }
+
+    fn split(attr: Self) -> (Self, Self) {
+        (attr, attr)
+    }
+}
+
+impl AttributeBind for Temperature {
+    type StorageType = AttrSparseVec<Self>;
+    type IdentifierType = VertexIdType;
+    const BIND_POLICY: OrbitPolicy = OrbitPolicy::Vertex;
+}
+
+impl From<f64> for Temperature {
+    fn from(val: f64) -> Self {
+        Temperature(val)
+    }
+}
+
+impl From<Temperature> for f64 {
+    fn from(val: Temperature) -> Self {
+        val.0
+    }
+}
+
+#[test]
+fn transfer_linear_field() {
+    let field = |v: Vertex2<f64>| 2.0 * v.x() - v.y() + 1.0;
+    // coarse grid, with a linear field
+    let from = CMapBuilder::from(
+        GridDescriptor::default()
+            .len_per_cell([1.5; 3])
+            .n_cells([2; 3]),
+    )
+    .add_attribute::<Temperature>()
+    .build()
+    .unwrap();
+    from.iter_vertices().for_each(|vid| {
+        let v = from.force_read_vertex(vid).unwrap();
+        from.force_write_attribute(vid, Temperature(field(v)));
+    });
+    // finer grid, covering the same domain
+    let mut to = CMapBuilder::unit_triangles(3)
+        .add_attribute::<Temperature>()
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        transfer_field::<f64, Temperature>(&from, &mut to),
+        to.iter_vertices().count()
+    );
+    to.iter_vertices().for_each(|vid| {
+        let v = to.force_read_vertex(vid).unwrap();
+        let val = to.force_read_attribute::<Temperature>(vid).unwrap();
+        assert!((val.0 - field(v)).abs() < 1e-10);
+    });
+}
+
+#[test]
+fn transfer_outside_domain() {
+    let from = CMapBuilder::unit_grid(1)
+        .add_attribute::<Temperature>()
+        .build()
+        .unwrap();
+    from.iter_vertices().for_each(|vid| {
+        from.force_write_attribute(vid, Temperature(1.0));
+    });
+    // only the bottom-left square overlaps with the source map
+    let mut to = CMapBuilder::unit_grid(2)
+        .add_attribute::<Temperature>()
+        .build()
+        .unwrap();
+
+    assert_eq!(transfer_field::<f64, Temperature>(&from, &mut to), 4);
+    to.iter_vertices().for_each(|vid| {
+        let v = to.force_read_vertex(vid).unwrap();
+        let val = to.force_read_attribute::<Temperature>(vid);
+        if v.x() <= 1.0 && v.y() <= 1.0 {
+            assert_eq!(val, Some(Temperature(1.0)));
+        } else {
+            assert_eq!(val, None);
+        }
+    });
+}
@@ -0,0 +1,77 @@
+//! inter-mesh field transfer functions
+
+// ------ IMPORTS
+
+use crate::locate::locate_containing_face_2d;
+use honeycomb_core::attributes::{AttributeBind, AttributeUpdate};
+use honeycomb_core::cmap::{CMap2, OrbitPolicy, VertexIdType};
+use honeycomb_core::geometry::CoordsFloat;
+
+// ------ CONTENT
+
+#[allow(clippy::must_use_candidate)]
+/// Transfer a vertex attribute from one map to another using barycentric interpolation.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// For each vertex of `to`, this function locates the face of `from` containing it (see
+/// [`locate_containing_face_2d`]), and interpolates the attribute `A` from the values of the
+/// face's vertices. Faces are split into triangles using a fan centered on their first vertex,
+/// meaning that:
+/// - faces of `from` are expected to be convex,
+/// - linear fields are transferred exactly.
+///
+/// Faces are located by exhaustive search, resulting in a `O(n_vertices * n_faces)` complexity.
+///
+/// # Arguments
+///
+/// - `from: &CMap2<T>` -- Map holding the attribute values.
+/// - `to: &mut CMap2<T>` -- Map receiving the attribute values.
+///
+/// # Generics
+///
+/// - `T: CoordsFloat` -- Floating point type used for coordinates.
+/// - `A` -- Transferred attribute. It must be bound to vertices, and convertible from & to `T`
+///   values in order to be interpolated.
+///
+/// # Return
+///
+/// Return the number of vertices of `to` for which a value was written. Vertices that are not
+/// located inside a face of `from`, or whose containing face has undefined attribute values, are
+/// left untouched.
+///
+/// # Panics
+///
+/// This function will panic if `A` is not bound to vertices.
+pub fn transfer_field<T, A>(from: &CMap2<T>, to: &mut CMap2<T>) -> usize
+where
+    T: CoordsFloat,
+    A: AttributeBind + AttributeUpdate + Copy + From<T> + Into<T>,
+{
+    assert_eq!(
+        A::BIND_POLICY,
+        OrbitPolicy::Vertex,
+        "E: transferred attribute must be bound to vertices"
+    );
+
+    let targets: Vec<VertexIdType> = to.iter_vertices().collect();
+    let mut n_written = 0;
+    for vid in targets {
+        let Some(p) = to.force_read_vertex(vid) else {
+            continue;
+        };
+        let val = locate_containing_face_2d(from, &p).and_then(|(_, weights)| {
+            weights.iter().try_fold(T::zero(), |acc, (v, l)| {
+                let a = from.force_read_attribute::<A>(A::IdentifierType::from(*v))?;
+                Some(acc + *l * a.into())
+            })
+        });
+        if let Some(val) = val {
+            to.force_write_attribute(A::IdentifierType::from(vid), A::from(val));
+            n_written += 1;
+        }
+    }
+    n_written
+}
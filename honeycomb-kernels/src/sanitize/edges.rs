@@ -0,0 +1,68 @@
+//! edge deduplication functions
+
+// ------ IMPORTS
+
+use honeycomb_core::cmap::{CMap2, DartIdType};
+use honeycomb_core::geometry::{CoordsFloat, Vertex2};
+
+// ------ CONTENT
+
+/// Tolerance used to compare vertex positions, relative to the diagonal of the bounding box.
+const TOLERANCE: f64 = 1e-8;
+
+#[allow(clippy::cast_possible_truncation, clippy::missing_panics_doc)]
+/// Sew duplicate edges of the map.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// This function detects pairs of 2-free darts modeling the same edge, i.e. darts whose
+/// endpoints coincide but go in opposite directions, and 2-sews them. This repairs maps where
+/// the faces on both sides of an edge were not sewn, e.g. because the shared vertices were
+/// duplicated with slightly different coordinates. Vertices of sewn darts are merged.
+///
+/// Positions are compared using a tolerance relative to the size of the map's bounding box.
+/// Darts with undefined endpoints, and duplicates going in the same direction, which cannot be
+/// sewn without breaking orientation, are left untouched.
+///
+/// Candidates are compared pairwise, resulting in a `O(n_free^2)` cost, where `n_free` is the
+/// number of 2-free darts of the map.
+///
+/// # Arguments
+///
+/// - `map: &mut CMap2<T>` -- Reference to the modified map.
+///
+/// # Return
+///
+/// Return the number of duplicate edges that were sewn.
+pub fn deduplicate_edges<T: CoordsFloat>(map: &mut CMap2<T>) -> usize {
+    let Some((min, max)) = map.bounding_box() else {
+        return 0;
+    };
+    let tol = T::from(TOLERANCE).unwrap() * (max - min).norm();
+    let coincide = |a: &Vertex2<T>, b: &Vertex2<T>| (*b - *a).norm() <= tol;
+
+    // 2-free darts belonging to a face, along with their endpoints
+    let mut candidates: Vec<(DartIdType, Vertex2<T>, Vertex2<T>)> = (1..map.n_darts()
+        as DartIdType)
+        .filter(|d| map.is_i_free::<2>(*d) && !map.is_i_free::<1>(*d))
+        .filter_map(|d| {
+            let src = map.force_read_vertex(map.vertex_id(d))?;
+            let dst = map.force_read_vertex(map.vertex_id(map.beta::<1>(d)))?;
+            Some((d, src, dst))
+        })
+        .collect();
+
+    let mut n_sewn = 0;
+    while let Some((d, src, dst)) = candidates.pop() {
+        if let Some(idx) = candidates.iter().position(|(_, other_src, other_dst)| {
+            coincide(&src, other_dst) && coincide(&dst, other_src)
+        }) {
+            let (e, _, _) = candidates.swap_remove(idx);
+            map.force_sew::<2>(d, e);
+            n_sewn += 1;
+        }
+    }
+    n_sewn
+}
@@ -0,0 +1,19 @@
+//! Mesh sanitization
+//!
+//! This module contains implementations of sanity passes used to repair maps obtained from
+//! imperfect inputs, e.g. files whose shared vertices were written with slightly different
+//! coordinates. We currently define:
+//! - edge deduplication -- sew boundary darts modeling the same edge
+
+// ------ MODULE DECLARATIONS
+
+mod edges;
+
+// ------ PUBLIC RE-EXPORTS
+
+pub use edges::deduplicate_edges;
+
+// ------ TESTS
+
+#[cfg(test)]
+mod tests;
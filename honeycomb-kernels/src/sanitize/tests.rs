@@ -0,0 +1,60 @@
+use super::*;
+use honeycomb_core::cmap::{CMap2, CMapBuilder, NULL_DART_ID};
+use honeycomb_core::geometry::Vertex2;
+
+//  (0, 1) +           + (0, 1) + eps
+//         | \       / |
+//         |   \   /   |
+//  (0, 0) +----+ +----+ (1, 1)
+//            (1, 0)
+fn unsewn_triangles(eps: f64) -> CMap2<f64> {
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(6).build().unwrap();
+    // (0, 0) -> (1, 0) -> (0, 1)
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 1);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (1.0, 0.0));
+    map.force_write_vertex(3, (0.0, 1.0));
+    // (1, 0) -> (1, 1) -> (0, 1)
+    map.force_link::<1>(4, 5);
+    map.force_link::<1>(5, 6);
+    map.force_link::<1>(6, 4);
+    map.force_write_vertex(4, (1.0, 0.0));
+    map.force_write_vertex(5, (1.0, 1.0));
+    map.force_write_vertex(6, (0.0, 1.0 + eps));
+    map
+}
+
+#[test]
+fn deduplicate_edges_sew() {
+    let mut map = unsewn_triangles(1e-12);
+    assert_eq!(map.iter_vertices().count(), 6);
+    assert_eq!(map.iter_edges().count(), 6);
+
+    assert_eq!(deduplicate_edges(&mut map), 1);
+    assert_eq!(map.beta::<2>(2), 6);
+    assert_eq!(map.beta::<2>(6), 2);
+    assert_eq!(map.iter_vertices().count(), 4);
+    assert_eq!(map.iter_edges().count(), 5);
+    assert_eq!(map.iter_faces().count(), 2);
+    let v = map.force_read_vertex(map.vertex_id(3)).unwrap();
+    assert!((v - Vertex2(0.0, 1.0)).norm() < 1e-10);
+
+    // nothing left to fix
+    assert_eq!(deduplicate_edges(&mut map), 0);
+}
+
+#[test]
+fn deduplicate_edges_distinct() {
+    // vertices are too far apart to be merged
+    let mut map = unsewn_triangles(1e-3);
+    assert_eq!(deduplicate_edges(&mut map), 0);
+    assert_eq!(map.beta::<2>(2), NULL_DART_ID);
+    assert_eq!(map.iter_vertices().count(), 6);
+
+    // sewn maps are left untouched
+    let mut map: CMap2<f64> = CMapBuilder::unit_triangles(3).build().unwrap();
+    assert_eq!(deduplicate_edges(&mut map), 0);
+    assert_eq!(map.iter_edges().count(), 33);
+}
@@ -2,7 +2,8 @@
 
 // ------ IMPORTS
 
-use crate::splits::SplitEdgeError;
+use crate::splits::{check_placements, SplitEdgeError};
+use crate::utils::{is_constraint_edge, ConstraintEdge};
 use honeycomb_core::cmap::{CMap2, DartIdType, EdgeIdType, NULL_DART_ID};
 use honeycomb_core::geometry::CoordsFloat;
 use honeycomb_core::stm::{atomically, Transaction};
@@ -15,6 +16,9 @@ use honeycomb_core::stm::{atomically, Transaction};
 /// This implementation is 2D specific.
 /// </div>
 ///
+/// If the edge is tagged as a constraint (see [`ConstraintEdge`]), all resulting edges are
+/// tagged as well.
+///
 /// # Arguments
 ///
 /// - `cmap: &mut CMap2<T>` -- Reference to the modified map.
@@ -24,7 +28,8 @@ use honeycomb_core::stm::{atomically, Transaction};
 ///
 /// ## Generics
 ///
-/// - `I: Iterator<Item = T>` -- Iterator over `T` values. These should be in the `]0; 1[` open range.
+/// - `I: Iterator<Item = T>` -- Iterator over `T` values. These should be in the `]0; 1[` open
+///   range, and strictly increasing.
 ///
 /// # Return / Errors
 ///
@@ -89,6 +94,8 @@ pub fn splitn_edge<T: CoordsFloat>(
     // check pre-allocated darts reqs
     let midpoint_vertices = midpoint_vertices.into_iter().collect::<Vec<_>>();
     let n_t = midpoint_vertices.len();
+    // check placements before allocating darts
+    check_placements(&midpoint_vertices)?;
 
     // base darts making up the edge
     let base_dart1 = edge_id as DartIdType;
@@ -119,6 +126,7 @@ pub fn splitn_edge<T: CoordsFloat>(
                 SplitEdgeError::FailedTransaction(stme) => Err(stme),
                 SplitEdgeError::UndefinedEdge
                 | SplitEdgeError::VertexBound
+                | SplitEdgeError::UnorderedVertices
                 | SplitEdgeError::InvalidDarts(_)
                 | SplitEdgeError::WrongAmountDarts(_, _) => Ok(Err(e)),
             }
@@ -128,6 +136,60 @@ pub fn splitn_edge<T: CoordsFloat>(
     })
 }
 
+#[allow(clippy::missing_errors_doc)]
+/// Split an edge into `n` segments of equal length.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// This is a shortcut for [`splitn_edge`] using `1/n, 2/n, ..., (n-1)/n` as relative positions
+/// of the new vertices. If `n` is lower than 2, the edge is left unchanged.
+///
+/// # Arguments
+///
+/// - `cmap: &mut CMap2<T>` -- Reference to the modified map.
+/// - `edge_id: EdgeIdentifier` -- Edge to split.
+/// - `n: usize` -- Number of segments resulting from the split.
+///
+/// # Return / Errors
+///
+/// This method will return:
+/// - `Ok(())` if the operation is successful & the edge was split
+/// - `Err(SplitEdgeError)` if the operation fails & the edge is left unchanged. Causes of failure
+///   are described in [`SplitEdgeError`]'s documentation.
+///
+/// # Panics
+///
+/// This function will panic if `n` cannot be converted to `T`.
+///
+/// # Example
+///
+/// ```
+/// # use honeycomb_core::prelude::{CMap2, CMapBuilder, Vertex2};
+/// # use honeycomb_kernels::splits::splitn_edge_uniform;
+/// let mut map: CMap2<f64> = CMapBuilder::default().n_darts(2).build().unwrap();
+/// map.force_link::<2>(1, 2);
+/// map.force_write_vertex(1, (0.0, 0.0));
+/// map.force_write_vertex(2, (1.0, 0.0));
+///
+/// assert!(splitn_edge_uniform(&mut map, 1, 4).is_ok());
+/// assert_eq!(map.force_read_vertex(3), Some(Vertex2(0.25, 0.0)));
+/// assert_eq!(map.force_read_vertex(4), Some(Vertex2(0.50, 0.0)));
+/// assert_eq!(map.force_read_vertex(5), Some(Vertex2(0.75, 0.0)));
+/// ```
+pub fn splitn_edge_uniform<T: CoordsFloat>(
+    cmap: &mut CMap2<T>,
+    edge_id: EdgeIdType,
+    n: usize,
+) -> Result<(), SplitEdgeError> {
+    if n < 2 {
+        return Ok(());
+    }
+    let n_t = T::from(n).unwrap();
+    splitn_edge(cmap, edge_id, (1..n).map(|i| T::from(i).unwrap() / n_t))
+}
+
 #[allow(clippy::missing_errors_doc)]
 /// Split an edge into `n` segments.
 ///
@@ -150,7 +212,8 @@ pub fn splitn_edge<T: CoordsFloat>(
 /// - `edge_id: EdgeIdentifier` -- Edge to split in two.
 /// - `new_darts: &[DartIdentifier]` -- Dart IDs used to build the new segments.
 /// - `midpoint_vertices: &[T]` -- Relative positions of new vertices, starting from the
-///   vertex of the dart sharing `edge_id` as its identifier.
+///   vertex of the dart sharing `edge_id` as its identifier. These should be in the `]0; 1[`
+///   open range, and strictly increasing.
 ///
 /// ## Dart IDs Requirements & Usage
 ///
@@ -225,14 +288,11 @@ fn inner_splitn<T: CoordsFloat>(
     darts_sh: &[DartIdType], //second half
     midpoint_vertices: &[T],
 ) -> Result<(), SplitEdgeError> {
-    if midpoint_vertices
-        .iter()
-        .any(|t| (*t >= T::one()) | (*t <= T::zero()))
-    {
-        return Err(SplitEdgeError::VertexBound);
-    }
+    check_placements(midpoint_vertices)?;
 
     let base_dart2 = cmap.beta_transac::<2>(trans, base_dart1)?;
+    let base_eid = cmap.edge_id_transac(trans, base_dart1)?;
+    let constrained = is_constraint_edge(cmap, trans, base_eid)?;
     let b1d1_old = cmap.beta_transac::<1>(trans, base_dart1)?;
 
     let (vid1, vid2) = (
@@ -254,7 +314,6 @@ fn inner_splitn<T: CoordsFloat>(
     ) else {
         return Err(SplitEdgeError::UndefinedEdge);
     };
-    let seg = v2 - v1;
 
     // unsew current dart
     if b1d1_old != NULL_DART_ID {
@@ -267,7 +326,7 @@ fn inner_splitn<T: CoordsFloat>(
     // insert new vertices / darts on base_dart1's side
     let mut prev_d = base_dart1;
     for (&t, &new_d) in midpoint_vertices.iter().zip(darts_fh.iter()) {
-        let new_v = v1 + seg * t;
+        let new_v = v1.lerp(&v2, t);
         cmap.link::<1>(trans, prev_d, new_d)?;
         cmap.write_vertex(trans, new_d, new_v)?;
         prev_d = new_d;
@@ -291,6 +350,12 @@ fn inner_splitn<T: CoordsFloat>(
         }
         cmap.link::<2>(trans, prev_d, base_dart1)?;
     }
+    if constrained {
+        for dart in std::iter::once(base_dart1).chain(darts_fh.iter().copied()) {
+            let eid = cmap.edge_id_transac(trans, dart)?;
+            cmap.write_attribute(trans, eid, ConstraintEdge(true))?;
+        }
+    }
 
     Ok(())
 }
@@ -3,8 +3,9 @@
 // ------ IMPORTS
 
 use crate::splits::SplitEdgeError;
+use crate::utils::{is_constraint_edge, ConstraintEdge};
 use honeycomb_core::cmap::{CMap2, DartIdType, EdgeIdType, NULL_DART_ID};
-use honeycomb_core::geometry::{CoordsFloat, Vertex2};
+use honeycomb_core::geometry::CoordsFloat;
 use honeycomb_core::stm::{atomically, Transaction};
 
 // ------ CONTENT
@@ -25,6 +26,9 @@ use honeycomb_core::stm::{atomically, Transaction};
 ///
 /// For an illustration of both principles, refer to the example.
 ///
+/// If the edge is tagged as a constraint (see [`ConstraintEdge`]), both resulting edges are
+/// tagged as well.
+///
 /// # Arguments
 ///
 /// - `cmap: &mut CMap2<T>` -- Reference to the modified map.
@@ -78,6 +82,7 @@ pub fn split_edge<T: CoordsFloat>(
                 SplitEdgeError::FailedTransaction(stme) => Err(stme),
                 SplitEdgeError::UndefinedEdge => Ok(Err(e)),
                 SplitEdgeError::VertexBound
+                | SplitEdgeError::UnorderedVertices
                 | SplitEdgeError::InvalidDarts(_)
                 | SplitEdgeError::WrongAmountDarts(_, _) => unreachable!(),
             }
@@ -170,6 +175,8 @@ fn inner_split<T: CoordsFloat>(
 ) -> Result<(), SplitEdgeError> {
     // base darts making up the edge
     let base_dart2 = cmap.beta_transac::<2>(trans, base_dart1)?;
+    let base_eid = cmap.edge_id_transac(trans, base_dart1)?;
+    let constrained = is_constraint_edge(cmap, trans, base_eid)?;
     if base_dart2 == NULL_DART_ID {
         let b1d1_old = cmap.beta_transac::<1>(trans, base_dart1)?;
         let b1d1_new = new_darts.0;
@@ -193,13 +200,18 @@ fn inner_split<T: CoordsFloat>(
         cmap.link::<1>(trans, base_dart1, b1d1_new)?;
         cmap.link::<1>(trans, b1d1_new, b1d1_old)?;
         // insert the new vertex
-        let seg = v2 - v1;
         let vnew = cmap.vertex_id_transac(trans, b1d1_new)?;
         cmap.write_vertex(
             trans,
             vnew,
-            midpoint_vertex.map_or(Vertex2::average(&v1, &v2), |t| v1 + seg * t),
+            midpoint_vertex.map_or(v1.midpoint(&v2), |t| v1.lerp(&v2, t)),
         )?;
+        if constrained {
+            for dart in [base_dart1, b1d1_new] {
+                let eid = cmap.edge_id_transac(trans, dart)?;
+                cmap.write_attribute(trans, eid, ConstraintEdge(true))?;
+            }
+        }
         Ok(())
     } else {
         let b1d1_old = cmap.beta_transac::<1>(trans, base_dart1)?;
@@ -239,13 +251,18 @@ fn inner_split<T: CoordsFloat>(
         cmap.link::<2>(trans, base_dart1, b1d2_new)?;
         cmap.link::<2>(trans, base_dart2, b1d1_new)?;
         // insert the new vertex
-        let seg = v2 - v1;
         let vnew = cmap.vertex_id_transac(trans, b1d1_new)?;
         cmap.write_vertex(
             trans,
             vnew,
-            midpoint_vertex.map_or(Vertex2::average(&v1, &v2), |t| v1 + seg * t),
+            midpoint_vertex.map_or(v1.midpoint(&v2), |t| v1.lerp(&v2, t)),
         )?;
+        if constrained {
+            for dart in [base_dart1, b1d1_new] {
+                let eid = cmap.edge_id_transac(trans, dart)?;
+                cmap.write_attribute(trans, eid, ConstraintEdge(true))?;
+            }
+        }
         Ok(())
     }
 }
@@ -12,8 +12,9 @@ mod edge_single;
 
 // ------ PUBLIC RE-EXPORTS
 
-pub use edge_multiple::{splitn_edge, splitn_edge_transac};
+pub use edge_multiple::{splitn_edge, splitn_edge_transac, splitn_edge_uniform};
 pub use edge_single::{split_edge, split_edge_transac};
+use honeycomb_core::geometry::CoordsFloat;
 use honeycomb_core::stm::StmError;
 
 // ------ CONTENT
@@ -27,6 +28,9 @@ pub enum SplitEdgeError {
     /// Relative position of the new vertex isn't located on the edge.
     #[error("vertex placement for split is not in ]0;1[")]
     VertexBound,
+    /// Relative positions of new vertices are not strictly increasing.
+    #[error("vertex placements for split are not strictly increasing")]
+    UnorderedVertices,
     /// One or both vertices of the edge are undefined.
     #[error("edge isn't defined correctly")]
     UndefinedEdge,
@@ -45,6 +49,20 @@ impl From<StmError> for SplitEdgeError {
     }
 }
 
+/// Check that relative positions of new vertices are in `]0;1[` and strictly increasing.
+fn check_placements<T: CoordsFloat>(midpoint_vertices: &[T]) -> Result<(), SplitEdgeError> {
+    if midpoint_vertices
+        .iter()
+        .any(|t| (*t >= T::one()) | (*t <= T::zero()))
+    {
+        return Err(SplitEdgeError::VertexBound);
+    }
+    if midpoint_vertices.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(SplitEdgeError::UnorderedVertices);
+    }
+    Ok(())
+}
+
 // ------ TESTS
 
 #[cfg(test)]
@@ -252,6 +252,50 @@ mod standard {
         assert!(splitn_edge(&mut map, 1, [0.25, 0.50, 0.75])
             .is_err_and(|e| e == SplitEdgeError::UndefinedEdge));
     }
+
+    #[test]
+    fn splitn_edge_invalid_placements() {
+        let mut map: CMap2<f64> = newmap(2);
+        map.force_link::<2>(1, 2);
+        map.force_write_vertex(1, (0.0, 0.0));
+        map.force_write_vertex(2, (1.0, 0.0));
+        assert_eq!(
+            splitn_edge(&mut map, 1, [0.25, 1.0]),
+            Err(SplitEdgeError::VertexBound)
+        );
+        assert_eq!(
+            splitn_edge(&mut map, 1, [0.75, 0.25]),
+            Err(SplitEdgeError::UnorderedVertices)
+        );
+        assert_eq!(
+            splitn_edge(&mut map, 1, [0.25, 0.25]),
+            Err(SplitEdgeError::UnorderedVertices)
+        );
+        // no dart was allocated
+        assert_eq!(map.n_darts(), 3);
+        assert_eq!(map.beta::<2>(1), 2);
+    }
+
+    #[test]
+    fn splitn_edge_uniform_complete() {
+        let mut map: CMap2<f64> = newmap(2);
+        map.force_link::<2>(1, 2);
+        map.force_write_vertex(1, (0.0, 0.0));
+        map.force_write_vertex(2, (3.0, 0.0));
+        assert!(splitn_edge_uniform(&mut map, 1, 3).is_ok());
+        assert_eq!(map.beta::<1>(1), 3);
+        assert_eq!(map.beta::<1>(3), 4);
+        assert_eq!(map.beta::<1>(4), NULL_DART_ID);
+        assert_eq!(map.force_read_vertex(3), Some(Vertex2(1.0, 0.0)));
+        assert_eq!(map.force_read_vertex(4), Some(Vertex2(2.0, 0.0)));
+        assert_eq!(map.beta::<2>(1), 6);
+        assert_eq!(map.beta::<2>(4), 2);
+
+        // a single segment leaves the edge as is
+        assert!(splitn_edge_uniform(&mut map, 1, 1).is_ok());
+        assert_eq!(map.n_darts(), 7);
+        assert_eq!(map.beta::<1>(1), 3);
+    }
 }
 
 mod noalloc {
@@ -285,6 +329,7 @@ mod noalloc {
                     SplitEdgeError::FailedTransaction(stme) => Err(stme),
                     SplitEdgeError::UndefinedEdge
                     | SplitEdgeError::VertexBound
+                    | SplitEdgeError::UnorderedVertices
                     | SplitEdgeError::InvalidDarts(_)
                     | SplitEdgeError::WrongAmountDarts(_, _) => Ok(Err(e)),
                 }
@@ -333,6 +378,7 @@ mod noalloc {
                     SplitEdgeError::FailedTransaction(stme) => Err(stme),
                     SplitEdgeError::UndefinedEdge
                     | SplitEdgeError::VertexBound
+                    | SplitEdgeError::UnorderedVertices
                     | SplitEdgeError::InvalidDarts(_)
                     | SplitEdgeError::WrongAmountDarts(_, _) => Ok(Err(e)),
                 }
@@ -375,6 +421,7 @@ mod noalloc {
                     SplitEdgeError::FailedTransaction(stme) => Err(stme),
                     SplitEdgeError::UndefinedEdge
                     | SplitEdgeError::VertexBound
+                    | SplitEdgeError::UnorderedVertices
                     | SplitEdgeError::InvalidDarts(_)
                     | SplitEdgeError::WrongAmountDarts(_, _) => Ok(Err(e)),
                 }
@@ -408,6 +455,7 @@ mod noalloc {
                     SplitEdgeError::FailedTransaction(stme) => Err(stme),
                     SplitEdgeError::UndefinedEdge
                     | SplitEdgeError::VertexBound
+                    | SplitEdgeError::UnorderedVertices
                     | SplitEdgeError::InvalidDarts(_)
                     | SplitEdgeError::WrongAmountDarts(_, _) => Ok(Err(e)),
                 }
@@ -447,6 +495,7 @@ mod noalloc {
                     SplitEdgeError::FailedTransaction(stme) => Err(stme),
                     SplitEdgeError::UndefinedEdge
                     | SplitEdgeError::VertexBound
+                    | SplitEdgeError::UnorderedVertices
                     | SplitEdgeError::InvalidDarts(_)
                     | SplitEdgeError::WrongAmountDarts(_, _) => Ok(Err(e)),
                 }
@@ -499,6 +548,7 @@ mod noalloc {
                     SplitEdgeError::FailedTransaction(stme) => Err(stme),
                     SplitEdgeError::UndefinedEdge
                     | SplitEdgeError::VertexBound
+                    | SplitEdgeError::UnorderedVertices
                     | SplitEdgeError::InvalidDarts(_)
                     | SplitEdgeError::WrongAmountDarts(_, _) => Ok(Err(e)),
                 }
@@ -561,6 +611,7 @@ mod noalloc {
                     SplitEdgeError::FailedTransaction(stme) => Err(stme),
                     SplitEdgeError::UndefinedEdge
                     | SplitEdgeError::VertexBound
+                    | SplitEdgeError::UnorderedVertices
                     | SplitEdgeError::InvalidDarts(_)
                     | SplitEdgeError::WrongAmountDarts(_, _) => Ok(Err(e)),
                 }
@@ -610,6 +661,7 @@ mod noalloc {
                     SplitEdgeError::FailedTransaction(stme) => Err(stme),
                     SplitEdgeError::UndefinedEdge
                     | SplitEdgeError::VertexBound
+                    | SplitEdgeError::UnorderedVertices
                     | SplitEdgeError::InvalidDarts(_)
                     | SplitEdgeError::WrongAmountDarts(_, _) => Ok(Err(e)),
                 }
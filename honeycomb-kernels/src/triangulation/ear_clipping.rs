@@ -1,10 +1,11 @@
 use crate::triangulation::{
-    check_requirements, crossp_from_verts, fetch_face_vertices, TriangulateError,
+    check_requirements, created_faces, crossp_from_verts, fetch_face_vertices, process_with_alloc,
+    TriangulateError,
 };
 use honeycomb_core::cmap::{CMap2, DartIdType, FaceIdType, Orbit2, OrbitPolicy};
-use honeycomb_core::geometry::CoordsFloat;
+use honeycomb_core::geometry::{CoordsFloat, Orientation, Vertex2};
 
-#[allow(clippy::missing_panics_doc)]
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
 /// Triangulates a face using the ear clipping method.
 ///
 /// This function triangulates a cell (face) of a 2D combinatorial map by iteratively
@@ -36,7 +37,9 @@ use honeycomb_core::geometry::CoordsFloat;
 /// - Updates the list of darts and vertices accordingly after each ear clip.
 /// - Continues until the polygon is reduced to a triangle.
 ///
-/// # Errors
+/// # Return / Errors
+///
+/// On success, this function returns the sorted IDs of the triangles resulting from the operation.
 ///
 /// This function will return an error if the face wasn't triangulated. There can be multiple
 /// reason for this:
@@ -57,7 +60,7 @@ pub fn process_cell<T: CoordsFloat>(
     cmap: &mut CMap2<T>,
     face_id: FaceIdType,
     new_darts: &[DartIdType],
-) -> Result<(), TriangulateError> {
+) -> Result<Vec<FaceIdType>, TriangulateError> {
     // fetch darts using a custom orbit so that they're ordered
     let mut darts: Vec<_> =
         Orbit2::new(cmap, OrbitPolicy::Custom(&[1]), face_id as DartIdType).collect();
@@ -78,10 +81,7 @@ pub fn process_cell<T: CoordsFloat>(
             let v3 = &vertices[(*idx + 2) % n]; // C
 
             // we assume the interior of the polygon is on the left side
-            let is_inside = {
-                let tmp = crossp_from_verts(v1, v2, v3);
-                tmp > T::epsilon()
-            };
+            let is_inside = Vertex2::orient(v1, v2, v3) == Orientation::CounterClockwise;
 
             let no_overlap = vertices
                 .iter()
@@ -133,5 +133,30 @@ pub fn process_cell<T: CoordsFloat>(
         n = Orbit2::new(cmap, OrbitPolicy::Custom(&[1]), nd2).count();
     }
 
-    Ok(())
+    Ok(created_faces(cmap, new_darts))
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Triangulates a face using the ear clipping method, allocating the required darts.
+///
+/// This function is equivalent to [`process_cell`], but allocates the `(n-3)*2` darts needed to
+/// triangulate an `n`-sided face using [`CMap2::add_free_darts`]. If the triangulation fails,
+/// the new darts are marked as unused; the number of darts of the map isn't restored.
+///
+/// Since the map is extended, this variant requires exclusive access to it. Batch processing
+/// should pre-allocate darts and use [`process_cell`] instead.
+///
+/// # Arguments
+///
+/// - `cmap: &mut CMap2` - A mutable reference to the modified `CMap2`.
+/// - `face_id: FaceIdentifier` - Identifier of the face to triangulate within the map.
+///
+/// # Return / Errors
+///
+/// Same as [`process_cell`], except that errors related to the number of darts cannot occur.
+pub fn process_cell_alloc<T: CoordsFloat>(
+    cmap: &mut CMap2<T>,
+    face_id: FaceIdType,
+) -> Result<Vec<FaceIdType>, TriangulateError> {
+    process_with_alloc(cmap, face_id, process_cell)
 }
@@ -1,10 +1,11 @@
 use crate::triangulation::{
-    check_requirements, crossp_from_verts, fetch_face_vertices, TriangulateError,
+    check_requirements, created_faces, crossp_from_verts, fetch_face_vertices, process_with_alloc,
+    TriangulateError,
 };
 use honeycomb_core::cmap::{CMap2, DartIdType, FaceIdType, Orbit2, OrbitPolicy};
 use honeycomb_core::geometry::CoordsFloat;
 
-#[allow(clippy::missing_panics_doc)]
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
 /// Triangulates a face using a fan triangulation method.
 ///
 /// This function triangulates a cell (face) in a 2D combinatorial map by creating a fan of
@@ -30,7 +31,9 @@ use honeycomb_core::geometry::CoordsFloat;
 /// - If such a star vertex is found, the function proceeds to create triangles by linking
 ///   new darts in a fan-like structure from this vertex. Otherwise, the cell is left unchanged
 ///
-/// # Errors
+/// # Return / Errors
+///
+/// On success, this function returns the sorted IDs of the triangles resulting from the operation.
 ///
 /// This function will return an error if the face wasn't triangulated. There can be multiple
 /// reason for this:
@@ -45,7 +48,7 @@ pub fn process_cell<T: CoordsFloat>(
     cmap: &mut CMap2<T>,
     face_id: FaceIdType,
     new_darts: &[DartIdType],
-) -> Result<(), TriangulateError> {
+) -> Result<Vec<FaceIdType>, TriangulateError> {
     // fetch darts using a custom orbit so that they're ordered
     let darts: Vec<_> =
         Orbit2::new(cmap, OrbitPolicy::Custom(&[1]), face_id as DartIdType).collect();
@@ -106,10 +109,31 @@ pub fn process_cell<T: CoordsFloat>(
         return Err(TriangulateError::NonFannable);
     }
 
-    Ok(())
+    Ok(created_faces(cmap, new_darts))
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Triangulates a face using a fan triangulation method, allocating the required darts.
+///
+/// This is the allocating variant of [`process_cell`]; allocation works as described for
+/// [`earclip_cell_alloc`][crate::triangulation::earclip_cell_alloc].
+///
+/// # Arguments
+///
+/// - `cmap: &mut CMap2` - A mutable reference to the modified `CMap2`.
+/// - `face_id: FaceIdentifier` - Identifier of the face to triangulate within the map.
+///
+/// # Return / Errors
+///
+/// Same as [`process_cell`], except that errors related to the number of darts cannot occur.
+pub fn process_cell_alloc<T: CoordsFloat>(
+    cmap: &mut CMap2<T>,
+    face_id: FaceIdType,
+) -> Result<Vec<FaceIdType>, TriangulateError> {
+    process_with_alloc(cmap, face_id, process_cell)
 }
 
-#[allow(clippy::missing_panics_doc)]
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
 /// Triangulates a face using a fan triangulation method.
 ///
 /// This function triangulates a cell (face) in a 2D combinatorial map by creating a fan of
@@ -135,7 +159,9 @@ pub fn process_cell<T: CoordsFloat>(
 /// - The function creates triangles by linking new darts in a fan-like structure to the first
 ///   vertex of the polygon. **This is done unconditionnally, whether the polygon is convex or not**.
 ///
-/// # Errors
+/// # Return / Errors
+///
+/// On success, this function returns the sorted IDs of the triangles resulting from the operation.
 ///
 /// This function will return an error if the face wasn't triangulated. There can be multiple
 /// reason for this:
@@ -148,7 +174,7 @@ pub fn process_convex_cell<T: CoordsFloat>(
     cmap: &mut CMap2<T>,
     face_id: FaceIdType,
     new_darts: &[DartIdType],
-) -> Result<(), TriangulateError> {
+) -> Result<Vec<FaceIdType>, TriangulateError> {
     let n = Orbit2::new(cmap, OrbitPolicy::Custom(&[1]), face_id as DartIdType).count();
 
     // early rets
@@ -176,5 +202,5 @@ pub fn process_convex_cell<T: CoordsFloat>(
     cmap.force_sew::<1>(cmap.beta::<1>(cmap.beta::<1>(d0)), d0);
     cmap.force_write_vertex(cmap.vertex_id(sdart), v0);
 
-    Ok(())
+    Ok(created_faces(cmap, new_darts))
 }
@@ -10,6 +10,9 @@
 //!     - a specific one which assume the cell is convex; it fans the polygon from its first vertex
 //! - ear clipping -- this method isn't algorithmically efficient, but (a) we operate on small
 //!   cells, and (b) it covers our needs (non-fannable polygons without holes)
+//!
+//! A dispatcher, `triangulate_cell`, picks the convex fanning method when the cell is convex,
+//! and falls back to ear clipping otherwise.
 
 // ------ MODULE DECLARATIONS
 
@@ -19,12 +22,14 @@ mod fan;
 // ------ PUBLIC RE-EXPORTS
 
 pub use ear_clipping::process_cell as earclip_cell;
+pub use ear_clipping::process_cell_alloc as earclip_cell_alloc;
 pub use fan::process_cell as fan_cell;
+pub use fan::process_cell_alloc as fan_cell_alloc;
 pub use fan::process_convex_cell as fan_convex_cell;
 
 // ------ CONTENT
 
-use honeycomb_core::cmap::{CMap2, DartIdType};
+use honeycomb_core::cmap::{CMap2, DartIdType, FaceIdType, Orbit2, OrbitPolicy};
 use honeycomb_core::geometry::{CoordsFloat, Vertex2};
 use thiserror::Error;
 
@@ -111,6 +116,118 @@ pub fn check_requirements(
     Ok(())
 }
 
+#[allow(clippy::missing_panics_doc)]
+/// Check whether a face is convex.
+///
+/// The check is done by computing the cross product of consecutive edges around the face (see
+/// `crossp_from_verts`); the face is convex if all non-zero values have the same sign, and if the
+/// total turning angle along the boundary is ±2π. The second condition rejects self-intersecting
+/// faces such as star polygons. Collinear consecutive edges are accepted. The orientation of the
+/// face doesn't matter.
+///
+/// # Arguments
+///
+/// - `cmap: &CMap2` - A reference to the map containing the face.
+/// - `face_id: FaceIdentifier` - Identifier of the face to check.
+///
+/// # Return
+///
+/// Return `true` if the face is convex. Faces with less than 3 vertices, or with undefined
+/// vertices, are not considered convex.
+#[must_use = "unused return value"]
+pub fn is_face_convex<T: CoordsFloat>(cmap: &CMap2<T>, face_id: FaceIdType) -> bool {
+    let darts: Vec<_> =
+        Orbit2::new(cmap, OrbitPolicy::Custom(&[1]), face_id as DartIdType).collect();
+    let n = darts.len();
+    if n < 3 {
+        return false;
+    }
+    let Ok(vertices) = fetch_face_vertices(cmap, &darts) else {
+        return false;
+    };
+
+    let (mut pos, mut neg) = (false, false);
+    let mut turning = T::zero();
+    for idx in 0..n {
+        let (v1, v2, v3) = (
+            &vertices[idx],
+            &vertices[(idx + 1) % n],
+            &vertices[(idx + 2) % n],
+        );
+        let crossp = crossp_from_verts(v1, v2, v3);
+        pos |= crossp > T::zero();
+        neg |= crossp < T::zero();
+        turning += crossp.atan2((*v2 - *v1).dot(&(*v3 - *v2)));
+    }
+    // turns of a single sign add up to a multiple of 2π; only simple polygons make one turn
+    let three_pi = T::from(3.0 * std::f64::consts::PI).unwrap();
+    !(pos && neg) && turning.abs() < three_pi
+}
+
+#[allow(clippy::missing_errors_doc)]
+/// Triangulates a face, choosing the method according to its convexity.
+///
+/// Convex faces (see [`is_face_convex`]) are processed using [`fan_convex_cell`], other faces are
+/// processed using [`earclip_cell`].
+///
+/// # Arguments
+///
+/// - `cmap: &mut CMap2` - A mutable reference to the modified `CMap2`.
+/// - `face_id: FaceIdentifier` - Identifier of the face to triangulate within the map.
+/// - `new_darts: &[DartIdentifier]` - Identifiers of pre-allocated darts for the new edges;
+///   the slice length should match the expected number of edges created by the triangulation. For
+///   an `n`-sided polygon, the number of created edge is `n-3`, so the number of dart is `(n-3)*2`.
+///
+/// # Return / Errors
+///
+/// On success, this function returns the sorted IDs of the triangles resulting from the operation.
+///
+/// Errors are the ones of the underlying method; refer to their documentation for more
+/// information.
+pub fn triangulate_cell<T: CoordsFloat>(
+    cmap: &mut CMap2<T>,
+    face_id: FaceIdType,
+    new_darts: &[DartIdType],
+) -> Result<Vec<FaceIdType>, TriangulateError> {
+    if is_face_convex(cmap, face_id) {
+        fan_convex_cell(cmap, face_id, new_darts)
+    } else {
+        earclip_cell(cmap, face_id, new_darts)
+    }
+}
+
+/// Allocate the darts needed to triangulate a face, and run a triangulation routine with them.
+///
+/// If the routine fails, allocated darts that are still free are removed from the map.
+fn process_with_alloc<T: CoordsFloat>(
+    cmap: &mut CMap2<T>,
+    face_id: FaceIdType,
+    routine: impl FnOnce(
+        &mut CMap2<T>,
+        FaceIdType,
+        &[DartIdType],
+    ) -> Result<Vec<FaceIdType>, TriangulateError>,
+) -> Result<Vec<FaceIdType>, TriangulateError> {
+    let n = Orbit2::new(cmap, OrbitPolicy::Custom(&[1]), face_id as DartIdType).count();
+    // let the routine report invalid faces
+    if n <= 3 {
+        return routine(cmap, face_id, &[]);
+    }
+
+    let n_new = (n - 3) * 2;
+    let nd = cmap.add_free_darts(n_new);
+    let new_darts: Vec<DartIdType> = (nd..).take(n_new).collect();
+    let res = routine(cmap, face_id, &new_darts);
+    if res.is_err() {
+        for d in new_darts {
+            if cmap.is_free(d) {
+                cmap.remove_free_dart(d);
+            }
+        }
+    }
+    res
+}
+
 fn fetch_face_vertices<T: CoordsFloat>(
     cmap: &CMap2<T>,
     darts: &[DartIdType],
@@ -127,8 +244,23 @@ fn fetch_face_vertices<T: CoordsFloat>(
     }
 }
 
+/// Return the sorted IDs of faces created by a triangulation.
+///
+/// Each triangle resulting from the operation contains at least one of the new darts, so there is
+/// no need to go through darts of the original face.
+fn created_faces<T: CoordsFloat>(cmap: &CMap2<T>, new_darts: &[DartIdType]) -> Vec<FaceIdType> {
+    let mut faces: Vec<FaceIdType> = new_darts.iter().map(|d| cmap.face_id(*d)).collect();
+    faces.sort_unstable();
+    faces.dedup();
+    faces
+}
+
 /// Compute the cross product: `v1v2 x v2v3`.
-fn crossp_from_verts<T: CoordsFloat>(v1: &Vertex2<T>, v2: &Vertex2<T>, v3: &Vertex2<T>) -> T {
+pub(crate) fn crossp_from_verts<T: CoordsFloat>(
+    v1: &Vertex2<T>,
+    v2: &Vertex2<T>,
+    v3: &Vertex2<T>,
+) -> T {
     (v2.x() - v1.x()) * (v3.y() - v2.y()) - (v2.y() - v1.y()) * (v3.x() - v2.x())
 }
 
@@ -1,4 +1,7 @@
-use crate::triangulation::{earclip_cell, fan_cell, TriangulateError};
+use crate::triangulation::{
+    earclip_cell, earclip_cell_alloc, fan_cell, fan_cell_alloc, is_face_convex, triangulate_cell,
+    TriangulateError,
+};
 use honeycomb_core::cmap::{CMap2, DartIdType, FaceIdType};
 use honeycomb_core::prelude::CMapBuilder;
 
@@ -133,6 +136,29 @@ fn fan_cells() {
     assert_eq!(map.i_cell::<2>(tri as DartIdType).count(), 3); // unchanged
 }
 
+#[test]
+fn fan_returned_faces() {
+    let mut map: CMap2<f64> = CMapBuilder::default().n_darts(5).build().unwrap();
+    (1..=5).for_each(|d| map.force_link::<1>(d, d % 5 + 1));
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (2.0, 0.0));
+    map.force_write_vertex(3, (3.0, 1.0));
+    map.force_write_vertex(4, (1.0, 2.0));
+    map.force_write_vertex(5, (-1.0, 1.0));
+
+    let nd = map.add_free_darts(4);
+    let new_darts = (nd..nd + 4).collect::<Vec<_>>();
+    let faces = fan_cell(&mut map, 1, &new_darts).unwrap();
+    assert_eq!(faces.len(), 3);
+    assert!(faces.windows(2).all(|w| w[0] < w[1]));
+    for fid in &faces {
+        assert_eq!(map.i_cell::<2>(*fid as DartIdType).count(), 3);
+    }
+    let mut all_faces: Vec<FaceIdType> = map.iter_faces().collect();
+    all_faces.sort_unstable();
+    assert_eq!(faces, all_faces);
+}
+
 #[test]
 fn earclip_cells() {
     // generate a map with all kinds of cell
@@ -175,7 +201,10 @@ fn earclip_cells() {
     // 9-gon is split in 7
     let nd = map.add_free_darts(12);
     let new_darts = (nd..nd + 12).collect::<Vec<_>>();
-    assert!(earclip_cell(&mut map, smh, &new_darts).is_ok());
+    assert_eq!(
+        earclip_cell(&mut map, smh, &new_darts).map(|faces| faces.len()),
+        Ok(7)
+    );
 
     assert_eq!(map.i_cell::<2>(smh as DartIdType).count(), 3);
     assert_eq!(map.i_cell::<2>(18).count(), 3);
@@ -192,3 +221,104 @@ fn earclip_cells() {
 
     assert_eq!(map.i_cell::<2>(tri as DartIdType).count(), 3); // unchanged
 }
+
+#[test]
+fn convexity() {
+    let map = generate_map();
+    assert!(is_face_convex(&map, 1));
+    assert!(!is_face_convex(&map, 7));
+    assert!(is_face_convex(&map, 13));
+    assert!(!is_face_convex(&map, 17));
+    assert!(is_face_convex(&map, 26));
+
+    // undefined vertices
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(4).build().unwrap();
+    (1..=4).for_each(|d| map.force_link::<1>(d, d % 4 + 1));
+    assert!(!is_face_convex(&map, 1));
+
+    // pentagram; all turns have the same sign, but the boundary makes two turns
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(5).build().unwrap();
+    (1..=5).for_each(|d| map.force_link::<1>(d, d % 5 + 1));
+    (1..=5u32).for_each(|d| {
+        let angle = f64::from(d * 2) * 2.0 * std::f64::consts::PI / 5.0;
+        map.force_write_vertex(d, (angle.cos(), angle.sin()));
+    });
+    assert!(!is_face_convex(&map, 1));
+
+    // same points, in pentagon order
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(5).build().unwrap();
+    (1..=5).for_each(|d| map.force_link::<1>(d, d % 5 + 1));
+    (1..=5u32).for_each(|d| {
+        let angle = f64::from(d) * 2.0 * std::f64::consts::PI / 5.0;
+        map.force_write_vertex(d, (angle.cos(), angle.sin()));
+    });
+    assert!(is_face_convex(&map, 1));
+}
+
+#[test]
+fn triangulate_cells() {
+    let mut map = generate_map();
+
+    // convex cells, fanned from their first vertex
+    let nd = map.add_free_darts(6);
+    let new_darts = (nd..nd + 6).collect::<Vec<_>>();
+    assert_eq!(
+        triangulate_cell(&mut map, 1, &new_darts).map(|faces| faces.len()),
+        Ok(4)
+    );
+    // square
+    let nd = map.add_free_darts(2);
+    let new_darts = (nd..nd + 2).collect::<Vec<_>>();
+    assert_eq!(
+        triangulate_cell(&mut map, 13, &new_darts).map(|faces| faces.len()),
+        Ok(2)
+    );
+
+    // concave hexagon & 9-gon, ear clipped
+    let nd = map.add_free_darts(6);
+    let new_darts = (nd..nd + 6).collect::<Vec<_>>();
+    assert_eq!(
+        triangulate_cell(&mut map, 7, &new_darts).map(|faces| faces.len()),
+        Ok(4)
+    );
+    let nd = map.add_free_darts(12);
+    let new_darts = (nd..nd + 12).collect::<Vec<_>>();
+    assert_eq!(
+        triangulate_cell(&mut map, 17, &new_darts).map(|faces| faces.len()),
+        Ok(7)
+    );
+
+    assert!(map.iter_faces().all(|fid| map.face_degree(fid) == 3));
+    assert_eq!(
+        triangulate_cell(&mut map, 26, &[]),
+        Err(TriangulateError::AlreadyTriangulated)
+    );
+}
+
+#[test]
+fn alloc_variants() {
+    let mut map = generate_map();
+    let n_darts = map.n_darts();
+
+    assert_eq!(fan_cell_alloc(&mut map, 1).map(|faces| faces.len()), Ok(4));
+    assert_eq!(map.n_darts(), n_darts + 6);
+
+    // darts allocated by failed triangulations are kept, but marked as unused
+    assert_eq!(
+        fan_cell_alloc(&mut map, 17),
+        Err(TriangulateError::NonFannable)
+    );
+    assert_eq!(map.n_darts(), n_darts + 18);
+    assert_eq!(map.n_unused_darts(), 12);
+    assert_eq!(
+        fan_cell_alloc(&mut map, 26),
+        Err(TriangulateError::AlreadyTriangulated)
+    );
+    assert_eq!(map.n_darts(), n_darts + 18);
+
+    assert_eq!(
+        earclip_cell_alloc(&mut map, 17).map(|faces| faces.len()),
+        Ok(7)
+    );
+    assert_eq!(map.i_cell::<2>(17).count(), 3);
+}
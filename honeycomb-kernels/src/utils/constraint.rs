@@ -0,0 +1,73 @@
+//! constraint edge attribute
+
+// ------ IMPORTS
+
+use honeycomb_core::attributes::{AttrSparseVec, AttributeBind, AttributeUpdate};
+use honeycomb_core::cmap::{CMap2, CMapResult, EdgeIdType, OrbitPolicy};
+use honeycomb_core::geometry::CoordsFloat;
+use honeycomb_core::stm::{StmClosureResult, Transaction};
+
+// ------ CONTENT
+
+/// Edge attribute used to tag constraint edges.
+///
+/// Constraint edges model features of the mesh that should be preserved by remeshing, e.g. an
+/// interface between two materials. Edges without a value, or with a `false` value, are not
+/// constrained.
+///
+/// Merging two edges results in a constraint edge if one of them is; splitting a constraint edge
+/// results in two constraint edges.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConstraintEdge(pub bool);
+
+impl AttributeUpdate for ConstraintEdge {
+    fn merge(attr1: Self, attr2: Self) -> Self {
+        ConstraintEdge(attr1.0 || attr2.0)
+    }
+
+    fn split(attr: Self) -> (Self, Self) {
+        (attr, attr)
+    }
+
+    fn merge_from_none() -> CMapResult<Self> {
+        Ok(ConstraintEdge(false))
+    }
+
+    fn split_from_none() -> CMapResult<(Self, Self)> {
+        Ok((ConstraintEdge(false), ConstraintEdge(false)))
+    }
+}
+
+impl AttributeBind for ConstraintEdge {
+    type StorageType = AttrSparseVec<Self>;
+    type IdentifierType = EdgeIdType;
+    const BIND_POLICY: OrbitPolicy = OrbitPolicy::Edge;
+}
+
+/// Return `true` if the edge is tagged as a constraint.
+pub(crate) fn is_constraint_edge<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    trans: &mut Transaction,
+    edge_id: EdgeIdType,
+) -> StmClosureResult<bool> {
+    if !cmap.contains_attribute_storage::<ConstraintEdge>() {
+        return Ok(false);
+    }
+    Ok(cmap
+        .read_attribute::<ConstraintEdge>(trans, edge_id)?
+        .is_some_and(|c| c.0))
+}
+
+/// Return `true` if the edge is tagged as a constraint.
+///
+/// This variant is equivalent to `is_constraint_edge`, but internally uses a transaction that
+/// will be retried until validated.
+pub(crate) fn force_is_constraint_edge<T: CoordsFloat>(
+    cmap: &CMap2<T>,
+    edge_id: EdgeIdType,
+) -> bool {
+    cmap.contains_attribute_storage::<ConstraintEdge>()
+        && cmap
+            .force_read_attribute::<ConstraintEdge>(edge_id)
+            .is_some_and(|c| c.0)
+}
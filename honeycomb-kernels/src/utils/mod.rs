@@ -0,0 +1,27 @@
+//! Miscellaneous utilities
+//!
+//! This module contains helper routines that are shared by, or useful alongside, meshing
+//! kernels. We currently define:
+//! - spatial sorting -- reordering of points along a space-filling curve (Morton or Hilbert),
+//!   used to improve locality of incremental insertion algorithms
+//! - partitioning -- assignment of the cells of a map to partitions of spatially close cells
+//! - constraint edges -- edge attribute marking features preserved by splits & remeshing
+
+// ------ MODULE DECLARATIONS
+
+mod constraint;
+mod partition;
+mod spatial_sort;
+
+// ------ PUBLIC RE-EXPORTS
+
+pub use constraint::ConstraintEdge;
+pub use partition::partition_faces_zcurve;
+pub use spatial_sort::{spatial_sort_2d, spatial_sort_3d, SortCurve};
+
+pub(crate) use constraint::{force_is_constraint_edge, is_constraint_edge};
+
+// ------ TESTS
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,60 @@
+//! cell partitioning functions
+
+// ------ IMPORTS
+
+use honeycomb_core::cmap::{CMap2, FaceIdType};
+use honeycomb_core::geometry::{CoordsFloat, Vertex2};
+
+use super::spatial_sort::{curve_keys, to_f64};
+use super::SortCurve;
+
+// ------ CONTENT
+
+/// Number of bits used to quantize each coordinate of face centroids.
+const BITS_2D: u32 = 32;
+
+/// Partition faces of a map along a Z-order curve.
+///
+/// The centroid of each face is computed from its vertices, and faces are ordered by the index
+/// of their centroid along a Morton curve (see [`SortCurve::Morton`]). The ordered faces are
+/// then split into `n_parts` contiguous chunks, whose sizes differ by at most one face.
+///
+/// Faces without any defined vertex are located at the origin.
+///
+/// # Arguments
+///
+/// - `map: &CMap2<T>` -- Map whose faces are partitioned.
+/// - `n_parts: usize` -- Number of partitions.
+///
+/// # Return
+///
+/// Return a list of `(face_id, partition_id)` pairs, with partition IDs in `0..n_parts`. Pairs
+/// are sorted along the curve, which means partition IDs are yielded in increasing order.
+///
+/// # Panics
+///
+/// This function will panic if `n_parts` is zero.
+#[must_use = "unused return value"]
+pub fn partition_faces_zcurve<T: CoordsFloat>(
+    map: &CMap2<T>,
+    n_parts: usize,
+) -> Vec<(FaceIdType, usize)> {
+    assert_ne!(n_parts, 0, "E: cannot partition faces into zero parts");
+
+    let (fids, coords): (Vec<FaceIdType>, Vec<[f64; 2]>) = map
+        .iter_faces_with_geometry()
+        .map(|(fid, vertices)| {
+            let centroid = Vertex2::centroid(&vertices).unwrap_or_default();
+            (fid, [to_f64(centroid.x()), to_f64(centroid.y())])
+        })
+        .unzip();
+    let keys = curve_keys(&coords, BITS_2D, SortCurve::Morton);
+
+    let mut idx: Vec<usize> = (0..fids.len()).collect();
+    idx.sort_by_key(|&i| keys[i]);
+    let n_faces = fids.len();
+    idx.into_iter()
+        .enumerate()
+        .map(|(rank, i)| (fids[i], rank * n_parts / n_faces))
+        .collect()
+}
@@ -0,0 +1,171 @@
+//! space-filling curve sorting functions
+
+// ------ IMPORTS
+
+use honeycomb_core::geometry::{CoordsFloat, Vertex2, Vertex3};
+
+// ------ CONTENT
+
+/// Space-filling curve used to order points.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortCurve {
+    /// Morton curve, also known as Z-order curve.
+    #[default]
+    Morton,
+    /// Hilbert curve.
+    ///
+    /// Consecutive points on the curve are always adjacent cells of the underlying grid, which
+    /// usually results in better locality than the Morton curve, at a slightly higher cost.
+    Hilbert,
+}
+
+/// Number of bits used to quantize each coordinate of 2D points.
+const BITS_2D: u32 = 32;
+/// Number of bits used to quantize each coordinate of 3D points.
+const BITS_3D: u32 = 21;
+
+/// Sort 2D points along a space-filling curve.
+///
+/// Points are quantized on a regular grid covering their bounding box, and sorted according to
+/// the index of their cell along the curve. The sort is stable, i.e. points falling in the same
+/// cell keep their relative order.
+///
+/// This is typically used to reorder points before an incremental insertion, so that
+/// consecutive points are spatially close to each other.
+///
+/// # Arguments
+///
+/// - `points: &mut [Vertex2<T>]` -- Points to sort in place.
+/// - `order: SortCurve` -- Space-filling curve used to compute the order.
+pub fn spatial_sort_2d<T: CoordsFloat>(points: &mut [Vertex2<T>], order: SortCurve) {
+    let coords: Vec<[f64; 2]> = points
+        .iter()
+        .map(|v| [to_f64(v.x()), to_f64(v.y())])
+        .collect();
+    let keys = curve_keys(&coords, BITS_2D, order);
+    reorder(points, &keys);
+}
+
+/// Sort 3D points along a space-filling curve.
+///
+/// Points are quantized on a regular grid covering their bounding box, and sorted according to
+/// the index of their cell along the curve. The sort is stable, i.e. points falling in the same
+/// cell keep their relative order.
+///
+/// This is typically used to reorder points before an incremental insertion, so that
+/// consecutive points are spatially close to each other.
+///
+/// # Arguments
+///
+/// - `points: &mut [Vertex3<T>]` -- Points to sort in place.
+/// - `order: SortCurve` -- Space-filling curve used to compute the order.
+pub fn spatial_sort_3d<T: CoordsFloat>(points: &mut [Vertex3<T>], order: SortCurve) {
+    let coords: Vec<[f64; 3]> = points
+        .iter()
+        .map(|v| [to_f64(v.x()), to_f64(v.y()), to_f64(v.z())])
+        .collect();
+    let keys = curve_keys(&coords, BITS_3D, order);
+    reorder(points, &keys);
+}
+
+/// Convert a coordinate to `f64`, mapping non-representable values to zero.
+pub(super) fn to_f64<T: CoordsFloat>(val: T) -> f64 {
+    val.to_f64().unwrap_or_default()
+}
+
+/// Stably reorder `points` by increasing key.
+fn reorder<V: Copy>(points: &mut [V], keys: &[u64]) {
+    let mut idx: Vec<usize> = (0..points.len()).collect();
+    idx.sort_by_key(|&i| keys[i]);
+    let sorted: Vec<V> = idx.iter().map(|&i| points[i]).collect();
+    points.copy_from_slice(&sorted);
+}
+
+/// Compute the index of each point along the curve.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub(super) fn curve_keys<const N: usize>(
+    coords: &[[f64; N]],
+    bits: u32,
+    order: SortCurve,
+) -> Vec<u64> {
+    let mut min = [f64::INFINITY; N];
+    let mut max = [f64::NEG_INFINITY; N];
+    for c in coords {
+        for i in 0..N {
+            min[i] = min[i].min(c[i]);
+            max[i] = max[i].max(c[i]);
+        }
+    }
+    let n_cells = ((1_u64 << bits) - 1) as f64;
+
+    coords
+        .iter()
+        .map(|c| {
+            let mut cell = [0_u32; N];
+            for i in 0..N {
+                let extent = max[i] - min[i];
+                if extent > 0.0 {
+                    // `as` saturates, which takes care of rounding overflows
+                    cell[i] = ((c[i] - min[i]) / extent * n_cells) as u32;
+                }
+            }
+            if order == SortCurve::Hilbert {
+                axes_to_transpose(&mut cell, bits);
+            }
+            interleave(&cell, bits)
+        })
+        .collect()
+}
+
+/// Interleave the bits of the coordinates, the first axis being the most significant.
+fn interleave<const N: usize>(cell: &[u32; N], bits: u32) -> u64 {
+    let mut key = 0_u64;
+    for b in (0..bits).rev() {
+        for c in cell {
+            key = (key << 1) | u64::from((c >> b) & 1);
+        }
+    }
+    key
+}
+
+/// Transform grid coordinates into the transposed Hilbert index.
+///
+/// This is J. Skilling's algorithm (*Programming the Hilbert curve*, 2004); interleaving the
+/// bits of the result yields the index of the cell along the curve.
+fn axes_to_transpose<const N: usize>(cell: &mut [u32; N], bits: u32) {
+    let m = 1_u32 << (bits - 1);
+    // inverse undo
+    let mut q = m;
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..N {
+            if cell[i] & q == 0 {
+                let t = (cell[0] ^ cell[i]) & p;
+                cell[0] ^= t;
+                cell[i] ^= t;
+            } else {
+                cell[0] ^= p;
+            }
+        }
+        q >>= 1;
+    }
+    // gray encode
+    for i in 1..N {
+        cell[i] ^= cell[i - 1];
+    }
+    let mut t = 0;
+    q = m;
+    while q > 1 {
+        if cell[N - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for c in cell.iter_mut() {
+        *c ^= t;
+    }
+}
@@ -0,0 +1,111 @@
+use super::*;
+use honeycomb_core::cmap::{CMap2, CMapBuilder};
+use honeycomb_core::geometry::{Vertex2, Vertex3};
+
+#[test]
+fn morton_2d() {
+    let mut points = vec![
+        Vertex2(1.0, 1.0),
+        Vertex2(0.0, 1.0),
+        Vertex2(1.0, 0.0),
+        Vertex2(0.0, 0.0),
+    ];
+    spatial_sort_2d(&mut points, SortCurve::Morton);
+    assert_eq!(
+        points,
+        vec![
+            Vertex2(0.0, 0.0),
+            Vertex2(0.0, 1.0),
+            Vertex2(1.0, 0.0),
+            Vertex2(1.0, 1.0),
+        ]
+    );
+}
+
+#[test]
+fn hilbert_2d_adjacency() {
+    // reversed 4x4 grid of points
+    let mut points: Vec<Vertex2<f64>> = (0..16)
+        .rev()
+        .map(|i| Vertex2(f64::from(i % 4), f64::from(i / 4)))
+        .collect();
+    spatial_sort_2d(&mut points, SortCurve::Hilbert);
+    assert_eq!(points[0], Vertex2(0.0, 0.0));
+    // consecutive points along the curve are neighbors on the grid
+    assert!(points.windows(2).all(|w| {
+        let d = w[1] - w[0];
+        (d.x().abs() + d.y().abs() - 1.0).abs() < f64::EPSILON
+    }));
+}
+
+#[test]
+fn hilbert_3d_adjacency() {
+    let mut points: Vec<Vertex3<f64>> = (0..64)
+        .rev()
+        .map(|i| Vertex3(f64::from(i % 4), f64::from((i / 4) % 4), f64::from(i / 16)))
+        .collect();
+    spatial_sort_3d(&mut points, SortCurve::Hilbert);
+    assert_eq!(points[0], Vertex3(0.0, 0.0, 0.0));
+    assert!(points.windows(2).all(|w| {
+        let d = w[1] - w[0];
+        (d.x().abs() + d.y().abs() + d.z().abs() - 1.0).abs() < f64::EPSILON
+    }));
+}
+
+#[test]
+fn sort_degenerate() {
+    // points sharing a coordinate keep their relative order along the other axes
+    let mut points = vec![
+        Vertex3(0.0, 2.0, 1.0),
+        Vertex3(0.0, 1.0, 1.0),
+        Vertex3(0.0, 1.0, 1.0),
+    ];
+    spatial_sort_3d(&mut points, SortCurve::Morton);
+    assert_eq!(points[0], Vertex3(0.0, 1.0, 1.0));
+    assert_eq!(points[2], Vertex3(0.0, 2.0, 1.0));
+
+    let mut empty: Vec<Vertex2<f32>> = Vec::new();
+    spatial_sort_2d(&mut empty, SortCurve::Hilbert);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn partition_grid_quadrants() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(4).build().unwrap();
+    let parts = partition_faces_zcurve(&map, 4);
+    assert_eq!(parts.len(), 16);
+    assert!(parts.windows(2).all(|w| w[0].1 <= w[1].1));
+
+    // each partition is a quadrant of the grid
+    for p in 0..4 {
+        let quadrants: Vec<(bool, bool)> = parts
+            .iter()
+            .filter(|(_, pid)| *pid == p)
+            .map(|(fid, _)| {
+                let (_, vertices) = map
+                    .iter_faces_with_geometry()
+                    .find(|(f, _)| f == fid)
+                    .unwrap();
+                let c = Vertex2::centroid(&vertices).unwrap();
+                (c.x() < 2.0, c.y() < 2.0)
+            })
+            .collect();
+        assert_eq!(quadrants.len(), 4);
+        assert!(quadrants.iter().all(|q| *q == quadrants[0]));
+    }
+}
+
+#[test]
+fn partition_uneven() {
+    let map: CMap2<f64> = CMapBuilder::unit_grid(3).build().unwrap();
+    let parts = partition_faces_zcurve(&map, 4);
+    let sizes: Vec<usize> = (0..4)
+        .map(|p| parts.iter().filter(|(_, pid)| *pid == p).count())
+        .collect();
+    assert_eq!(sizes.iter().sum::<usize>(), 9);
+    assert!(sizes.iter().all(|n| (2..=3).contains(n)));
+
+    // more partitions than faces
+    let parts = partition_faces_zcurve(&map, 12);
+    assert!(parts.iter().all(|(_, pid)| *pid < 12));
+}
@@ -0,0 +1,19 @@
+//! Voronoi diagram construction
+//!
+//! This module contains implementations of Voronoi diagram constructions from existing
+//! triangulations. We currently define:
+//! - 2D dual Voronoi -- the diagram is built as the dual of a Delaunay triangulation, and
+//!   clipped to its boundary
+
+// ------ MODULE DECLARATIONS
+
+mod voronoi_2d;
+
+// ------ PUBLIC RE-EXPORTS
+
+pub use voronoi_2d::dual_voronoi;
+
+// ------ TESTS
+
+#[cfg(test)]
+mod tests;
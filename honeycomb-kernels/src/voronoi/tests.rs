@@ -0,0 +1,65 @@
+use super::*;
+use honeycomb_core::cmap::{CMap2, CMapBuilder};
+use honeycomb_core::geometry::Vertex2;
+
+use crate::quality::signed_face_area;
+
+#[test]
+fn voronoi_single_triangle() {
+    let map: CMap2<f64> = CMapBuilder::default().n_darts(3).build().unwrap();
+    map.force_link::<1>(1, 2);
+    map.force_link::<1>(2, 3);
+    map.force_link::<1>(3, 1);
+    map.force_write_vertex(1, (0.0, 0.0));
+    map.force_write_vertex(2, (2.0, 0.0));
+    map.force_write_vertex(3, (0.0, 2.0));
+
+    let voronoi = dual_voronoi(&map);
+    // one quadrilateral cell per vertex, sharing the circumcenter
+    assert_eq!(voronoi.n_darts(), 13);
+    assert_eq!(voronoi.iter_faces().count(), 3);
+    assert_eq!(voronoi.iter_edges().count(), 9);
+    assert_eq!(voronoi.iter_vertices().count(), 7);
+    let (center, _) = voronoi
+        .iter_vertices_with_coords()
+        .find(|(_, v)| *v == Vertex2(1.0, 1.0))
+        .unwrap();
+    assert_eq!(voronoi.vertex_valence(center), 3);
+    // cells of the right angle & of the other corners
+    let mut areas: Vec<f64> = voronoi
+        .iter_faces()
+        .map(|fid| signed_face_area(&voronoi, fid))
+        .collect();
+    areas.sort_by(f64::total_cmp);
+    assert_eq!(areas, vec![0.5, 0.5, 1.0]);
+}
+
+#[test]
+fn voronoi_grid() {
+    let map: CMap2<f64> = CMapBuilder::unit_triangles(2).build().unwrap();
+    let voronoi = dual_voronoi(&map);
+    // one cell per point
+    assert_eq!(voronoi.iter_faces().count(), 9);
+    // cells are oriented like the triangulation & cover its hull
+    let areas: Vec<f64> = voronoi
+        .iter_faces()
+        .map(|fid| signed_face_area(&voronoi, fid))
+        .collect();
+    assert!(areas.iter().all(|a| *a > 0.0));
+    assert!((areas.iter().sum::<f64>() - 4.0).abs() < 1e-10);
+    // the cell of the interior point is the unit square centered on it
+    let (vid, _) = map
+        .iter_vertices_with_coords()
+        .find(|(_, v)| *v == Vertex2(1.0, 1.0))
+        .unwrap();
+    assert_eq!(map.vertex_valence(vid), 6);
+    assert!(areas.iter().any(|a| (a - 1.0).abs() < 1e-10));
+    // the diagram is sewn along interior segments only
+    assert_eq!(
+        voronoi
+            .iter_edges()
+            .filter(|eid| voronoi.is_i_free::<2>(*eid as _))
+            .count(),
+        16
+    );
+}
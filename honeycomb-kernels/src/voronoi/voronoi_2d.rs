@@ -0,0 +1,152 @@
+//! 2D Voronoi construction functions
+
+// ------ IMPORTS
+
+use std::collections::HashMap;
+
+use honeycomb_core::cmap::{
+    CMap2, CMapBuilder, DartIdType, EdgeIdType, FaceIdType, VertexIdType, NULL_DART_ID,
+};
+use honeycomb_core::geometry::{CoordsFloat, Vertex2};
+
+// ------ CONTENT
+
+/// Points making up the Voronoi diagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum VoronoiPoint {
+    /// Circumcenter of a Delaunay triangle.
+    Circumcenter(FaceIdType),
+    /// Midpoint of a boundary edge of the triangulation.
+    Midpoint(EdgeIdType),
+    /// Vertex of the triangulation, located on its boundary.
+    Corner(VertexIdType),
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::missing_panics_doc)]
+/// Build the Voronoi diagram of the vertices of a Delaunay triangulation.
+///
+/// <div class="warning">
+/// This implementation is 2D specific.
+/// </div>
+///
+/// The diagram is built as the dual of the triangulation: each triangle results in a Voronoi
+/// vertex, placed at its circumcenter, and each vertex of the triangulation results in a
+/// Voronoi cell. Cells are oriented consistently with the original map.
+///
+/// Unbounded cells, i.e. cells of vertices on the boundary of the triangulation, are closed
+/// along this boundary: their last circumcenters are connected to the midpoints of the two
+/// incident boundary edges, which are connected to the vertex itself. This corresponds to the
+/// clipping of the diagram by the convex hull, as long as circumcenters of boundary triangles
+/// lie inside of it.
+///
+/// Faces of the triangulation are expected to be triangles. The Voronoi vertex of other faces,
+/// or of degenerate triangles, has no associated value. Unused & free darts are ignored.
+///
+/// # Arguments
+///
+/// - `delaunay: &CMap2<T>` -- Reference to the Delaunay triangulation.
+///
+/// # Return
+///
+/// Return the Voronoi diagram.
+#[must_use = "unused return value"]
+pub fn dual_voronoi<T: CoordsFloat>(delaunay: &CMap2<T>) -> CMap2<T> {
+    // list the points of each cell, oriented counterclockwise
+    let cells: Vec<Vec<VoronoiPoint>> = delaunay
+        .iter_vertices()
+        .filter(|vid| !delaunay.is_free(*vid as DartIdType))
+        .map(|vid| cell_points(delaunay, vid))
+        .filter(|cell| cell.len() > 2)
+        .collect();
+    let n_darts: usize = cells.iter().map(Vec::len).sum();
+
+    // build the topology; β2 links darts modeling the same segment with opposite directions
+    let voronoi: CMap2<T> = CMapBuilder::default()
+        .n_darts(n_darts)
+        .build()
+        .expect("E: unreachable");
+    let mut segments: HashMap<(VoronoiPoint, VoronoiPoint), DartIdType> = HashMap::new();
+    let mut starts: Vec<VoronoiPoint> = Vec::with_capacity(n_darts);
+    let mut offset = 1;
+    for cell in &cells {
+        let n = cell.len() as DartIdType;
+        for i in 0..n {
+            let (d, next) = (offset + i, offset + (i + 1) % n);
+            let (p, q) = (cell[i as usize], cell[((i + 1) % n) as usize]);
+            voronoi.force_link::<1>(d, next);
+            if let Some(opposite) = segments.get(&(q, p)) {
+                voronoi.force_link::<2>(*opposite, d);
+            }
+            segments.insert((p, q), d);
+            starts.push(p);
+        }
+        offset += n;
+    }
+
+    // place vertices
+    let circumcenters = delaunay.face_circumcenters();
+    for (d, p) in (1..).zip(starts) {
+        let v = match p {
+            VoronoiPoint::Circumcenter(fid) => circumcenters.get(&fid).copied(),
+            VoronoiPoint::Midpoint(eid) => {
+                let d = eid as DartIdType;
+                let b1d = delaunay.beta::<1>(d);
+                match (
+                    delaunay.force_read_vertex(delaunay.vertex_id(d)),
+                    delaunay.force_read_vertex(delaunay.vertex_id(b1d)),
+                ) {
+                    (Some(v1), Some(v2)) => Some(Vertex2::average(&v1, &v2)),
+                    _ => None,
+                }
+            }
+            VoronoiPoint::Corner(vid) => delaunay.force_read_vertex(vid),
+        };
+        if let Some(v) = v {
+            voronoi.force_write_vertex(voronoi.vertex_id(d), v);
+        }
+    }
+
+    voronoi
+}
+
+/// Return the points of the Voronoi cell of vertex `vid`, oriented counterclockwise.
+fn cell_points<T: CoordsFloat>(map: &CMap2<T>, vid: VertexIdType) -> Vec<VoronoiPoint> {
+    // darts starting from the vertex are ordered counterclockwise by `β2 o β0`
+    let rotate = |d: DartIdType| map.beta::<2>(map.beta::<0>(d));
+    let unrotate = |d: DartIdType| map.beta::<1>(map.beta::<2>(d));
+    let start = vid as DartIdType;
+
+    // find the first dart of the fan if the vertex is on the boundary
+    let mut first = start;
+    let mut n = 0;
+    let on_boundary = loop {
+        let prev = unrotate(first);
+        if prev == NULL_DART_ID {
+            break true;
+        }
+        if prev == start || n >= map.n_darts() {
+            break false;
+        }
+        first = prev;
+        n += 1;
+    };
+
+    let mut points = Vec::new();
+    if on_boundary {
+        points.push(VoronoiPoint::Midpoint(map.edge_id(first)));
+    }
+    let mut d = first;
+    loop {
+        points.push(VoronoiPoint::Circumcenter(map.face_id(d)));
+        let next = rotate(d);
+        if next == NULL_DART_ID || next == first || points.len() > map.n_darts() {
+            break;
+        }
+        d = next;
+    }
+    if on_boundary {
+        points.push(VoronoiPoint::Midpoint(map.edge_id(map.beta::<0>(d))));
+        points.push(VoronoiPoint::Corner(vid));
+    }
+    points
+}
@@ -1,8 +1,11 @@
 use crate::capture::{Capture, CaptureList};
 use crate::plugins::{CapturePlugin, GuiPlugin, OptionsPlugin, ScenePlugin};
+use crate::resources::{ColorMap, FaceColorBy};
 use bevy::prelude::App as BevyApp;
 use bevy::prelude::*;
-use honeycomb_core::prelude::{CMap2, CoordsFloat};
+use honeycomb_core::prelude::{
+    AttributeBind, AttributeUpdate, CMap2, CoordsFloat, DartIdType, FaceIdType,
+};
 
 /// Default render structure.
 ///
@@ -25,6 +28,37 @@ impl App {
         cap_id
     }
 
+    /// Add a capture to the collection of the app, flagging boundary darts.
+    ///
+    /// Darts for which the attribute `A` is a boundary value, according to `is_boundary`, are
+    /// rendered using the [`BoundaryDartColor`][crate::resources::BoundaryDartColor] option. If
+    /// the map doesn't hold a storage for `A`, this is equivalent to [`App::add_capture`].
+    pub fn add_capture_with_boundary<T: CoordsFloat, A>(
+        &mut self,
+        cmap: &CMap2<T>,
+        is_boundary: impl Fn(A) -> bool,
+    ) -> usize
+    where
+        A: AttributeBind<IdentifierType = DartIdType> + AttributeUpdate,
+    {
+        let cap_id = self.capture_list.0.len();
+        let capture = Capture::with_boundary(cap_id, cmap, is_boundary);
+        self.capture_list.0.push(capture);
+        cap_id
+    }
+
+    /// Color faces according to a scalar value, using the specified colormap.
+    ///
+    /// Values are normalized over the faces of each capture. Faces are only displayed if enabled
+    /// in the render options.
+    pub fn color_faces_by(
+        &mut self,
+        scalar: impl Fn(FaceIdType) -> f32 + Send + Sync + 'static,
+        colormap: ColorMap,
+    ) {
+        self.app.insert_resource(FaceColorBy::new(scalar, colormap));
+    }
+
     /// Launch the inner `bevy` app.
     pub fn run(mut self) {
         self.app.insert_resource(self.capture_list);
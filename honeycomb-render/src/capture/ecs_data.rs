@@ -18,7 +18,7 @@ pub struct FaceNormals(pub HashMap<FaceIdType, Vec<Vec3>>);
 #[derive(Bundle, Clone)]
 pub struct DartHeadBundle {
     pub(crate) capture_id: CaptureId,
-    id: DartId,
+    pub(crate) id: DartId,
     vertex_id: VertexId,
     edge_id: EdgeId,
     pub(crate) face_id: FaceId,
@@ -52,7 +52,7 @@ impl DartHeadBundle {
 #[derive(Bundle, Clone)]
 pub struct DartBodyBundle {
     pub(crate) capture_id: CaptureId,
-    id: DartId,
+    pub(crate) id: DartId,
     vertex_id: VertexId,
     edge_id: EdgeId,
     pub(crate) face_id: FaceId,
@@ -182,6 +182,12 @@ pub struct DartBody {
     pub(crate) normals: (usize, usize),  // vertex normals (for shrink ops)
 }
 
+/// Boundary dart component.
+///
+/// This marker is added to dart heads & bodies flagged as boundary darts during the capture.
+#[derive(Component, Clone)]
+pub struct BoundaryDart;
+
 /// Beta component.
 #[derive(Component, Clone)]
 pub struct Beta(pub u8, pub usize, pub usize); // beta id, v0_id, v1_id ?
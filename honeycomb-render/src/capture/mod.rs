@@ -3,11 +3,12 @@ pub mod system;
 
 use crate::bundles::{DartBodyBundle, DartHeadBundle, EdgeBundle, FaceBundle, VertexBundle};
 use crate::capture::ecs_data::CaptureId;
-use crate::capture::system::{populate_darts, populate_edges, populate_vertices};
+use crate::capture::system::{populate_darts, populate_edges, populate_faces, populate_vertices};
 use bevy::prelude::*;
-use bevy::utils::HashMap;
+use bevy::utils::{HashMap, HashSet};
 use honeycomb_core::prelude::{
-    CMap2, CoordsFloat, DartIdType, FaceIdType, Orbit2, OrbitPolicy, VertexIdType,
+    AttributeBind, AttributeUpdate, CMap2, CoordsFloat, DartIdType, FaceIdType, Orbit2,
+    OrbitPolicy, VertexIdType,
 };
 
 /// Plugin handling capture data & entity generation from it.
@@ -21,8 +22,8 @@ impl Plugin for CapturePlugin {
         // systems
         app.add_systems(Startup, populate_darts)
             .add_systems(Startup, populate_vertices)
-            .add_systems(Startup, populate_edges);
-        //.add_systems(Startup, populate_faces);
+            .add_systems(Startup, populate_edges)
+            .add_systems(Startup, populate_faces);
     }
 }
 
@@ -43,6 +44,7 @@ pub struct Capture {
     pub vertex_vals: Vec<Vec3>,
     pub normals: HashMap<FaceIdType, Vec<Vec3>>,
     pub darts: Vec<(DartHeadBundle, DartBodyBundle)>,
+    pub boundary_darts: HashSet<DartIdType>,
     pub vertices: Vec<VertexBundle>,
     pub edges: Vec<EdgeBundle>,
     pub faces: Vec<FaceBundle>,
@@ -194,11 +196,35 @@ impl Capture {
             vertex_vals,
             normals,
             darts,
+            boundary_darts: HashSet::new(),
             vertices,
             edges,
             faces,
         }
     }
+
+    /// Create a capture, flagging darts for which the attribute `A` is a boundary value.
+    ///
+    /// If the map doesn't hold a storage for `A`, no dart is flagged.
+    pub fn with_boundary<T: CoordsFloat, A>(
+        cap_id: usize,
+        cmap: &CMap2<T>,
+        is_boundary: impl Fn(A) -> bool,
+    ) -> Self
+    where
+        A: AttributeBind<IdentifierType = DartIdType> + AttributeUpdate,
+    {
+        let mut capture = Self::new(cap_id, cmap);
+        if cmap.contains_attribute_storage::<A>() {
+            capture.boundary_darts = capture
+                .darts
+                .iter()
+                .map(|(head, _)| head.id.0)
+                .filter(|d| cmap.force_read_attribute::<A>(*d).is_some_and(&is_boundary))
+                .collect();
+        }
+        capture
+    }
 }
 
 pub struct CaptureMD {
@@ -1,11 +1,15 @@
+use crate::capture::ecs_data::BoundaryDart;
 use crate::capture::{CaptureList, FocusedCapture};
 use crate::resources::{
-    DartHeadHandle, DartHeadMul, DartMatHandle, DartRenderColor, DartShrink, DartWidth,
-    EdgeMatHandle, EdgeRenderColor, EdgeWidth, FaceMatHandle, FaceNormals, FaceRenderColor,
-    FaceShrink, MapVertices, VertexHandle, VertexMatHandle, VertexRenderColor, VertexWidth,
+    BoundaryDartColor, BoundaryDartMatHandle, DartHeadHandle, DartHeadMul, DartMatHandle,
+    DartRenderColor, DartShrink, DartWidth, EdgeMatHandle, EdgeRenderColor, EdgeWidth,
+    FaceMatHandle, FaceNormals, FaceRenderColor, FaceShrink, MapVertices, VertexHandle,
+    VertexMatHandle, VertexRenderColor, VertexWidth,
 };
 use bevy::color::Color;
 use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
 use bevy_mod_outline::{OutlineBundle, OutlineVolume};
 use bevy_mod_picking::PickableBundle;
 
@@ -21,6 +25,7 @@ pub fn populate_darts(
     dart_head_mul: Res<DartHeadMul>,
     dart_width: Res<DartWidth>,
     dart_shrink: Res<DartShrink>,
+    boundary_dart_color: Res<BoundaryDartColor>,
 ) {
     let head_shape = Cone {
         radius: dart_head_mul.0 * dart_width.0 / 2.,
@@ -30,6 +35,9 @@ pub fn populate_darts(
     let dart_mat = materials.add(Color::Srgba(Srgba::from_u8_array(
         dart_render_color.1.to_array(),
     )));
+    let boundary_dart_mat = materials.add(Color::Srgba(Srgba::from_u8_array(
+        boundary_dart_color.1.to_array(),
+    )));
     for capture in &captures.0 {
         let vertices = &capture.vertex_vals;
         let normals = &capture.normals;
@@ -41,6 +49,12 @@ pub fn populate_darts(
             };
         for (head, body) in &capture.darts {
             let face_id = head.face_id.0;
+            let is_boundary = capture.boundary_darts.contains(&head.id.0);
+            let mat = if is_boundary && boundary_dart_color.0 {
+                boundary_dart_mat.clone()
+            } else {
+                dart_mat.clone()
+            };
             let (n1, n2) = (
                 &normals[&face_id][body.dart_body.normals.0],
                 &normals[&face_id][body.dart_body.normals.1],
@@ -59,62 +73,67 @@ pub fn populate_darts(
                 Quat::from_rotation_arc(Vec3::Y, dir)
             };
             // dart body
-            commands
-                .spawn((
-                    body.clone(),
-                    PbrBundle {
-                        mesh: meshes.add(Cylinder::new(
-                            dart_width.0,
-                            // FIXME: clunky
-                            len * (1. - dart_shrink.0.abs()),
-                        )),
-                        material: dart_mat.clone(),
-                        transform,
-                        visibility,
-                        ..Default::default()
-                    },
-                    PickableBundle::default(),
-                ))
-                .insert(OutlineBundle {
-                    outline: OutlineVolume {
-                        visible: false,
-                        colour: Color::WHITE,
-                        width: 1.0,
-                    },
-                    ..default()
-                });
+            let mut body_entity = commands.spawn((
+                body.clone(),
+                PbrBundle {
+                    mesh: meshes.add(Cylinder::new(
+                        dart_width.0,
+                        // FIXME: clunky
+                        len * (1. - dart_shrink.0.abs()),
+                    )),
+                    material: mat.clone(),
+                    transform,
+                    visibility,
+                    ..Default::default()
+                },
+                PickableBundle::default(),
+            ));
+            body_entity.insert(OutlineBundle {
+                outline: OutlineVolume {
+                    visible: false,
+                    colour: Color::WHITE,
+                    width: 1.0,
+                },
+                ..default()
+            });
+            if is_boundary {
+                body_entity.insert(BoundaryDart);
+            }
             // dart head
             // FIXME: clunky
             let mut transform_head = Transform::from_translation(
                 (v1 + v2 + dir * len * (1. - dart_shrink.0.abs())) / 2.,
             );
             transform_head.rotation = Quat::from_rotation_arc(Vec3::Y, dir);
-            commands
-                .spawn((
-                    head.clone(),
-                    PbrBundle {
-                        mesh: dart_head_handle.clone(),
-                        material: dart_mat.clone(),
-                        transform: transform_head,
-                        visibility,
-                        ..Default::default()
-                    },
-                    PickableBundle::default(),
-                ))
-                .insert(OutlineBundle {
-                    outline: OutlineVolume {
-                        visible: false,
-                        colour: Color::WHITE,
-                        width: 1.0,
-                    },
-                    ..default()
-                });
+            let mut head_entity = commands.spawn((
+                head.clone(),
+                PbrBundle {
+                    mesh: dart_head_handle.clone(),
+                    material: mat,
+                    transform: transform_head,
+                    visibility,
+                    ..Default::default()
+                },
+                PickableBundle::default(),
+            ));
+            head_entity.insert(OutlineBundle {
+                outline: OutlineVolume {
+                    visible: false,
+                    colour: Color::WHITE,
+                    width: 1.0,
+                },
+                ..default()
+            });
+            if is_boundary {
+                head_entity.insert(BoundaryDart);
+            }
         }
         commands.insert_resource(MapVertices(vertices.clone()));
         commands.insert_resource(FaceNormals(normals.clone()));
     }
     commands.insert_resource(DartHeadHandle(dart_head_handle));
     commands.insert_resource(DartMatHandle(dart_mat));
+    commands.insert_resource(BoundaryDartMatHandle(boundary_dart_mat));
 }
 
 /// System used to generate beta entities in the ECS.
@@ -231,10 +250,10 @@ pub fn populate_edges(
 
 /// System used to generate face entities in the ECS.
 ///
-/// NOT YET IMPLEMENTED
-#[allow(unused)]
+/// Faces are meshed as triangle fans, which is only correct for convex faces.
 pub fn populate_faces(
     mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     focused_capture: Res<FocusedCapture>,
     captures: Res<CaptureList>,
@@ -255,18 +274,39 @@ pub fn populate_faces(
             };
         for face in &capture.faces {
             let loc_n = &normals[&face.id.0];
-            let ovs = face
+            let positions: Vec<[f32; 3]> = face
                 .face
                 .0
                 .iter()
-                .map(|id| vertices[*id])
-                .collect::<Vec<_>>();
-            let nvs: Vec<_> = loc_n
-                .iter()
-                .zip(ovs.iter())
-                .map(|(vertex, normal)| (*vertex + *normal * face_shrink.0).truncate())
+                .zip(loc_n.iter())
+                .map(|(id, normal)| (vertices[*id] + *normal * face_shrink.0).to_array())
                 .collect();
+            commands.spawn((
+                face.clone(),
+                PbrBundle {
+                    mesh: meshes.add(face_mesh(positions)),
+                    material: face_mat.clone(),
+                    visibility,
+                    ..Default::default()
+                },
+                PickableBundle::default(),
+            ));
         }
     }
     commands.insert_resource(FaceMatHandle(face_mat));
 }
+
+/// Build a triangle fan mesh from the vertices of a face.
+fn face_mesh(positions: Vec<[f32; 3]>) -> Mesh {
+    let n_v = positions.len() as u32;
+    let indices: Vec<u32> = (1..n_v.saturating_sub(1))
+        .flat_map(|i| [0, i, i + 1])
+        .collect();
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0., 0., 1.]; positions.len()])
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_indices(Indices::U32(indices))
+}
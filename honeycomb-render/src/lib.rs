@@ -59,8 +59,8 @@ pub mod bundles {
 /// components used to build the default [`App`]
 pub mod components {
     pub use crate::capture::ecs_data::{
-        Beta, CaptureId, DartBody, DartHead, DartId, Edge, EdgeId, Face, FaceId, Vertex, VertexId,
-        Volume, VolumeId,
+        Beta, BoundaryDart, CaptureId, DartBody, DartHead, DartId, Edge, EdgeId, Face, FaceId,
+        Vertex, VertexId, Volume, VolumeId,
     };
     pub use crate::render::camera::PanOrbitCamera;
 }
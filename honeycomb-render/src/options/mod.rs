@@ -10,6 +10,7 @@ impl Plugin for OptionsPlugin {
     fn build(&self, app: &mut App) {
         // render color
         app.insert_resource(resource::DartRenderColor::default())
+            .insert_resource(resource::BoundaryDartColor::default())
             .insert_resource(resource::BetaRenderColor::default())
             .insert_resource(resource::VertexRenderColor::default())
             .insert_resource(resource::EdgeRenderColor::default())
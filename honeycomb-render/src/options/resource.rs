@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use bevy_egui::egui::Color32;
+use honeycomb_core::prelude::FaceIdType;
 
 macro_rules! declare_newtype_resource {
     ($nam: ident, $inr: ty) => {
@@ -37,6 +38,12 @@ macro_rules! declare_newtype_resource {
 // -- indicate if objects of the given type should be rendered, & what color should be used
 
 declare_newtype_resource!(DartRenderColor, bool, Color32, (true, Color32::BLACK));
+declare_newtype_resource!(
+    BoundaryDartColor,
+    bool,
+    Color32,
+    (true, Color32::LIGHT_BLUE)
+);
 #[rustfmt::skip]
 declare_newtype_resource!(BetaRenderColor, bool, Color32, (false, Color32::TRANSPARENT));
 declare_newtype_resource!(VertexRenderColor, bool, Color32, (true, Color32::GOLD));
@@ -47,6 +54,7 @@ declare_newtype_resource!(VolumeRenderColor, bool, Color32, (false, Color32::DAR
 // -- material handle for objects of the given type; those exist for efficiency reasons
 
 declare_newtype_resource!(DartMatHandle, Handle<StandardMaterial>);
+declare_newtype_resource!(BoundaryDartMatHandle, Handle<StandardMaterial>);
 declare_newtype_resource!(BetaMatHandle, Handle<StandardMaterial>);
 declare_newtype_resource!(VertexMatHandle, Handle<StandardMaterial>);
 declare_newtype_resource!(EdgeMatHandle, Handle<StandardMaterial>);
@@ -71,3 +79,95 @@ declare_newtype_resource!(EdgeWidth, f32);
 declare_newtype_resource!(VertexHandle, Handle<Mesh>);
 declare_newtype_resource!(DartHeadHandle, Handle<Mesh>);
 declare_newtype_resource!(DartHeadMul, f32, 2.0);
+
+// -- face coloring
+
+/// Colormap used to convert normalized scalar values to colors.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMap {
+    /// Perceptually uniform, blue-green-yellow colormap.
+    #[default]
+    Viridis,
+    /// Blue-cyan-yellow-red colormap.
+    Jet,
+}
+
+/// Control points of the viridis colormap, evenly spaced over `[0; 1]`.
+const VIRIDIS: [[f32; 3]; 9] = [
+    [68., 1., 84.],
+    [71., 44., 122.],
+    [59., 81., 139.],
+    [44., 113., 142.],
+    [33., 144., 141.],
+    [39., 173., 129.],
+    [92., 200., 99.],
+    [170., 220., 50.],
+    [253., 231., 37.],
+];
+
+impl ColorMap {
+    /// Convert a value in `[0; 1]` to a color. Values outside of this range are clamped.
+    pub fn color(self, t: f32) -> Color32 {
+        let t = if t.is_nan() { 0. } else { t.clamp(0., 1.) };
+        let [r, g, b] = match self {
+            ColorMap::Viridis => {
+                let x = t * (VIRIDIS.len() - 1) as f32;
+                let i = (x.floor() as usize).min(VIRIDIS.len() - 2);
+                let w = x - i as f32;
+                [0, 1, 2].map(|c| VIRIDIS[i][c] * (1. - w) + VIRIDIS[i + 1][c] * w)
+            }
+            ColorMap::Jet => {
+                [3., 2., 1.].map(|offset: f32| (1.5 - (4. * t - offset).abs()).clamp(0., 1.) * 255.)
+            }
+        };
+        Color32::from_rgb(r as u8, g as u8, b as u8)
+    }
+}
+
+/// Rendering option used to color faces according to a scalar value.
+///
+/// Values are normalized over the faces of each capture before being converted to colors.
+/// When this resource is present, it overrides the color of [`FaceRenderColor`].
+#[derive(Resource)]
+pub struct FaceColorBy {
+    /// Scalar value associated to a given face.
+    pub scalar: Box<dyn Fn(FaceIdType) -> f32 + Send + Sync>,
+    /// Colormap used to convert normalized values to colors.
+    pub colormap: ColorMap,
+}
+
+impl FaceColorBy {
+    /// Constructor.
+    pub fn new(
+        scalar: impl Fn(FaceIdType) -> f32 + Send + Sync + 'static,
+        colormap: ColorMap,
+    ) -> Self {
+        Self {
+            scalar: Box::new(scalar),
+            colormap,
+        }
+    }
+
+    /// Compute the colors of the specified faces.
+    ///
+    /// If all values are equal, faces are given the color in the middle of the colormap.
+    pub fn colors(&self, faces: &[FaceIdType]) -> Vec<Color32> {
+        let values: Vec<f32> = faces.iter().map(|fid| (self.scalar)(*fid)).collect();
+        let (min, max) = values
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), v| {
+                (min.min(*v), max.max(*v))
+            });
+        values
+            .iter()
+            .map(|v| {
+                let t = if max > min {
+                    (v - min) / (max - min)
+                } else {
+                    0.5
+                };
+                self.colormap.color(t)
+            })
+            .collect()
+    }
+}
@@ -1,7 +1,7 @@
 use crate::resources::{
-    BetaRenderColor, BetaWidth, DartHeadMul, DartRenderColor, DartShrink, DartWidth,
-    EdgeRenderColor, EdgeWidth, FaceRenderColor, FaceShrink, VertexRenderColor, VertexWidth,
-    VolumeRenderColor, VolumeShrink,
+    BetaRenderColor, BetaWidth, BoundaryDartColor, DartHeadMul, DartRenderColor, DartShrink,
+    DartWidth, EdgeRenderColor, EdgeWidth, FaceRenderColor, FaceShrink, VertexRenderColor,
+    VertexWidth, VolumeRenderColor, VolumeShrink,
 };
 use bevy::prelude::*;
 use bevy_mod_picking::picking_core::PickingPluginsSettings;
@@ -57,6 +57,10 @@ fn draw_map_options(ui: &mut egui::Ui, world: &mut World) {
             opt_dragvalue!(ui, world, DartWidth);
             opt_dragvalue!(ui, world, DartHeadMul);
             ui.end_row();
+            // boundary darts
+            ui.label("Boundary Darts");
+            opt_rendercol!(ui, world, BoundaryDartColor);
+            ui.end_row();
             // betas
             ui.label("Beta Functions");
             opt_rendercol!(ui, world, BetaRenderColor);
@@ -5,8 +5,8 @@ pub mod update;
 
 use crate::capture::FocusedCapture;
 use crate::resources::{
-    DartHeadMul, DartRenderColor, DartShrink, DartWidth, EdgeRenderColor, EdgeWidth,
-    VertexRenderColor, VertexWidth,
+    BoundaryDartColor, DartHeadMul, DartRenderColor, DartShrink, DartWidth, EdgeRenderColor,
+    EdgeWidth, FaceColorBy, FaceRenderColor, VertexRenderColor, VertexWidth,
 };
 use bevy::prelude::*;
 use bevy_mod_outline::OutlinePlugin;
@@ -39,6 +39,7 @@ impl Plugin for ScenePlugin {
                 update::dart_render,
                 update::vertices_render,
                 update::edges_render,
+                update::faces_render,
             )
                 .run_if(
                     resource_changed::<FocusedCapture>
@@ -54,6 +55,13 @@ impl Plugin for ScenePlugin {
                     .and_then(not(resource_added::<DartRenderColor>)),
             ),
         );
+        app.add_systems(
+            Update,
+            update::boundary_darts_mat_handle.run_if(
+                resource_changed::<BoundaryDartColor>
+                    .and_then(not(resource_added::<BoundaryDartColor>)),
+            ),
+        );
         app.add_systems(
             Update,
             update::dart_heads_handle.run_if(
@@ -108,5 +116,17 @@ impl Plugin for ScenePlugin {
             update::edges_handle
                 .run_if(resource_changed::<EdgeWidth>.and_then(not(resource_added::<EdgeWidth>))),
         );
+        // face updates
+        app.add_systems(
+            Update,
+            (update::faces_render, update::faces_mat_handle).run_if(
+                resource_changed::<FaceRenderColor>
+                    .and_then(not(resource_added::<FaceRenderColor>)),
+            ),
+        );
+        app.add_systems(
+            Update,
+            update::faces_color_by.run_if(resource_exists_and_changed::<FaceColorBy>),
+        );
     }
 }
@@ -1,14 +1,17 @@
 use crate::capture::ecs_data::{
-    CaptureId, DartBody, DartHead, DartId, Edge, FaceId, FaceNormals, MapVertices, Vertex,
+    BoundaryDart, CaptureId, DartBody, DartHead, DartId, Edge, Face, FaceId, FaceNormals,
+    MapVertices, Vertex,
 };
 use crate::capture::FocusedCapture;
 use crate::options::resource::{
-    DartHeadHandle, DartHeadMul, DartMatHandle, DartRenderColor, DartShrink, DartWidth,
-    EdgeMatHandle, EdgeRenderColor, EdgeWidth, VertexHandle, VertexMatHandle, VertexRenderColor,
-    VertexWidth,
+    BoundaryDartColor, BoundaryDartMatHandle, DartHeadHandle, DartHeadMul, DartMatHandle,
+    DartRenderColor, DartShrink, DartWidth, EdgeMatHandle, EdgeRenderColor, EdgeWidth, FaceColorBy,
+    FaceMatHandle, FaceRenderColor, VertexHandle, VertexMatHandle, VertexRenderColor, VertexWidth,
 };
 use bevy::math::{Quat, Vec3};
 use bevy::prelude::*;
+use bevy::utils::HashMap;
+use honeycomb_core::prelude::FaceIdType;
 
 // --- darts
 
@@ -23,6 +26,29 @@ pub fn dart_mat_handle(
     *mat = Color::Srgba(Srgba::from_u8_array(render_color.1.to_array())).into();
 }
 
+#[allow(clippy::missing_panics_doc)]
+/// Boundary dart material update system.
+///
+/// Boundary darts use the regular dart material when their specific color is disabled.
+pub fn boundary_darts_mat_handle(
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut darts: Query<&mut Handle<StandardMaterial>, With<BoundaryDart>>,
+    handle: Res<BoundaryDartMatHandle>,
+    dart_handle: Res<DartMatHandle>,
+    render_color: Res<BoundaryDartColor>,
+) {
+    let mat = materials.get_mut(&handle.0).expect("unreachable");
+    *mat = Color::Srgba(Srgba::from_u8_array(render_color.1.to_array())).into();
+    let new_handle = if render_color.0 {
+        &handle.0
+    } else {
+        &dart_handle.0
+    };
+    darts.iter_mut().for_each(|mut mat_handle| {
+        *mat_handle = new_handle.clone();
+    });
+}
+
 /// Dart render color and status update system.
 pub fn dart_render(
     mut dart_comps: Query<(&CaptureId, &mut Visibility), With<DartId>>, // with dart_id == heads & bodies
@@ -222,3 +248,60 @@ pub fn edges_mat_handle(
     let mat = materials.get_mut(&handle.0).expect("unreachable");
     *mat = Color::Srgba(Srgba::from_u8_array(render_color.1.to_array())).into();
 }
+
+// --- faces
+
+/// Face render color and status update system.
+pub fn faces_render(
+    mut query: Query<(&CaptureId, &mut Visibility), With<Face>>,
+    focused_capture: Res<FocusedCapture>,
+    render_color: Res<FaceRenderColor>,
+) {
+    query.par_iter_mut().for_each(|(cap_id, mut visibility)| {
+        *visibility.as_mut() = if render_color.0 && (focused_capture.0 == *cap_id) {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        }
+    });
+}
+
+#[allow(clippy::missing_panics_doc)]
+/// Face material handle update system.
+pub fn faces_mat_handle(
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    handle: Res<FaceMatHandle>,
+    render_color: Res<FaceRenderColor>,
+) {
+    let mat = materials.get_mut(&handle.0).expect("unreachable");
+    *mat = Color::Srgba(Srgba::from_u8_array(render_color.1.to_array())).into();
+}
+
+/// Face color update system.
+///
+/// Each face is given its own material, colored according to the [`FaceColorBy`] resource.
+pub fn faces_color_by(
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut faces: Query<(&CaptureId, &FaceId, &mut Handle<StandardMaterial>), With<Face>>,
+    color_by: Res<FaceColorBy>,
+) {
+    // values are normalized per capture
+    let mut captures: HashMap<usize, Vec<FaceIdType>> = HashMap::new();
+    faces.iter().for_each(|(cap_id, face_id, _)| {
+        captures.entry(cap_id.0).or_default().push(face_id.0);
+    });
+    let colors: HashMap<(usize, FaceIdType), _> = captures
+        .into_iter()
+        .flat_map(|(cap_id, face_ids)| {
+            let colors = color_by.colors(&face_ids);
+            face_ids
+                .into_iter()
+                .zip(colors)
+                .map(move |(face_id, color)| ((cap_id, face_id), color))
+        })
+        .collect();
+    faces.iter_mut().for_each(|(cap_id, face_id, mut handle)| {
+        let color = colors[&(cap_id.0, face_id.0)];
+        *handle = materials.add(Color::Srgba(Srgba::from_u8_array(color.to_array())));
+    });
+}